@@ -267,18 +267,18 @@ fn run_annotate_with_backend(
 
     for request in &requests {
         match results.get(&request.key) {
-            Some(Some(annotation)) => {
+            Some(Some(annotation))
                 if apply_annotation(
                     &mut codec,
                     annotation_format,
                     &request.key,
                     &annotation.comment,
-                )? {
-                    changed += 1;
-                }
+                )? =>
+            {
+                changed += 1;
             }
             Some(None) => unmatched += 1,
-            None => {}
+            Some(Some(_)) | None => {}
         }
     }
 
@@ -1164,6 +1164,10 @@ fn translation_to_text(value: &Translation, fallback_key: &str) -> String {
             .next()
             .cloned()
             .unwrap_or_else(|| fallback_key.to_string()),
+        Translation::Select(select) => select
+            .fallback()
+            .or_else(|| select.cases.values().next().map(String::as_str))
+            .map_or_else(|| fallback_key.to_string(), str::to_string),
     }
 }
 
@@ -1,4 +1,6 @@
+use langcodec::{LengthLimit, LengthLimits, LengthUnit};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -15,6 +17,12 @@ pub struct CliConfig {
     pub translate: TranslateConfig,
     #[serde(default)]
     pub annotate: AnnotateConfig,
+    #[serde(default)]
+    pub glossary: GlossaryConfig,
+    #[serde(default)]
+    pub lint: LintConfig,
+    #[serde(default)]
+    pub length: LengthConfig,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -116,6 +124,98 @@ pub struct AnnotateConfig {
     pub concurrency: Option<usize>,
 }
 
+/// `[glossary]`: glossary files plus inline terms.
+///
+/// ```toml
+/// [glossary]
+/// path = "glossary.csv"
+/// do_not_translate = ["Acme"]
+///
+/// [glossary.terms]
+/// "Sign in" = { fr = "Se connecter", de = "Anmelden" }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GlossaryConfig {
+    /// CSV or TBX files, relative to the config file.
+    #[serde(default, deserialize_with = "deserialize_optional_string_or_vec")]
+    pub path: Option<Vec<String>>,
+    pub source_lang: Option<String>,
+    #[serde(default)]
+    pub do_not_translate: Vec<String>,
+    /// Required translations by source term, then language.
+    #[serde(default)]
+    pub terms: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl GlossaryConfig {
+    pub fn is_empty(&self) -> bool {
+        self.path.as_ref().is_none_or(Vec::is_empty)
+            && self.do_not_translate.is_empty()
+            && self.terms.is_empty()
+    }
+}
+
+/// `[lint]`: rule severities for `langcodec lint`.
+///
+/// ```toml
+/// [lint]
+/// fail_on = "warning"
+///
+/// [lint.rules]
+/// identical_to_source = "off"
+/// double_space = "error"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LintConfig {
+    pub source_lang: Option<String>,
+    /// Lowest severity that makes the command fail (default: `error`).
+    pub fail_on: Option<String>,
+    /// Severity (`off`, `info`, `warning`, `error`) by rule id.
+    #[serde(default)]
+    pub rules: BTreeMap<String, String>,
+}
+
+/// `[length]`: maximum lengths by key pattern for `lint` and `stats`.
+///
+/// ```toml
+/// [length]
+/// unit = "col"
+///
+/// [length.limits]
+/// "button.*" = 20
+/// "button.save" = 12
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LengthConfig {
+    /// `char` (graphemes, default) or `col` (terminal display width).
+    pub unit: Option<String>,
+    /// Maximum length by key pattern; `*` and `?` are wildcards.
+    #[serde(default)]
+    pub limits: BTreeMap<String, usize>,
+}
+
+impl LengthConfig {
+    /// Builds the pattern limits, trying more specific patterns (more literal
+    /// characters) first so `button.save` wins over `button.*`.
+    pub fn length_limits(&self) -> Result<LengthLimits, String> {
+        let unit = match self.unit.as_deref() {
+            Some(unit) => unit
+                .parse::<LengthUnit>()
+                .map_err(|e| format!("Invalid [length] unit: {}", e))?,
+            None => LengthUnit::default(),
+        };
+        let mut patterns: Vec<_> = self.limits.iter().collect();
+        patterns.sort_by_key(|(pattern, _)| {
+            std::cmp::Reverse(pattern.chars().filter(|c| !matches!(c, '*' | '?')).count())
+        });
+        let mut limits = LengthLimits::new();
+        for (pattern, max) in patterns {
+            limits.add_pattern(pattern.clone(), LengthLimit::new(*max, unit));
+        }
+        Ok(limits)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LoadedConfig {
     pub path: PathBuf,
@@ -299,6 +399,70 @@ mod tests {
     use super::*;
     use std::fs;
 
+    #[test]
+    fn cli_config_reads_glossary_section() {
+        let config: CliConfig = toml::from_str(
+            r#"
+[glossary]
+path = "terms.tbx"
+do_not_translate = ["Acme"]
+
+[glossary.terms]
+"Sign in" = { fr = "Se connecter" }
+"#,
+        )
+        .expect("parse config");
+
+        assert_eq!(config.glossary.path, Some(vec!["terms.tbx".to_string()]));
+        assert_eq!(config.glossary.do_not_translate, vec!["Acme".to_string()]);
+        assert_eq!(config.glossary.terms["Sign in"]["fr"], "Se connecter");
+        assert!(!config.glossary.is_empty());
+    }
+
+    #[test]
+    fn cli_config_reads_lint_section() {
+        let config: CliConfig = toml::from_str(
+            r#"
+[lint]
+fail_on = "warning"
+
+[lint.rules]
+double_space = "error"
+"#,
+        )
+        .expect("parse config");
+
+        assert_eq!(config.lint.fail_on.as_deref(), Some("warning"));
+        assert_eq!(config.lint.rules["double_space"], "error");
+        assert_eq!(config.lint.source_lang, None);
+    }
+
+    #[test]
+    fn cli_config_reads_length_section() {
+        let config: CliConfig = toml::from_str(
+            r#"
+[length]
+unit = "col"
+
+[length.limits]
+"button.*" = 20
+"button.save" = 12
+"#,
+        )
+        .expect("parse config");
+
+        let limits = config.length.length_limits().expect("limits");
+        assert_eq!(
+            limits.limit_for("button.save"),
+            Some(LengthLimit::new(12, LengthUnit::DisplayWidth))
+        );
+        assert_eq!(
+            limits.limit_for("button.cancel"),
+            Some(LengthLimit::new(20, LengthUnit::DisplayWidth))
+        );
+        assert_eq!(limits.limit_for("title"), None);
+    }
+
     #[test]
     fn cli_config_lists_provider_sections() {
         let config: CliConfig = toml::from_str(
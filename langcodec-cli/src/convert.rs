@@ -3,7 +3,10 @@ use crate::transformers::custom_format_to_resource;
 use crate::ui;
 use crate::validation::{self, validate_custom_format_file};
 
-use langcodec::{Codec, ReadOptions, convert_auto, formats::FormatType};
+use langcodec::{
+    Codec, ReadOptions, convert_auto,
+    formats::{FormatType, StringsEncoding},
+};
 use std::fs::File;
 use std::io::BufWriter;
 
@@ -16,9 +19,20 @@ pub struct ConvertOptions {
     pub output_lang: Option<String>,
     pub exclude_lang: Vec<String>,
     pub include_lang: Vec<String>,
+    pub encoding: Option<String>,
 }
 
-fn parse_standard_output_format(format: &str) -> Result<FormatType, String> {
+/// Parse a `--encoding` value for `.strings` output.
+pub fn parse_strings_encoding(input: &str) -> Result<StringsEncoding, String> {
+    input.parse::<StringsEncoding>().map_err(|_| {
+        format!(
+            "Invalid --encoding '{}'. Expected one of: utf8, utf16le, utf16be",
+            input
+        )
+    })
+}
+
+pub(crate) fn parse_standard_output_format(format: &str) -> Result<FormatType, String> {
     match format.to_lowercase().as_str() {
         "strings" => Ok(FormatType::Strings(None)),
         "android" | "androidstrings" => Ok(FormatType::AndroidStrings(None)),
@@ -26,8 +40,9 @@ fn parse_standard_output_format(format: &str) -> Result<FormatType, String> {
         "xliff" => Ok(FormatType::Xliff(None)),
         "csv" => Ok(FormatType::CSV),
         "tsv" => Ok(FormatType::TSV),
+        "xlsx" => Ok(FormatType::XLSX),
         _ => Err(format!(
-            "Unsupported output format: '{}'. Supported formats: strings, android, xcstrings, xliff, csv, tsv",
+            "Unsupported output format: '{}'. Supported formats: strings, android, xcstrings, xliff, csv, tsv, xlsx",
             format
         )),
     }
@@ -186,7 +201,7 @@ fn resolve_convert_output_format(
                 )
             }
         }
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV => {
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
             if let Some(language) = output_lang {
                 Err(format!(
                     "--output-lang '{}' is only supported for .strings, strings.xml, or .xliff output",
@@ -199,12 +214,78 @@ fn resolve_convert_output_format(
     }
 }
 
+/// Converts to `.strings` output written with an explicit encoding.
+fn convert_with_strings_encoding(
+    input: &str,
+    output: &str,
+    options: &ConvertOptions,
+    strict: bool,
+    encoding: StringsEncoding,
+) -> Result<(), String> {
+    let output_format = resolve_convert_output_format(
+        output,
+        options.output_format.as_ref(),
+        options.output_lang.as_ref(),
+    )?;
+    if !matches!(output_format, FormatType::Strings(_)) {
+        return Err("--encoding is only supported for .strings output".to_string());
+    }
+    let mut resources =
+        read_resources_from_any_input(input, options.input_format.as_ref(), strict)?;
+    for resource in &mut resources {
+        encoding.apply_to(&mut resource.metadata);
+    }
+    convert_resources_to_format(resources, output, output_format)
+        .map_err(|e| format!("Error converting to output format: {}", e))
+}
+
 pub fn run_unified_convert_command(
     input: String,
     output: String,
     options: ConvertOptions,
     strict: bool,
 ) {
+    if let Some(encoding) = options.encoding.as_ref() {
+        let encoding = match parse_strings_encoding(encoding) {
+            Ok(encoding) => encoding,
+            Err(e) => {
+                println!(
+                    "{}",
+                    ui::status_line_stdout(ui::Tone::Error, "Conversion failed")
+                );
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        };
+        println!(
+            "{}",
+            ui::status_line_stdout(
+                ui::Tone::Info,
+                &format!("Converting to .strings with {} encoding...", encoding),
+            )
+        );
+        match convert_with_strings_encoding(&input, &output, &options, strict, encoding) {
+            Ok(()) => {
+                println!(
+                    "{}",
+                    ui::status_line_stdout(
+                        ui::Tone::Success,
+                        "Successfully converted with explicit encoding",
+                    )
+                );
+                return;
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    ui::status_line_stdout(ui::Tone::Error, "Conversion failed")
+                );
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     let wants_xliff = wants_xliff_output(&output, options.output_format.as_ref());
     if wants_xliff {
         println!(
@@ -795,6 +876,7 @@ fn print_conversion_error(input: &str, output: &str) {
     eprintln!("- .xliff (Apple/Xcode XLIFF 1.2 files)");
     eprintln!("- .csv (CSV files)");
     eprintln!("- .tsv (TSV files)");
+    eprintln!("- .xlsx (Excel workbooks)");
     eprintln!("- .langcodec (Resource JSON array)");
     eprintln!("- .json (JSON key-value pairs or Resource format)");
     eprintln!("- .yaml/.yml (YAML language map format)");
@@ -806,6 +888,7 @@ fn print_conversion_error(input: &str, output: &str) {
     eprintln!("- .xliff (Apple/Xcode XLIFF 1.2 files)");
     eprintln!("- .csv (CSV files)");
     eprintln!("- .tsv (TSV files)");
+    eprintln!("- .xlsx (Excel workbooks)");
     eprintln!("- .langcodec (Resource JSON array)");
     eprintln!();
     eprintln!(
@@ -849,9 +932,10 @@ fn try_explicit_format_conversion(
         "xliff" => langcodec::formats::FormatType::Xliff(None),
         "csv" => langcodec::formats::FormatType::CSV,
         "tsv" => langcodec::formats::FormatType::TSV,
+        "xlsx" => langcodec::formats::FormatType::XLSX,
         _ => {
             return Err(format!(
-                "Unsupported input format: '{}'. Supported formats: strings, android, xcstrings, xliff, csv, tsv",
+                "Unsupported input format: '{}'. Supported formats: strings, android, xcstrings, xliff, csv, tsv, xlsx",
                 input_format
             ));
         }
@@ -932,6 +1016,7 @@ pub fn read_resources_from_any_input(
                 "xliff" => Some(langcodec::formats::FormatType::Xliff(None)),
                 "csv" => Some(langcodec::formats::FormatType::CSV),
                 "tsv" => Some(langcodec::formats::FormatType::TSV),
+                "xlsx" => Some(langcodec::formats::FormatType::XLSX),
                 _ => None,
             };
 
@@ -958,6 +1043,7 @@ pub fn read_resources_from_any_input(
             || input.ends_with(".xliff")
             || input.ends_with(".csv")
             || input.ends_with(".tsv")
+            || input.ends_with(".xlsx")
         {
             let mut codec = Codec::new();
             codec
@@ -980,7 +1066,7 @@ pub fn read_resources_from_any_input(
         }
 
         return Err(format!(
-            "Unsupported input format or file extension: '{}'. Supported formats: .strings, .xml, .xcstrings, .xliff, .csv, .tsv, .json, .yaml, .yml, .langcodec",
+            "Unsupported input format or file extension: '{}'. Supported formats: .strings, .xml, .xcstrings, .xliff, .csv, .tsv, .xlsx, .json, .yaml, .yml, .langcodec",
             input
         ));
     }
@@ -997,6 +1083,7 @@ pub fn read_resources_from_any_input(
             "xliff" => Some(langcodec::formats::FormatType::Xliff(None)),
             "csv" => Some(langcodec::formats::FormatType::CSV),
             "tsv" => Some(langcodec::formats::FormatType::TSV),
+            "xlsx" => Some(langcodec::formats::FormatType::XLSX),
             _ => None,
         };
         if let Some(std_fmt) = maybe_std {
@@ -1075,6 +1162,8 @@ pub fn read_resources_from_any_input(
                     Some(langcodec::formats::FormatType::CSV)
                 } else if input.ends_with(".tsv") {
                     Some(langcodec::formats::FormatType::TSV)
+                } else if input.ends_with(".xlsx") {
+                    Some(langcodec::formats::FormatType::XLSX)
                 } else {
                     None
                 };
@@ -1114,7 +1203,7 @@ pub fn read_resources_from_any_input(
     }
 
     Err(format!(
-        "Unsupported input format or file extension: '{}'. Supported formats: .strings, .xml, .xcstrings, .xliff, .csv, .tsv, .json, .yaml, .yml, .langcodec",
+        "Unsupported input format or file extension: '{}'. Supported formats: .strings, .xml, .xcstrings, .xliff, .csv, .tsv, .xlsx, .json, .yaml, .yml, .langcodec",
         input
     ))
 }
@@ -1,7 +1,7 @@
 use crate::formats::parse_custom_format;
 use crate::transformers::custom_format_to_resource;
 
-use langcodec::{Codec, Plural, ReadOptions, Translation};
+use langcodec::{Codec, Plural, ReadOptions, Select, Translation};
 use std::fs::File;
 use std::io::{self, Write};
 
@@ -77,6 +77,16 @@ pub fn run_debug_command(
                         .into_iter()
                         .map(|(k, v)| (k, v.replace("\\n", "\n")))
                         .collect(),
+                    kind: p.kind,
+                }),
+                Translation::Select(s) => Translation::Select(Select {
+                    selector: s.selector.clone(),
+                    cases: s
+                        .cases
+                        .clone()
+                        .into_iter()
+                        .map(|(k, v)| (k, v.replace("\\n", "\n")))
+                        .collect(),
                 }),
             };
         }
@@ -24,6 +24,13 @@ fn translation_as_text(value: &Translation) -> String {
             }
             parts.join(" | ")
         }
+        Translation::Select(s) => {
+            let mut parts = Vec::new();
+            for (case, text) in &s.cases {
+                parts.push(format!("{}={}", case, text));
+            }
+            parts.join(" | ")
+        }
     }
 }
 
@@ -80,12 +80,17 @@ fn write_back(
 
     match fmt {
         FormatType::Strings(_) | FormatType::AndroidStrings(_) => {
-            // Single-language per file formats: write only one resource
+            // Single-language per file formats: write only one resource. In-place
+            // edits splice changes into the existing file to keep its layout.
             let res = pick_single_resource(codec, lang)?;
-            langcodec::Codec::write_resource_to_file(res, out)
-                .map_err(|e| format!("Error writing output: {}", e))
+            if out == input_path {
+                langcodec::Codec::update_resource_file(res, out)
+            } else {
+                langcodec::Codec::write_resource_to_file(res, out)
+            }
+            .map_err(|e| format!("Error writing output: {}", e))
         }
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV => {
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
             // Multi-language formats: write all resources
             let resources = codec.resources.clone();
             langcodec::converter::convert_resources_to_format(resources, out, fmt)
@@ -252,7 +257,7 @@ fn apply_set_to_file(
             .unwrap_or("");
         if matches!(ext, "json" | "yaml" | "yml" | "langcodec") {
             return Err(
-                "Edit currently supports standard formats (.strings, .xml, .xcstrings, .csv, .tsv)"
+                "Edit currently supports standard formats (.strings, .xml, .xcstrings, .csv, .tsv, .xlsx)"
                     .to_string(),
             );
         }
@@ -360,6 +365,7 @@ fn apply_set_to_file(
                     Translation::Empty => String::new(),
                     Translation::Singular(s) => s.clone(),
                     Translation::Plural(p) => p.id.clone(),
+                    Translation::Select(s) => s.to_icu(),
                 })
                 .unwrap_or_default();
             if dry_run {
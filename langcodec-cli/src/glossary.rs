@@ -0,0 +1,176 @@
+use crate::config::{LoadedConfig, load_config, resolve_config_relative_path};
+use crate::convert::read_resources_from_any_input;
+use crate::ui;
+use crate::validation::validate_file_path;
+use langcodec::{Glossary, GlossaryTerm, Resource, collect_glossary_issues};
+use serde_json::json;
+
+#[derive(Debug, Clone)]
+pub struct GlossaryCheckOptions {
+    pub input: String,
+    pub glossary: Vec<String>,
+    pub config: Option<String>,
+    pub source_lang: Option<String>,
+    pub lang: Option<String>,
+    pub json: bool,
+    pub strict: bool,
+}
+
+/// Combines the `[glossary]` config table with glossary files passed on the
+/// command line. Returns `None` when neither defines any term source.
+pub fn load_glossary(
+    files: &[String],
+    config: Option<&LoadedConfig>,
+    source_lang: Option<&str>,
+) -> Result<Option<Glossary>, String> {
+    let section = config.map(|cfg| &cfg.data.glossary);
+    if files.is_empty() && section.is_none_or(|section| section.is_empty()) {
+        return Ok(None);
+    }
+    let source_lang = source_lang.or_else(|| section.and_then(|s| s.source_lang.as_deref()));
+
+    let mut paths: Vec<String> = Vec::new();
+    if let Some(section) = section {
+        let config_dir = config.and_then(LoadedConfig::config_dir);
+        for path in section.path.iter().flatten() {
+            paths.push(resolve_config_relative_path(config_dir, path));
+        }
+    }
+    paths.extend(files.iter().cloned());
+
+    let mut glossary = Glossary::new();
+    for path in &paths {
+        validate_file_path(path)?;
+        let loaded = Glossary::read_file(path, source_lang)
+            .map_err(|e| format!("Failed to read glossary '{}': {}", path, e))?;
+        glossary.extend(loaded);
+    }
+    if let Some(section) = section {
+        for term in &section.do_not_translate {
+            glossary.add(GlossaryTerm::protected(term.clone()));
+        }
+        for (source, translations) in &section.terms {
+            let mut term = GlossaryTerm::new(source.clone());
+            term.translations
+                .extend(translations.iter().map(|(l, t)| (l.clone(), t.clone())));
+            glossary.add(term);
+        }
+    }
+    Ok(Some(glossary))
+}
+
+/// Builds prompt lines for the glossary terms found in `source`.
+pub fn glossary_prompt_lines(glossary: &Glossary, source: &str, language: &str) -> Vec<String> {
+    glossary
+        .terms_in(source)
+        .into_iter()
+        .filter_map(|term| {
+            let line = if term.do_not_translate {
+                format!("\"{}\": keep unchanged, do not translate", term.source)
+            } else {
+                format!(
+                    "\"{}\" => \"{}\"",
+                    term.source,
+                    term.translation_for(language)?
+                )
+            };
+            Some(match &term.note {
+                Some(note) => format!("{} ({})", line, note),
+                None => line,
+            })
+        })
+        .collect()
+}
+
+fn resolve_source_language(
+    resources: &[Resource],
+    requested: Option<&String>,
+    configured: Option<&str>,
+) -> Result<String, String> {
+    if let Some(language) = requested {
+        return Ok(language.clone());
+    }
+    if let Some(language) = configured {
+        return Ok(language.to_string());
+    }
+    resources
+        .iter()
+        .find_map(|res| res.metadata.custom.get("source_language").cloned())
+        .ok_or_else(|| {
+            "Could not infer the source language from the input. Pass --source-lang.".to_string()
+        })
+}
+
+/// Checks translations in `opts.input` against the glossary; fails when any
+/// issue is found.
+pub fn run_glossary_command(opts: GlossaryCheckOptions) -> Result<(), String> {
+    let config = load_config(opts.config.as_deref())?;
+    let configured_source = config
+        .as_ref()
+        .and_then(|cfg| cfg.data.glossary.source_lang.clone());
+    let glossary = load_glossary(&opts.glossary, config.as_ref(), opts.source_lang.as_deref())?
+        .ok_or_else(|| {
+            "No glossary configured. Pass --glossary or add a [glossary] table to langcodec.toml."
+                .to_string()
+        })?;
+
+    validate_file_path(&opts.input)?;
+    let resources = read_resources_from_any_input(&opts.input, None, opts.strict)?;
+    let source_language = resolve_source_language(
+        &resources,
+        opts.source_lang.as_ref(),
+        configured_source.as_deref(),
+    )?;
+    let source_language = resources
+        .iter()
+        .map(|res| res.metadata.language.clone())
+        .find(|lang| lang.eq_ignore_ascii_case(&source_language))
+        .ok_or_else(|| {
+            format!(
+                "Source language '{}' not found in {}",
+                source_language, opts.input
+            )
+        })?;
+
+    let mut issues = collect_glossary_issues(&resources, &glossary, &source_language);
+    if let Some(lang) = &opts.lang {
+        issues.retain(|issue| issue.language.eq_ignore_ascii_case(lang));
+    }
+
+    if opts.json {
+        let body = json!({
+            "input": opts.input,
+            "source_language": source_language,
+            "terms": glossary.len(),
+            "issues": issues,
+        });
+        let rendered = serde_json::to_string_pretty(&body)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+        println!("{}", rendered);
+    } else {
+        for issue in &issues {
+            println!(
+                "{}",
+                ui::status_line_stdout(ui::Tone::Warning, &issue.to_string())
+            );
+        }
+        if issues.is_empty() {
+            println!(
+                "{}",
+                ui::status_line_stdout(
+                    ui::Tone::Success,
+                    &format!(
+                        "All translations follow {} glossary term(s)",
+                        glossary.len()
+                    ),
+                )
+            );
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} glossary issue(s) found", issues.len()))
+    }
+}
@@ -3,7 +3,9 @@
 pub mod ai;
 pub mod annotate;
 pub mod config;
+pub mod convert;
 pub mod formats;
+pub mod glossary;
 pub mod merge;
 pub mod path_glob;
 pub mod tolgee;
@@ -0,0 +1,313 @@
+use crate::config::load_config;
+use crate::convert::read_resources_from_any_input;
+use crate::path_glob;
+use crate::ui;
+use crate::validation::validate_file_path;
+use langcodec::{
+    Codec, Diagnostic, LintFinding, LintReport, LintSeverity, Linter, ReadOptions, ReportFormat,
+    Resource, entry_provenance, report::locate_key, to_github_annotations, to_junit, to_sarif,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct LintCliOptions {
+    pub inputs: Vec<String>,
+    pub config: Option<String>,
+    pub source_lang: Option<String>,
+    pub lang: Option<String>,
+    pub rules: Vec<String>,
+    pub fail_on: Option<String>,
+    pub list_rules: bool,
+    pub report_format: ReportFormat,
+    pub strict: bool,
+}
+
+/// A finding together with the file, line and column holding the translation.
+#[derive(Debug, Clone, Serialize)]
+struct FileFinding<'a> {
+    file: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(flatten)]
+    finding: &'a LintFinding,
+}
+
+impl FileFinding<'_> {
+    fn to_diagnostic(&self) -> Diagnostic {
+        Diagnostic::from(self.finding)
+            .with_location(self.file, self.line)
+            .with_column(self.column)
+    }
+}
+
+fn parse_severity(value: &str, context: &str) -> Result<LintSeverity, String> {
+    value
+        .parse::<LintSeverity>()
+        .map_err(|e| format!("{} in {}", e, context))
+}
+
+/// Builds the linter from `[lint.rules]` and `--rule id=severity` overrides.
+fn build_linter(
+    configured: &std::collections::BTreeMap<String, String>,
+    overrides: &[String],
+) -> Result<Linter, String> {
+    let mut linter = Linter::new();
+    for (rule, severity) in configured {
+        let severity = parse_severity(severity, "[lint.rules]")?;
+        linter
+            .set_severity(rule, severity)
+            .map_err(|e| e.to_string())?;
+    }
+    for item in overrides {
+        let (rule, severity) = item
+            .split_once('=')
+            .ok_or_else(|| format!("Expected --rule <id>=<severity>, got '{}'", item))?;
+        let severity = parse_severity(severity, "--rule")?;
+        linter
+            .set_severity(rule.trim(), severity)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(linter)
+}
+
+fn print_rules(linter: &Linter) {
+    for rule in linter.rules() {
+        let severity = linter.severity(rule.id()).unwrap_or(LintSeverity::Off);
+        println!("{:<22} {:<8} {}", rule.id(), severity, rule.description());
+    }
+}
+
+fn resolve_source_language(
+    resources: &[Resource],
+    requested: Option<&str>,
+) -> Result<String, String> {
+    let wanted = requested
+        .map(str::to_string)
+        .or_else(|| {
+            resources
+                .iter()
+                .find_map(|res| res.metadata.custom.get("source_language").cloned())
+        })
+        .ok_or_else(|| {
+            "Could not infer the source language from the inputs. Pass --source-lang.".to_string()
+        })?;
+    resources
+        .iter()
+        .map(|res| res.metadata.language.clone())
+        .find(|lang| lang.eq_ignore_ascii_case(&wanted))
+        .ok_or_else(|| format!("Source language '{}' not found in the inputs", wanted))
+}
+
+/// The input file whose resource holds the finding's translation.
+fn file_for<'a>(sources: &'a [(String, Resource)], finding: &LintFinding) -> &'a str {
+    sources
+        .iter()
+        .find(|(_, res)| {
+            res.metadata.language == finding.language && res.find_entry(&finding.key).is_some()
+        })
+        .map(|(path, _)| path.as_str())
+        .unwrap_or_default()
+}
+
+fn tone_for(severity: LintSeverity) -> ui::Tone {
+    match severity {
+        LintSeverity::Error => ui::Tone::Error,
+        LintSeverity::Warning => ui::Tone::Warning,
+        LintSeverity::Info | LintSeverity::Off => ui::Tone::Info,
+    }
+}
+
+/// Finds where entries are defined, parsing each file at most once.
+#[derive(Default)]
+struct Locator {
+    parsed: HashMap<String, Vec<Resource>>,
+    texts: HashMap<String, Option<String>>,
+}
+
+impl Locator {
+    /// Line and column of `key` in `file`, from the parser's entry positions
+    /// when the format records them, otherwise the line found by a text search.
+    fn locate(&mut self, file: &str, language: &str, key: &str) -> (Option<usize>, Option<usize>) {
+        let resources = self.parsed.entry(file.to_string()).or_insert_with(|| {
+            let mut codec = Codec::new();
+            codec
+                .read_file_by_extension_with_options(
+                    file,
+                    &ReadOptions::new().with_provenance(true),
+                )
+                .map(|()| codec.resources)
+                .unwrap_or_default()
+        });
+        let find = |same_language: bool| {
+            resources
+                .iter()
+                .filter(|res| !same_language || res.metadata.language == language)
+                .filter_map(|res| res.find_entry(key))
+                .find_map(|entry| entry_provenance(entry)?.position())
+        };
+        if let Some(position) = find(true).or_else(|| find(false)) {
+            return (Some(position.line), Some(position.column));
+        }
+        let text = self
+            .texts
+            .entry(file.to_string())
+            .or_insert_with(|| std::fs::read_to_string(file).ok());
+        (text.as_deref().and_then(|text| locate_key(text, key)), None)
+    }
+}
+
+fn print_text(report: &LintReport, findings: &[FileFinding<'_>]) {
+    for item in findings {
+        let location = match (item.line, item.column) {
+            (Some(line), Some(column)) => format!("{}:{}:{}", item.file, line, column),
+            (Some(line), None) => format!("{}:{}", item.file, line),
+            _ => item.file.to_string(),
+        };
+        println!(
+            "{}",
+            ui::status_line_stdout(
+                tone_for(item.finding.severity),
+                &format!("{}: {}", location, item.finding),
+            )
+        );
+    }
+    let count = |severity| {
+        findings
+            .iter()
+            .filter(|item| item.finding.severity == severity)
+            .count()
+    };
+    let tone = if findings.is_empty() {
+        ui::Tone::Success
+    } else {
+        ui::Tone::Info
+    };
+    println!(
+        "{}",
+        ui::status_line_stdout(
+            tone,
+            &format!(
+                "Checked {} translations: {} error(s), {} warning(s), {} info",
+                report.checked,
+                count(LintSeverity::Error),
+                count(LintSeverity::Warning),
+                count(LintSeverity::Info)
+            ),
+        )
+    );
+}
+
+/// Runs translation quality rules over the inputs; fails when a finding
+/// reaches the `fail_on` severity.
+pub fn run_lint_command(opts: LintCliOptions) -> Result<(), String> {
+    let config = load_config(opts.config.as_deref())?;
+    let lint_config = config
+        .as_ref()
+        .map(|cfg| cfg.data.lint.clone())
+        .unwrap_or_default();
+    let linter = build_linter(&lint_config.rules, &opts.rules)?;
+    if opts.list_rules {
+        print_rules(&linter);
+        return Ok(());
+    }
+    let fail_on = match opts.fail_on.as_deref().or(lint_config.fail_on.as_deref()) {
+        Some(value) => parse_severity(value, "fail_on")?,
+        None => LintSeverity::Error,
+    };
+
+    let inputs = path_glob::expand_input_globs(&opts.inputs)
+        .map_err(|e| format!("Failed to expand input patterns: {}", e))?;
+    if inputs.is_empty() {
+        return Err("No input files matched the provided patterns".to_string());
+    }
+    let mut sources: Vec<(String, Resource)> = Vec::new();
+    for input in &inputs {
+        validate_file_path(input)?;
+        for resource in read_resources_from_any_input(input, None, opts.strict)? {
+            sources.push((input.clone(), resource));
+        }
+    }
+    let mut resources: Vec<Resource> = sources.iter().map(|(_, res)| res.clone()).collect();
+    if let Some(cfg) = &config {
+        cfg.data.length.length_limits()?.apply(&mut resources);
+    }
+    let source_language = resolve_source_language(
+        &resources,
+        opts.source_lang
+            .as_deref()
+            .or(lint_config.source_lang.as_deref()),
+    )?;
+
+    let mut report = linter.lint(&resources, &source_language);
+    if let Some(lang) = &opts.lang {
+        report
+            .findings
+            .retain(|finding| finding.language.eq_ignore_ascii_case(lang));
+    }
+    let mut locator = Locator::default();
+    let findings: Vec<FileFinding<'_>> = report
+        .findings
+        .iter()
+        .map(|finding| {
+            let file = file_for(&sources, finding);
+            let (line, column) = locator.locate(file, &finding.language, &finding.key);
+            FileFinding {
+                file,
+                line,
+                column,
+                finding,
+            }
+        })
+        .collect();
+
+    match opts.report_format {
+        ReportFormat::Text => print_text(&report, &findings),
+        ReportFormat::Json => {
+            let body = serde_json::json!({
+                "source_language": report.source_language,
+                "checked": report.checked,
+                "findings": findings,
+            });
+            let text = serde_json::to_string_pretty(&body)
+                .map_err(|e| format!("Failed to serialize report JSON: {}", e))?;
+            println!("{}", text);
+        }
+        ReportFormat::Sarif => {
+            let rules: Vec<(&str, &str)> = linter
+                .rules()
+                .map(|rule| (rule.id(), rule.description()))
+                .collect();
+            let diagnostics: Vec<Diagnostic> =
+                findings.iter().map(FileFinding::to_diagnostic).collect();
+            let text = serde_json::to_string_pretty(&to_sarif(&diagnostics, &rules))
+                .map_err(|e| format!("Failed to serialize SARIF: {}", e))?;
+            println!("{}", text);
+        }
+        ReportFormat::Junit => {
+            let diagnostics: Vec<Diagnostic> =
+                findings.iter().map(FileFinding::to_diagnostic).collect();
+            print!("{}", to_junit(&diagnostics));
+        }
+        ReportFormat::Github => {
+            let diagnostics: Vec<Diagnostic> =
+                findings.iter().map(FileFinding::to_diagnostic).collect();
+            print!("{}", to_github_annotations(&diagnostics));
+        }
+    }
+
+    // `fail_on = "off"` reports findings without failing.
+    let failing = findings
+        .iter()
+        .filter(|item| item.finding.severity >= fail_on)
+        .count();
+    if fail_on != LintSeverity::Off && failing > 0 {
+        return Err(format!(
+            "{} lint finding(s) at or above '{}'",
+            failing, fail_on
+        ));
+    }
+    Ok(())
+}
@@ -6,11 +6,18 @@ mod debug;
 mod diff;
 mod edit;
 mod formats;
+mod glossary;
+mod lint;
 mod merge;
 mod normalize;
 mod path_glob;
+mod preview;
+mod pseudo;
+mod refresh_status;
+mod rename;
 mod stats;
 mod sync;
+mod tm;
 mod tolgee;
 mod transformers;
 mod translate;
@@ -24,9 +31,16 @@ use crate::convert::{ConvertOptions, run_unified_convert_command, try_custom_for
 use crate::debug::run_debug_command;
 use crate::diff::{DiffOptions, run_diff_command};
 use crate::edit::{EditSetOptions, run_edit_set_command};
+use crate::glossary::{GlossaryCheckOptions, run_glossary_command};
+use crate::lint::{LintCliOptions, run_lint_command};
 use crate::merge::{ConflictStrategy, run_merge_command};
 use crate::normalize::{NormalizeCliOptions, run_normalize_command};
+use crate::preview::{PreviewOptions, run_preview_command};
+use crate::pseudo::{PseudoCliOptions, run_pseudo_command};
+use crate::refresh_status::{RefreshStatusCliOptions, run_refresh_status_command};
+use crate::rename::{RenameCliOptions, run_rename_command};
 use crate::sync::{SyncOptions, run_sync_command};
+use crate::tm::{TmBuildOptions, TmFillCliOptions, run_tm_build_command, run_tm_fill_command};
 use crate::tolgee::{
     TolgeePullOptions, TolgeePushOptions, run_tolgee_pull_command, run_tolgee_push_command,
 };
@@ -87,6 +101,9 @@ enum Commands {
         /// Language codes to include in output (e.g., "en", "fr"). Can be specified multiple times or as comma-separated values (e.g., "--include-lang en,fr,zh-hans"). If specified, only these languages will be included. Only affects .langcodec output format.
         #[arg(long, value_name = "LANG", value_delimiter = ',')]
         include_lang: Vec<String>,
+        /// Text encoding for `.strings` output: utf8|utf16le|utf16be (default: keep the input file's encoding, or UTF-8)
+        #[arg(long, value_name = "ENCODING")]
+        encoding: Option<String>,
     },
 
     /// Edit localization files in-place.
@@ -206,7 +223,7 @@ enum Commands {
     /// input formats and converting to the output format based on the file extension.
     /// Supports merging files with the same language and provides conflict resolution strategies.
     Merge {
-        /// The input files to merge (supports multiple formats: .strings, .xml, .csv, .tsv, .xcstrings, .json, .yaml)
+        /// The input files to merge (supports multiple formats: .strings, .xml, .csv, .tsv, .xlsx, .xcstrings, .json, .yaml)
         #[arg(short, long, num_args = 1.., help = "Input files. Supports glob patterns. Quote patterns to avoid slow shell-side expansion (e.g., '/path/**/*/strings.xml').")]
         inputs: Vec<String>,
         /// The output file path (format automatically determined from extension)
@@ -244,10 +261,42 @@ enum Commands {
         #[arg(long, default_value_t = false)]
         check: bool,
 
+        /// With --dry-run or --check, print the report as JSON instead of per-key text
+        #[arg(long, default_value_t = false)]
+        json: bool,
+
         /// Disable placeholder normalization
         #[arg(long, default_value_t = false)]
         no_placeholders: bool,
 
+        /// Wrap placeholders in RTL translations (ar, he, fa, ur, ...) in FSI/PDI bidi isolates
+        #[arg(long, default_value_t = false)]
+        isolate_bidi: bool,
+
+        /// Apply Unicode normalization to values: nfc|nfkc
+        #[arg(long = "unicode", value_name = "FORM")]
+        unicode_form: Option<String>,
+
+        /// Use no-break spaces before ; : ! ? and inside « » in French
+        #[arg(long, default_value_t = false)]
+        french_spacing: bool,
+
+        /// Replace paired straight double quotes with the language's quotation marks
+        #[arg(long, default_value_t = false)]
+        smart_quotes: bool,
+
+        /// Replace `...` with `…`
+        #[arg(long, default_value_t = false)]
+        ellipsis: bool,
+
+        /// Remove spaces and tabs at the end of every line of a value
+        #[arg(long, default_value_t = false)]
+        trim_trailing_whitespace: bool,
+
+        /// Convert CRLF and CR line breaks in values to LF
+        #[arg(long, default_value_t = false)]
+        line_endings: bool,
+
         /// Key renaming style: none|snake|kebab|camel
         #[arg(long, default_value = "none")]
         key_style: String,
@@ -255,6 +304,40 @@ enum Commands {
         /// Continue processing remaining files when a file fails
         #[arg(long, default_value_t = false)]
         continue_on_error: bool,
+
+        /// Re-encode `.strings` files: utf8|utf16le|utf16be (default: keep each file's encoding)
+        #[arg(long, value_name = "ENCODING")]
+        encoding: Option<String>,
+    },
+
+    /// Rename keys in every language of every input.
+    ///
+    /// Keys are renamed from a mapping CSV (`from`,`to` columns) or with a regular
+    /// expression. Nothing is written when a new key would collide with another key.
+    Rename {
+        /// The input files (supports glob patterns)
+        #[arg(short, long, required = true, num_args = 1..)]
+        inputs: Vec<String>,
+
+        /// CSV file with `from` and `to` columns
+        #[arg(short, long)]
+        mapping: Option<String>,
+
+        /// Regular expression matched against every key
+        #[arg(long)]
+        pattern: Option<String>,
+
+        /// Replacement for --pattern matches (`$1` refers to capture groups)
+        #[arg(long)]
+        replacement: Option<String>,
+
+        /// Report renames without writing files
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Print the report as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
     },
 
     /// Show translation coverage and per-status counts.
@@ -268,6 +351,182 @@ enum Commands {
         /// Output JSON instead of human-readable text
         #[arg(long)]
         json: bool,
+        /// Config file with `[length]` limits (default: discovered langcodec.toml)
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Render an entry with sample arguments, as the app would show it.
+    ///
+    /// Plural forms are picked with CLDR rules for each language; missing
+    /// translations fall back to parent locales and the source language.
+    Preview {
+        /// Key of the entry to render
+        key: String,
+
+        /// Input files. Supports glob patterns.
+        #[arg(short, long, required = true, num_args = 1.., help = "Input files. Supports glob patterns. Quote patterns to avoid slow shell-side expansion (e.g., '/path/**/*/strings.xml').")]
+        inputs: Vec<String>,
+
+        /// Render only this language (default: every loaded language)
+        #[arg(short, long)]
+        lang: Option<String>,
+
+        /// Argument value, repeatable (e.g. --arg 5 --arg Anna); use NAME=VALUE for named arguments
+        #[arg(long = "arg", value_name = "VALUE", allow_hyphen_values = true)]
+        args: Vec<String>,
+
+        /// Output JSON instead of human-readable text
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Generate a pseudo-locale from the source language to catch truncation and hard-coded strings.
+    ///
+    /// `en-XA` gets accented, padded and bracketed text; `ar-XB` gets
+    /// right-to-left wrapped text. Placeholders, tags and escapes are kept.
+    Pseudo {
+        /// The input file to read the source language from
+        #[arg(short, long)]
+        input: String,
+
+        /// The output file; any supported format
+        #[arg(short, long)]
+        output: String,
+
+        /// Pseudo-locale to generate (e.g. en-XA for accents, ar-XB for bidi)
+        #[arg(long, default_value = "en-XA")]
+        locale: String,
+
+        /// Source language (default: the input's source language, or its only language)
+        #[arg(long)]
+        source_lang: Option<String>,
+
+        /// Optional output format hint (e.g., "xcstrings", "strings", "android", "csv")
+        #[arg(long)]
+        output_format: Option<String>,
+
+        /// Extra length as a percentage of the text, e.g. 30 to 50 (default: 40 for accented, 0 for bidi)
+        #[arg(long, value_name = "PERCENT")]
+        expansion: Option<u32>,
+
+        /// Keep letters unaccented
+        #[arg(long, default_value_t = false)]
+        no_accents: bool,
+
+        /// Do not wrap values in [ ] markers
+        #[arg(long, default_value_t = false)]
+        no_brackets: bool,
+
+        /// Wrap words in right-to-left override marks (default for -XB locales)
+        #[arg(long, default_value_t = false)]
+        rtl: bool,
+    },
+
+    /// Mark translations stale when their source text changed since they were translated.
+    ///
+    /// Source fingerprints are kept in a JSON state file, so this works for formats
+    /// without per-entry metadata such as Android `strings.xml` and CSV.
+    RefreshStatus {
+        /// The input files; the source language and translations may be in different files (supports glob patterns)
+        #[arg(short, long, required = true, num_args = 1..)]
+        inputs: Vec<String>,
+
+        /// JSON file storing source fingerprints between runs (created if missing)
+        #[arg(long)]
+        state: String,
+
+        /// Source language (default: the inputs' source language)
+        #[arg(long)]
+        source_lang: Option<String>,
+
+        /// Only check this target language
+        #[arg(short, long)]
+        lang: Option<String>,
+
+        /// Record the current source for all non-stale translations instead of marking them stale
+        #[arg(long, default_value_t = false)]
+        accept: bool,
+
+        /// Report changes without writing files or the state file
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Print the report as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Check translations against a glossary of required and do-not-translate terms.
+    ///
+    /// Terms come from CSV or TBX files and the `[glossary]` table in `langcodec.toml`.
+    Glossary {
+        /// The input file to check
+        #[arg(short, long)]
+        input: String,
+
+        /// Glossary files (.csv or .tbx); combined with the configured glossary
+        #[arg(short, long, num_args = 1..)]
+        glossary: Vec<String>,
+
+        /// Optional langcodec.toml path
+        #[arg(long)]
+        config: Option<String>,
+
+        /// Source language (default: the glossary config or the input's source language)
+        #[arg(long)]
+        source_lang: Option<String>,
+
+        /// Only check this target language
+        #[arg(short, long)]
+        lang: Option<String>,
+
+        /// Print issues as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Check translation quality with configurable lint rules.
+    ///
+    /// Rules cover placeholders, whitespace, punctuation, line breaks, tags, URLs,
+    /// double spaces, ellipses and untranslated copies of the source. Severities can
+    /// be set in the `[lint]` table of `langcodec.toml` or with `--rule`.
+    Lint {
+        /// The input files; the source language and translations may be in different files (supports glob patterns)
+        #[arg(short, long, num_args = 1..)]
+        inputs: Vec<String>,
+
+        /// Optional langcodec.toml path
+        #[arg(long)]
+        config: Option<String>,
+
+        /// Source language (default: the config or the inputs' source language)
+        #[arg(long)]
+        source_lang: Option<String>,
+
+        /// Only report this target language
+        #[arg(short, long)]
+        lang: Option<String>,
+
+        /// Override a rule severity, e.g. `--rule double_space=error` (off, info, warning, error)
+        #[arg(long = "rule", value_name = "ID=SEVERITY")]
+        rules: Vec<String>,
+
+        /// Lowest severity that fails the command (default: error)
+        #[arg(long, value_name = "SEVERITY")]
+        fail_on: Option<String>,
+
+        /// List the available rules and their severities
+        #[arg(long, default_value_t = false)]
+        list_rules: bool,
+
+        /// Output format: text, json, sarif, junit, or github (workflow annotations)
+        #[arg(long, value_name = "FORMAT", default_value = "text")]
+        report_format: String,
+
+        /// Print findings as JSON (same as `--report-format json`)
+        #[arg(long, default_value_t = false)]
+        json: bool,
     },
 
     /// Translate source entries into a target language using Mentra-backed providers.
@@ -324,6 +583,10 @@ enum Commands {
         #[arg(long, value_name = "NAMESPACE", value_delimiter = ',')]
         tolgee_namespace: Vec<String>,
 
+        /// Glossary files (.csv or .tbx) whose terms are passed to the model and enforced
+        #[arg(long, value_name = "FILE")]
+        glossary: Vec<String>,
+
         /// Preview the translation run without writing files
         #[arg(long, default_value_t = false)]
         dry_run: bool,
@@ -380,6 +643,12 @@ enum Commands {
         ui_mode: UiMode,
     },
 
+    /// Build a translation memory and pre-fill new keys from it.
+    Tm {
+        #[command(subcommand)]
+        command: TmCommands,
+    },
+
     /// Sync xcstrings catalogs with Tolgee using langcodec.toml or .tolgeerc.json mappings.
     Tolgee {
         #[command(subcommand)]
@@ -413,6 +682,63 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum TmCommands {
+    /// Collect translations from localization and TMX files into a TMX memory.
+    Build {
+        /// Localization or TMX files to index (supports glob patterns)
+        #[arg(short, long, required = true, num_args = 1..)]
+        inputs: Vec<String>,
+
+        /// The TMX file to write
+        #[arg(short, long)]
+        output: String,
+
+        /// Source language (default: each file's source language; required for single-language files)
+        #[arg(long)]
+        source_lang: Option<String>,
+    },
+
+    /// Pre-fill untranslated entries with close matches from translation memory as needs_review.
+    Fill {
+        /// The file to fill
+        #[arg(short, long)]
+        input: String,
+
+        /// Translation memory: TMX or localization files (supports glob patterns)
+        #[arg(long = "tm", required = true, num_args = 1..)]
+        memory: Vec<String>,
+
+        /// Separate file holding the source language, e.g. values/strings.xml for values-fr/strings.xml
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Optional output file (default: write back to --input)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Source language (default: the input's source language)
+        #[arg(long)]
+        source_lang: Option<String>,
+
+        /// Languages to fill (default: every non-source language). Comma-separated or repeated.
+        #[arg(short, long = "lang", value_name = "LANG", value_delimiter = ',')]
+        langs: Vec<String>,
+
+        /// Minimum similarity score from 0 to 100
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+        min_score: u8,
+
+        /// Report matches without writing
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Print the report as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum TolgeeCommands {
     /// Pull translations from Tolgee and merge them into mapped local xcstrings files.
@@ -553,6 +879,7 @@ fn main() {
             include_lang,
             source_language,
             version,
+            encoding,
         } => {
             // Create validation context
             let mut context = ValidationContext::new()
@@ -589,6 +916,7 @@ fn main() {
                     output_lang,
                     exclude_lang,
                     include_lang,
+                    encoding,
                 },
                 strict,
             );
@@ -904,6 +1232,7 @@ fn main() {
             tolgee,
             tolgee_config,
             tolgee_namespace,
+            glossary,
             dry_run,
             ui_mode,
         } => {
@@ -958,6 +1287,7 @@ fn main() {
                 use_tolgee: tolgee,
                 tolgee_config,
                 tolgee_namespaces: tolgee_namespace,
+                glossary,
                 dry_run,
                 strict,
                 ui_mode,
@@ -969,6 +1299,77 @@ fn main() {
                 std::process::exit(1);
             }
         }
+        Commands::Tm { command } => {
+            let result = match command {
+                TmCommands::Build {
+                    inputs,
+                    output,
+                    source_lang,
+                } => {
+                    if let Some(lang_code) = &source_lang
+                        && let Err(e) = validate_language_code(lang_code)
+                    {
+                        eprintln!(
+                            "{}",
+                            ui::status_line_stderr(
+                                ui::Tone::Error,
+                                &format!("Validation failed: {}", e)
+                            )
+                        );
+                        std::process::exit(1);
+                    }
+                    run_tm_build_command(TmBuildOptions {
+                        inputs,
+                        output,
+                        source_lang,
+                        strict,
+                    })
+                }
+                TmCommands::Fill {
+                    input,
+                    memory,
+                    source,
+                    output,
+                    source_lang,
+                    langs,
+                    min_score,
+                    dry_run,
+                    json,
+                } => {
+                    for lang_code in source_lang.iter().chain(&langs) {
+                        if let Err(e) = validate_language_code(lang_code) {
+                            eprintln!(
+                                "{}",
+                                ui::status_line_stderr(
+                                    ui::Tone::Error,
+                                    &format!("Validation failed: {}", e)
+                                )
+                            );
+                            std::process::exit(1);
+                        }
+                    }
+                    run_tm_fill_command(TmFillCliOptions {
+                        input,
+                        source,
+                        memory,
+                        output,
+                        source_lang,
+                        langs,
+                        min_score,
+                        dry_run,
+                        json,
+                        strict,
+                    })
+                }
+            };
+            if let Err(e) = result {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("TM failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+        }
         Commands::Tolgee { command } => match command {
             TolgeeCommands::Pull {
                 config,
@@ -1099,9 +1500,18 @@ fn main() {
             output,
             dry_run,
             check,
+            json,
             no_placeholders,
+            isolate_bidi,
+            unicode_form,
+            french_spacing,
+            smart_quotes,
+            ellipsis,
+            trim_trailing_whitespace,
+            line_endings,
             key_style,
             continue_on_error,
+            encoding,
         } => {
             let opts = NormalizeCliOptions {
                 inputs,
@@ -1109,9 +1519,18 @@ fn main() {
                 dry_run,
                 check,
                 no_placeholders,
+                isolate_bidi,
+                unicode_form,
+                french_spacing,
+                smart_quotes,
+                ellipsis,
+                trim_trailing_whitespace,
+                line_endings,
                 key_style,
                 continue_on_error,
                 strict,
+                encoding,
+                json,
             };
             if let Err(e) = run_normalize_command(opts) {
                 eprintln!(
@@ -1121,7 +1540,37 @@ fn main() {
                 std::process::exit(1);
             }
         }
-        Commands::Stats { input, lang, json } => {
+        Commands::Rename {
+            inputs,
+            mapping,
+            pattern,
+            replacement,
+            dry_run,
+            json,
+        } => {
+            let opts = RenameCliOptions {
+                inputs,
+                mapping,
+                pattern,
+                replacement,
+                dry_run,
+                json,
+                strict,
+            };
+            if let Err(e) = run_rename_command(opts) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("Rename failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+        }
+        Commands::Stats {
+            input,
+            lang,
+            json,
+            config,
+        } => {
             // Validate
             let mut context = ValidationContext::new().with_input_file(input.clone());
             if let Some(l) = &lang {
@@ -1135,7 +1584,7 @@ fn main() {
                 std::process::exit(1);
             }
 
-            let codec = match load_codec_for_readonly_command(&input, &lang, strict) {
+            let mut codec = match load_codec_for_readonly_command(&input, &lang, strict) {
                 Ok(codec) => codec,
                 Err(e) => {
                     eprintln!(
@@ -1148,6 +1597,21 @@ fn main() {
                     std::process::exit(1);
                 }
             };
+            let length_limits = config::load_config(config.as_deref())
+                .and_then(|cfg| cfg.map(|cfg| cfg.data.length.length_limits()).transpose());
+            match length_limits {
+                Ok(Some(limits)) => {
+                    limits.apply(&mut codec.resources);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(ui::Tone::Error, &format!("Stats failed: {}", e))
+                    );
+                    std::process::exit(1);
+                }
+            }
 
             if let Err(e) = stats::print_stats(&codec, &lang, json) {
                 eprintln!(
@@ -1157,5 +1621,277 @@ fn main() {
                 std::process::exit(1);
             }
         }
+        Commands::Pseudo {
+            input,
+            output,
+            locale,
+            source_lang,
+            output_format,
+            expansion,
+            no_accents,
+            no_brackets,
+            rtl,
+        } => {
+            for lang_code in std::iter::once(&locale).chain(source_lang.as_ref()) {
+                if let Err(e) = validate_language_code(lang_code) {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Validation failed: {}", e)
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            }
+
+            let opts = PseudoCliOptions {
+                input,
+                output,
+                locale,
+                source_lang,
+                output_format,
+                expansion,
+                no_accents,
+                no_brackets,
+                rtl,
+            };
+            if let Err(e) = run_pseudo_command(&opts, strict) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("Pseudo failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+        }
+        Commands::RefreshStatus {
+            inputs,
+            state,
+            source_lang,
+            lang,
+            accept,
+            dry_run,
+            json,
+        } => {
+            for lang_code in source_lang.iter().chain(lang.as_ref()) {
+                if let Err(e) = validate_language_code(lang_code) {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Validation failed: {}", e)
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            }
+
+            let opts = RefreshStatusCliOptions {
+                inputs,
+                state,
+                source_lang,
+                lang,
+                accept,
+                dry_run,
+                json,
+                strict,
+            };
+            if let Err(e) = run_refresh_status_command(opts) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(
+                        ui::Tone::Error,
+                        &format!("Refresh status failed: {}", e)
+                    )
+                );
+                std::process::exit(1);
+            }
+        }
+        Commands::Glossary {
+            input,
+            glossary,
+            config,
+            source_lang,
+            lang,
+            json,
+        } => {
+            for lang_code in source_lang.iter().chain(lang.as_ref()) {
+                if let Err(e) = validate_language_code(lang_code) {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Validation failed: {}", e)
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            }
+
+            let opts = GlossaryCheckOptions {
+                input,
+                glossary,
+                config,
+                source_lang,
+                lang,
+                json,
+                strict,
+            };
+            if let Err(e) = run_glossary_command(opts) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(
+                        ui::Tone::Error,
+                        &format!("Glossary check failed: {}", e)
+                    )
+                );
+                std::process::exit(1);
+            }
+        }
+        Commands::Lint {
+            inputs,
+            config,
+            source_lang,
+            lang,
+            rules,
+            fail_on,
+            list_rules,
+            report_format,
+            json,
+        } => {
+            for lang_code in source_lang.iter().chain(lang.as_ref()) {
+                if let Err(e) = validate_language_code(lang_code) {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Validation failed: {}", e)
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            }
+            let report_format = if json {
+                Ok(langcodec::ReportFormat::Json)
+            } else {
+                report_format.parse::<langcodec::ReportFormat>()
+            };
+            let report_format = match report_format {
+                Ok(format) => format,
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Validation failed: {}", e)
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            };
+            if inputs.is_empty() && !list_rules {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(
+                        ui::Tone::Error,
+                        "Validation failed: --inputs is required"
+                    )
+                );
+                std::process::exit(1);
+            }
+
+            let opts = LintCliOptions {
+                inputs,
+                config,
+                source_lang,
+                lang,
+                rules,
+                fail_on,
+                list_rules,
+                report_format,
+                strict,
+            };
+            if let Err(e) = run_lint_command(opts) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("Lint failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+        }
+        Commands::Preview {
+            key,
+            inputs,
+            lang,
+            args,
+            json,
+        } => {
+            if let Some(lang_code) = &lang
+                && let Err(e) = validate_language_code(lang_code)
+            {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("Validation failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+
+            let expanded_inputs = match path_glob::expand_input_globs(&inputs) {
+                Ok(list) if !list.is_empty() => list,
+                Ok(_) => {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            "No input files matched the provided patterns",
+                        )
+                    );
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        ui::status_line_stderr(
+                            ui::Tone::Error,
+                            &format!("Failed to expand input patterns: {}", e),
+                        )
+                    );
+                    std::process::exit(1);
+                }
+            };
+
+            let mut codec = Codec::new();
+            for input in &expanded_inputs {
+                match load_codec_for_readonly_command(input, &None, strict) {
+                    Ok(loaded) => codec.extend_from(loaded),
+                    Err(e) => {
+                        eprintln!(
+                            "{}",
+                            ui::status_line_stderr(
+                                ui::Tone::Error,
+                                &format!("Failed to read file {}: {}", input, e)
+                            )
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            if let Err(e) = run_preview_command(
+                &codec,
+                &PreviewOptions {
+                    key,
+                    lang,
+                    args,
+                    json,
+                },
+            ) {
+                eprintln!(
+                    "{}",
+                    ui::status_line_stderr(ui::Tone::Error, &format!("Preview failed: {}", e))
+                );
+                std::process::exit(1);
+            }
+        }
     }
 }
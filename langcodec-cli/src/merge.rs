@@ -50,7 +50,8 @@ fn resolve_merge_output_format(
         ),
         langcodec::FormatType::Xcstrings
         | langcodec::FormatType::CSV
-        | langcodec::FormatType::TSV => Ok(output_format),
+        | langcodec::FormatType::TSV
+        | langcodec::FormatType::XLSX => Ok(output_format),
     }
 }
 
@@ -1,8 +1,9 @@
 use crate::path_glob;
 use crate::validation::{validate_file_path, validate_output_path};
 use langcodec::{
-    Codec, FormatType, KeyStyle, NormalizeOptions as EngineNormalizeOptions, ReadOptions,
-    normalize_codec,
+    Codec, FormatType, KeyStyle, NormalizeOptions as EngineNormalizeOptions, NormalizeReport,
+    NormalizeRule, ReadOptions, UnicodeForm, formats::StringsEncoding, normalize_codec,
+    preview_normalize,
 };
 use std::collections::HashSet;
 use std::path::Path;
@@ -14,9 +15,19 @@ pub struct NormalizeCliOptions {
     pub dry_run: bool,
     pub check: bool,
     pub no_placeholders: bool,
+    pub isolate_bidi: bool,
+    pub unicode_form: Option<String>,
+    pub french_spacing: bool,
+    pub smart_quotes: bool,
+    pub ellipsis: bool,
+    pub trim_trailing_whitespace: bool,
+    pub line_endings: bool,
     pub key_style: String,
     pub continue_on_error: bool,
     pub strict: bool,
+    pub encoding: Option<String>,
+    /// Print `--dry-run`/`--check` reports as JSON instead of per-key text.
+    pub json: bool,
 }
 
 fn parse_key_style(input: &str) -> Result<KeyStyle, String> {
@@ -65,11 +76,16 @@ fn write_back(codec: &Codec, input_path: &str, output_path: &Option<String>) ->
 
     match fmt {
         FormatType::Strings(_) | FormatType::AndroidStrings(_) => {
+            // In-place rewrites splice changes into the existing file to keep its layout.
             let resource = pick_single_resource(codec)?;
-            Codec::write_resource_to_file(resource, out)
-                .map_err(|e| format!("Error writing output: {}", e))
+            if out == input_path {
+                Codec::update_resource_file(resource, out)
+            } else {
+                Codec::write_resource_to_file(resource, out)
+            }
+            .map_err(|e| format!("Error writing output: {}", e))
         }
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV => {
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
             langcodec::converter::convert_resources_to_format(codec.resources.clone(), out, fmt)
                 .map_err(|e| format!("Error writing output: {}", e))
         }
@@ -92,54 +108,103 @@ fn has_glob_meta(input: &str) -> bool {
         .any(|b| matches!(b, b'*' | b'?' | b'[' | b'{'))
 }
 
+/// Apply an explicit `.strings` encoding to every resource, returning whether
+/// the written bytes would differ from the encoding the file was read with.
+fn apply_strings_encoding(
+    codec: &mut Codec,
+    input_path: &str,
+    output_path: &Option<String>,
+    encoding: StringsEncoding,
+) -> Result<bool, String> {
+    let out = output_path.as_deref().unwrap_or(input_path);
+    if !matches!(infer_output_format_from_path(out)?, FormatType::Strings(_)) {
+        return Err(format!(
+            "--encoding is only supported for .strings files: {}",
+            out
+        ));
+    }
+
+    let mut changed = false;
+    for resource in &mut codec.resources {
+        let before = resource.metadata.custom.clone();
+        encoding.apply_to(&mut resource.metadata);
+        changed |= before != resource.metadata.custom;
+    }
+    Ok(changed)
+}
+
 fn run_normalize_for_file(
     input: &str,
-    output: &Option<String>,
-    dry_run: bool,
-    check: bool,
-    no_placeholders: bool,
-    key_style: &KeyStyle,
-    strict: bool,
+    opts: &NormalizeCliOptions,
+    engine_options: &EngineNormalizeOptions,
+    encoding: Option<StringsEncoding>,
+    json_reports: &mut Vec<serde_json::Value>,
 ) -> Result<bool, String> {
+    let output = &opts.output;
     reject_xliff_normalize_paths(input, output.as_ref())?;
 
     validate_file_path(input)?;
 
     let mut codec = Codec::new();
     codec
-        .read_file_by_extension_with_options(input, &ReadOptions::new().with_strict(strict))
+        .read_file_by_extension_with_options(input, &ReadOptions::new().with_strict(opts.strict))
         .map_err(|e| format!("Failed to read input '{}': {}", input, e))?;
 
-    let report = normalize_codec(
-        &mut codec,
-        &EngineNormalizeOptions {
-            normalize_placeholders: !no_placeholders,
-            key_style: *key_style,
-        },
-    )
-    .map_err(|e| e.to_string())?;
+    let preview = opts.check || opts.dry_run;
+    let report = if preview {
+        preview_normalize(&codec, engine_options)
+    } else {
+        normalize_codec(&mut codec, engine_options).map_err(|e| e.to_string())?
+    };
+
+    let encoding_changed = match encoding {
+        Some(encoding) => apply_strings_encoding(&mut codec, input, output, encoding)?,
+        None => false,
+    };
+    let changed = report.changed || encoding_changed;
+
+    if preview {
+        if opts.json {
+            let mut value = serde_json::to_value(&report)
+                .map_err(|e| format!("Failed to serialize normalize report: {}", e))?;
+            value["path"] = input.into();
+            value["encoding_changed"] = encoding_changed.into();
+            value["changed"] = changed.into();
+            json_reports.push(value);
+        } else if changed || !report.collisions.is_empty() {
+            print!("{}", render_report(input, &report));
+        }
+        if let Some(collision) = report.collisions.first() {
+            return Err(collision.to_string());
+        }
+    }
 
-    if check {
-        if report.changed {
-            println!("would change: {}", input);
+    if opts.check {
+        if changed {
+            if !opts.json {
+                println!("would change: {}", input);
+            }
             return Err(format!("would change: {}", input));
         }
 
-        println!("No changes needed: {}", input);
+        if !opts.json {
+            println!("No changes needed: {}", input);
+        }
         return Ok(false);
     }
 
-    if dry_run {
-        if report.changed {
-            println!("DRY-RUN: would change {}", input);
-            return Ok(true);
-        } else {
-            println!("No changes needed: {}", input);
+    if opts.dry_run {
+        if !opts.json {
+            if changed {
+                println!("DRY-RUN: would change {}", input);
+            } else {
+                println!("No changes needed: {}", input);
+            }
         }
-        return Ok(false);
+        return Ok(changed);
     }
 
-    if !report.changed {
+    if !changed {
         if has_distinct_output_path(input, output) {
             if let Some(output) = output {
                 validate_output_path(output)?;
@@ -163,6 +228,72 @@ fn run_normalize_for_file(
     Ok(true)
 }
 
+/// Renders a report as a plain listing under `input`: one block per changed
+/// key with its old and new value, then renamed keys, re-sorted languages and
+/// collisions. Values are shown on one line with invisible characters escaped.
+fn render_report(input: &str, report: &NormalizeReport) -> String {
+    let mut out = format!("{}\n", input);
+    for rename in &report.renamed_keys {
+        out.push_str(&format!(
+            "  [{}] {}: renamed to {} ({})\n",
+            rename.language,
+            rename.from,
+            rename.to,
+            NormalizeRule::KeyStyle
+        ));
+    }
+    for change in &report.values {
+        let form = change
+            .form
+            .as_ref()
+            .map(|form| format!(" [{}]", form))
+            .unwrap_or_default();
+        let rules: Vec<&str> = change.rules.iter().map(|rule| rule.id()).collect();
+        out.push_str(&format!(
+            "  [{}] {}{} ({})\n    before: {}\n    after:  {}\n",
+            change.language,
+            change.key,
+            form,
+            rules.join(", "),
+            escape_invisible(&change.before),
+            escape_invisible(&change.after)
+        ));
+    }
+    for language in &report.reordered {
+        out.push_str(&format!("  [{}] entries sorted by key\n", language));
+    }
+    for collision in &report.collisions {
+        out.push_str(&format!("  {}\n", collision));
+    }
+    out
+}
+
+fn escape_invisible(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ' ' => out.push(' '),
+            c if c.is_whitespace()
+                || matches!(
+                    c,
+                    '\u{061C}'
+                        | '\u{200B}'..='\u{200F}'
+                        | '\u{202A}'..='\u{202E}'
+                        | '\u{2060}'..='\u{2069}'
+                        | '\u{FEFF}'
+                ) =>
+            {
+                out.push_str(&format!("\\u{{{:04X}}}", u32::from(c)));
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
     let expanded = path_glob::expand_input_globs(&opts.inputs)
         .map_err(|e| format!("Failed to expand input patterns: {}", e))?;
@@ -174,11 +305,35 @@ pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
         return Err("No input files matched the provided patterns".to_string());
     }
 
+    if opts.json && !opts.dry_run && !opts.check {
+        return Err("--json requires --dry-run or --check".to_string());
+    }
+
     if expanded.len() > 1 && opts.output.is_some() {
         return Err("--output cannot be used with multiple input files".to_string());
     }
 
-    let key_style = parse_key_style(&opts.key_style)?;
+    let engine_options = EngineNormalizeOptions {
+        normalize_placeholders: !opts.no_placeholders,
+        key_style: parse_key_style(&opts.key_style)?,
+        isolate_bidi: opts.isolate_bidi,
+        unicode_form: opts
+            .unicode_form
+            .as_deref()
+            .map(str::parse::<UnicodeForm>)
+            .transpose()
+            .map_err(|e| format!("Invalid --unicode: {}", e))?,
+        french_spacing: opts.french_spacing,
+        smart_quotes: opts.smart_quotes,
+        ellipsis: opts.ellipsis,
+        trim_trailing_whitespace: opts.trim_trailing_whitespace,
+        line_endings: opts.line_endings,
+    };
+    let encoding = opts
+        .encoding
+        .as_deref()
+        .map(crate::convert::parse_strings_encoding)
+        .transpose()?;
 
     let mut skip_missing: HashSet<String> = HashSet::new();
     let mut failures: Vec<String> = Vec::new();
@@ -186,6 +341,7 @@ pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
     let mut success_count: usize = 0;
     let mut failed_count: usize = 0;
     let mut changed_count: usize = 0;
+    let mut json_reports: Vec<serde_json::Value> = Vec::new();
 
     for original in &opts.inputs {
         if !has_glob_meta(original) && !Path::new(original).is_file() {
@@ -209,15 +365,7 @@ pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
 
         processed_count += 1;
 
-        match run_normalize_for_file(
-            &input,
-            &opts.output,
-            opts.dry_run,
-            opts.check,
-            opts.no_placeholders,
-            &key_style,
-            opts.strict,
-        ) {
+        match run_normalize_for_file(&input, &opts, &engine_options, encoding, &mut json_reports) {
             Ok(changed) => {
                 success_count += 1;
                 if changed {
@@ -232,19 +380,21 @@ pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
                     continue;
                 }
 
-                println!(
-                    "Summary: processed {}; success: {}; failed: {}; changed: {}",
-                    processed_count, success_count, failed_count, changed_count
-                );
+                print_summary(
+                    opts.json,
+                    json_reports,
+                    [processed_count, success_count, failed_count, changed_count],
+                )?;
                 return Err(err);
             }
         }
     }
 
-    println!(
-        "Summary: processed {}; success: {}; failed: {}; changed: {}",
-        processed_count, success_count, failed_count, changed_count
-    );
+    print_summary(
+        opts.json,
+        json_reports,
+        [processed_count, success_count, failed_count, changed_count],
+    )?;
 
     if failures.is_empty() {
         return Ok(());
@@ -255,3 +405,31 @@ pub fn run_normalize_command(opts: NormalizeCliOptions) -> Result<(), String> {
         failures.len()
     ))
 }
+
+fn print_summary(
+    json: bool,
+    reports: Vec<serde_json::Value>,
+    [processed, success, failed, changed]: [usize; 4],
+) -> Result<(), String> {
+    if !json {
+        println!(
+            "Summary: processed {}; success: {}; failed: {}; changed: {}",
+            processed, success, failed, changed
+        );
+        return Ok(());
+    }
+
+    let body = serde_json::json!({
+        "files": reports,
+        "summary": {
+            "processed": processed,
+            "success": success,
+            "failed": failed,
+            "changed": changed,
+        },
+    });
+    let rendered = serde_json::to_string_pretty(&body)
+        .map_err(|e| format!("Failed to serialize normalize report: {}", e))?;
+    println!("{}", rendered);
+    Ok(())
+}
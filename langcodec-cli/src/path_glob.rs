@@ -22,14 +22,11 @@ pub fn expand_input_globs(inputs: &Vec<String>) -> Result<Vec<String>, String> {
                 _ => idx += 1,
             }
         }
-        let prefix = &pattern[..idx];
-        let p = Path::new(prefix);
-        if p.is_dir() {
-            p.to_path_buf()
-        } else {
-            p.parent()
-                .map(|pp| pp.to_path_buf())
-                .unwrap_or_else(|| PathBuf::from("."))
+        // The glob may start mid-segment (`values*/`), so walk from the last
+        // complete directory rather than from `values/`.
+        match pattern[..idx].rfind('/') {
+            Some(slash) => PathBuf::from(&pattern[..=slash]),
+            None => PathBuf::from("."),
         }
     }
 
@@ -54,6 +51,7 @@ pub fn expand_input_globs(inputs: &Vec<String>) -> Result<Vec<String>, String> {
         } else {
             Path::new(pat)
                 .parent()
+                .filter(|p| !p.as_os_str().is_empty())
                 .map(|p| p.to_path_buf())
                 .unwrap_or_else(|| PathBuf::from("."))
         };
@@ -89,8 +87,11 @@ pub fn expand_input_globs(inputs: &Vec<String>) -> Result<Vec<String>, String> {
                     continue;
                 }
                 let s = dent.path().to_string_lossy();
+                let relative = s.strip_prefix("./").unwrap_or(&s);
                 if set.is_match(s.as_ref()) {
                     out.push(s.to_string());
+                } else if set.is_match(relative) {
+                    out.push(relative.to_string());
                 }
             }
             out
@@ -0,0 +1,114 @@
+use crate::ui;
+use langcodec::{Codec, FormatArg, FormatArgs, format_entry};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+pub struct PreviewOptions {
+    pub key: String,
+    pub lang: Option<String>,
+    pub args: Vec<String>,
+    pub json: bool,
+}
+
+/// Builds render arguments from `--arg` values; `NAME=VALUE` sets a named argument.
+fn parse_args(values: &[String]) -> FormatArgs {
+    let mut args = FormatArgs::new();
+    for value in values {
+        match value.split_once('=') {
+            Some((name, named))
+                if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+            {
+                args.named.insert(name.to_string(), FormatArg::infer(named));
+            }
+            _ => args.positional.push(FormatArg::infer(value)),
+        }
+    }
+    args
+}
+
+/// Renders `opts.key` in the requested language, or in every loaded language.
+pub fn run_preview_command(codec: &Codec, opts: &PreviewOptions) -> Result<(), String> {
+    let args = parse_args(&opts.args);
+    let languages: Vec<String> = match &opts.lang {
+        Some(lang) => vec![lang.clone()],
+        None => codec
+            .languages()
+            .map(str::to_string)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+    };
+
+    let mut rows = Vec::new();
+    let mut found = false;
+    for language in &languages {
+        let row = match codec.resolve(&opts.key, language) {
+            Some(resolved) => {
+                found = true;
+                // Plural rules follow the language the text is written in.
+                let rendered =
+                    format_entry(resolved.entry, resolved.locale, &args).map_err(|e| e.to_string());
+                (
+                    language.clone(),
+                    resolved.is_fallback().then(|| resolved.locale.to_string()),
+                    rendered,
+                )
+            }
+            None => (
+                language.clone(),
+                None,
+                Err(format!("Key '{}' not found", opts.key)),
+            ),
+        };
+        rows.push(row);
+    }
+
+    if !found {
+        return Err(format!(
+            "Key '{}' not found in any of: {}",
+            opts.key,
+            languages.join(", ")
+        ));
+    }
+
+    if opts.json {
+        let payload: Vec<_> = rows
+            .iter()
+            .map(|(language, fallback, rendered)| match rendered {
+                Ok(text) => json!({
+                    "language": language,
+                    "fallback_from": fallback,
+                    "value": text,
+                }),
+                Err(error) => json!({
+                    "language": language,
+                    "fallback_from": fallback,
+                    "error": error,
+                }),
+            })
+            .collect();
+        let out = serde_json::to_string_pretty(&json!({
+            "key": opts.key,
+            "previews": payload,
+        }))
+        .map_err(|e| format!("Failed to render preview JSON: {}", e))?;
+        println!("{}", out);
+        return Ok(());
+    }
+
+    println!("{}", ui::header(&format!("Preview: {}", opts.key)));
+    for (language, fallback, rendered) in rows {
+        let label = match &fallback {
+            Some(source) => format!("{} (from {})", language, source),
+            None => language,
+        };
+        match rendered {
+            Ok(text) => println!("{}", ui::key_value(&label, text)),
+            Err(error) => println!(
+                "{}",
+                ui::key_value(&label, ui::tone_text(&error, ui::Tone::Error))
+            ),
+        }
+    }
+    Ok(())
+}
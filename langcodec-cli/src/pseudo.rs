@@ -0,0 +1,123 @@
+use crate::convert::{parse_standard_output_format, read_resources_from_any_input};
+use crate::ui;
+use langcodec::{
+    PseudoOptions, Resource, convert_resources_to_format, formats::FormatType,
+    pseudolocalize_resource,
+};
+
+pub struct PseudoCliOptions {
+    pub input: String,
+    pub output: String,
+    pub locale: String,
+    pub source_lang: Option<String>,
+    pub output_format: Option<String>,
+    /// Extra length in percent; `None` keeps the locale's default.
+    pub expansion: Option<u32>,
+    pub no_accents: bool,
+    pub no_brackets: bool,
+    pub rtl: bool,
+}
+
+impl PseudoCliOptions {
+    fn pseudo_options(&self) -> PseudoOptions {
+        let mut options = PseudoOptions::for_locale(&self.locale);
+        if let Some(percent) = self.expansion {
+            options.expansion = f64::from(percent) / 100.0;
+        }
+        if self.no_accents {
+            options.accents = false;
+        }
+        if self.no_brackets {
+            options.brackets = false;
+        }
+        if self.rtl {
+            options.rtl = true;
+        }
+        options
+    }
+}
+
+fn resolve_source_language(
+    resources: &[Resource],
+    requested: Option<&String>,
+) -> Result<String, String> {
+    if let Some(language) = requested {
+        return Ok(language.clone());
+    }
+    if let Some(language) = resources
+        .iter()
+        .find_map(|res| res.metadata.custom.get("source_language"))
+    {
+        return Ok(language.clone());
+    }
+    match resources {
+        [resource] => Ok(resource.metadata.language.clone()),
+        _ => Err(
+            "Could not infer the source language from the input. Pass --source-lang.".to_string(),
+        ),
+    }
+}
+
+/// Generates the pseudo-locale from the source language and writes it to `opts.output`.
+///
+/// Single-language outputs (`.strings`, `strings.xml`) receive only the
+/// pseudo-locale; multi-language outputs keep the input languages and add
+/// or replace the pseudo-locale.
+pub fn run_pseudo_command(opts: &PseudoCliOptions, strict: bool) -> Result<(), String> {
+    let mut resources = read_resources_from_any_input(&opts.input, None, strict)?;
+    let source_language = resolve_source_language(&resources, opts.source_lang.as_ref())?;
+    let mut source = resources
+        .iter()
+        .find(|res| res.metadata.language == source_language)
+        .cloned()
+        .ok_or_else(|| {
+            format!(
+                "Source language '{}' not found in {}",
+                source_language, opts.input
+            )
+        })?;
+
+    source
+        .metadata
+        .custom
+        .insert("source_language".to_string(), source_language.clone());
+    let pseudo = pseudolocalize_resource(&source, &opts.locale, &opts.pseudo_options());
+    let entry_count = pseudo.entries.len();
+
+    let output_format = match &opts.output_format {
+        Some(hint) => parse_standard_output_format(hint)?,
+        None => langcodec::infer_format_from_path(&opts.output)
+            .or_else(|| langcodec::infer_format_from_extension(&opts.output))
+            .ok_or_else(|| format!("Cannot infer output format from extension: {}", opts.output))?,
+    };
+    let (output_format, resources) = match output_format {
+        FormatType::Strings(_) | FormatType::AndroidStrings(_) => (
+            output_format.with_language(Some(opts.locale.clone())),
+            vec![pseudo],
+        ),
+        FormatType::Xliff(_) => (
+            output_format.with_language(Some(opts.locale.clone())),
+            vec![source, pseudo],
+        ),
+        other => {
+            resources.retain(|res| res.metadata.language != opts.locale);
+            resources.push(pseudo);
+            (other, resources)
+        }
+    };
+
+    convert_resources_to_format(resources, &opts.output, output_format)
+        .map_err(|e| format!("Error writing {}: {}", opts.output, e))?;
+
+    println!(
+        "{}",
+        ui::status_line_stdout(
+            ui::Tone::Success,
+            &format!(
+                "Generated {} entries for {} from {} into {}",
+                entry_count, opts.locale, source_language, opts.output
+            ),
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use crate::path_glob;
+use crate::ui;
+use crate::validation::validate_file_path;
+use langcodec::{
+    Codec, EntryStatus, FormatType, ReadOptions, RefreshAction, RefreshStatusOptions,
+    RefreshStatusReport, Resource,
+    operations::{SOURCE_FINGERPRINT_KEY, TRANSLATION_FINGERPRINT_KEY},
+    refresh_stale_status,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct RefreshStatusCliOptions {
+    pub inputs: Vec<String>,
+    pub state: String,
+    pub source_lang: Option<String>,
+    pub lang: Option<String>,
+    pub accept: bool,
+    pub dry_run: bool,
+    pub json: bool,
+    pub strict: bool,
+}
+
+/// Fingerprints recorded for one translation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct StateEntry {
+    source: String,
+    translation: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    stale: bool,
+}
+
+/// Sidecar state keyed by language, then entry key.
+///
+/// Most formats cannot store per-entry metadata, so fingerprints (and the
+/// stale flag for formats without a status field) are kept here between runs.
+type StateFile = BTreeMap<String, BTreeMap<String, StateEntry>>;
+
+fn load_state(path: &str) -> Result<StateFile, String> {
+    if !Path::new(path).exists() {
+        return Ok(StateFile::new());
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read state file '{}': {}", path, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("Invalid state file '{}': {}", path, e))
+}
+
+fn save_state(path: &str, state: &StateFile) -> Result<(), String> {
+    let text = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize state: {}", e))?;
+    std::fs::write(path, text).map_err(|e| format!("Failed to write state file '{}': {}", path, e))
+}
+
+/// Copies recorded fingerprints onto the entries; entries whose translation
+/// is unchanged since they were marked stale become stale again.
+fn apply_state(resources: &mut [Resource], state: &StateFile) {
+    for resource in resources {
+        let Some(recorded) = state.get(&resource.metadata.language) else {
+            continue;
+        };
+        for entry in &mut resource.entries {
+            let Some(record) = recorded.get(&entry.id) else {
+                continue;
+            };
+            entry
+                .custom
+                .insert(SOURCE_FINGERPRINT_KEY.to_string(), record.source.clone());
+            entry.custom.insert(
+                TRANSLATION_FINGERPRINT_KEY.to_string(),
+                record.translation.clone(),
+            );
+            if record.stale
+                && entry.status != EntryStatus::DoNotTranslate
+                && langcodec::operations::fingerprint(&entry.value).as_ref()
+                    == Some(&record.translation)
+            {
+                entry.status = EntryStatus::Stale;
+            }
+        }
+    }
+}
+
+fn collect_state(resources: &[Resource]) -> StateFile {
+    let mut state = StateFile::new();
+    for resource in resources {
+        for entry in &resource.entries {
+            let (Some(source), Some(translation)) = (
+                entry.custom.get(SOURCE_FINGERPRINT_KEY),
+                entry.custom.get(TRANSLATION_FINGERPRINT_KEY),
+            ) else {
+                continue;
+            };
+            state
+                .entry(resource.metadata.language.clone())
+                .or_default()
+                .insert(
+                    entry.id.clone(),
+                    StateEntry {
+                        source: source.clone(),
+                        translation: translation.clone(),
+                        stale: entry.status == EntryStatus::Stale,
+                    },
+                );
+        }
+    }
+    state
+}
+
+fn resolve_source_language(
+    resources: &[Resource],
+    requested: Option<&String>,
+) -> Result<String, String> {
+    if let Some(language) = requested {
+        return Ok(language.clone());
+    }
+    if let Some(language) = resources
+        .iter()
+        .find_map(|res| res.metadata.custom.get("source_language"))
+    {
+        return Ok(language.clone());
+    }
+    Err("Could not infer the source language from the inputs. Pass --source-lang.".to_string())
+}
+
+fn write_back(codec: &Codec, path: &str) -> Result<(), String> {
+    let fmt = langcodec::infer_format_from_extension(path)
+        .ok_or_else(|| format!("Cannot infer format from path: {}", path))?;
+    match fmt {
+        FormatType::Strings(_) | FormatType::AndroidStrings(_) => {
+            for resource in &codec.resources {
+                Codec::update_resource_file(resource, path)
+                    .map_err(|e| format!("Error writing {}: {}", path, e))?;
+            }
+            Ok(())
+        }
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
+            langcodec::converter::convert_resources_to_format(codec.resources.clone(), path, fmt)
+                .map_err(|e| format!("Error writing {}: {}", path, e))
+        }
+        FormatType::Xliff(_) => Err(
+            ".xliff is not supported by `refresh-status`. Convert XLIFF into a standard project format first."
+                .to_string(),
+        ),
+    }
+}
+
+fn print_report(report: &RefreshStatusReport, dry_run: bool) {
+    for change in &report.changes {
+        let (tone, label) = match change.action {
+            RefreshAction::Tracked => continue,
+            RefreshAction::MarkedStale => (ui::Tone::Warning, "stale"),
+            RefreshAction::Restamped => (ui::Tone::Info, "updated"),
+        };
+        println!(
+            "{}",
+            ui::status_line_stdout(
+                tone,
+                &format!("{} {}: {}", change.language, change.key, label)
+            )
+        );
+    }
+    let prefix = if dry_run { "DRY-RUN: " } else { "" };
+    println!(
+        "{}",
+        ui::status_line_stdout(
+            ui::Tone::Success,
+            &format!(
+                "{}Checked {} translations against {}: {} up to date, {} newly tracked, {} marked stale, {} already stale, {} updated",
+                prefix,
+                report.checked,
+                report.source_language,
+                report.up_to_date,
+                report.tracked,
+                report.marked_stale,
+                report.already_stale,
+                report.restamped
+            ),
+        )
+    );
+}
+
+/// Compares translations with the current source text and marks outdated ones stale.
+///
+/// All inputs are checked together so the source language may live in a
+/// different file (e.g. `values/strings.xml`) than its translations.
+pub fn run_refresh_status_command(opts: RefreshStatusCliOptions) -> Result<(), String> {
+    let inputs = path_glob::expand_input_globs(&opts.inputs)
+        .map_err(|e| format!("Failed to expand input patterns: {}", e))?;
+    if inputs.is_empty() {
+        return Err("No input files matched the provided patterns".to_string());
+    }
+
+    let mut codecs = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        validate_file_path(input)?;
+        let mut codec = Codec::new();
+        codec
+            .read_file_by_extension_with_options(
+                input,
+                &ReadOptions::new().with_strict(opts.strict),
+            )
+            .map_err(|e| format!("Failed to read input '{}': {}", input, e))?;
+        codecs.push(codec);
+    }
+
+    let mut resources: Vec<Resource> = codecs
+        .iter()
+        .flat_map(|codec| codec.resources.iter().cloned())
+        .collect();
+    let source_language = resolve_source_language(&resources, opts.source_lang.as_ref())?;
+    let state = load_state(&opts.state)?;
+    apply_state(&mut resources, &state);
+    let loaded_statuses: Vec<EntryStatus> = resources
+        .iter()
+        .flat_map(|res| res.entries.iter().map(|entry| entry.status.clone()))
+        .collect();
+
+    let report = refresh_stale_status(
+        &mut resources,
+        &RefreshStatusOptions {
+            source_language: Some(source_language),
+            language_filter: opts.lang.clone(),
+            accept: opts.accept,
+        },
+    )
+    .map_err(|e| e.to_string())?;
+
+    if opts.json {
+        let text = serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize report JSON: {}", e))?;
+        println!("{}", text);
+    } else {
+        print_report(&report, opts.dry_run);
+    }
+    if opts.dry_run {
+        return Ok(());
+    }
+
+    let new_state = collect_state(&resources);
+    let mut statuses = loaded_statuses.into_iter();
+    let mut refreshed = resources.into_iter();
+    for (codec, input) in codecs.iter_mut().zip(&inputs) {
+        let mut status_changed = false;
+        for resource in &mut codec.resources {
+            let updated = refreshed
+                .next()
+                .expect("refreshed resources match the loaded ones");
+            for (entry, before) in updated.entries.iter().zip(statuses.by_ref()) {
+                status_changed |= entry.status != before;
+            }
+            *resource = updated;
+        }
+        if status_changed {
+            write_back(codec, input)?;
+        }
+    }
+    if new_state != state {
+        save_state(&opts.state, &new_state)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::path_glob;
+use crate::ui;
+use crate::validation::validate_file_path;
+use langcodec::{
+    Codec, FormatType, KeyMapping, ReadOptions, RenameReport, Resource, find_rename_collisions,
+};
+use serde_json::json;
+
+#[derive(Debug, Clone)]
+pub struct RenameCliOptions {
+    pub inputs: Vec<String>,
+    pub mapping: Option<String>,
+    pub pattern: Option<String>,
+    pub replacement: Option<String>,
+    pub dry_run: bool,
+    pub json: bool,
+    pub strict: bool,
+}
+
+fn load_mapping(opts: &RenameCliOptions) -> Result<KeyMapping, String> {
+    match (&opts.mapping, &opts.pattern, &opts.replacement) {
+        (Some(path), None, None) => {
+            validate_file_path(path)?;
+            KeyMapping::read_file(path)
+                .map_err(|e| format!("Failed to read mapping '{}': {}", path, e))
+        }
+        (None, Some(pattern), Some(replacement)) => {
+            KeyMapping::pattern(pattern, replacement.as_str()).map_err(|e| e.to_string())
+        }
+        (None, Some(_), None) => Err("--pattern requires --replacement".to_string()),
+        (None, None, _) => Err("Pass --mapping or --pattern with --replacement".to_string()),
+        (Some(_), _, _) => {
+            Err("--mapping cannot be combined with --pattern or --replacement".to_string())
+        }
+    }
+}
+
+fn write_back(codec: &Codec, path: &str) -> Result<(), String> {
+    let fmt = langcodec::infer_format_from_extension(path)
+        .ok_or_else(|| format!("Cannot infer format from path: {}", path))?;
+    match fmt {
+        FormatType::Strings(_) | FormatType::AndroidStrings(_) => {
+            for resource in &codec.resources {
+                Codec::update_resource_file(resource, path)
+                    .map_err(|e| format!("Error writing {}: {}", path, e))?;
+            }
+            Ok(())
+        }
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
+            langcodec::converter::convert_resources_to_format(codec.resources.clone(), path, fmt)
+                .map_err(|e| format!("Error writing {}: {}", path, e))
+        }
+        FormatType::Xliff(_) => Err(
+            ".xliff is not supported by `rename`. Convert XLIFF into a standard project format first."
+                .to_string(),
+        ),
+    }
+}
+
+/// Renames keys in every language of every input.
+///
+/// Collisions are checked across all inputs before anything is written, so a
+/// rename either applies to every file or to none.
+pub fn run_rename_command(opts: RenameCliOptions) -> Result<(), String> {
+    let mapping = load_mapping(&opts)?;
+    let inputs = path_glob::expand_input_globs(&opts.inputs)
+        .map_err(|e| format!("Failed to expand input patterns: {}", e))?;
+    if inputs.is_empty() {
+        return Err("No input files matched the provided patterns".to_string());
+    }
+
+    let mut codecs = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        validate_file_path(input)?;
+        let mut codec = Codec::new();
+        codec
+            .read_file_by_extension_with_options(
+                input,
+                &ReadOptions::new().with_strict(opts.strict),
+            )
+            .map_err(|e| format!("Failed to read input '{}': {}", input, e))?;
+        codecs.push(codec);
+    }
+
+    let resources: Vec<Resource> = codecs
+        .iter()
+        .flat_map(|codec| codec.resources.iter().cloned())
+        .collect();
+    let collisions = find_rename_collisions(&resources, &mapping);
+    if !collisions.is_empty() {
+        for collision in &collisions {
+            eprintln!(
+                "{}",
+                ui::status_line_stderr(ui::Tone::Error, &collision.to_string())
+            );
+        }
+        return Err(format!(
+            "{} collision(s); no files were changed",
+            collisions.len()
+        ));
+    }
+
+    let mut reports: Vec<(String, RenameReport)> = Vec::with_capacity(inputs.len());
+    for (codec, input) in codecs.iter_mut().zip(&inputs) {
+        let report = codec.bulk_rename(&mapping).map_err(|e| e.to_string())?;
+        reports.push((input.clone(), report));
+    }
+    // A mapped key is only unmatched if no input has it.
+    let mut unmatched: Vec<String> = match &mapping {
+        KeyMapping::Pairs(pairs) => pairs.keys().cloned().collect(),
+        KeyMapping::Pattern { .. } => Vec::new(),
+    };
+    unmatched.retain(|key| {
+        reports
+            .iter()
+            .all(|(_, report)| report.unmatched.contains(key))
+    });
+    let renamed: usize = reports.iter().map(|(_, report)| report.renamed.len()).sum();
+    let changed_files = reports
+        .iter()
+        .filter(|(_, report)| !report.renamed.is_empty())
+        .count();
+
+    if opts.json {
+        let files: Vec<_> = reports
+            .iter()
+            .map(|(path, report)| json!({ "path": path, "renamed": report.renamed }))
+            .collect();
+        let body = json!({
+            "files": files,
+            "unmatched": unmatched,
+            "summary": { "renamed": renamed, "files_changed": changed_files },
+        });
+        let text = serde_json::to_string_pretty(&body)
+            .map_err(|e| format!("Failed to serialize report JSON: {}", e))?;
+        println!("{}", text);
+    } else {
+        for (path, report) in &reports {
+            for rename in &report.renamed {
+                println!(
+                    "{}",
+                    ui::status_line_stdout(
+                        ui::Tone::Info,
+                        &format!(
+                            "{} [{}] {} -> {}",
+                            path, rename.language, rename.from, rename.to
+                        )
+                    )
+                );
+            }
+        }
+        for key in &unmatched {
+            println!(
+                "{}",
+                ui::status_line_stdout(
+                    ui::Tone::Warning,
+                    &format!("Key not found in any input: {}", key)
+                )
+            );
+        }
+        let prefix = if opts.dry_run { "DRY-RUN: " } else { "" };
+        println!(
+            "{}",
+            ui::status_line_stdout(
+                ui::Tone::Success,
+                &format!(
+                    "{}Renamed {} entries in {} file(s)",
+                    prefix, renamed, changed_files
+                )
+            )
+        );
+    }
+    if opts.dry_run {
+        return Ok(());
+    }
+
+    for ((codec, input), (_, report)) in codecs.iter().zip(&inputs).zip(&reports) {
+        if !report.renamed.is_empty() {
+            write_back(codec, input)?;
+        }
+    }
+    Ok(())
+}
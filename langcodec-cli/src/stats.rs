@@ -1,5 +1,8 @@
 use crate::ui;
-use langcodec::{Codec, collect_resource_plural_issues, types::EntryStatus};
+use langcodec::{
+    Codec, LengthOverrun, PlaceholderIssue, check_lengths, collect_resource_plural_issues,
+    types::EntryStatus,
+};
 use serde_json::json;
 use std::collections::HashMap;
 
@@ -42,6 +45,22 @@ pub fn print_stats(
             .collect(),
         None => codec.resources.iter().collect(),
     };
+    let placeholder_issues = codec.collect_placeholder_issues();
+    let issues_for = |language: &str| -> Vec<&PlaceholderIssue> {
+        placeholder_issues
+            .iter()
+            .filter(|issue| issue.language == language)
+            .collect()
+    };
+    // Limits on source entries apply to every language, so check all
+    // resources even when filtering.
+    let length_overruns = check_lengths(&codec.resources);
+    let overruns_for = |language: &str| -> Vec<&LengthOverrun> {
+        length_overruns
+            .iter()
+            .filter(|overrun| overrun.language == language)
+            .collect()
+    };
 
     if json_output {
         // Build JSON object
@@ -67,11 +86,21 @@ pub fn print_stats(
                 "completion_percent": (percent * 100.0).round() / 100.0,
                 "missing_plural_entries": missing_plural_entries,
                 "missing_plural_categories_total": missing_plural_categories_total,
+                "placeholder_issues": issues_for(&res.metadata.language),
+                "length_overruns": overruns_for(&res.metadata.language),
             }));
         }
         let summary = json!({
             "languages": resources.len(),
             "unique_keys": codec.all_keys().count(),
+            "placeholder_issues": resources
+                .iter()
+                .map(|res| issues_for(&res.metadata.language).len())
+                .sum::<usize>(),
+            "length_overruns": resources
+                .iter()
+                .map(|res| overruns_for(&res.metadata.language).len())
+                .sum::<usize>(),
         });
         let body = json!({
             "summary": summary,
@@ -129,6 +158,39 @@ pub fn print_stats(
                     ),
                 )
             );
+            let issue_count = issues_for(&res.metadata.language).len();
+            println!(
+                "{}",
+                ui::key_value(
+                    "Placeholder issues",
+                    ui::tone_text(
+                        &issue_count.to_string(),
+                        if issue_count == 0 {
+                            ui::Tone::Success
+                        } else {
+                            ui::Tone::Warning
+                        },
+                    ),
+                )
+            );
+            let overruns = overruns_for(&res.metadata.language);
+            println!(
+                "{}",
+                ui::key_value(
+                    "Length overruns",
+                    ui::tone_text(
+                        &overruns.len().to_string(),
+                        if overruns.is_empty() {
+                            ui::Tone::Success
+                        } else {
+                            ui::Tone::Warning
+                        },
+                    ),
+                )
+            );
+            for overrun in overruns {
+                println!("  {}", ui::tone_text(&overrun.to_string(), ui::Tone::Muted));
+            }
             println!(
                 "{}",
                 ui::key_value(
@@ -252,6 +314,15 @@ pub fn print_stats(
             "  Missing plurals: {} (missing categories: {})",
             missing_plural_entries, missing_plural_categories_total
         );
+        println!(
+            "  Placeholder issues: {}",
+            issues_for(&res.metadata.language).len()
+        );
+        let overruns = overruns_for(&res.metadata.language);
+        println!("  Length overruns: {}", overruns.len());
+        for overrun in overruns {
+            println!("    {}", overrun);
+        }
     }
     Ok(())
 }
@@ -90,7 +90,7 @@ fn write_back(
             langcodec::Codec::write_resource_to_file(res, out)
                 .map_err(|e| format!("Error writing output: {}", e))
         }
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV => {
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
             langcodec::converter::convert_resources_to_format(codec.resources.clone(), out, fmt)
                 .map_err(|e| format!("Error writing output: {}", e))
         }
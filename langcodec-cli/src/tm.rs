@@ -0,0 +1,217 @@
+use crate::convert::read_resources_from_any_input;
+use crate::path_glob;
+use crate::ui;
+use crate::validation::{validate_file_path, validate_output_path};
+use langcodec::{
+    Codec, FormatType, Resource, TmFillOptions, TmFillReport, TranslationMemory, fill_from_memory,
+};
+
+#[derive(Debug, Clone)]
+pub struct TmBuildOptions {
+    pub inputs: Vec<String>,
+    pub output: String,
+    pub source_lang: Option<String>,
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TmFillCliOptions {
+    pub input: String,
+    pub source: Option<String>,
+    pub memory: Vec<String>,
+    pub output: Option<String>,
+    pub source_lang: Option<String>,
+    pub langs: Vec<String>,
+    pub min_score: u8,
+    pub dry_run: bool,
+    pub json: bool,
+    pub strict: bool,
+}
+
+fn is_tmx(path: &str) -> bool {
+    path.to_ascii_lowercase().ends_with(".tmx")
+}
+
+/// Loads TMX files and localization files into one memory.
+///
+/// Files holding a single language (`.strings`, `strings.xml`) are pooled so
+/// the source language and its translations may come from different files.
+fn load_memory(
+    inputs: &[String],
+    source_lang: Option<&String>,
+    strict: bool,
+) -> Result<TranslationMemory, String> {
+    let inputs = path_glob::expand_input_globs(&inputs.to_vec())
+        .map_err(|e| format!("Failed to expand input patterns: {}", e))?;
+    if inputs.is_empty() {
+        return Err("No input files matched the provided patterns".to_string());
+    }
+
+    let mut memory = TranslationMemory::new();
+    let mut pooled: Vec<Resource> = Vec::new();
+    for input in &inputs {
+        validate_file_path(input)?;
+        if is_tmx(input) {
+            let loaded = TranslationMemory::read_tmx(input)
+                .map_err(|e| format!("Failed to read TMX '{}': {}", input, e))?;
+            memory.extend(loaded);
+            continue;
+        }
+        let resources = read_resources_from_any_input(input, None, strict)?;
+        if resources.len() == 1 {
+            pooled.extend(resources);
+        } else {
+            memory
+                .add_resources(&resources, source_lang.map(String::as_str))
+                .map_err(|e| format!("Failed to index '{}': {}", input, e))?;
+        }
+    }
+    if !pooled.is_empty() {
+        let source_lang = source_lang.map(String::as_str).ok_or_else(|| {
+            "Pass --source-lang to index single-language files such as strings.xml".to_string()
+        })?;
+        memory
+            .add_resources(&pooled, Some(source_lang))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(memory)
+}
+
+/// Builds a TMX translation memory from localization files and TMX files.
+pub fn run_tm_build_command(opts: TmBuildOptions) -> Result<(), String> {
+    if !is_tmx(&opts.output) {
+        return Err(format!(
+            "Translation memories are written as TMX; use a .tmx output path: {}",
+            opts.output
+        ));
+    }
+    validate_output_path(&opts.output)?;
+    let memory = load_memory(&opts.inputs, opts.source_lang.as_ref(), opts.strict)?;
+    memory
+        .write_tmx(&opts.output)
+        .map_err(|e| format!("Error writing {}: {}", opts.output, e))?;
+
+    println!(
+        "{}",
+        ui::status_line_stdout(
+            ui::Tone::Success,
+            &format!(
+                "Wrote {} translation units to {}",
+                memory.len(),
+                opts.output
+            ),
+        )
+    );
+    Ok(())
+}
+
+fn write_back(resources: &[Resource], path: &str) -> Result<(), String> {
+    let fmt = langcodec::infer_format_from_path(path)
+        .or_else(|| langcodec::infer_format_from_extension(path))
+        .ok_or_else(|| format!("Cannot infer format from path: {}", path))?;
+    match fmt {
+        FormatType::Strings(_) | FormatType::AndroidStrings(_) => {
+            let [resource] = resources else {
+                return Err(
+                    "Multiple languages present; single-language output requires exactly one resource"
+                        .to_string(),
+                );
+            };
+            if std::path::Path::new(path).exists() {
+                Codec::update_resource_file(resource, path)
+            } else {
+                Codec::write_resource_to_file(resource, path)
+            }
+            .map_err(|e| format!("Error writing {}: {}", path, e))
+        }
+        other => langcodec::converter::convert_resources_to_format(resources.to_vec(), path, other)
+            .map_err(|e| format!("Error writing {}: {}", path, e)),
+    }
+}
+
+fn print_report(report: &TmFillReport, min_score: u8, dry_run: bool) {
+    for fill in &report.fills {
+        println!(
+            "{}",
+            ui::status_line_stdout(
+                ui::Tone::Info,
+                &format!(
+                    "{} {}: {}% match for \"{}\"",
+                    fill.language, fill.key, fill.score, fill.matched_source
+                ),
+            )
+        );
+    }
+    let prefix = if dry_run { "DRY-RUN: " } else { "" };
+    println!(
+        "{}",
+        ui::status_line_stdout(
+            ui::Tone::Success,
+            &format!(
+                "{}Filled {} of {} untranslated entries ({} exact, {} fuzzy); {} below {}%",
+                prefix,
+                report.exact + report.fuzzy,
+                report.candidates,
+                report.exact,
+                report.fuzzy,
+                report.unmatched,
+                min_score
+            ),
+        )
+    );
+}
+
+/// Pre-fills untranslated entries of `opts.input` from translation memory as
+/// `needs_review`.
+pub fn run_tm_fill_command(opts: TmFillCliOptions) -> Result<(), String> {
+    let memory = load_memory(&opts.memory, opts.source_lang.as_ref(), opts.strict)?;
+
+    validate_file_path(&opts.input)?;
+    let mut resources = read_resources_from_any_input(&opts.input, None, opts.strict)?;
+    let mut source_file_languages: Vec<String> = Vec::new();
+    if let Some(source) = &opts.source {
+        validate_file_path(source)?;
+        for resource in read_resources_from_any_input(source, None, opts.strict)? {
+            if !resources
+                .iter()
+                .any(|res| res.metadata.language == resource.metadata.language)
+            {
+                source_file_languages.push(resource.metadata.language.clone());
+                resources.push(resource);
+            }
+        }
+    }
+
+    let report = fill_from_memory(
+        &mut resources,
+        &memory,
+        &TmFillOptions {
+            source_language: opts.source_lang.clone(),
+            target_languages: opts.langs.clone(),
+            min_score: opts.min_score,
+        },
+    )
+    .map_err(|e| e.to_string())?;
+
+    if opts.json {
+        let text = serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize report JSON: {}", e))?;
+        println!("{}", text);
+    } else {
+        print_report(&report, opts.min_score, opts.dry_run);
+    }
+    if opts.dry_run || report.fills.is_empty() {
+        return Ok(());
+    }
+
+    // The separate source file is read-only; everything else belongs to the input.
+    let written: Vec<Resource> = resources
+        .into_iter()
+        .filter(|res| !source_file_languages.contains(&res.metadata.language))
+        .collect();
+    let output = opts.output.as_deref().unwrap_or(&opts.input);
+    if output != opts.input {
+        validate_output_path(output)?;
+    }
+    write_back(&written, output)
+}
@@ -870,7 +870,7 @@ fn translation_is_empty(translation: &Translation) -> bool {
     match translation {
         Translation::Empty => true,
         Translation::Singular(value) => value.trim().is_empty(),
-        Translation::Plural(_) => false,
+        Translation::Plural(_) | Translation::Select(_) => false,
     }
 }
 
@@ -2,6 +2,7 @@ use crate::validation::{validate_language_code, validate_output_path};
 use crate::{
     ai::{ProviderKind, build_provider, resolve_model, resolve_provider},
     config::{LoadedConfig, load_config, resolve_config_relative_path},
+    glossary::{glossary_prompt_lines, load_glossary},
     path_glob,
     tolgee::{
         TranslateTolgeeContext, TranslateTolgeeSettings, prefill_translate_from_tolgee,
@@ -15,9 +16,11 @@ use crate::{
 };
 use async_trait::async_trait;
 use langcodec::{
-    Codec, Entry, EntryStatus, FormatType, Metadata, ReadOptions, Resource, Translation,
-    convert_resources_to_format,
-    formats::{AndroidStringsFormat, CSVFormat, StringsFormat, TSVFormat, XcstringsFormat},
+    Codec, Entry, EntryStatus, FormatType, Glossary, GlossaryIssue, Metadata, ReadOptions,
+    Resource, Translation, convert_resources_to_format,
+    formats::{
+        AndroidStringsFormat, CSVFormat, StringsFormat, TSVFormat, XLSXFormat, XcstringsFormat,
+    },
     infer_format_from_extension, infer_language_from_path,
     traits::Parser,
 };
@@ -57,6 +60,7 @@ pub struct TranslateOptions {
     pub use_tolgee: bool,
     pub tolgee_config: Option<String>,
     pub tolgee_namespaces: Vec<String>,
+    pub glossary: Vec<String>,
     pub dry_run: bool,
     pub strict: bool,
     pub ui_mode: UiMode,
@@ -79,6 +83,7 @@ struct ResolvedOptions {
     use_tolgee: bool,
     tolgee_config: Option<String>,
     tolgee_namespaces: Vec<String>,
+    glossary: Vec<String>,
     dry_run: bool,
     strict: bool,
     ui_mode: ResolvedUiMode,
@@ -98,6 +103,7 @@ struct TranslationJob {
     source_value: String,
     source_comment: Option<String>,
     existing_comment: Option<String>,
+    glossary_hints: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -139,6 +145,8 @@ struct PreparedTranslation {
     source_resource: SelectedResource,
     target_codec: Codec,
     tolgee_context: Option<TranslateTolgeeContext>,
+    glossary: Option<Glossary>,
+    glossary_issues: Vec<GlossaryIssue>,
     jobs: Vec<TranslationJob>,
     summary: TranslationSummary,
 }
@@ -156,6 +164,7 @@ struct BackendRequest {
     target_lang: String,
     source_value: String,
     source_comment: Option<String>,
+    glossary_hints: Vec<String>,
 }
 
 enum TranslationWorkerUpdate {
@@ -347,6 +356,7 @@ fn expand_translate_invocations(opts: &TranslateOptions) -> Result<Vec<Translate
             use_tolgee: opts.use_tolgee,
             tolgee_config: opts.tolgee_config.clone(),
             tolgee_namespaces: opts.tolgee_namespaces.clone(),
+            glossary: opts.glossary.clone(),
             dry_run: opts.dry_run,
             strict: opts.strict,
             ui_mode: opts.ui_mode,
@@ -472,6 +482,7 @@ async fn async_run_translation(
                         target_lang: job.target_lang.clone(),
                         source_value: job.source_value.clone(),
                         source_comment: job.source_comment.clone(),
+                        glossary_hints: job.glossary_hints.clone(),
                     })
                     .await
                     .map(|translated_value| TranslationResult {
@@ -585,6 +596,12 @@ async fn async_run_translation(
         tone: DashboardLogTone::Info,
         message: "Applying translated values".to_string(),
     });
+    for issue in &prepared.glossary_issues {
+        reporter.emit(DashboardEvent::Log {
+            tone: DashboardLogTone::Warning,
+            message: format!("{} (marked needs_review)", issue),
+        });
+    }
     if let Err(err) = validate_translated_output(&prepared) {
         reporter.emit(DashboardEvent::Log {
             tone: DashboardLogTone::Error,
@@ -755,13 +772,24 @@ fn prepare_translation(opts: &TranslateOptions) -> Result<PreparedTranslation, S
         resolved.strict,
     )?;
 
-    let (jobs, summary) = build_jobs(
+    let (mut jobs, summary) = build_jobs(
         &source_resource.resource,
         &target_codec,
         &resolved.target_langs,
         &resolved.statuses,
         target_supports_explicit_status(&target_path),
     )?;
+    let glossary = load_glossary(
+        &resolved.glossary,
+        config.as_ref(),
+        Some(&source_resource.language),
+    )?;
+    if let Some(glossary) = &glossary {
+        for job in &mut jobs {
+            job.glossary_hints =
+                glossary_prompt_lines(glossary, &job.source_value, &job.target_lang);
+        }
+    }
 
     Ok(PreparedTranslation {
         opts: resolved,
@@ -773,6 +801,8 @@ fn prepare_translation(opts: &TranslateOptions) -> Result<PreparedTranslation, S
         source_resource,
         target_codec,
         tolgee_context,
+        glossary,
+        glossary_issues: Vec::new(),
         jobs,
         summary,
     })
@@ -931,13 +961,32 @@ fn apply_translation_results(
         else {
             continue;
         };
+        let issues = prepared
+            .glossary
+            .as_ref()
+            .map(|glossary| {
+                glossary.check_translation(
+                    &job.key,
+                    &job.target_lang,
+                    &job.source_value,
+                    translated_value,
+                )
+            })
+            .unwrap_or_default();
+        // Glossary violations are kept but flagged for a reviewer.
+        let status = if issues.is_empty() {
+            prepared.opts.output_status.clone()
+        } else {
+            EntryStatus::NeedsReview
+        };
+        prepared.glossary_issues.extend(issues);
 
         if let Some(existing) = prepared
             .target_codec
             .find_entry_mut(&job.key, &job.target_lang)
         {
             existing.value = Translation::Singular(translated_value.clone());
-            existing.status = prepared.opts.output_status.clone();
+            existing.status = status;
         } else {
             prepared
                 .target_codec
@@ -948,7 +997,7 @@ fn apply_translation_results(
                     job.existing_comment
                         .clone()
                         .or_else(|| job.source_comment.clone()),
-                    Some(prepared.opts.output_status.clone()),
+                    Some(status),
                 )
                 .map_err(|e| e.to_string())?;
         }
@@ -1045,6 +1094,14 @@ fn validate_output_serialization(
                 .to_writer(&mut out)
                 .map_err(|e| format!("Error serializing TSV output: {}", e))
         }
+        FormatType::XLSX => {
+            let format = XLSXFormat::try_from(codec.resources.clone())
+                .map_err(|e| format!("Error building XLSX output: {}", e))?;
+            let mut out = Vec::new();
+            format
+                .to_writer(&mut out)
+                .map_err(|e| format!("Error serializing XLSX output: {}", e))
+        }
     }
     .map_err(|err| format!("{} ({})", err, output_path))
 }
@@ -1070,7 +1127,8 @@ fn build_jobs(
             }
 
             let source_text = match &entry.value {
-                Translation::Plural(_) => {
+                // Plural and select variants are not sent for translation yet.
+                Translation::Plural(_) | Translation::Select(_) => {
                     summary.skipped_plural += 1;
                     continue;
                 }
@@ -1108,6 +1166,7 @@ fn build_jobs(
                 source_value: source_text.clone(),
                 source_comment: entry.comment.clone(),
                 existing_comment: target_entry.and_then(|item| item.comment.clone()),
+                glossary_hints: Vec::new(),
             });
             summary.queued += 1;
         }
@@ -1317,6 +1376,7 @@ fn resolve_options(
         use_tolgee,
         tolgee_config,
         tolgee_namespaces,
+        glossary: opts.glossary.clone(),
         dry_run: opts.dry_run,
         strict: opts.strict,
         ui_mode,
@@ -1491,7 +1551,7 @@ fn normalize_lang(lang: &str) -> String {
 fn is_multi_language_format(format: &FormatType) -> bool {
     matches!(
         format,
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX
     )
 }
 
@@ -1529,7 +1589,7 @@ fn write_back(
             Codec::write_resource_to_file(resource, output_path)
                 .map_err(|e| format!("Error writing output: {}", e))
         }
-        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV => {
+        FormatType::Xcstrings | FormatType::CSV | FormatType::TSV | FormatType::XLSX => {
             convert_resources_to_format(codec.resources.clone(), output_path, output_format.clone())
                 .map_err(|e| format!("Error writing output: {}", e))
         }
@@ -1587,6 +1647,14 @@ fn build_prompt(request: &BackendRequest) -> String {
         prompt.push_str(comment);
         prompt.push('\n');
     }
+    if !request.glossary_hints.is_empty() {
+        prompt.push_str("\nGlossary (use these terms exactly):\n");
+        for hint in &request.glossary_hints {
+            prompt.push_str("- ");
+            prompt.push_str(hint);
+            prompt.push('\n');
+        }
+    }
     prompt.push_str(
         "\nReturn JSON only in this exact shape: {\"translation\":\"...\"}. Do not wrap in markdown fences unless necessary.",
     );
@@ -1699,6 +1767,7 @@ mod tests {
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: false,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2044,6 +2113,7 @@ status = ["new", "stale"]
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2089,6 +2159,7 @@ lang = ["fr", "de"]
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2157,6 +2228,7 @@ status = "translated"
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: false,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2182,6 +2254,102 @@ status = "translated"
         );
     }
 
+    #[test]
+    fn glossary_terms_reach_prompt_and_flag_violations() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("Localizable.xcstrings");
+        let config = temp_dir.path().join("langcodec.toml");
+        fs::write(
+            &source,
+            r#"{
+  "sourceLanguage" : "en",
+  "version" : "1.0",
+  "strings" : {
+    "sign_in" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "new", "value" : "Sign in to Acme" } }
+      }
+    },
+    "welcome" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "new", "value" : "Welcome to Acme" } }
+      }
+    }
+  }
+}"#,
+        )
+        .unwrap();
+        fs::write(
+            &config,
+            r#"[openai]
+model = "gpt-5.4"
+
+[translate.input]
+source = "Localizable.xcstrings"
+lang = "en"
+
+[translate.output]
+lang = ["fr"]
+status = "translated"
+
+[glossary]
+do_not_translate = ["Acme"]
+
+[glossary.terms."Sign in"]
+fr = "Se connecter"
+"#,
+        )
+        .unwrap();
+
+        let mut options = base_options(&source, None);
+        options.source = None;
+        options.config = Some(config.to_string_lossy().to_string());
+        let runs = expand_translate_invocations(&options).unwrap();
+        let prepared = prepare_translation(&runs[0]).unwrap();
+        let sign_in = prepared
+            .jobs
+            .iter()
+            .find(|job| job.key == "sign_in")
+            .unwrap();
+        let prompt = build_prompt(&BackendRequest {
+            key: sign_in.key.clone(),
+            source_lang: sign_in.source_lang.clone(),
+            target_lang: sign_in.target_lang.clone(),
+            source_value: sign_in.source_value.clone(),
+            source_comment: None,
+            glossary_hints: sign_in.glossary_hints.clone(),
+        });
+        assert!(prompt.contains("Glossary"), "{}", prompt);
+        assert!(
+            prompt.contains("\"Sign in\" => \"Se connecter\""),
+            "{}",
+            prompt
+        );
+        assert!(prompt.contains("\"Acme\": keep unchanged"), "{}", prompt);
+
+        let output_path = prepared.output_path.clone();
+        run_prepared_translation(
+            prepared,
+            Some(Arc::new(MockBackend::new(vec![
+                (("sign_in", "fr"), Ok("Connectez-vous à Acme".to_string())),
+                (("welcome", "fr"), Ok("Bienvenue sur Acme".to_string())),
+            ]))),
+        )
+        .unwrap();
+
+        let written = fs::read_to_string(output_path).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&written).unwrap();
+        let localizations = |key: &str| parsed["strings"][key]["localizations"]["fr"].clone();
+        assert_eq!(
+            localizations("sign_in")["stringUnit"]["state"],
+            "needs_review"
+        );
+        assert_eq!(
+            localizations("welcome")["stringUnit"]["state"],
+            "translated"
+        );
+    }
+
     #[test]
     fn rejects_invalid_output_status_from_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -2217,6 +2385,7 @@ status = "new"
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2256,6 +2425,7 @@ target = "output/Translated.xcstrings"
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2311,6 +2481,7 @@ sources = ["one.xcstrings", "two.xcstrings"]
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2383,6 +2554,7 @@ sources = ["Modules/*/Localizable.xcstrings"]
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2431,6 +2603,7 @@ target = "translated.xcstrings"
             use_tolgee: false,
             tolgee_config: None,
             tolgee_namespaces: Vec::new(),
+            glossary: Vec::new(),
             dry_run: true,
             strict: false,
             ui_mode: UiMode::Plain,
@@ -2553,6 +2726,7 @@ target = "translated.xcstrings"
             target_lang: "fr".to_string(),
             source_value: "代码过期倒计时".to_string(),
             source_comment: Some("A label displayed below the code expiration timer.".to_string()),
+            glossary_hints: Vec::new(),
         });
 
         assert!(prompt.contains("Comment:"));
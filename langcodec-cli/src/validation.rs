@@ -153,8 +153,9 @@ pub fn validate_standard_format(format: &str) -> Result<(), String> {
         "xliff" => Ok(()),
         "csv" => Ok(()),
         "tsv" => Ok(()),
+        "xlsx" => Ok(()),
         _ => Err(format!(
-            "Unsupported standard format: {}. Supported formats: android, strings, xcstrings, xliff, csv, tsv",
+            "Unsupported standard format: {}. Supported formats: android, strings, xcstrings, xliff, csv, tsv, xlsx",
             format
         )),
     }
@@ -1,6 +1,6 @@
 use crate::ui;
 use langcodec::{
-    Codec,
+    Codec, PlaceholderIssue,
     types::{EntryStatus, PluralCategory, Translation},
 };
 use serde_json::{Map, Value, json};
@@ -105,6 +105,7 @@ fn styled_status_label(status: &EntryStatus) -> String {
 
 fn render_json_output(
     filtered_resources: &[(&langcodec::Resource, Vec<&langcodec::types::Entry>)],
+    placeholder_issues: &[PlaceholderIssue],
     keys_only: bool,
 ) -> Result<String, String> {
     let mut total_matches = 0usize;
@@ -112,6 +113,7 @@ fn render_json_output(
     let mut status_counts: BTreeMap<String, usize> = BTreeMap::new();
     let mut entries_payload = Vec::new();
     let mut keys_payload = Vec::new();
+    let mut issue_count = 0usize;
 
     for (resource, entries) in filtered_resources {
         for entry in entries {
@@ -119,6 +121,13 @@ fn render_json_output(
             total_matches += 1;
             let status = status_label(&entry.status).to_string();
             *status_counts.entry(status.clone()).or_insert(0) += 1;
+            let issues: Vec<&PlaceholderIssue> = placeholder_issues
+                .iter()
+                .filter(|issue| {
+                    issue.key == entry.id && issue.language == resource.metadata.language
+                })
+                .collect();
+            issue_count += issues.len();
 
             if keys_only {
                 keys_payload.push(json!({
@@ -151,12 +160,21 @@ fn render_json_output(
                     }
                     entry_json.insert("forms".to_string(), Value::Object(forms));
                 }
+                Translation::Select(select) => {
+                    entry_json.insert("type".to_string(), json!("select"));
+                    entry_json.insert("selector".to_string(), json!(select.selector));
+                    entry_json.insert("cases".to_string(), json!(select.cases));
+                }
             }
 
             if let Some(comment) = &entry.comment {
                 entry_json.insert("comment".to_string(), json!(comment));
             }
 
+            if !issues.is_empty() {
+                entry_json.insert("placeholder_issues".to_string(), json!(issues));
+            }
+
             entries_payload.push(Value::Object(entry_json));
         }
     }
@@ -165,6 +183,7 @@ fn render_json_output(
         "total_matches": total_matches,
         "languages": languages.into_iter().collect::<Vec<_>>(),
         "statuses": status_counts,
+        "placeholder_issues": issue_count,
     });
 
     let payload = if keys_only {
@@ -292,7 +311,11 @@ pub fn print_view(codec: &Codec, lang_filter: &Option<String>, opts: &ViewOption
     }
 
     if opts.json {
-        let rendered = match render_json_output(&visible_resources, opts.keys_only) {
+        let rendered = match render_json_output(
+            &visible_resources,
+            &codec.collect_placeholder_issues(),
+            opts.keys_only,
+        ) {
             Ok(text) => text,
             Err(err) => {
                 eprintln!("{}", ui::status_line_stderr(ui::Tone::Error, &err));
@@ -394,6 +417,21 @@ pub fn print_view(codec: &Codec, lang_filter: &Option<String>, opts: &ViewOption
                             );
                         }
                     }
+                    Translation::Select(select) => {
+                        println!(
+                            "  {}",
+                            ui::key_value("Type", ui::tone_text("select", ui::Tone::Accent))
+                        );
+                        println!("  {}", ui::key_value("Selector", &select.selector));
+                        for (case, value) in &select.cases {
+                            let rendered = if opts.full {
+                                value.clone()
+                            } else {
+                                truncate_chars(value, 90)
+                            };
+                            println!("  {}", ui::key_value(case, rendered));
+                        }
+                    }
                 }
             }
         }
@@ -466,6 +504,18 @@ pub fn print_view(codec: &Codec, lang_filter: &Option<String>, opts: &ViewOption
                         }
                     }
                 }
+                Translation::Select(select) => {
+                    println!("    Type: Select");
+                    println!("    Selector: {}", select.selector);
+                    for (case, value) in &select.cases {
+                        if opts.full {
+                            println!("      {}: {}", case, value);
+                        } else {
+                            let truncated = truncate_chars(value, 50);
+                            println!("      {}: {}", case, truncated);
+                        }
+                    }
+                }
             }
         }
     }
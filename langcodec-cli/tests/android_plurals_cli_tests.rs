@@ -39,3 +39,36 @@ fn test_cli_view_android_plurals() {
     assert!(stdout.contains("One apple") || stdout.contains("one"));
     assert!(stdout.contains("%d apples") || stdout.contains("other"));
 }
+
+#[test]
+fn test_convert_strings_to_android_escapes_for_aapt() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    let output_path = temp_dir.path().join("values").join("strings.xml");
+    fs::write(
+        &input,
+        "\"apostrophe\" = \"Don't stop\";\n\"handle\" = \"@username\";\n\"quoted\" = \"He said \\\"Hi\\\"\";\n",
+    )
+    .unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output_path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let xml = fs::read_to_string(&output_path).unwrap();
+    assert!(xml.contains(r"Don\'t stop"), "{xml}");
+    assert!(xml.contains(r"\@username"), "{xml}");
+    assert!(xml.contains(r#"He said \"Hi\""#), "{xml}");
+}
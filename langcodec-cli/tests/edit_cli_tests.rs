@@ -631,3 +631,87 @@ fn test_edit_set_xcstrings_preserves_translatable_metadata_only_entry() {
         "Game Updated"
     );
 }
+
+#[test]
+fn test_edit_set_in_place_preserves_android_layout() {
+    let temp_dir = TempDir::new().unwrap();
+    let values_dir = temp_dir.path().join("values");
+    fs::create_dir_all(&values_dir).unwrap();
+    let input_file = values_dir.join("strings.xml");
+
+    let initial = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:tools="http://schemas.android.com/tools">
+    <!-- Onboarding -->
+    <string name="welcome" tools:ignore="MissingTranslation">Welcome</string>
+    <string name="skip">Skip</string>
+
+    <dimen name="padding">16dp</dimen>
+</resources>
+"#;
+    fs::write(&input_file, initial).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "edit",
+            "set",
+            "-i",
+            input_file.to_str().unwrap(),
+            "--lang",
+            "en",
+            "-k",
+            "skip",
+            "-v",
+            "Not now",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let after = fs::read_to_string(&input_file).unwrap();
+    assert_eq!(after, initial.replace(">Skip<", ">Not now<"));
+}
+
+#[test]
+fn test_edit_set_in_place_preserves_strings_comments() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("en.strings");
+
+    let initial = r#"// MARK: - Onboarding
+
+/* Greeting */
+"hello" = "Hello";
+
+// MARK: - Inbox
+"inbox" = "Inbox";
+"#;
+    fs::write(&input_file, initial).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "edit",
+            "set",
+            "-i",
+            input_file.to_str().unwrap(),
+            "-k",
+            "hello",
+            "-v",
+            "Hi there",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let after = fs::read_to_string(&input_file).unwrap();
+    assert_eq!(
+        after,
+        initial.replace("\"hello\" = \"Hello\";", "\"hello\" = \"Hi there\";")
+    );
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+const CATALOG: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "sign_in" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Sign in to Acme" } },
+        "fr" : { "stringUnit" : { "state" : "translated", "value" : "Connectez-vous à Akme" } },
+        "de" : { "stringUnit" : { "state" : "translated", "value" : "Bei Acme anmelden" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+#[test]
+fn test_glossary_check_reports_csv_term_violations() {
+    let temp_dir = TempDir::new().unwrap();
+    let catalog = temp_dir.path().join("Localizable.xcstrings");
+    let glossary = temp_dir.path().join("glossary.csv");
+    fs::write(&catalog, CATALOG).unwrap();
+    fs::write(
+        &glossary,
+        "term,fr,de,do_not_translate,note\nSign in,Se connecter,Anmelden,,Login action\nAcme,,,true,Brand name\n",
+    )
+    .unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "glossary",
+            "-i",
+            catalog.to_str().unwrap(),
+            "--glossary",
+            glossary.to_str().unwrap(),
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(stderr.contains("2 glossary issue(s) found"), "{}", stderr);
+
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    assert_eq!(report["source_language"], "en");
+    let issues = report["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 2, "{:?}", issues);
+    assert!(issues.iter().all(|issue| issue["language"] == "fr"));
+    let kinds: Vec<&str> = issues
+        .iter()
+        .map(|issue| issue["kind"].as_str().unwrap())
+        .collect();
+    assert!(kinds.contains(&"missing_term"), "{:?}", kinds);
+    assert!(kinds.contains(&"translated_protected_term"), "{:?}", kinds);
+}
+
+#[test]
+fn test_glossary_check_uses_config_terms() {
+    let temp_dir = TempDir::new().unwrap();
+    let catalog = temp_dir.path().join("Localizable.xcstrings");
+    let config = temp_dir.path().join("langcodec.toml");
+    fs::write(&catalog, CATALOG).unwrap();
+    fs::write(
+        &config,
+        "[glossary]\nsource_lang = \"en\"\ndo_not_translate = [\"Acme\"]\n",
+    )
+    .unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "glossary",
+            "-i",
+            catalog.to_str().unwrap(),
+            "--config",
+            config.to_str().unwrap(),
+            "--lang",
+            "de",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&result.stdout);
+    assert!(
+        stdout.contains("All translations follow 1 glossary term(s)"),
+        "{}",
+        stdout
+    );
+}
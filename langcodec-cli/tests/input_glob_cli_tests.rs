@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn write_android(dir: &Path, values_dir: &str) {
+    let values = dir.join(values_dir);
+    fs::create_dir_all(&values).unwrap();
+    fs::write(
+        values.join("strings.xml"),
+        "<resources>\n    <string name=\"ok\">OK</string>\n</resources>\n",
+    )
+    .unwrap();
+}
+
+/// Runs `normalize --check` in `dir` and returns the inputs it checked.
+fn checked_inputs(dir: &Path, inputs: &[&str]) -> Vec<String> {
+    let mut cmd = langcodec_cmd();
+    cmd.current_dir(dir).args(["normalize", "--check"]);
+    for input in inputs {
+        cmd.args(["-i", input]);
+    }
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "normalize failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let mut checked: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.strip_prefix("No changes needed: "))
+        .map(str::to_string)
+        .collect();
+    checked.sort();
+    checked
+}
+
+#[test]
+fn test_glob_starting_mid_segment_matches_every_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    for values_dir in ["values", "values-fr", "values-b+zh+Hans"] {
+        write_android(temp_dir.path(), values_dir);
+    }
+    write_android(temp_dir.path(), "other");
+
+    assert_eq!(
+        checked_inputs(temp_dir.path(), &["values*/strings.xml"]),
+        [
+            "values-b+zh+Hans/strings.xml",
+            "values-fr/strings.xml",
+            "values/strings.xml"
+        ]
+    );
+
+    let absolute = format!("{}/values*/strings.xml", temp_dir.path().display());
+    assert_eq!(checked_inputs(temp_dir.path(), &[&absolute]).len(), 3);
+}
+
+#[test]
+fn test_bare_relative_paths_are_kept_next_to_globs() {
+    let temp_dir = TempDir::new().unwrap();
+    write_android(temp_dir.path(), "values");
+    write_android(temp_dir.path(), "values-fr");
+    fs::write(temp_dir.path().join("en.strings"), "\"ok\" = \"OK\";\n").unwrap();
+
+    assert_eq!(
+        checked_inputs(
+            temp_dir.path(),
+            &[
+                "en.strings",
+                "./values/strings.xml",
+                "values-f*/strings.xml"
+            ]
+        ),
+        [
+            "./values/strings.xml",
+            "en.strings",
+            "values-fr/strings.xml"
+        ]
+    );
+}
@@ -162,6 +162,9 @@ fn test_langcodec_output_structure() {
                 langcodec::Translation::Plural(p) => {
                     assert!(!p.id.is_empty(), "Plural id should not be empty")
                 }
+                langcodec::Translation::Select(s) => {
+                    assert!(!s.cases.is_empty(), "Select cases should not be empty")
+                }
             }
         }
     }
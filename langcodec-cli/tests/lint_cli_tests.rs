@@ -0,0 +1,272 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+const CATALOG: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "greeting" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Hello, %@!" } },
+        "fr" : { "stringUnit" : { "state" : "translated", "value" : "Bonjour  %@ !" } }
+      }
+    },
+    "help" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Visit https://example.com/help" } },
+        "fr" : { "stringUnit" : { "state" : "translated", "value" : "Visitez https://example.fr/aide" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+#[test]
+fn test_lint_reports_findings_as_json_and_fails_on_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let catalog = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(&catalog, CATALOG).unwrap();
+
+    let result = langcodec_cmd()
+        .args(["lint", "-i", catalog.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    // Only warnings: the default `fail_on = error` passes.
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    assert_eq!(report["source_language"], "en");
+    assert_eq!(report["checked"], 2);
+    let rules: Vec<&str> = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|finding| finding["rule"].as_str().unwrap())
+        .collect();
+    assert_eq!(rules, vec!["double_space", "url_mismatch", "url_mismatch"]);
+    assert_eq!(
+        report["findings"][0]["file"],
+        catalog.to_str().unwrap(),
+        "{}",
+        report
+    );
+    // Points at the French localization of `greeting`.
+    assert_eq!(report["findings"][0]["line"], 7, "{}", report);
+    assert_eq!(report["findings"][0]["column"], 9, "{}", report);
+
+    let result = langcodec_cmd()
+        .args([
+            "lint",
+            "-i",
+            catalog.to_str().unwrap(),
+            "--rule",
+            "url_mismatch=error",
+        ])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(
+        stderr.contains("2 lint finding(s) at or above 'error'"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_lint_android_pair_with_config_severities() {
+    let temp_dir = TempDir::new().unwrap();
+    let values = temp_dir.path().join("values");
+    let values_de = temp_dir.path().join("values-de");
+    fs::create_dir_all(&values).unwrap();
+    fs::create_dir_all(&values_de).unwrap();
+    fs::write(
+        values.join("strings.xml"),
+        "<resources>\n    <string name=\"ok\">OK</string>\n    <string name=\"saved\">Saved.</string>\n</resources>\n",
+    )
+    .unwrap();
+    fs::write(
+        values_de.join("strings.xml"),
+        "<resources>\n    <string name=\"ok\">OK</string>\n    <string name=\"saved\">Gespeichert</string>\n</resources>\n",
+    )
+    .unwrap();
+    let config = temp_dir.path().join("langcodec.toml");
+    fs::write(
+        &config,
+        "[lint]\nsource_lang = \"en\"\nfail_on = \"warning\"\n\n[lint.rules]\nidentical_to_source = \"off\"\n",
+    )
+    .unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "lint",
+            "-i",
+            values.join("strings.xml").to_str().unwrap(),
+            values_de.join("strings.xml").to_str().unwrap(),
+            "--config",
+            config.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+    let stdout = String::from_utf8_lossy(&result.stdout);
+    assert!(
+        stdout.contains("key='saved': translation is missing the final '.' (terminal_punctuation)"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("identical_to_source"), "{}", stdout);
+    assert!(
+        stdout.contains("Checked 2 translations: 0 error(s), 1 warning(s), 0 info"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_lint_report_formats_point_at_android_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let values = temp_dir.path().join("values");
+    let values_fr = temp_dir.path().join("values-fr");
+    fs::create_dir_all(&values).unwrap();
+    fs::create_dir_all(&values_fr).unwrap();
+    let source = values.join("strings.xml");
+    let target = values_fr.join("strings.xml");
+    fs::write(
+        &source,
+        "<resources>\n    <string name=\"title\">Title</string>\n    <plurals name=\"files\">\n        <item quantity=\"one\">%d file</item>\n        <item quantity=\"other\">%d files</item>\n    </plurals>\n</resources>\n",
+    )
+    .unwrap();
+    fs::write(
+        &target,
+        "<resources>\n    <string name=\"title\">Titre</string>\n    <plurals name=\"files\">\n        <item quantity=\"one\">%d fichier</item>\n        <item quantity=\"other\">%d fichiers</item>\n    </plurals>\n</resources>\n",
+    )
+    .unwrap();
+    let run = |format: &str| {
+        let result = langcodec_cmd()
+            .args([
+                "lint",
+                "-i",
+                source.to_str().unwrap(),
+                target.to_str().unwrap(),
+                "--source-lang",
+                "en",
+                "--report-format",
+                format,
+            ])
+            .output()
+            .unwrap();
+        assert!(!result.status.success());
+        String::from_utf8_lossy(&result.stdout).into_owned()
+    };
+
+    let sarif: serde_json::Value = serde_json::from_str(&run("sarif")).unwrap();
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "plural_forms");
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        target.to_str().unwrap().replace('\\', "/")
+    );
+    assert_eq!(location["region"]["startLine"], 3);
+    assert_eq!(location["region"]["startColumn"], 5);
+
+    let github = run("github");
+    assert!(
+        github.starts_with(&format!(
+            "::error file={},line=3,col=5,title=langcodec plural_forms::lang='fr' key='files': missing plural categories: many",
+            target.to_str().unwrap().replace(':', "%3A").replace(',', "%2C")
+        )),
+        "{}",
+        github
+    );
+
+    let junit = run("junit");
+    assert!(junit.starts_with("<?xml"), "{}", junit);
+    assert!(junit.contains("name=\"fr: files\""), "{}", junit);
+}
+
+#[test]
+fn test_lint_applies_config_length_limits() {
+    let temp_dir = TempDir::new().unwrap();
+    let values = temp_dir.path().join("values");
+    let values_de = temp_dir.path().join("values-de");
+    fs::create_dir_all(&values).unwrap();
+    fs::create_dir_all(&values_de).unwrap();
+    fs::write(
+        values.join("strings.xml"),
+        "<resources>\n    <string name=\"save\">Save</string>\n</resources>\n",
+    )
+    .unwrap();
+    fs::write(
+        values_de.join("strings.xml"),
+        "<resources>\n    <string name=\"save\">Speichern</string>\n</resources>\n",
+    )
+    .unwrap();
+    let config = temp_dir.path().join("langcodec.toml");
+    fs::write(
+        &config,
+        "[lint]\nsource_lang = \"en\"\n\n[length.limits]\n\"sa*\" = 8\n",
+    )
+    .unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "lint",
+            "-i",
+            values.join("strings.xml").to_str().unwrap(),
+            values_de.join("strings.xml").to_str().unwrap(),
+            "--config",
+            config.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&result.stdout);
+    assert!(result.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("key='save': length 9 exceeds the limit of 8 characters (max_length)"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_lint_reports_select_cases_missing_from_translations() {
+    let temp_dir = TempDir::new().unwrap();
+    let catalog = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(
+        &catalog,
+        r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "invite" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "{gender, select, male {He invited you} female {She invited you} other {They invited you}}" } },
+        "de" : { "stringUnit" : { "state" : "translated", "value" : "{gender, select, male {Er hat dich eingeladen} other {Sie haben dich eingeladen}}" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#,
+    )
+    .unwrap();
+
+    let result = langcodec_cmd()
+        .args(["lint", "-i", catalog.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    let finding = &report["findings"][0];
+    assert_eq!(finding["rule"], "select_cases");
+    assert_eq!(finding["language"], "de");
+    assert_eq!(finding["message"], "missing select cases: [female]");
+}
@@ -471,6 +471,7 @@ fn test_merge_plurals() {
             value: Translation::Plural(langcodec::types::Plural {
                 id: "apples".to_string(),
                 forms: forms1,
+                kind: Default::default(),
             }),
             comment: None,
             status: EntryStatus::Translated,
@@ -489,6 +490,7 @@ fn test_merge_plurals() {
             value: Translation::Plural(langcodec::types::Plural {
                 id: "oranges".to_string(),
                 forms: forms2,
+                kind: Default::default(),
             }),
             comment: None,
             status: EntryStatus::Translated,
@@ -398,3 +398,184 @@ fn test_normalize_reports_no_matches_for_glob_patterns() {
         "expected no-match glob error, got: {combined}"
     );
 }
+
+#[test]
+fn test_normalize_isolate_bidi_wraps_rtl_placeholders() {
+    let temp_dir = TempDir::new().unwrap();
+    let lproj = temp_dir.path().join("ar.lproj");
+    fs::create_dir_all(&lproj).unwrap();
+    let input = lproj.join("Localizable.strings");
+    fs::write(&input, "\"items\" = \"%@ عناصر\";\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args(["normalize", "-i", input.to_str().unwrap(), "--isolate-bidi"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "normalize failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(&input).unwrap(),
+        "\"items\" = \"\u{2068}%@\u{2069} عناصر\";\n"
+    );
+
+    // Already isolated: nothing left to change.
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--isolate-bidi",
+            "--no-placeholders",
+            "--check",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_normalize_typography_flags() {
+    let temp_dir = TempDir::new().unwrap();
+    let lproj = temp_dir.path().join("fr.lproj");
+    fs::create_dir_all(&lproj).unwrap();
+    let input = lproj.join("Localizable.strings");
+    fs::write(
+        &input,
+        "\"confirm\" = \"Supprimer \\\"Photos\\\" ?\";\n\"loading\" = \"Chargement... \";\n",
+    )
+    .unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--unicode",
+            "nfc",
+            "--french-spacing",
+            "--smart-quotes",
+            "--ellipsis",
+            "--trim-trailing-whitespace",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "normalize failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(&input).unwrap(),
+        "\"confirm\" = \"Supprimer «\u{a0}Photos\u{a0}»\u{a0}?\";\n\"loading\" = \"Chargement…\";\n"
+    );
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--unicode",
+            "nfd",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid --unicode"));
+}
+
+#[test]
+fn test_normalize_dry_run_prints_per_key_and_json_report() {
+    let temp_dir = TempDir::new().unwrap();
+    let lproj = temp_dir.path().join("en.lproj");
+    fs::create_dir_all(&lproj).unwrap();
+    let input = lproj.join("Localizable.strings");
+    let original = "\"loading_title\" = \"Loading...\";\n\"ok\" = \"OK\";\n";
+    fs::write(&input, original).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--dry-run",
+            "--ellipsis",
+            "--key-style",
+            "camel",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("  [en] loading_title: renamed to loadingTitle (key_style)\n"));
+    assert!(stdout.contains(
+        "  [en] loadingTitle (ellipsis)\n    before: Loading...\n    after:  Loading…\n"
+    ));
+    assert_eq!(fs::read_to_string(&input).unwrap(), original);
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--check",
+            "--json",
+            "--ellipsis",
+            "--key-style",
+            "camel",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let file = &report["files"][0];
+    assert_eq!(file["changed"], true);
+    assert_eq!(file["renamed_keys"][0]["from"], "loading_title");
+    assert_eq!(file["renamed_keys"][0]["to"], "loadingTitle");
+    assert_eq!(file["values"][0]["before"], "Loading...");
+    assert_eq!(file["values"][0]["after"], "Loading…");
+    assert_eq!(report["summary"]["failed"], 1);
+
+    let output = langcodec_cmd()
+        .args(["normalize", "-i", input.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--json requires"));
+}
+
+#[test]
+fn test_normalize_check_reports_key_style_collisions() {
+    let temp_dir = TempDir::new().unwrap();
+    let lproj = temp_dir.path().join("en.lproj");
+    fs::create_dir_all(&lproj).unwrap();
+    let input = lproj.join("Localizable.strings");
+    fs::write(
+        &input,
+        "\"Sign_In\" = \"Sign in\";\n\"sign-in\" = \"Sign in\";\n",
+    )
+    .unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--check",
+            "--no-placeholders",
+            "--key-style",
+            "snake",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("  key-style collision"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'Sign_In' and 'sign-in'"));
+}
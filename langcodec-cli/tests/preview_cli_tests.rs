@@ -0,0 +1,119 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+const CATALOG: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "files" : {
+      "localizations" : {
+        "en" : {
+          "variations" : {
+            "plural" : {
+              "one" : { "stringUnit" : { "state" : "translated", "value" : "%lld file" } },
+              "other" : { "stringUnit" : { "state" : "translated", "value" : "%lld files" } }
+            }
+          }
+        },
+        "ru" : {
+          "variations" : {
+            "plural" : {
+              "one" : { "stringUnit" : { "state" : "translated", "value" : "%lld файл" } },
+              "few" : { "stringUnit" : { "state" : "translated", "value" : "%lld файла" } },
+              "many" : { "stringUnit" : { "state" : "translated", "value" : "%lld файлов" } },
+              "other" : { "stringUnit" : { "state" : "translated", "value" : "%lld файла" } }
+            }
+          }
+        }
+      }
+    },
+    "greeting" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Hello, %@!" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+#[test]
+fn test_preview_picks_russian_plural_form() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(&input, CATALOG).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "preview",
+            "files",
+            "-i",
+            input.to_str().unwrap(),
+            "--lang",
+            "ru",
+            "--arg",
+            "5",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("5 файлов"), "stdout: {}", stdout);
+}
+
+#[test]
+fn test_preview_json_reports_fallback_locale() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(&input, CATALOG).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "preview",
+            "greeting",
+            "-i",
+            input.to_str().unwrap(),
+            "--arg",
+            "Anna",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let previews = v["previews"].as_array().unwrap();
+    assert_eq!(previews.len(), 2);
+    let ru = previews.iter().find(|p| p["language"] == "ru").unwrap();
+    assert_eq!(ru["value"], "Hello, Anna!");
+    assert_eq!(ru["fallback_from"], "en");
+    let en = previews.iter().find(|p| p["language"] == "en").unwrap();
+    assert!(en["fallback_from"].is_null());
+}
+
+#[test]
+fn test_preview_unknown_key_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(&input, CATALOG).unwrap();
+
+    let output = langcodec_cmd()
+        .args(["preview", "missing", "-i", input.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Key 'missing' not found"));
+}
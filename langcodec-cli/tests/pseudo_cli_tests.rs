@@ -0,0 +1,170 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+const CATALOG: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "files" : {
+      "localizations" : {
+        "en" : {
+          "variations" : {
+            "plural" : {
+              "one" : { "stringUnit" : { "state" : "translated", "value" : "%lld file" } },
+              "other" : { "stringUnit" : { "state" : "translated", "value" : "%lld files" } }
+            }
+          }
+        },
+        "fr" : {
+          "variations" : {
+            "plural" : {
+              "one" : { "stringUnit" : { "state" : "translated", "value" : "%lld fichier" } },
+              "other" : { "stringUnit" : { "state" : "translated", "value" : "%lld fichiers" } }
+            }
+          }
+        }
+      }
+    },
+    "greeting" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Hello, %@!" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+#[test]
+fn test_pseudo_adds_accented_locale_to_xcstrings() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    let output = temp_dir.path().join("Pseudo.xcstrings");
+    fs::write(&input, CATALOG).unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "pseudo",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let v: serde_json::Value = serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+    let greeting = &v["strings"]["greeting"]["localizations"];
+    assert_eq!(greeting["en-XA"]["stringUnit"]["value"], "[Ĥéļļö, %@! one]");
+    let plural = &v["strings"]["files"]["localizations"]["en-XA"]["variations"]["plural"];
+    assert_eq!(plural["one"]["stringUnit"]["value"], "[%lld ƒîļé one]");
+    assert_eq!(plural["other"]["stringUnit"]["value"], "[%lld ƒîļéš one]");
+    // Existing translations are kept.
+    assert!(v["strings"]["files"]["localizations"]["fr"].is_object());
+}
+
+#[test]
+fn test_pseudo_bidi_to_android_strings_fills_plural_forms() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    let values_dir = temp_dir.path().join("values-ar-rXB");
+    fs::create_dir_all(&values_dir).unwrap();
+    let output = values_dir.join("strings.xml");
+    fs::write(&input, CATALOG).unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "pseudo",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--locale",
+            "ar-XB",
+            "--expansion",
+            "50",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let written = fs::read_to_string(&output).unwrap();
+    assert!(
+        written
+            .contains("\u{200F}\u{202E}Hello,\u{202C}\u{200F} %@\u{200F}\u{202E}!\u{202C}\u{200F}"),
+        "output: {}",
+        written
+    );
+    assert!(
+        written.contains("\u{202E}one\u{202C}"),
+        "output: {}",
+        written
+    );
+    for category in ["zero", "one", "two", "few", "many", "other"] {
+        assert!(
+            written.contains(&format!("quantity=\"{}\"", category)),
+            "missing {} in output: {}",
+            category,
+            written
+        );
+    }
+}
+
+#[test]
+fn test_pseudo_csv_uses_source_language_and_keeps_other_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("strings.csv");
+    let output = temp_dir.path().join("out.csv");
+    fs::write(&input, "key,en,fr\nhello,Hello,Bonjour\n").unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "pseudo",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--source-lang",
+            "de",
+        ])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("Source language 'de' not found"));
+
+    let result = langcodec_cmd()
+        .args([
+            "pseudo",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--source-lang",
+            "en",
+            "--no-brackets",
+            "--expansion",
+            "0",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let written = fs::read_to_string(&output).unwrap();
+    assert!(written.contains("Ĥéļļö"), "output: {}", written);
+    assert!(written.contains("Bonjour"), "output: {}", written);
+}
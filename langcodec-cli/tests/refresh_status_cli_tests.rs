@@ -0,0 +1,134 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let result = langcodec_cmd().args(args).output().unwrap();
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    serde_json::from_slice(&result.stdout).unwrap()
+}
+
+fn write_android(dir: &Path, values_dir: &str, body: &str) -> String {
+    let values = dir.join(values_dir);
+    fs::create_dir_all(&values).unwrap();
+    let path = values.join("strings.xml");
+    fs::write(&path, format!("<resources>\n{}</resources>\n", body)).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_refresh_status_marks_android_translations_stale_after_source_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = write_android(
+        temp_dir.path(),
+        "values",
+        "    <string name=\"hello\">Hello</string>\n    <string name=\"bye\">Bye</string>\n",
+    );
+    let target = write_android(
+        temp_dir.path(),
+        "values-fr",
+        "    <string name=\"hello\">Bonjour</string>\n    <string name=\"bye\">Au revoir</string>\n",
+    );
+    let state = temp_dir.path().join("langcodec-state.json");
+    let state = state.to_str().unwrap();
+    let args = [
+        "refresh-status",
+        "-i",
+        &source,
+        &target,
+        "--state",
+        state,
+        "--source-lang",
+        "en",
+        "--json",
+    ];
+
+    let report = run_json(&args);
+    assert_eq!(report["tracked"], 2);
+    assert!(Path::new(state).exists());
+
+    fs::write(
+        &source,
+        "<resources>\n    <string name=\"hello\">Hello there</string>\n    <string name=\"bye\">Bye</string>\n</resources>\n",
+    )
+    .unwrap();
+    let report = run_json(&args);
+    assert_eq!(report["marked_stale"], 1);
+    assert_eq!(report["up_to_date"], 1);
+    assert_eq!(report["changes"][0]["key"], "hello");
+    assert_eq!(report["changes"][0]["action"], "marked_stale");
+
+    // The stale flag survives in the state file until the translation is updated.
+    let report = run_json(&args);
+    assert_eq!(report["already_stale"], 1);
+
+    fs::write(
+        &target,
+        "<resources>\n    <string name=\"hello\">Bonjour à tous</string>\n    <string name=\"bye\">Au revoir</string>\n</resources>\n",
+    )
+    .unwrap();
+    let report = run_json(&args);
+    assert_eq!(report["restamped"], 1);
+    let report = run_json(&args);
+    assert_eq!(report["up_to_date"], 2);
+    assert_eq!(report["already_stale"], 0);
+}
+
+#[test]
+fn test_refresh_status_csv_dry_run_does_not_write_state() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("strings.csv");
+    let input = input.to_str().unwrap();
+    let state = temp_dir.path().join("state.json");
+    let state = state.to_str().unwrap();
+    fs::write(input, "key,en,fr\nhello,Hello,Bonjour\n").unwrap();
+
+    let result = langcodec_cmd()
+        .args([
+            "refresh-status",
+            "-i",
+            input,
+            "--state",
+            state,
+            "--source-lang",
+            "en",
+            "--dry-run",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    assert!(!Path::new(state).exists());
+
+    let args = [
+        "refresh-status",
+        "-i",
+        input,
+        "--state",
+        state,
+        "--source-lang",
+        "en",
+        "--json",
+    ];
+    run_json(&args);
+    fs::write(input, "key,en,fr\nhello,Hi,Bonjour\n").unwrap();
+    let report = run_json(&args);
+    assert_eq!(report["marked_stale"], 1);
+    assert_eq!(report["changes"][0]["language"], "fr");
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(state).unwrap()).unwrap();
+    assert_eq!(recorded["fr"]["hello"]["stale"], true);
+}
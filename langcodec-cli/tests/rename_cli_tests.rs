@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn write_android(dir: &Path, values_dir: &str, body: &str) -> String {
+    let values = dir.join(values_dir);
+    fs::create_dir_all(&values).unwrap();
+    let path = values.join("strings.xml");
+    fs::write(&path, format!("<resources>\n{}</resources>\n", body)).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_rename_applies_mapping_csv_to_every_locale() {
+    let temp_dir = TempDir::new().unwrap();
+    let en = write_android(
+        temp_dir.path(),
+        "values",
+        "    <string name=\"login\">Log in</string>\n    <string name=\"ok\">OK</string>\n",
+    );
+    let fr = write_android(
+        temp_dir.path(),
+        "values-fr",
+        "    <string name=\"login\">Connexion</string>\n",
+    );
+    let mapping = temp_dir.path().join("mapping.csv");
+    fs::write(&mapping, "from,to\nlogin,auth_sign_in\nunused,x\n").unwrap();
+    let pattern = format!("{}/values*/strings.xml", temp_dir.path().display());
+
+    let output = langcodec_cmd()
+        .args(["rename", "-i", &pattern, "-m", mapping.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "rename failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Key not found in any input: unused"));
+    assert!(stdout.contains("Renamed 2 entries in 2 file(s)"));
+
+    assert_eq!(
+        fs::read_to_string(&en).unwrap(),
+        "<resources>\n    <string name=\"auth_sign_in\">Log in</string>\n    <string name=\"ok\">OK</string>\n</resources>\n"
+    );
+    assert!(
+        fs::read_to_string(&fr)
+            .unwrap()
+            .contains("<string name=\"auth_sign_in\">Connexion</string>")
+    );
+}
+
+#[test]
+fn test_rename_rewrites_only_the_key_token() {
+    let temp_dir = TempDir::new().unwrap();
+    let android = write_android(
+        temp_dir.path(),
+        "values",
+        "    <string name=\"title\">Title</string>\n\n    <!-- Okay   button -->\n    <string name=\"ok\">OK</string>\n",
+    );
+    let strings = temp_dir.path().join("en.strings");
+    fs::write(
+        &strings,
+        "\"title\" = \"Title\";\n\n/* Okay   button */\n\"ok\" = \"OK\"; // trailing\n",
+    )
+    .unwrap();
+    let mapping = temp_dir.path().join("mapping.csv");
+    fs::write(&mapping, "from,to\nok,confirm\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "rename",
+            "-i",
+            &android,
+            "-i",
+            strings.to_str().unwrap(),
+            "-m",
+            mapping.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "rename failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        fs::read_to_string(&android).unwrap(),
+        "<resources>\n    <string name=\"title\">Title</string>\n\n    <!-- Okay   button -->\n    <string name=\"confirm\">OK</string>\n</resources>\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&strings).unwrap(),
+        "\"title\" = \"Title\";\n\n/* Okay   button */\n\"confirm\" = \"OK\"; // trailing\n"
+    );
+}
+
+#[test]
+fn test_rename_pattern_dry_run_reports_json_without_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    let original = "\"login\" = \"Log in\";\n\"ok\" = \"OK\";\n";
+    fs::write(&input, original).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "rename",
+            "-i",
+            input.to_str().unwrap(),
+            "--pattern",
+            "^(.+)$",
+            "--replacement",
+            "common.$1",
+            "--dry-run",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["summary"]["renamed"], 2);
+    assert_eq!(report["files"][0]["renamed"][0]["from"], "login");
+    assert_eq!(report["files"][0]["renamed"][0]["to"], "common.login");
+    assert_eq!(fs::read_to_string(&input).unwrap(), original);
+}
+
+#[test]
+fn test_rename_refuses_collisions_in_any_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let en = write_android(
+        temp_dir.path(),
+        "values",
+        "    <string name=\"login\">Log in</string>\n",
+    );
+    let de = write_android(
+        temp_dir.path(),
+        "values-de",
+        "    <string name=\"login\">Anmelden</string>\n    <string name=\"sign_in\">Einloggen</string>\n",
+    );
+    let en_before = fs::read_to_string(&en).unwrap();
+    let mapping = temp_dir.path().join("mapping.csv");
+    fs::write(&mapping, "old_key,new_key\nlogin,sign_in\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args(["rename", "-i", &en, &de, "-m", mapping.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("'login' and 'sign_in' would both be named 'sign_in'"),
+        "stderr: {}",
+        stderr
+    );
+    assert_eq!(fs::read_to_string(&en).unwrap(), en_before);
+
+    let output = langcodec_cmd()
+        .args(["rename", "-i", &en, "--pattern", "login"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--pattern requires --replacement"));
+}
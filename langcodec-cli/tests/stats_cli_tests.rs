@@ -44,3 +44,135 @@ fn test_stats_json_on_android_strings() {
     assert_eq!(by_status["do_not_translate"], 1);
     assert_eq!(by_status["new"], 1);
 }
+
+#[test]
+fn test_stats_json_reports_placeholder_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("Localizable.xcstrings");
+    let catalog = r#"{
+      "sourceLanguage" : "en",
+      "strings" : {
+        "score" : {
+          "localizations" : {
+            "en" : { "stringUnit" : { "state" : "translated", "value" : "%@ scored %d" } },
+            "fr" : { "stringUnit" : { "state" : "translated", "value" : "%d points pour %@" } }
+          }
+        }
+      },
+      "version" : "1.0"
+    }"#;
+    fs::write(&input_file, catalog).unwrap();
+
+    let output = langcodec_cmd()
+        .args(["stats", "-i", input_file.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(v["summary"]["placeholder_issues"], 1);
+    let fr = v["languages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|l| l["language"] == "fr")
+        .unwrap();
+    let issue = &fr["placeholder_issues"][0];
+    assert_eq!(issue["key"], "score");
+    assert_eq!(issue["kind"], "reordered_without_positions");
+    assert_eq!(issue["fix"]["value"], "%2$d points pour %1$@");
+}
+
+#[test]
+fn test_stats_reports_length_overruns_from_config_and_xliff() {
+    let temp_dir = TempDir::new().unwrap();
+    let catalog = temp_dir.path().join("Localizable.xcstrings");
+    fs::write(
+        &catalog,
+        r#"{
+      "sourceLanguage" : "en",
+      "strings" : {
+        "button.save" : {
+          "localizations" : {
+            "en" : { "stringUnit" : { "state" : "translated", "value" : "Save" } },
+            "ja" : { "stringUnit" : { "state" : "translated", "value" : "保存する" } }
+          }
+        }
+      },
+      "version" : "1.0"
+    }"#,
+    )
+    .unwrap();
+    let config = temp_dir.path().join("langcodec.toml");
+    fs::write(
+        &config,
+        "[length]\nunit = \"col\"\n\n[length.limits]\n\"button.*\" = 6\n",
+    )
+    .unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "stats",
+            "-i",
+            catalog.to_str().unwrap(),
+            "--json",
+            "--config",
+            config.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(v["summary"]["length_overruns"], 1);
+    let ja = v["languages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|lang| lang["language"] == "ja")
+        .unwrap();
+    let overrun = &ja["length_overruns"][0];
+    assert_eq!(overrun["key"], "button.save");
+    assert_eq!(overrun["length"], 8);
+    assert_eq!(overrun["limit"]["max"], 6);
+
+    let xliff = temp_dir.path().join("Localizable.xliff");
+    fs::write(
+        &xliff,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="Localizable.strings" source-language="en" target-language="de" datatype="plaintext">
+    <body>
+      <trans-unit id="save" maxwidth="8">
+        <source>Save</source>
+        <target>Speichern</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#,
+    )
+    .unwrap();
+    let output = langcodec_cmd()
+        .args(["stats", "-i", xliff.to_str().unwrap(), "--lang", "de"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Length overruns: 1"), "{}", stdout);
+    assert!(
+        stdout.contains("length 9 exceeds the limit of 8 characters"),
+        "{}",
+        stdout
+    );
+}
@@ -0,0 +1,204 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn utf16le_with_bom(content: &str) -> Vec<u8> {
+    let mut bytes = vec![0xFF, 0xFE];
+    bytes.extend(content.encode_utf16().flat_map(u16::to_le_bytes));
+    bytes
+}
+
+fn decode_utf16le(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).unwrap()
+}
+
+#[test]
+fn test_edit_set_preserves_utf16le_strings_encoding() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    fs::write(&input, utf16le_with_bom("\"hello\" = \"Hello\";\n")).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "edit",
+            "set",
+            "-i",
+            input.to_str().unwrap(),
+            "-k",
+            "bye",
+            "-v",
+            "Goodbye",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let bytes = fs::read(&input).unwrap();
+    assert!(bytes.starts_with(&[0xFF, 0xFE]), "BOM was not preserved");
+    let text = decode_utf16le(&bytes[2..]);
+    assert!(text.contains("\"hello\" = \"Hello\";"));
+    assert!(text.contains("\"bye\" = \"Goodbye\";"));
+}
+
+#[test]
+fn test_convert_with_explicit_encoding_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    let utf8_output = temp_dir.path().join("utf8.strings");
+    let utf16_output = temp_dir.path().join("utf16.strings");
+    fs::write(&input, utf16le_with_bom("\"hello\" = \"Hallo\";\n")).unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            utf8_output.to_str().unwrap(),
+            "--encoding",
+            "utf8",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let text = fs::read_to_string(&utf8_output).unwrap();
+    assert!(!text.starts_with('\u{feff}'));
+    assert!(text.contains("\"hello\" = \"Hallo\";"));
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            utf8_output.to_str().unwrap(),
+            "-o",
+            utf16_output.to_str().unwrap(),
+            "--encoding",
+            "utf16be",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(fs::read(&utf16_output).unwrap().starts_with(&[0xFE, 0xFF]));
+}
+
+#[test]
+fn test_convert_rejects_encoding_for_non_strings_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    let output_path = temp_dir.path().join("strings.xml");
+    fs::write(&input, "\"hello\" = \"Hello\";\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output_path.to_str().unwrap(),
+            "--encoding",
+            "utf16le",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--encoding is only supported for .strings output"));
+}
+
+#[test]
+fn test_convert_rejects_invalid_encoding_before_converting() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    let output_path = temp_dir.path().join("out.strings");
+    fs::write(&input, "\"hello\" = \"Hello\";\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output_path.to_str().unwrap(),
+            "--encoding",
+            "latin1",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Converting to .strings"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid --encoding 'latin1'"));
+    assert!(!output_path.exists());
+}
+
+#[test]
+fn test_normalize_reencodes_strings_and_check_detects_encoding_drift() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("en.strings");
+    fs::write(&input, "\"a\" = \"A\";\n").unwrap();
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--encoding",
+            "utf16le",
+            "--check",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--encoding",
+            "utf16le",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let bytes = fs::read(&input).unwrap();
+    assert!(bytes.starts_with(&[0xFF, 0xFE]));
+    assert!(decode_utf16le(&bytes[2..]).contains("\"a\" = \"A\";"));
+
+    // Already UTF-16LE: nothing left to change.
+    let output = langcodec_cmd()
+        .args([
+            "normalize",
+            "-i",
+            input.to_str().unwrap(),
+            "--encoding",
+            "utf16le",
+            "--check",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
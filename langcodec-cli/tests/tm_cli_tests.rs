@@ -0,0 +1,168 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn run_ok(args: &[&str]) -> String {
+    let result = langcodec_cmd().args(args).output().unwrap();
+    assert!(
+        result.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    String::from_utf8_lossy(&result.stdout).into_owned()
+}
+
+const TRANSLATED: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "delete_file" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Delete this file?" } },
+        "fr" : { "stringUnit" : { "state" : "translated", "value" : "Supprimer ce fichier ?" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+const UNTRANSLATED: &str = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "confirm_delete" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Delete this file!" } },
+        "fr" : { "stringUnit" : { "state" : "new", "value" : "" } }
+      }
+    },
+    "open" : {
+      "localizations" : {
+        "en" : { "stringUnit" : { "state" : "translated", "value" : "Open settings" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+
+#[test]
+fn test_tm_build_and_fill_xcstrings_marks_needs_review() {
+    let temp_dir = TempDir::new().unwrap();
+    let old = temp_dir.path().join("Old.xcstrings");
+    let new = temp_dir.path().join("New.xcstrings");
+    let memory = temp_dir.path().join("memory.tmx");
+    fs::write(&old, TRANSLATED).unwrap();
+    fs::write(&new, UNTRANSLATED).unwrap();
+
+    let stdout = run_ok(&[
+        "tm",
+        "build",
+        "-i",
+        old.to_str().unwrap(),
+        "-o",
+        memory.to_str().unwrap(),
+    ]);
+    assert!(stdout.contains("Wrote 1 translation units"), "{}", stdout);
+    let tmx = fs::read_to_string(&memory).unwrap();
+    assert!(tmx.contains("<seg>Supprimer ce fichier ?</seg>"), "{}", tmx);
+
+    // Below the threshold nothing is filled.
+    let report: serde_json::Value = serde_json::from_str(&run_ok(&[
+        "tm",
+        "fill",
+        "-i",
+        new.to_str().unwrap(),
+        "--tm",
+        memory.to_str().unwrap(),
+        "--min-score",
+        "95",
+        "--json",
+    ]))
+    .unwrap();
+    assert_eq!(report["candidates"], 2);
+    assert_eq!(report["unmatched"], 2);
+
+    let report: serde_json::Value = serde_json::from_str(&run_ok(&[
+        "tm",
+        "fill",
+        "-i",
+        new.to_str().unwrap(),
+        "--tm",
+        memory.to_str().unwrap(),
+        "--min-score",
+        "75",
+        "--json",
+    ]))
+    .unwrap();
+    assert_eq!(report["fuzzy"], 1);
+    assert_eq!(report["fills"][0]["key"], "confirm_delete");
+    assert_eq!(report["fills"][0]["matched_key"], "delete_file");
+
+    let v: serde_json::Value = serde_json::from_str(&fs::read_to_string(&new).unwrap()).unwrap();
+    let unit = &v["strings"]["confirm_delete"]["localizations"]["fr"]["stringUnit"];
+    assert_eq!(unit["value"], "Supprimer ce fichier ?");
+    assert_eq!(unit["state"], "needs_review");
+}
+
+#[test]
+fn test_tm_fill_android_with_separate_source_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let values = temp_dir.path().join("values");
+    let values_fr = temp_dir.path().join("values-fr");
+    fs::create_dir_all(&values).unwrap();
+    fs::create_dir_all(&values_fr).unwrap();
+    let source = values.join("strings.xml");
+    let target = values_fr.join("strings.xml");
+    fs::write(
+        &source,
+        "<resources>\n    <string name=\"save\">Save</string>\n    <string name=\"save_changes\">Save changes</string>\n</resources>\n",
+    )
+    .unwrap();
+    fs::write(
+        &target,
+        "<resources>\n    <!-- Reviewed -->\n    <string name=\"save\">Enregistrer</string>\n</resources>\n",
+    )
+    .unwrap();
+    let memory = temp_dir.path().join("memory.tmx");
+    fs::write(
+        &memory,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<tmx version="1.4">
+  <header srclang="en" datatype="plaintext"/>
+  <body>
+    <tu tuid="save_all"><tuv xml:lang="en"><seg>Save changes</seg></tuv><tuv xml:lang="fr"><seg>Enregistrer les modifications</seg></tuv></tu>
+  </body>
+</tmx>"#,
+    )
+    .unwrap();
+
+    let stdout = run_ok(&[
+        "tm",
+        "fill",
+        "-i",
+        target.to_str().unwrap(),
+        "--source",
+        source.to_str().unwrap(),
+        "--tm",
+        memory.to_str().unwrap(),
+        "--source-lang",
+        "en",
+        "--min-score",
+        "90",
+    ]);
+    assert!(stdout.contains("Filled 1 of 1"), "{}", stdout);
+
+    let written = fs::read_to_string(&target).unwrap();
+    assert!(
+        written.contains("<string name=\"save_changes\">Enregistrer les modifications</string>"),
+        "{}",
+        written
+    );
+    assert!(written.contains("<!-- Reviewed -->"), "{}", written);
+    assert_eq!(
+        fs::read_to_string(&source).unwrap(),
+        "<resources>\n    <string name=\"save\">Save</string>\n    <string name=\"save_changes\">Save changes</string>\n</resources>\n"
+    );
+}
@@ -791,3 +791,45 @@ fn test_view_status_text_excludes_zero_match_languages_from_output_and_summary()
         stdout
     );
 }
+
+#[test]
+fn test_view_json_includes_placeholder_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("Localizable.xcstrings");
+    let xcstrings = r#"{
+  "sourceLanguage": "en",
+  "version": "1.0",
+  "strings": {
+    "files": {
+      "localizations": {
+        "en": { "stringUnit": { "state": "translated", "value": "%d files" } },
+        "fr": { "stringUnit": { "state": "translated", "value": "%@ fichiers" } }
+      }
+    }
+  }
+}
+"#;
+    fs::write(&input_file, xcstrings).unwrap();
+
+    let output = langcodec_cmd()
+        .args(["view", "-i", input_file.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "CLI failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(v["summary"]["placeholder_issues"], 1);
+    let entries = v["entries"].as_array().unwrap();
+    let en = entries.iter().find(|e| e["lang"] == "en").unwrap();
+    assert!(en.get("placeholder_issues").is_none());
+    let fr = entries.iter().find(|e| e["lang"] == "fr").unwrap();
+    let issue = &fr["placeholder_issues"][0];
+    assert_eq!(issue["kind"], "type_mismatch");
+    assert_eq!(issue["expected"][0], "d");
+    assert_eq!(issue["actual"][0], "s");
+    assert_eq!(issue["fix"]["value"], "%d fichiers");
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn langcodec_cmd() -> Command {
+    Command::new(assert_cmd::cargo::cargo_bin!("langcodec"))
+}
+
+fn write_xcstrings_fixture(path: &std::path::Path) {
+    let xcstrings = r#"{
+  "sourceLanguage": "en",
+  "version": "1.0",
+  "strings": {
+    "greeting": {
+      "comment": "Shown on the home screen.",
+      "localizations": {
+        "en": {
+          "stringUnit": {
+            "state": "translated",
+            "value": "Hello,\nfriend"
+          }
+        },
+        "fr": {
+          "stringUnit": {
+            "state": "needs_review",
+            "value": "Bonjour,\nl'ami"
+          }
+        }
+      }
+    },
+    "pending": {
+      "localizations": {
+        "en": {
+          "stringUnit": {
+            "state": "translated",
+            "value": "Pending"
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+    fs::write(path, xcstrings).unwrap();
+}
+
+#[test]
+fn test_convert_xcstrings_to_xlsx_and_back() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    let workbook = temp_dir.path().join("translations.xlsx");
+    let roundtrip = temp_dir.path().join("roundtrip.xcstrings");
+    write_xcstrings_fixture(&input);
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            workbook.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "convert to xlsx failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(fs::read(&workbook).unwrap().starts_with(b"PK"));
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            workbook.to_str().unwrap(),
+            "-o",
+            roundtrip.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "convert from xlsx failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&roundtrip).unwrap()).unwrap();
+    assert_eq!(json["sourceLanguage"], "en");
+    let greeting = &json["strings"]["greeting"];
+    assert_eq!(greeting["comment"], "Shown on the home screen.");
+    assert_eq!(
+        greeting["localizations"]["en"]["stringUnit"]["value"],
+        "Hello,\nfriend"
+    );
+    assert_eq!(
+        greeting["localizations"]["fr"]["stringUnit"]["state"],
+        "needs_review"
+    );
+    assert!(json["strings"]["pending"]["localizations"]["fr"].is_null());
+}
+
+#[test]
+fn test_view_reads_xlsx_input() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("Localizable.xcstrings");
+    let workbook = temp_dir.path().join("translations.xlsx");
+    write_xcstrings_fixture(&input);
+
+    let output = langcodec_cmd()
+        .args([
+            "convert",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            workbook.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = langcodec_cmd()
+        .args(["view", "-i", workbook.to_str().unwrap(), "--lang", "fr"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "view failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("greeting"));
+    assert!(stdout.contains("Bonjour"));
+}
@@ -0,0 +1,416 @@
+//! Right-to-left and bidirectional text checks.
+//!
+//! In RTL languages a placeholder is usually filled with left-to-right text
+//! (a name, a number, a file name). Unless the placeholder sits inside a bidi
+//! isolate (FSI … PDI), the Unicode bidi algorithm can merge the substituted
+//! value with neighbouring numbers and punctuation, or even flip the direction
+//! of the whole paragraph when the message starts with it, so `%@ عناصر`
+//! renders in the wrong order. [`check_bidi`] reports such runs along with
+//! unbalanced or stray direction controls, brackets that do not pair up and
+//! Latin punctuation in Arabic-script text; [`isolate_ltr_runs`] is the
+//! matching autofix.
+
+use std::{fmt, ops::Range};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    placeholder::{forms_of_mut, parse_placeholder},
+    types::Resource,
+};
+
+/// LEFT-TO-RIGHT ISOLATE.
+pub const LRI: char = '\u{2066}';
+/// RIGHT-TO-LEFT ISOLATE.
+pub const RLI: char = '\u{2067}';
+/// FIRST STRONG ISOLATE, which takes the direction of its content.
+pub const FSI: char = '\u{2068}';
+/// POP DIRECTIONAL ISOLATE, closing [`LRI`], [`RLI`] and [`FSI`].
+pub const PDI: char = '\u{2069}';
+/// LEFT-TO-RIGHT MARK.
+pub const LRM: char = '\u{200E}';
+/// RIGHT-TO-LEFT MARK.
+pub const RLM: char = '\u{200F}';
+/// ARABIC LETTER MARK.
+pub const ALM: char = '\u{061C}';
+
+/// Legacy embeddings and overrides (LRE, RLE, PDF, LRO, RLO).
+const EMBEDDINGS: &[char] = &['\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}'];
+
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "ps", "sd", "syr", "ug", "ur", "yi",
+];
+const RTL_SCRIPTS: &[&str] = &["arab", "hebr", "syrc", "thaa", "nkoo", "adlm", "rohg"];
+/// Languages written in Arabic script, which has its own comma, semicolon and
+/// question mark.
+const ARABIC_SCRIPT_LANGUAGES: &[&str] = &["ar", "ckb", "fa", "ks", "ps", "sd", "ug", "ur"];
+
+/// Whether `language` (a BCP 47 tag) is written right to left.
+///
+/// An explicit script subtag wins, so `ku-Arab` is RTL and `az-Latn` is not.
+pub fn is_rtl_language(language: &str) -> bool {
+    let mut subtags = language.split(['-', '_']);
+    let base = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    for subtag in subtags {
+        if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+            return RTL_SCRIPTS.contains(&subtag.to_ascii_lowercase().as_str());
+        }
+    }
+    RTL_LANGUAGES.contains(&base.as_str())
+}
+
+fn is_arabic_script_language(language: &str) -> bool {
+    let base = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    is_rtl_language(language) && ARABIC_SCRIPT_LANGUAGES.contains(&base.as_str())
+}
+
+/// Characters with strong right-to-left direction (Hebrew, Arabic, Syriac,
+/// Thaana, N'Ko and their presentation forms).
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}')
+        && c != ALM
+}
+
+fn is_arabic_letter(c: char) -> bool {
+    matches!(c, '\u{0620}'..='\u{064A}' | '\u{066E}'..='\u{06D3}' | '\u{FB50}'..='\u{FEFC}')
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, LRI | RLI | FSI | PDI | LRM | RLM | ALM) || EMBEDDINGS.contains(&c)
+}
+
+/// A bidi problem in one translated form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BidiIssue {
+    /// Placeholders, with the numbers or Latin text next to them, that are
+    /// not wrapped in an isolate.
+    UnisolatedRun { text: String },
+    /// A PDI without an open isolate, or an isolate that is never closed.
+    UnbalancedIsolates,
+    /// A direction control the source does not have: embeddings and
+    /// overrides, repeated marks, or any control in left-to-right text.
+    StrayControl { character: char },
+    /// Parentheses or square brackets that pair up in the source but not in
+    /// logical order in the translation.
+    MismatchedBrackets,
+    /// Latin punctuation right after Arabic-script text.
+    LatinPunctuation { found: char, expected: char },
+}
+
+impl fmt::Display for BidiIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnisolatedRun { text } => {
+                write!(f, "'{}' is not wrapped in bidi isolates (FSI/PDI)", text)
+            }
+            Self::UnbalancedIsolates => f.write_str("bidi isolates are not balanced"),
+            Self::StrayControl { character } => {
+                write!(f, "stray direction control U+{:04X}", u32::from(*character))
+            }
+            Self::MismatchedBrackets => {
+                f.write_str("brackets do not pair up in logical order (mirrored by hand?)")
+            }
+            Self::LatinPunctuation { found, expected } => {
+                write!(f, "uses '{}' instead of '{}'", found, expected)
+            }
+        }
+    }
+}
+
+/// Checks a translation in `language` against its source text.
+///
+/// Isolates and stray controls are checked in every language; the remaining
+/// checks only apply to RTL languages.
+pub fn check_bidi(source: &str, translation: &str, language: &str) -> Vec<BidiIssue> {
+    let mut issues = Vec::new();
+    let rtl = is_rtl_language(language);
+
+    if !isolates_balanced(translation) {
+        issues.push(BidiIssue::UnbalancedIsolates);
+    }
+
+    let mut stray: Vec<char> = Vec::new();
+    let mut previous = None;
+    for c in translation.chars() {
+        if is_bidi_control(c) && !source.contains(c) && !stray.contains(&c) {
+            let repeated = previous == Some(c) && matches!(c, LRM | RLM | ALM);
+            if !rtl || EMBEDDINGS.contains(&c) || repeated {
+                stray.push(c);
+            }
+        }
+        previous = Some(c);
+    }
+    issues.extend(
+        stray
+            .into_iter()
+            .map(|character| BidiIssue::StrayControl { character }),
+    );
+
+    if !rtl {
+        return issues;
+    }
+
+    for run in unisolated_ltr_runs(translation) {
+        issues.push(BidiIssue::UnisolatedRun {
+            text: translation[run].to_string(),
+        });
+    }
+
+    if brackets_balanced(source) && !brackets_balanced(translation) {
+        issues.push(BidiIssue::MismatchedBrackets);
+    }
+
+    if is_arabic_script_language(language) {
+        let mut reported = Vec::new();
+        let mut previous = None;
+        for c in translation.chars() {
+            let expected = match c {
+                ',' => Some('،'),
+                ';' => Some('؛'),
+                '?' => Some('؟'),
+                _ => None,
+            };
+            if let Some(expected) = expected
+                && previous.is_some_and(is_arabic_letter)
+                && !reported.contains(&c)
+            {
+                reported.push(c);
+                issues.push(BidiIssue::LatinPunctuation { found: c, expected });
+            }
+            previous = Some(c);
+        }
+    }
+
+    issues
+}
+
+/// Wraps every run reported as [`BidiIssue::UnisolatedRun`] in FSI … PDI.
+///
+/// Text without right-to-left characters is returned unchanged.
+pub fn isolate_ltr_runs(text: &str) -> String {
+    let runs = unisolated_ltr_runs(text);
+    let mut out = String::with_capacity(text.len() + runs.len() * 6);
+    let mut last = 0;
+    for run in runs {
+        out.push_str(&text[last..run.start]);
+        out.push(FSI);
+        out.push_str(&text[run.clone()]);
+        out.push(PDI);
+        last = run.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Applies [`isolate_ltr_runs`] to every form of every RTL resource and
+/// returns the number of entries changed.
+pub fn isolate_resource_ltr_runs(resources: &mut [Resource]) -> usize {
+    let mut changed = 0;
+    for resource in resources
+        .iter_mut()
+        .filter(|res| is_rtl_language(&res.metadata.language))
+    {
+        for entry in &mut resource.entries {
+            let mut entry_changed = false;
+            for text in forms_of_mut(&mut entry.value) {
+                let isolated = isolate_ltr_runs(text);
+                if *text != isolated {
+                    *text = isolated;
+                    entry_changed = true;
+                }
+            }
+            changed += usize::from(entry_changed);
+        }
+    }
+    changed
+}
+
+fn isolates_balanced(text: &str) -> bool {
+    let mut depth = 0usize;
+    for c in text.chars() {
+        match c {
+            LRI | RLI | FSI => depth += 1,
+            PDI => match depth.checked_sub(1) {
+                Some(next) => depth = next,
+                None => return false,
+            },
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+fn brackets_balanced(text: &str) -> bool {
+    let mut open = Vec::new();
+    for c in text.chars() {
+        match c {
+            '(' | '[' => open.push(c),
+            ')' if open.pop() != Some('(') => return false,
+            ']' if open.pop() != Some('[') => return false,
+            _ => {}
+        }
+    }
+    open.is_empty()
+}
+
+/// Length of a simple ICU argument such as `{name}` or `{0}` at the start of
+/// `text`.
+fn icu_argument_len(text: &str) -> Option<usize> {
+    let end = text.find('}')?;
+    let name = &text[1..end];
+    (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')).then_some(end + 1)
+}
+
+/// Byte ranges of left-to-right runs that contain a placeholder and are not
+/// inside an isolate.
+///
+/// A run is a stretch of placeholders, ASCII letters and digits and the
+/// spaces and ASCII punctuation between them; it ends at any other character,
+/// such as RTL letters or direction controls, and is trimmed to start and end
+/// with a placeholder, letter or digit. Text without RTL characters has no
+/// runs, since its placeholders cannot be reordered.
+fn unisolated_ltr_runs(text: &str) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    if !text.chars().any(is_rtl_char) {
+        return runs;
+    }
+
+    // (start, end of the last strong unit, has a placeholder, isolated)
+    let mut current: Option<(usize, usize, bool, bool)> = None;
+    let mut finish = |current: &mut Option<(usize, usize, bool, bool)>| {
+        if let Some((start, end, placeholder, isolated)) = current.take()
+            && placeholder
+            && !isolated
+        {
+            runs.push(start..end);
+        }
+    };
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().unwrap_or_default();
+        let (len, placeholder) = if rest.starts_with("%%") {
+            (2, None)
+        } else if let Some(parsed) = rest
+            .starts_with('%')
+            .then(|| parse_placeholder(rest))
+            .flatten()
+        {
+            (parsed.len, Some(true))
+        } else if let Some(len) = rest
+            .starts_with('{')
+            .then(|| icu_argument_len(rest))
+            .flatten()
+        {
+            (len, Some(true))
+        } else if c.is_ascii_alphanumeric() {
+            (1, Some(false))
+        } else if c == ' ' || (c.is_ascii_punctuation() && c != '{') {
+            (1, None)
+        } else {
+            match c {
+                LRI | RLI | FSI => depth += 1,
+                PDI => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            finish(&mut current);
+            i += c.len_utf8();
+            continue;
+        };
+        if let Some(is_placeholder) = placeholder {
+            let run = current.get_or_insert((i, i, false, depth > 0));
+            run.1 = i + len;
+            run.2 |= is_placeholder;
+        }
+        i += len;
+    }
+    finish(&mut current);
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_rtl_languages_by_language_and_script() {
+        assert!(is_rtl_language("ar"));
+        assert!(is_rtl_language("he-IL"));
+        assert!(is_rtl_language("fa_IR"));
+        assert!(is_rtl_language("ku-Arab"));
+        assert!(!is_rtl_language("en"));
+        assert!(!is_rtl_language("az-Latn"));
+        assert!(!is_rtl_language("uz-Latn-UZ"));
+    }
+
+    #[test]
+    fn reports_and_isolates_placeholder_runs() {
+        let issues = check_bidi("%@ items", "%@ عناصر", "ar");
+        assert_eq!(
+            issues,
+            vec![BidiIssue::UnisolatedRun {
+                text: "%@".to_string()
+            }]
+        );
+
+        // Numbers and separators next to a placeholder form one run.
+        let text = "الصفحة %1$d/%2$d من {total}!";
+        assert_eq!(
+            isolate_ltr_runs(text),
+            "الصفحة \u{2068}%1$d/%2$d\u{2069} من \u{2068}{total}\u{2069}!"
+        );
+
+        let fixed = isolate_ltr_runs("%@ عناصر");
+        assert!(check_bidi("%@ items", &fixed, "ar").is_empty());
+        assert_eq!(isolate_ltr_runs(&fixed), fixed);
+        // No RTL text, nothing to isolate.
+        assert_eq!(isolate_ltr_runs("%@ items"), "%@ items");
+    }
+
+    #[test]
+    fn reports_controls_brackets_and_punctuation() {
+        let issues = check_bidi("Delete (%d)?", "\u{202B}حذف )%d(?\u{2069}", "ar");
+        assert!(issues.contains(&BidiIssue::UnbalancedIsolates));
+        assert!(issues.contains(&BidiIssue::StrayControl {
+            character: '\u{202B}'
+        }));
+        assert!(issues.contains(&BidiIssue::MismatchedBrackets));
+        assert!(
+            !issues
+                .iter()
+                .any(|issue| matches!(issue, BidiIssue::LatinPunctuation { .. }))
+        );
+
+        let issues = check_bidi("Really?", "حقا?", "fa");
+        assert_eq!(
+            issues,
+            vec![BidiIssue::LatinPunctuation {
+                found: '?',
+                expected: '؟'
+            }]
+        );
+        // Hebrew uses Latin punctuation.
+        assert!(check_bidi("Really?", "באמת?", "he").is_empty());
+
+        assert_eq!(
+            check_bidi("Hello", "Hallo\u{200F}", "de"),
+            vec![BidiIssue::StrayControl { character: RLM }]
+        );
+        assert_eq!(
+            check_bidi("Hello", "مرحبا\u{200F}\u{200F}", "ar"),
+            vec![BidiIssue::StrayControl { character: RLM }]
+        );
+        assert!(check_bidi("Hello", "مرحبا\u{200F}", "ar").is_empty());
+    }
+}
@@ -16,7 +16,7 @@
 ///     .build();
 /// # Ok::<(), langcodec::Error>(())
 /// ```
-use crate::formats::{CSVFormat, TSVFormat, XliffFormat};
+use crate::formats::{CSVFormat, TSVFormat, XLSXFormat, XliffFormat};
 use crate::{error::Error, formats::*, traits::Parser, types::Resource};
 use std::path::Path;
 
@@ -79,6 +79,7 @@ impl CodecBuilder {
                 let tsv_format = TSVFormat::read_from(path)?;
                 Vec::<Resource>::try_from(tsv_format)?
             }
+            FormatType::XLSX => Vec::<Resource>::try_from(XLSXFormat::read_from(path)?)?,
         };
 
         let should_override_language = matches!(
@@ -151,6 +152,7 @@ impl CodecBuilder {
                 let tsv_format = TSVFormat::read_from(path)?;
                 Vec::<Resource>::try_from(tsv_format)?
             }
+            FormatType::XLSX => Vec::<Resource>::try_from(XLSXFormat::read_from(path)?)?,
         };
 
         let should_override_language = matches!(
@@ -198,6 +200,7 @@ impl CodecBuilder {
             Some("xliff") => FormatType::Xliff(lang),
             Some("csv") => FormatType::CSV,
             Some("tsv") => FormatType::TSV,
+            Some("xlsx") => FormatType::XLSX,
             extension => {
                 return Err(Error::UnsupportedFormat(format!(
                     "Unsupported file extension: {:?}.",
@@ -7,12 +7,16 @@
 /// Android XML strings, and `.xcstrings`, providing methods to read from files by type
 /// or extension, write resources back to files, and cache resources to JSON.
 ///
-use crate::formats::{CSVFormat, TSVFormat};
+use crate::formats::{CSVFormat, TSVFormat, XLSXFormat};
 use crate::{ConflictStrategy, merge_resources};
 use crate::{
     error::Error,
+    fallback::{FallbackChain, ResolvedEntry},
     formats::*,
-    provenance::{ProvenanceRecord, set_resource_provenance},
+    provenance::{
+        EntryPosition, ProvenanceRecord, SourcePosition, set_entry_provenance,
+        set_resource_provenance,
+    },
     read_options::ReadOptions,
     traits::Parser,
     types::{Entry, Resource},
@@ -219,6 +223,53 @@ impl Codec {
         self.get_mut_by_language(language)?.find_entry_mut(key)
     }
 
+    /// Resolves `key` for `locale`, falling back along BCP 47 parents and then
+    /// to the source language.
+    ///
+    /// The source language is read from the `source_language` metadata of the
+    /// loaded resources, or else taken from the first resource, as formats
+    /// such as `.strings` do not record it. Empty entries are skipped, so an
+    /// untranslated `fr-CA` string resolves to its `fr` translation. Use
+    /// [`Codec::resolve_with`] for custom parents.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langcodec::{Codec, types::{Entry, EntryStatus, Metadata, Resource, Translation}};
+    ///
+    /// let mut codec = Codec::new();
+    /// codec.add_resource(Resource {
+    ///     metadata: Metadata { language: "zh-Hant".into(), domain: String::new(), custom: Default::default() },
+    ///     entries: vec![Entry { id: "hello".into(), value: Translation::Singular("你好".into()), comment: None, status: EntryStatus::Translated, custom: Default::default() }],
+    /// });
+    ///
+    /// let resolved = codec.resolve("hello", "zh-Hant-HK").unwrap();
+    /// assert_eq!(resolved.locale, "zh-Hant");
+    /// assert!(resolved.is_fallback());
+    /// ```
+    pub fn resolve(&self, key: &str, locale: &str) -> Option<ResolvedEntry<'_>> {
+        let mut chain = FallbackChain::new();
+        if let Some(source) = self
+            .resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language"))
+            .or_else(|| self.resources.first().map(|res| &res.metadata.language))
+        {
+            chain = chain.with_default(source);
+        }
+        self.resolve_with(key, locale, &chain)
+    }
+
+    /// Resolves `key` for `locale` using the given fallback chain.
+    pub fn resolve_with(
+        &self,
+        key: &str,
+        locale: &str,
+        chain: &FallbackChain,
+    ) -> Option<ResolvedEntry<'_>> {
+        chain.resolve(&self.resources, key, locale)
+    }
+
     /// Updates a translation for a specific key and language.
     ///
     /// # Arguments
@@ -661,6 +712,25 @@ impl Codec {
         total
     }
 
+    /// Validates that select (gender/enum) messages offer the same selector
+    /// keys in every language as in `source_language`.
+    ///
+    /// Select messages are recognized both as [`Translation::Select`] and as
+    /// singular ICU `select` strings.
+    ///
+    /// [`Translation::Select`]: crate::types::Translation::Select
+    pub fn validate_selects(&self, source_language: &str) -> Result<(), Error> {
+        crate::select::validate_selects(&self.resources, source_language)
+    }
+
+    /// Collects non-fatal select validation reports against `source_language`.
+    pub fn collect_select_issues(
+        &self,
+        source_language: &str,
+    ) -> Vec<crate::select::SelectValidationReport> {
+        crate::select::collect_select_issues(&self.resources, source_language)
+    }
+
     /// Cleans up resources by removing empty resources and entries.
     pub fn clean_up_resources(&mut self) {
         self.resources
@@ -691,122 +761,35 @@ impl Codec {
     /// assert!(codec.validate_placeholders(true).is_ok());
     /// ```
     pub fn validate_placeholders(&self, strict: bool) -> Result<(), Error> {
-        use crate::placeholder::signature;
-        use crate::types::Translation;
-        use std::collections::HashMap;
-
-        // key -> lang -> Vec<signatures per form or single>
-        let mut map: HashMap<String, HashMap<String, Vec<Vec<String>>>> = HashMap::new();
-
-        for res in &self.resources {
-            for entry in &res.entries {
-                let sigs: Vec<Vec<String>> = match &entry.value {
-                    Translation::Empty => vec![],
-                    Translation::Singular(v) => vec![signature(v)],
-                    Translation::Plural(p) => p.forms.values().map(|v| signature(v)).collect(),
-                };
-                map.entry(entry.id.clone())
-                    .or_default()
-                    .entry(res.metadata.language.clone())
-                    .or_default()
-                    .push(sigs.into_iter().flatten().collect());
-            }
-        }
-
-        let mut problems = Vec::new();
-
-        for (key, langs) in map {
-            // Per-language: ensure all collected signatures for this entry are identical
-            let mut per_lang_sig: HashMap<String, Vec<String>> = HashMap::new();
-            for (lang, sig_lists) in langs {
-                if let Some(first) = sig_lists.first() {
-                    if sig_lists.iter().any(|s| s != first) {
-                        problems.push(format!(
-                            "Key '{}' in '{}': inconsistent placeholders across forms: {:?}",
-                            key, lang, sig_lists
-                        ));
-                    }
-                    per_lang_sig.insert(lang, first.clone());
-                }
-            }
-
-            // Across languages, pick one baseline and compare
-            if let Some((base_lang, base_sig)) = per_lang_sig.iter().next() {
-                for (lang, sig) in &per_lang_sig {
-                    if sig != base_sig {
-                        problems.push(format!(
-                            "Key '{}' mismatch: {} {:?} vs {} {:?}",
-                            key, base_lang, base_sig, lang, sig
-                        ));
-                    }
-                }
-            }
-        }
-
-        if problems.is_empty() {
+        let issues = self.collect_placeholder_issues();
+        if issues.is_empty() || !strict {
+            // Non-strict mode: treat as success
             return Ok(());
         }
-        if strict {
-            return Err(Error::validation_error(format!(
-                "Placeholder issues: {}",
-                problems.join(" | ")
-            )));
-        }
-        // Non-strict mode: treat as success
-        Ok(())
+        let problems: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        Err(Error::validation_error(format!(
+            "Placeholder issues: {}",
+            problems.join(" | ")
+        )))
     }
 
     /// Collect placeholder issues without failing.
-    /// Returns a list of human-readable messages; empty if none.
+    ///
+    /// Translations are compared with the source language, read from the
+    /// `source_language` metadata or else the first resource. Each issue
+    /// names the affected form and may carry a suggested fix.
     ///
     /// Useful to warn in non-strict mode.
-    pub fn collect_placeholder_issues(&self) -> Vec<String> {
-        use crate::placeholder::signature;
-        use crate::types::Translation;
-        use std::collections::HashMap;
-
-        let mut map: HashMap<String, HashMap<String, Vec<Vec<String>>>> = HashMap::new();
-        for res in &self.resources {
-            for entry in &res.entries {
-                let sigs: Vec<Vec<String>> = match &entry.value {
-                    Translation::Empty => vec![],
-                    Translation::Singular(v) => vec![signature(v)],
-                    Translation::Plural(p) => p.forms.values().map(|v| signature(v)).collect(),
-                };
-                map.entry(entry.id.clone())
-                    .or_default()
-                    .entry(res.metadata.language.clone())
-                    .or_default()
-                    .push(sigs.into_iter().flatten().collect());
-            }
-        }
-
-        let mut problems = Vec::new();
-        for (key, langs) in map {
-            let mut per_lang_sig: HashMap<String, Vec<String>> = HashMap::new();
-            for (lang, sig_lists) in langs {
-                if let Some(first) = sig_lists.first() {
-                    if sig_lists.iter().any(|s| s != first) {
-                        problems.push(format!(
-                            "Key '{}' in '{}': inconsistent placeholders across forms: {:?}",
-                            key, lang, sig_lists
-                        ));
-                    }
-                    per_lang_sig.insert(lang, first.clone());
-                }
-            }
-            if let Some((base_lang, base_sig)) = per_lang_sig.iter().next() {
-                for (lang, sig) in &per_lang_sig {
-                    if sig != base_sig {
-                        problems.push(format!(
-                            "Key '{}' mismatch: {} {:?} vs {} {:?}",
-                            key, base_lang, base_sig, lang, sig
-                        ));
-                    }
-                }
-            }
-        }
-        problems
+    pub fn collect_placeholder_issues(&self) -> Vec<crate::placeholder::PlaceholderIssue> {
+        let Some(source_language) = self
+            .resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language"))
+            .or_else(|| self.resources.first().map(|res| &res.metadata.language))
+        else {
+            return Vec::new();
+        };
+        crate::placeholder::collect_placeholder_issues(&self.resources, source_language)
     }
 
     /// Normalize placeholders in all entries (mutates in place).
@@ -843,9 +826,181 @@ impl Codec {
                             *v = nv;
                         }
                     }
+                    Translation::Select(s) => {
+                        for v in s.cases.values_mut() {
+                            let nv = normalize_placeholders(v);
+                            *v = nv;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs `linter` over every translation, comparing with the source
+    /// language from the `source_language` metadata or else the first
+    /// resource; see [`crate::lint::Linter::lint`].
+    pub fn lint(&self, linter: &crate::lint::Linter) -> crate::lint::LintReport {
+        let Some(source_language) = self
+            .resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language"))
+            .or_else(|| self.resources.first().map(|res| &res.metadata.language))
+        else {
+            return crate::lint::LintReport::default();
+        };
+        linter.lint(&self.resources, source_language)
+    }
+
+    /// Marks translations whose source text changed since they were translated
+    /// as stale; see [`crate::operations::refresh_stale_status`].
+    pub fn refresh_stale_status(
+        &mut self,
+        options: &crate::operations::RefreshStatusOptions,
+    ) -> Result<crate::operations::RefreshStatusReport, Error> {
+        crate::operations::refresh_stale_status(&mut self.resources, options)
+    }
+
+    /// Rewrites placeholders in every entry from one dialect to another.
+    ///
+    /// All languages of a key share one [`PlaceholderNames`](crate::placeholder::PlaceholderNames)
+    /// table, filled from the source language first (the `source_language`
+    /// metadata, or the first resource), so a named argument gets the same
+    /// position in every translation regardless of word order. The table is
+    /// stored on each converted entry; an existing table is reused.
+    ///
+    /// Returns the number of entries whose value changed.
+    ///
+    /// Example
+    /// ```rust
+    /// use langcodec::{Codec, PlaceholderDialect, types::{Entry, EntryStatus, Metadata, Resource, Translation}};
+    /// let mut codec = Codec::new();
+    /// for (lang, text) in [("en", "{{user}} sent {{count}}"), ("de", "{{count}} von {{user}}")] {
+    ///     codec.add_resource(Resource{
+    ///         metadata: Metadata{ language: lang.into(), domain: String::new(), custom: Default::default() },
+    ///         entries: vec![Entry{ id: "sent".into(), value: Translation::Singular(text.into()), comment: None, status: EntryStatus::Translated, custom: Default::default() }]
+    ///     });
+    /// }
+    /// codec.convert_placeholders(PlaceholderDialect::I18next, PlaceholderDialect::Printf);
+    /// assert_eq!(codec.find_entry("sent", "de").unwrap().value, Translation::Singular("%2$s von %1$s".into()));
+    /// ```
+    pub fn convert_placeholders(
+        &mut self,
+        from: crate::placeholder::PlaceholderDialect,
+        to: crate::placeholder::PlaceholderDialect,
+    ) -> usize {
+        use crate::placeholder::{
+            PlaceholderNames, convert_entry_placeholders, register_entry_placeholder_names,
+        };
+
+        let source_language = self
+            .resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language"))
+            .or_else(|| self.resources.first().map(|res| &res.metadata.language))
+            .cloned()
+            .unwrap_or_default();
+        // Source resources first so that they decide argument positions.
+        let mut order: Vec<usize> = (0..self.resources.len()).collect();
+        order.sort_by_key(|&i| self.resources[i].metadata.language != source_language);
+
+        let mut tables: std::collections::HashMap<String, PlaceholderNames> =
+            std::collections::HashMap::new();
+        for &i in &order {
+            for entry in &self.resources[i].entries {
+                let names = tables.entry(entry.id.clone()).or_default();
+                if names.is_empty()
+                    && let Some(stored) = PlaceholderNames::from_entry(entry)
+                {
+                    *names = stored;
+                }
+            }
+        }
+        for &i in &order {
+            for entry in &self.resources[i].entries {
+                if let Some(names) = tables.get_mut(&entry.id) {
+                    register_entry_placeholder_names(entry, from, names);
+                }
+            }
+        }
+
+        let mut changed = 0;
+        for &i in &order {
+            for entry in &mut self.resources[i].entries {
+                if let Some(names) = tables.get_mut(&entry.id)
+                    && convert_entry_placeholders(entry, from, to, names)
+                {
+                    changed += 1;
+                }
+            }
+        }
+        // Names invented while converting later entries apply to earlier ones too.
+        for res in &mut self.resources {
+            for entry in &mut res.entries {
+                if let Some(names) = tables.get(&entry.id) {
+                    names.store(entry);
                 }
             }
         }
+        changed
+    }
+
+    /// Renames `from` to `to` in every language.
+    ///
+    /// Fails without changing anything when no language has `from` or when
+    /// `to` already exists next to it; see [`crate::rename::rename_keys`].
+    ///
+    /// Example
+    /// ```rust
+    /// use langcodec::{Codec, types::Translation};
+    /// let mut codec = Codec::new();
+    /// codec.add_entry("login", "en", Translation::Singular("Log in".into()), None, None)?;
+    /// codec.add_entry("login", "fr", Translation::Singular("Connexion".into()), None, None)?;
+    /// codec.rename_key("login", "auth.login")?;
+    /// assert!(codec.has_entry("auth.login", "fr"));
+    /// # Ok::<(), langcodec::Error>(())
+    /// ```
+    pub fn rename_key(
+        &mut self,
+        from: &str,
+        to: &str,
+    ) -> Result<crate::rename::RenameReport, Error> {
+        let mapping = crate::rename::KeyMapping::pairs([(from, to)])?;
+        if !self.all_keys().any(|key| key == from) {
+            return Err(Error::InvalidResource(format!(
+                "Entry '{}' not found in any language",
+                from
+            )));
+        }
+        crate::rename::rename_keys(&mut self.resources, &mapping)
+    }
+
+    /// Renames every key matched by `mapping` in every language, or nothing
+    /// when any language has a collision; see [`crate::rename::rename_keys`].
+    pub fn bulk_rename(
+        &mut self,
+        mapping: &crate::rename::KeyMapping,
+    ) -> Result<crate::rename::RenameReport, Error> {
+        crate::rename::rename_keys(&mut self.resources, mapping)
+    }
+
+    /// Replaces every text, including each plural form and select case, with
+    /// `f(language, key, text)` and returns the number of changed entries.
+    ///
+    /// Example
+    /// ```rust
+    /// use langcodec::{Codec, types::Translation};
+    /// let mut codec = Codec::new();
+    /// codec.add_entry("title", "en", Translation::Singular("Acme Notes".into()), None, None)?;
+    /// codec.map_values(|_language, _key, text| text.replace("Acme", "Globex"));
+    /// assert_eq!(codec.find_entry("title", "en").unwrap().value, Translation::Singular("Globex Notes".into()));
+    /// # Ok::<(), langcodec::Error>(())
+    /// ```
+    pub fn map_values<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&str, &str, &str) -> String,
+    {
+        crate::rename::map_values(&mut self.resources, f)
     }
 
     /// Merge resources with the same language by the given strategy.
@@ -948,7 +1103,7 @@ impl Codec {
     /// ```
     pub fn write_resource_to_file(resource: &Resource, output_path: &str) -> Result<(), Error> {
         use crate::formats::{
-            AndroidStringsFormat, CSVFormat, StringsFormat, TSVFormat, XcstringsFormat,
+            AndroidStringsFormat, CSVFormat, StringsFormat, TSVFormat, XLSXFormat, XcstringsFormat,
         };
         use std::path::Path;
 
@@ -1000,6 +1155,61 @@ impl Codec {
                 .map_err(|e| {
                     Error::conversion_error(format!("Error writing TSV output: {}", e), None)
                 }),
+            crate::formats::FormatType::XLSX => XLSXFormat::try_from(vec![resource.clone()])
+                .and_then(|f| f.write_to(Path::new(output_path)))
+                .map_err(|e| {
+                    Error::conversion_error(format!("Error writing XLSX output: {}", e), None)
+                }),
+        }
+    }
+
+    /// Writes a resource back to an existing file, preserving its layout when possible.
+    ///
+    /// For Apple `.strings` and Android `strings.xml` files that already exist at
+    /// `path`, only the entries that changed are rewritten (see
+    /// [`crate::formats::strings::Document`] and
+    /// [`crate::formats::android_strings::Document`]), so comments, blank lines,
+    /// extra attributes and unknown elements survive the edit. Other formats, or
+    /// paths that do not exist yet, fall back to [`Codec::write_resource_to_file`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use langcodec::Codec;
+    ///
+    /// let mut codec = Codec::new();
+    /// codec.read_file_by_extension("values/strings.xml", Some("en".to_string()))?;
+    /// codec.update_translation("welcome", "en", langcodec::types::Translation::Singular("Hi".into()), None)?;
+    /// Codec::update_resource_file(&codec.resources[0], "values/strings.xml")?;
+    /// # Ok::<(), langcodec::Error>(())
+    /// ```
+    pub fn update_resource_file(resource: &Resource, path: &str) -> Result<(), Error> {
+        use crate::formats::{FormatType, android_strings, strings};
+        use std::path::Path;
+
+        if !Path::new(path).is_file() {
+            return Self::write_resource_to_file(resource, path);
+        }
+
+        match crate::converter::infer_format_from_extension(path) {
+            Some(FormatType::Strings(_)) => {
+                let mut document = strings::Document::read_from(path)?;
+                document.apply(resource)?;
+                document.write_to(path).map_err(|e| {
+                    Error::conversion_error(format!("Error writing Strings output: {}", e), None)
+                })
+            }
+            Some(FormatType::AndroidStrings(_)) => {
+                let mut document = android_strings::Document::read_from(path)?;
+                document.apply(resource)?;
+                document.write_to(path).map_err(|e| {
+                    Error::conversion_error(
+                        format!("Error writing AndroidStrings output: {}", e),
+                        None,
+                    )
+                })
+            }
+            _ => Self::write_resource_to_file(resource, path),
         }
     }
 
@@ -1064,26 +1274,45 @@ impl Codec {
             .to_string();
         let path = path.as_ref();
 
-        let mut new_resources = match &format_type {
+        let open = || {
+            std::fs::File::open(path)
+                .map(std::io::BufReader::new)
+                .map_err(Error::Io)
+        };
+        let (mut new_resources, positions) = match &format_type {
             FormatType::Strings(_) => {
-                vec![Resource::from(StringsFormat::read_from(path)?)]
+                let (format, positions) = StringsFormat::from_reader_with_positions(open()?)?;
+                (vec![Resource::from(format)], positions)
             }
             FormatType::AndroidStrings(_) => {
-                vec![Resource::from(AndroidStringsFormat::read_from(path)?)]
+                let (format, positions) =
+                    AndroidStringsFormat::from_reader_with_positions(open()?)?;
+                (vec![Resource::from(format)], positions)
+            }
+            FormatType::Xcstrings => {
+                let (format, positions) = XcstringsFormat::from_reader_with_positions(open()?)?;
+                (Vec::<Resource>::try_from(format)?, positions)
+            }
+            FormatType::Xliff(_) => {
+                let (format, positions) = XliffFormat::from_reader_with_positions(open()?)?;
+                (Vec::<Resource>::try_from(format)?, positions)
             }
-            FormatType::Xcstrings => Vec::<Resource>::try_from(XcstringsFormat::read_from(path)?)?,
-            FormatType::Xliff(_) => Vec::<Resource>::try_from(XliffFormat::read_from(path)?)?,
             FormatType::CSV => {
                 // Parse CSV format and convert to resources
-                let csv_format = CSVFormat::read_from(path)?;
-                Vec::<Resource>::try_from(csv_format)?
+                let (format, positions) = CSVFormat::from_reader_with_positions(open()?)?;
+                (Vec::<Resource>::try_from(format)?, positions)
             }
             FormatType::TSV => {
                 // Parse TSV format and convert to resources
-                let tsv_format = TSVFormat::read_from(path)?;
-                Vec::<Resource>::try_from(tsv_format)?
+                let (format, positions) = TSVFormat::from_reader_with_positions(open()?)?;
+                (Vec::<Resource>::try_from(format)?, positions)
             }
+            FormatType::XLSX => (
+                Vec::<Resource>::try_from(XLSXFormat::read_from(path)?)?,
+                Vec::new(),
+            ),
         };
+        let positions = index_positions(&positions);
 
         for new_resource in &mut new_resources {
             if requires_language && let Some(ref lang) = language {
@@ -1105,6 +1334,23 @@ impl Codec {
                         ..ProvenanceRecord::default()
                     },
                 );
+                let resource_language = new_resource.metadata.language.clone();
+                for entry in &mut new_resource.entries {
+                    let position = positions
+                        .get(&(entry.id.as_str(), Some(resource_language.as_str())))
+                        .or_else(|| positions.get(&(entry.id.as_str(), None)));
+                    if let Some(position) = position {
+                        set_entry_provenance(
+                            entry,
+                            &ProvenanceRecord {
+                                source_path: Some(source_path.clone()),
+                                source_format: Some(format_name.clone()),
+                                ..ProvenanceRecord::default()
+                            }
+                            .with_position(*position),
+                        );
+                    }
+                }
             }
         }
         self.resources.append(&mut new_resources);
@@ -1144,6 +1390,7 @@ impl Codec {
             Some("xliff") => FormatType::Xliff(None),
             Some("csv") => FormatType::CSV,
             Some("tsv") => FormatType::TSV,
+            Some("xlsx") => FormatType::XLSX,
             extension => {
                 return Err(Error::UnsupportedFormat(format!(
                     "Unsupported file extension: {:?}.",
@@ -1217,6 +1464,20 @@ impl Codec {
     }
 }
 
+/// Indexes parsed entry positions by key and language, keeping the first
+/// position reported for each pair.
+fn index_positions(
+    positions: &[EntryPosition],
+) -> std::collections::HashMap<(&str, Option<&str>), SourcePosition> {
+    let mut index = std::collections::HashMap::new();
+    for item in positions {
+        index
+            .entry((item.key.as_str(), item.language.as_deref()))
+            .or_insert(item.position);
+    }
+    index
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2045,4 +2306,108 @@ mod tests {
         );
         assert_eq!(provenance.source_format, Some("strings".to_string()));
     }
+
+    #[test]
+    fn test_read_file_records_entry_positions() {
+        let temp = tempfile::tempdir().unwrap();
+        let files = [
+            (
+                "values-fr/strings.xml",
+                "<resources>\n    <string name=\"a\">A</string>\n  <string name=\"b\">B</string>\n</resources>\n",
+                (3, 3),
+            ),
+            (
+                "fr.lproj/Localizable.strings",
+                "/* greeting */\n\"a\" = \"A\";\n  \"b\" = \"B\";\n",
+                (3, 3),
+            ),
+            (
+                "Localizable.xcstrings",
+                "{\n  \"sourceLanguage\" : \"fr\",\n  \"strings\" : {\n    \"a\" : {},\n    \"b\" : { \"localizations\" : {\n      \"fr\" : { \"stringUnit\" : { \"state\" : \"translated\", \"value\" : \"B\" } } } }\n  },\n  \"version\" : \"1.0\"\n}",
+                // The French localization, not the key.
+                (6, 7),
+            ),
+            (
+                "fr.xliff",
+                "<?xml version=\"1.0\"?>\n<xliff version=\"1.2\">\n <file source-language=\"en\" target-language=\"fr\" datatype=\"plaintext\">\n  <body>\n   <trans-unit id=\"a\"><source>A</source><target>A</target></trans-unit>\n    <trans-unit id=\"b\"><source>B</source><target>B</target></trans-unit>\n  </body>\n </file>\n</xliff>\n",
+                (6, 5),
+            ),
+            ("table.csv", "key,en,fr\na,A,A\nb,B,B\n", (3, 1)),
+        ];
+
+        for (name, content, (line, column)) in files {
+            let input = temp.path().join(name);
+            std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+            std::fs::write(&input, content).unwrap();
+
+            let mut codec = Codec::new();
+            codec
+                .read_file_by_extension_with_options(
+                    &input,
+                    &ReadOptions::new().with_provenance(true),
+                )
+                .unwrap();
+            let resource = codec.get_by_language("fr").unwrap();
+            let entry = resource.find_entry("b").unwrap();
+            let provenance = crate::entry_provenance(entry).unwrap();
+            assert_eq!(
+                provenance.source_path,
+                Some(input.to_string_lossy().to_string()),
+                "{}",
+                name
+            );
+            let position = provenance.position().unwrap();
+            assert_eq!((position.line, position.column), (line, column), "{}", name);
+            assert_eq!(
+                SourcePosition::from_offset(content, position.offset),
+                position
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_file_reports_parse_error_position() {
+        let temp = tempfile::tempdir().unwrap();
+        let input = temp.path().join("values").join("strings.xml");
+        std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+        std::fs::write(
+            &input,
+            "<resources>\n    <string name=\"ok\">OK</string>\n    <string>Nameless</string>\n</resources>\n",
+        )
+        .unwrap();
+
+        let mut codec = Codec::new();
+        let error = codec
+            .read_file_by_extension_with_options(&input, &ReadOptions::new())
+            .unwrap_err();
+        let context = error.structured().context.unwrap();
+        assert_eq!((context.line, context.column), (Some(3), Some(5)));
+        assert!(
+            error.to_string().ends_with("at line 3, column 5"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_first_strings_resource() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut codec = Codec::new();
+        for (language, content) in [
+            ("en", "\"hello\" = \"Hello\";\n\"bye\" = \"Bye\";\n"),
+            ("fr", "\"hello\" = \"Bonjour\";\n"),
+        ] {
+            let lproj = temp.path().join(format!("{}.lproj", language));
+            std::fs::create_dir_all(&lproj).unwrap();
+            let input = lproj.join("Localizable.strings");
+            std::fs::write(&input, content).unwrap();
+            codec.read_file_by_extension(&input, None).unwrap();
+        }
+
+        let resolved = codec.resolve("hello", "fr-CA").unwrap();
+        assert_eq!(resolved.locale, "fr");
+        let resolved = codec.resolve("bye", "fr-CA").unwrap();
+        assert_eq!(resolved.locale, "en");
+        assert!(resolved.is_fallback());
+    }
 }
@@ -7,8 +7,8 @@ use crate::{
     ConflictStrategy,
     error::Error,
     formats::{
-        AndroidStringsFormat, CSVFormat, FormatType, StringsFormat, TSVFormat, XcstringsFormat,
-        XliffFormat,
+        AndroidStringsFormat, CSVFormat, FormatType, StringsFormat, TSVFormat, XLSXFormat,
+        XcstringsFormat, XliffFormat,
     },
     placeholder::normalize_placeholders,
     traits::Parser,
@@ -42,9 +42,11 @@ fn single_language_output_label(output_format: &FormatType) -> &'static str {
     match output_format {
         FormatType::AndroidStrings(_) => "Android strings.xml",
         FormatType::Strings(_) => "Apple .strings",
-        FormatType::Xcstrings | FormatType::Xliff(_) | FormatType::CSV | FormatType::TSV => {
-            "single-language"
-        }
+        FormatType::Xcstrings
+        | FormatType::Xliff(_)
+        | FormatType::CSV
+        | FormatType::TSV
+        | FormatType::XLSX => "single-language",
     }
 }
 
@@ -116,12 +118,14 @@ fn select_single_language_resource(
                 describe_resource_languages(resources)
             ))),
         },
-        FormatType::Xcstrings | FormatType::Xliff(_) | FormatType::CSV | FormatType::TSV => {
-            Err(Error::InvalidResource(
-                "single-language resource selection requires a single-language output format"
-                    .to_string(),
-            ))
-        }
+        FormatType::Xcstrings
+        | FormatType::Xliff(_)
+        | FormatType::CSV
+        | FormatType::TSV
+        | FormatType::XLSX => Err(Error::InvalidResource(
+            "single-language resource selection requires a single-language output format"
+                .to_string(),
+        )),
     }
 }
 
@@ -203,6 +207,11 @@ pub fn convert_resources_to_format(
         FormatType::TSV => TSVFormat::try_from(resources)
             .and_then(|f| f.write_to(Path::new(output_path)))
             .map_err(|e| Error::conversion_error(format!("Error writing TSV output: {}", e), None)),
+        FormatType::XLSX => XLSXFormat::try_from(resources)
+            .and_then(|f| f.write_to(Path::new(output_path)))
+            .map_err(|e| {
+                Error::conversion_error(format!("Error writing XLSX output: {}", e), None)
+            }),
     }
 }
 
@@ -262,6 +271,7 @@ pub fn convert<P: AsRef<Path>>(
         FormatType::Xliff(_) => Vec::<Resource>::try_from(XliffFormat::read_from(input)?)?,
         FormatType::CSV => Vec::<Resource>::try_from(CSVFormat::read_from(input)?)?,
         FormatType::TSV => Vec::<Resource>::try_from(TSVFormat::read_from(input)?)?,
+        FormatType::XLSX => Vec::<Resource>::try_from(XLSXFormat::read_from(input)?)?,
     };
 
     // Ensure language is set for single-language inputs if provided on input_format
@@ -293,6 +303,7 @@ pub fn convert<P: AsRef<Path>>(
         }
         FormatType::CSV => CSVFormat::try_from(resources)?.write_to(output),
         FormatType::TSV => TSVFormat::try_from(resources)?.write_to(output),
+        FormatType::XLSX => XLSXFormat::try_from(resources)?.write_to(output),
     }
 }
 
@@ -350,6 +361,7 @@ pub fn convert_with_normalization<P: AsRef<Path>>(
         FormatType::Xliff(_) => Vec::<Resource>::try_from(XliffFormat::read_from(input)?)?,
         FormatType::CSV => Vec::<Resource>::try_from(CSVFormat::read_from(input)?)?,
         FormatType::TSV => Vec::<Resource>::try_from(TSVFormat::read_from(input)?)?,
+        FormatType::XLSX => Vec::<Resource>::try_from(XLSXFormat::read_from(input)?)?,
     };
 
     // Ensure language is set for single-language inputs if provided on input_format
@@ -376,6 +388,11 @@ pub fn convert_with_normalization<P: AsRef<Path>>(
                             *v = normalize_placeholders(v);
                         }
                     }
+                    crate::types::Translation::Select(s) => {
+                        for v in s.cases.values_mut() {
+                            *v = normalize_placeholders(v);
+                        }
+                    }
                 }
             }
         }
@@ -401,6 +418,7 @@ pub fn convert_with_normalization<P: AsRef<Path>>(
         }
         FormatType::CSV => CSVFormat::try_from(resources)?.write_to(output),
         FormatType::TSV => TSVFormat::try_from(resources)?.write_to(output),
+        FormatType::XLSX => XLSXFormat::try_from(resources)?.write_to(output),
     }
 }
 
@@ -477,7 +495,7 @@ mod normalize_tests {
         let content = fs::read_to_string(&xml).unwrap();
         assert!(content.contains("%s"));
         assert!(content.contains("%1$s"));
-        assert!(content.contains("%d"));
+        assert!(content.contains("%ld"));
     }
 }
 
@@ -559,6 +577,7 @@ pub fn infer_format_from_extension<P: AsRef<Path>>(path: P) -> Option<FormatType
         "xliff" => Some(FormatType::Xliff(None)),
         "csv" => Some(FormatType::CSV),
         "tsv" => Some(FormatType::TSV),
+        "xlsx" => Some(FormatType::XLSX),
         _ => None,
     }
 }
@@ -598,9 +617,11 @@ pub fn infer_format_from_path<P: AsRef<Path>>(path: P) -> Option<FormatType> {
     match infer_format_from_extension(&path) {
         Some(format) => match format {
             // Multi-language formats, no language inference needed
-            FormatType::Xcstrings | FormatType::Xliff(_) | FormatType::CSV | FormatType::TSV => {
-                Some(format)
-            }
+            FormatType::Xcstrings
+            | FormatType::Xliff(_)
+            | FormatType::CSV
+            | FormatType::TSV
+            | FormatType::XLSX => Some(format),
             FormatType::AndroidStrings(_) | FormatType::Strings(_) => {
                 let lang = infer_language_from_path(&path, &format).ok().flatten();
                 Some(format.with_language(lang))
@@ -788,6 +809,7 @@ pub fn write_resources_to_file(resources: &[Resource], file_path: &String) -> Re
             }
             Some("CSV") => CSVFormat::try_from(resources.to_vec())?.write_to(path)?,
             Some("TSV") => TSVFormat::try_from(resources.to_vec())?.write_to(path)?,
+            Some("XLSX") => XLSXFormat::try_from(resources.to_vec())?.write_to(path)?,
             _ => Err(Error::UnsupportedFormat(format!(
                 "Unsupported format: {:?}",
                 first.metadata.custom.get("format")
@@ -911,7 +933,9 @@ pub fn merge_resources(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Entry, EntryStatus, Metadata, Plural, PluralCategory, Translation};
+    use crate::types::{
+        Entry, EntryStatus, Metadata, Plural, PluralCategory, PluralKind, Translation,
+    };
     use std::collections::{BTreeMap, HashMap};
 
     #[test]
@@ -974,6 +998,7 @@ mod tests {
                 value: Translation::Plural(Plural {
                     id: "apples".into(),
                     forms,
+                    kind: PluralKind::Cardinal,
                 }),
                 comment: Some("Count apples".into()),
                 status: EntryStatus::Translated,
@@ -1017,6 +1042,41 @@ mod tests {
         assert!(qs.iter().any(|(q, v)| *q == "other" && v == "%d apples"));
     }
 
+    #[test]
+    fn test_select_messages_are_written_as_icu_text() {
+        let tmp = tempfile::tempdir().unwrap();
+        let output = tmp.path().join("Localizable.strings");
+
+        let mut resource = build_resource("en", &[]);
+        resource.entries.push(Entry {
+            id: "replied".into(),
+            value: Translation::Select(
+                crate::types::Select::new(
+                    "gender",
+                    [
+                        ("female".to_string(), "She replied".to_string()),
+                        ("other".to_string(), "They replied".to_string()),
+                    ],
+                )
+                .unwrap(),
+            ),
+            comment: None,
+            status: EntryStatus::Translated,
+            custom: HashMap::new(),
+        });
+        convert_resources_to_format(
+            vec![resource],
+            output.to_str().unwrap(),
+            FormatType::Strings(Some("en".into())),
+        )
+        .unwrap();
+
+        let written = std::fs::read_to_string(&output).unwrap();
+        assert!(written.contains(
+            r#""replied" = "{gender, select, female {She replied} other {They replied}}";"#
+        ));
+    }
+
     #[test]
     fn test_convert_resources_to_strings_requires_language_selection_for_multilang_input() {
         let tmp = tempfile::tempdir().unwrap();
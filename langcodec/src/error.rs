@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::provenance::SourcePosition;
+
 /// Stable machine-readable category for [`enum@Error`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -37,6 +39,12 @@ pub struct ErrorContext {
     pub language: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub candidates: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
 }
 
 /// Serializable structured representation of an [`enum@Error`].
@@ -96,6 +104,12 @@ pub enum Error {
 
     #[error("policy violation: {0}")]
     PolicyViolation(String),
+
+    #[error("{source} at line {}, column {}", .position.line, .position.column)]
+    Located {
+        source: Box<Error>,
+        position: SourcePosition,
+    },
 }
 
 impl Error {
@@ -128,6 +142,32 @@ impl Error {
         Error::PolicyViolation(message.into())
     }
 
+    /// Attaches the source position where this error occurred.
+    ///
+    /// Errors that already carry a position are returned unchanged.
+    pub fn located(self, position: SourcePosition) -> Self {
+        match self {
+            Error::Located { .. } => self,
+            source => Error::Located {
+                source: Box::new(source),
+                position,
+            },
+        }
+    }
+
+    /// Returns the source position of the error, when the parser knows it.
+    pub fn position(&self) -> Option<SourcePosition> {
+        match self {
+            Error::Located { position, .. } => Some(*position),
+            Error::Parse(e) if e.line() > 0 => Some(SourcePosition {
+                offset: 0,
+                line: e.line(),
+                column: e.column(),
+            }),
+            _ => None,
+        }
+    }
+
     /// Returns a machine-readable error code.
     pub fn error_code(&self) -> ErrorCode {
         match self {
@@ -144,6 +184,7 @@ impl Error {
             Error::MissingLanguage { .. } => ErrorCode::MissingLanguage,
             Error::AmbiguousMatch { .. } => ErrorCode::AmbiguousMatch,
             Error::PolicyViolation(_) => ErrorCode::PolicyViolation,
+            Error::Located { source, .. } => source.error_code(),
         }
     }
 
@@ -165,6 +206,22 @@ impl Error {
                 candidates: candidates.clone(),
                 ..ErrorContext::default()
             }),
+            Error::Located { source, position } => Some(ErrorContext {
+                offset: Some(position.offset),
+                line: Some(position.line),
+                column: Some(position.column),
+                ..source.context().unwrap_or_default()
+            }),
+            Error::Parse(_) => self.position().map(|position| ErrorContext {
+                line: Some(position.line),
+                column: Some(position.column),
+                ..ErrorContext::default()
+            }),
+            Error::CsvParse(e) => e.position().map(|position| ErrorContext {
+                offset: Some(position.byte() as usize),
+                line: Some(position.line() as usize),
+                ..ErrorContext::default()
+            }),
             _ => None,
         }
     }
@@ -303,4 +360,29 @@ mod tests {
             Some(vec!["a".to_string(), "b".to_string()])
         );
     }
+
+    #[test]
+    fn test_structured_error_carries_parse_position() {
+        let position = SourcePosition {
+            offset: 30,
+            line: 2,
+            column: 7,
+        };
+        let error = Error::InvalidResource("bad".to_string()).located(position);
+        assert_eq!(
+            error.to_string(),
+            "invalid resource: bad at line 2, column 7"
+        );
+        let structured = error.structured();
+        assert_eq!(structured.code, ErrorCode::InvalidResource);
+        let context = structured.context.unwrap();
+        assert_eq!(
+            (context.offset, context.line, context.column),
+            (Some(30), Some(2), Some(7))
+        );
+
+        let json_error = serde_json::from_str::<serde_json::Value>("{\n  oops }").unwrap_err();
+        let context = Error::Parse(json_error).context().unwrap();
+        assert_eq!((context.line, context.column), (Some(2), Some(3)));
+    }
 }
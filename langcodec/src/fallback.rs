@@ -0,0 +1,210 @@
+//! Locale fallback resolution.
+//!
+//! A [`FallbackChain`] lists the locales to try for a requested locale:
+//! the locale itself, its BCP 47 parents obtained by dropping trailing
+//! subtags (`zh-Hant-HK` → `zh-Hant` → `zh`), and finally a default locale,
+//! usually the source language. Custom parents override truncation, e.g. to
+//! send `es-419` to `es-MX` or `pt-AO` to `pt-PT`.
+//!
+//! Locales are compared case-insensitively and `_` is treated like `-`, so
+//! `zh_Hant` and `zh-hant` name the same resource.
+
+use std::collections::HashMap;
+
+use crate::types::{Entry, Resource, Translation};
+
+/// Ordered locale fallback rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FallbackChain {
+    /// Custom parents keyed by normalized locale.
+    parents: HashMap<String, String>,
+    /// Locale tried after all parents, usually the source language.
+    default_locale: Option<String>,
+}
+
+impl FallbackChain {
+    /// Creates a chain that only walks BCP 47 parents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses `parent` instead of the truncated tag as the parent of `locale`.
+    pub fn with_parent(mut self, locale: &str, parent: &str) -> Self {
+        self.parents
+            .insert(normalize_locale(locale), parent.to_string());
+        self
+    }
+
+    /// Tries `locale` after every parent of the requested locale.
+    pub fn with_default(mut self, locale: &str) -> Self {
+        self.default_locale = Some(locale.to_string());
+        self
+    }
+
+    /// The configured default locale, if any.
+    pub fn default_locale(&self) -> Option<&str> {
+        self.default_locale.as_deref()
+    }
+
+    /// Returns the parent of `locale`, or `None` for a bare language.
+    pub fn parent(&self, locale: &str) -> Option<String> {
+        let normalized = normalize_locale(locale);
+        if let Some(parent) = self.parents.get(&normalized) {
+            return Some(parent.clone());
+        }
+        let tag = locale.replace('_', "-");
+        let subtags: Vec<&str> = tag.split('-').collect();
+        // Extensions and private use (`-u-…`, `-x-…`) are dropped in one step.
+        let end = match subtags.iter().skip(1).position(|subtag| subtag.len() == 1) {
+            Some(singleton) => singleton + 1,
+            None => subtags.len() - 1,
+        };
+        (end > 0).then(|| subtags[..end].join("-"))
+    }
+
+    /// Lists the locales to try for `locale`, most specific first, without duplicates.
+    ///
+    /// ```rust
+    /// use langcodec::FallbackChain;
+    ///
+    /// let chain = FallbackChain::new()
+    ///     .with_parent("es-419", "es-MX")
+    ///     .with_default("en");
+    /// assert_eq!(chain.locales("zh-Hant-HK"), ["zh-Hant-HK", "zh-Hant", "zh", "en"]);
+    /// assert_eq!(chain.locales("es-419"), ["es-419", "es-MX", "es", "en"]);
+    /// ```
+    pub fn locales(&self, locale: &str) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        let push = |candidate: String, out: &mut Vec<String>| {
+            let seen = out
+                .iter()
+                .any(|existing| normalize_locale(existing) == normalize_locale(&candidate));
+            if seen {
+                false
+            } else {
+                out.push(candidate);
+                true
+            }
+        };
+
+        let mut current = Some(locale.to_string());
+        while let Some(candidate) = current {
+            // Stop on cycles in custom parents.
+            if !push(candidate.clone(), &mut out) {
+                break;
+            }
+            current = self.parent(&candidate);
+        }
+        if let Some(default) = &self.default_locale {
+            push(default.clone(), &mut out);
+        }
+        out
+    }
+
+    /// Finds the first resource along the chain for `locale` that has a
+    /// non-empty entry for `key`.
+    pub fn resolve<'a>(
+        &self,
+        resources: &'a [Resource],
+        key: &str,
+        locale: &str,
+    ) -> Option<ResolvedEntry<'a>> {
+        self.locales(locale).iter().find_map(|candidate| {
+            let wanted = normalize_locale(candidate);
+            resources
+                .iter()
+                .filter(|res| normalize_locale(&res.metadata.language) == wanted)
+                .find_map(|res| {
+                    res.find_entry(key)
+                        .filter(|entry| has_value(entry))
+                        .map(|entry| ResolvedEntry {
+                            entry,
+                            locale: &res.metadata.language,
+                            requested: normalize_locale(locale) == wanted,
+                        })
+                })
+        })
+    }
+}
+
+/// An entry found by fallback resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedEntry<'a> {
+    /// The matching entry.
+    pub entry: &'a Entry,
+    /// Language of the resource the entry came from, as written in its metadata.
+    pub locale: &'a str,
+    requested: bool,
+}
+
+impl ResolvedEntry<'_> {
+    /// Returns `true` when the entry came from a fallback rather than the requested locale.
+    pub fn is_fallback(&self) -> bool {
+        !self.requested
+    }
+}
+
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+fn has_value(entry: &Entry) -> bool {
+    match &entry.value {
+        Translation::Empty => false,
+        Translation::Singular(value) => !value.is_empty(),
+        Translation::Plural(plural) => !plural.forms.is_empty(),
+        Translation::Select(select) => !select.cases.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::text_resource;
+
+    #[test]
+    fn test_bcp47_parents() {
+        let chain = FallbackChain::new();
+        assert_eq!(chain.parent("zh-Hant-HK").as_deref(), Some("zh-Hant"));
+        assert_eq!(chain.parent("zh_Hant").as_deref(), Some("zh"));
+        assert_eq!(chain.parent("zh"), None);
+        assert_eq!(chain.parent("de-DE-u-co-phonebk").as_deref(), Some("de-DE"));
+        assert_eq!(chain.parent("en-x-pseudo").as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn test_custom_parents_and_cycles() {
+        let chain = FallbackChain::new()
+            .with_parent("pt-AO", "pt-PT")
+            .with_parent("a", "b")
+            .with_parent("b", "a")
+            .with_default("EN");
+        assert_eq!(chain.locales("pt_AO"), ["pt_AO", "pt-PT", "pt", "EN"]);
+        assert_eq!(chain.locales("a"), ["a", "b", "EN"]);
+        assert_eq!(chain.locales("en"), ["en"]);
+    }
+
+    #[test]
+    fn test_resolve_walks_chain_and_skips_empty_entries() {
+        let resources = vec![
+            text_resource("en", &[("title", "Title"), ("hello", "Hello")]),
+            text_resource("zh-Hant", &[("hello", "你好"), ("title", "")]),
+            text_resource("zh_Hant_HK", &[("bye", "再見")]),
+        ];
+        let chain = FallbackChain::new().with_default("en");
+
+        let hit = chain.resolve(&resources, "bye", "zh-Hant-HK").unwrap();
+        assert_eq!(hit.locale, "zh_Hant_HK");
+        assert!(!hit.is_fallback());
+
+        let hit = chain.resolve(&resources, "hello", "zh-Hant-HK").unwrap();
+        assert_eq!(hit.locale, "zh-Hant");
+        assert!(hit.is_fallback());
+        assert_eq!(hit.entry.value, Translation::Singular("你好".into()));
+
+        let hit = chain.resolve(&resources, "title", "zh-Hant-HK").unwrap();
+        assert_eq!(hit.locale, "en");
+
+        assert!(chain.resolve(&resources, "missing", "zh-Hant-HK").is_none());
+    }
+}
@@ -0,0 +1,875 @@
+//! Rendering entries with runtime arguments.
+//!
+//! [`format_entry`] picks the plural form or select case of an [`Entry`] the
+//! way an app would at runtime and substitutes its placeholders:
+//!
+//! - printf-style specifiers as used by Apple and Android (`%d`, `%1$@`,
+//!   `%.2f`, `%5s`, `%%`), with flags, width, precision and length modifiers;
+//! - ICU MessageFormat arguments (`{name}`, `{0}`, `{n, number}`), including
+//!   nested `plural`, `selectordinal` and `select` expressions with `#`.
+//!
+//! Plural forms are chosen with the CLDR rules for the target language, so
+//! previewing a Russian string with `5` picks `many`.
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::{
+    error::Error,
+    placeholder::parse_placeholder,
+    plural_rules::{PluralOperands, category_for_kind},
+    types::{Entry, PluralCategory, PluralKind, Select, Translation},
+};
+
+/// A typed runtime argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatArg {
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl FormatArg {
+    /// Parses `value` as an integer, then as a float, falling back to a string.
+    pub fn infer(value: &str) -> Self {
+        if let Ok(int) = value.parse::<i64>() {
+            FormatArg::Int(int)
+        } else if let Ok(float) = value.parse::<f64>()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            FormatArg::Float(float)
+        } else {
+            FormatArg::Str(value.to_string())
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            FormatArg::Int(value) => Some(*value as f64),
+            FormatArg::Float(value) => Some(*value),
+            FormatArg::Str(value) => value.trim().parse().ok(),
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            FormatArg::Int(value) => Some(*value),
+            FormatArg::Float(value) => Some(value.trunc() as i64),
+            FormatArg::Str(value) => value.trim().parse().ok(),
+        }
+    }
+
+    fn operands(&self) -> Option<PluralOperands> {
+        match self {
+            FormatArg::Int(value) => Some((*value).into()),
+            FormatArg::Float(value) => value.to_string().parse().ok(),
+            FormatArg::Str(value) => value.parse().ok(),
+        }
+    }
+}
+
+impl Display for FormatArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatArg::Int(value) => write!(f, "{}", value),
+            FormatArg::Float(value) => write!(f, "{}", value),
+            FormatArg::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl FromStr for FormatArg {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(FormatArg::infer(s))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for FormatArg {
+                fn from(value: $ty) -> Self {
+                    FormatArg::Int(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, usize);
+
+impl From<f32> for FormatArg {
+    fn from(value: f32) -> Self {
+        FormatArg::Float(value as f64)
+    }
+}
+
+impl From<f64> for FormatArg {
+    fn from(value: f64) -> Self {
+        FormatArg::Float(value)
+    }
+}
+
+impl From<&str> for FormatArg {
+    fn from(value: &str) -> Self {
+        FormatArg::Str(value.to_string())
+    }
+}
+
+impl From<String> for FormatArg {
+    fn from(value: String) -> Self {
+        FormatArg::Str(value)
+    }
+}
+
+/// Positional and named arguments for rendering a message.
+///
+/// Positional arguments are 1-based in printf (`%1$d`) and 0-based in ICU
+/// (`{0}`). Named arguments are looked up by ICU argument and select names.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormatArgs {
+    pub positional: Vec<FormatArg>,
+    pub named: BTreeMap<String, FormatArg>,
+}
+
+impl FormatArgs {
+    /// Creates an empty argument list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a positional argument.
+    pub fn arg(mut self, value: impl Into<FormatArg>) -> Self {
+        self.positional.push(value.into());
+        self
+    }
+
+    /// Sets a named argument.
+    pub fn named(mut self, name: &str, value: impl Into<FormatArg>) -> Self {
+        self.named.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Looks up an ICU argument: by name, or by 0-based index for numeric names.
+    fn lookup(&self, name: &str) -> Option<&FormatArg> {
+        self.named.get(name).or_else(|| {
+            name.parse::<usize>()
+                .ok()
+                .and_then(|index| self.positional.get(index))
+        })
+    }
+
+    /// The quantity that selects an entry's plural form: the `count` argument,
+    /// or else the first numeric positional argument.
+    fn count(&self) -> Option<&FormatArg> {
+        self.named.get("count").or_else(|| {
+            self.positional
+                .iter()
+                .find(|arg| !matches!(arg, FormatArg::Str(_)))
+        })
+    }
+}
+
+/// Renders `entry` in `language` with `args`.
+///
+/// Plural entries use the `count` argument (or the first numeric positional
+/// argument) to pick a form, falling back to `other`. Select entries use the
+/// argument named after their selector (or the first positional argument) to
+/// pick a case.
+///
+/// ```rust
+/// use langcodec::{FormatArgs, format_entry, types::{Entry, EntryStatus, Plural, PluralCategory, Translation}};
+///
+/// let entry = Entry {
+///     id: "files".into(),
+///     value: Translation::Plural(Plural {
+///         id: "files".into(),
+///         forms: [
+///             (PluralCategory::One, "%d файл".to_string()),
+///             (PluralCategory::Few, "%d файла".to_string()),
+///             (PluralCategory::Many, "%d файлов".to_string()),
+///             (PluralCategory::Other, "%d файла".to_string()),
+///         ]
+///         .into_iter()
+///         .collect(),
+///         kind: Default::default(),
+///     }),
+///     comment: None,
+///     status: EntryStatus::Translated,
+///     custom: Default::default(),
+/// };
+/// let rendered = format_entry(&entry, "ru", &FormatArgs::new().arg(5)).unwrap();
+/// assert_eq!(rendered, "5 файлов");
+/// ```
+pub fn format_entry(entry: &Entry, language: &str, args: &FormatArgs) -> Result<String, Error> {
+    match &entry.value {
+        Translation::Empty => Ok(String::new()),
+        Translation::Singular(message) => format_message(message, language, args),
+        Translation::Plural(plural) => {
+            let count = args.count().ok_or_else(|| {
+                Error::DataMismatch(format!(
+                    "Plural entry '{}' needs a numeric argument to select a form",
+                    entry.id
+                ))
+            })?;
+            let operands = count.operands().ok_or_else(|| {
+                Error::DataMismatch(format!("'{}' is not a number for plural selection", count))
+            })?;
+            let category = category_for_kind(language, operands, plural.kind);
+            let message = plural
+                .forms
+                .get(&category)
+                .or_else(|| plural.forms.get(&PluralCategory::Other))
+                .ok_or_else(|| {
+                    Error::DataMismatch(format!(
+                        "Plural entry '{}' has no '{}' or 'other' form",
+                        entry.id,
+                        category_name(&category)
+                    ))
+                })?;
+            format_message(message, language, args)
+        }
+        Translation::Select(select) => {
+            let value = args
+                .lookup(&select.selector)
+                .or_else(|| args.positional.first())
+                .ok_or_else(|| {
+                    Error::DataMismatch(format!(
+                        "Select entry '{}' needs a '{}' argument",
+                        entry.id, select.selector
+                    ))
+                })?
+                .to_string();
+            let message = select
+                .cases
+                .get(&value)
+                .or_else(|| select.cases.get(Select::FALLBACK_KEY))
+                .ok_or_else(|| {
+                    Error::DataMismatch(format!(
+                        "Select entry '{}' has no '{}' or 'other' case",
+                        entry.id, value
+                    ))
+                })?;
+            format_message(message, language, args)
+        }
+    }
+}
+
+/// Renders a single message, substituting printf-style and ICU placeholders.
+///
+/// ```rust
+/// use langcodec::{FormatArgs, format_message};
+///
+/// let args = FormatArgs::new().arg("Anna").arg(12.5).named("count", 2);
+/// assert_eq!(
+///     format_message("%1$@ paid %2$.2f — {count, plural, one {# item} other {# items}}", "en", &args).unwrap(),
+///     "Anna paid 12.50 — 2 items"
+/// );
+/// ```
+pub fn format_message(message: &str, language: &str, args: &FormatArgs) -> Result<String, Error> {
+    Renderer {
+        language,
+        args,
+        next_positional: 0,
+    }
+    .render(message, None)
+}
+
+struct Renderer<'a> {
+    language: &'a str,
+    args: &'a FormatArgs,
+    /// Index of the next non-positional printf argument.
+    next_positional: usize,
+}
+
+impl Renderer<'_> {
+    /// Renders `message`; `hash` replaces `#` inside a plural case.
+    fn render(&mut self, message: &str, hash: Option<&str>) -> Result<String, Error> {
+        let mut out = String::with_capacity(message.len());
+        let mut rest = message;
+        while let Some(ch) = rest.chars().next() {
+            match ch {
+                '\'' => {
+                    rest = self.render_quote(rest, hash.is_some(), &mut out);
+                    continue;
+                }
+                '{' => {
+                    if let Some(close) = matching_brace(rest)
+                        && let Some(rendered) = self.render_argument(&rest[1..close])?
+                    {
+                        out.push_str(&rendered);
+                        rest = &rest[close + 1..];
+                        continue;
+                    }
+                }
+                '#' => {
+                    if let Some(hash) = hash {
+                        out.push_str(hash);
+                        rest = &rest[1..];
+                        continue;
+                    }
+                }
+                '%' => {
+                    if let Some((spec, len)) = PrintfSpec::parse(rest) {
+                        out.push_str(&self.render_printf(&spec, &rest[..len])?);
+                        rest = &rest[len..];
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            out.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+        Ok(out)
+    }
+
+    /// Applies ICU apostrophe quoting: `''` is a literal apostrophe and an
+    /// apostrophe before a syntax character quotes text up to the next one.
+    fn render_quote<'m>(&self, rest: &'m str, in_plural: bool, out: &mut String) -> &'m str {
+        let after = &rest[1..];
+        if let Some(stripped) = after.strip_prefix('\'') {
+            out.push('\'');
+            return stripped;
+        }
+        let starts_syntax = after
+            .chars()
+            .next()
+            .is_some_and(|c| c == '{' || c == '}' || (in_plural && c == '#'));
+        if !starts_syntax {
+            out.push('\'');
+            return after;
+        }
+        let mut quoted = after;
+        loop {
+            match quoted.find('\'') {
+                Some(pos) if quoted[pos + 1..].starts_with('\'') => {
+                    out.push_str(&quoted[..pos + 1]);
+                    quoted = &quoted[pos + 2..];
+                }
+                Some(pos) => {
+                    out.push_str(&quoted[..pos]);
+                    return &quoted[pos + 1..];
+                }
+                None => {
+                    out.push_str(quoted);
+                    return "";
+                }
+            }
+        }
+    }
+
+    /// Renders the inside of `{…}`; `None` when it is not an ICU argument.
+    fn render_argument(&mut self, inner: &str) -> Result<Option<String>, Error> {
+        let mut parts = inner.splitn(3, ',');
+        let name = parts.next().unwrap_or_default().trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        {
+            return Ok(None);
+        }
+        let kind = parts.next().map(str::trim);
+        let style = parts.next().unwrap_or_default();
+
+        let value = self.args.lookup(name).ok_or_else(|| {
+            Error::DataMismatch(format!("Missing argument '{}' for '{{{}}}'", name, inner))
+        })?;
+
+        let rendered = match kind {
+            None | Some("") => value.to_string(),
+            Some("number") => match style.trim() {
+                "integer" => number_arg(value, name)?.trunc().to_string(),
+                "percent" => format!("{}%", number_arg(value, name)? * 100.0),
+                _ => value.to_string(),
+            },
+            Some("plural") => self.render_plural(value, name, style, PluralKind::Cardinal)?,
+            Some("selectordinal") => self.render_plural(value, name, style, PluralKind::Ordinal)?,
+            Some("select") => {
+                let cases = parse_cases(style, name)?;
+                let key = value.to_string();
+                let message = find_case(&cases, &key)
+                    .or_else(|| find_case(&cases, Select::FALLBACK_KEY))
+                    .ok_or_else(|| {
+                        Error::DataMismatch(format!(
+                            "No '{}' or 'other' case for select argument '{}'",
+                            key, name
+                        ))
+                    })?;
+                self.render(message, None)?
+            }
+            // Dates, times and other types are shown as given.
+            Some(_) => value.to_string(),
+        };
+        Ok(Some(rendered))
+    }
+
+    fn render_plural(
+        &mut self,
+        value: &FormatArg,
+        name: &str,
+        style: &str,
+        kind: PluralKind,
+    ) -> Result<String, Error> {
+        let number = number_arg(value, name)?;
+        let mut style = style.trim_start();
+        let mut offset = 0.0;
+        if let Some(rest) = style.strip_prefix("offset:") {
+            let rest = rest.trim_start();
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            offset = rest[..end].parse().unwrap_or(0.0);
+            style = &rest[end..];
+        }
+        let cases = parse_cases(style, name)?;
+
+        let exact = cases.iter().find(|(key, _)| {
+            key.strip_prefix('=')
+                .and_then(|n| n.parse::<f64>().ok())
+                .is_some_and(|n| n == number)
+        });
+        // `#` and the rule operands use the value minus the offset.
+        let shown = if offset == 0.0 {
+            value.clone()
+        } else if matches!(value, FormatArg::Float(_)) {
+            FormatArg::Float(number - offset)
+        } else {
+            FormatArg::Int((number - offset) as i64)
+        };
+        let message = match exact {
+            Some((_, message)) => message.as_str(),
+            None => {
+                let operands = shown.operands().ok_or_else(|| {
+                    Error::DataMismatch(format!("'{}' is not a number for plural selection", shown))
+                })?;
+                let category = category_name(&category_for_kind(self.language, operands, kind));
+                find_case(&cases, category)
+                    .or_else(|| find_case(&cases, "other"))
+                    .ok_or_else(|| {
+                        Error::DataMismatch(format!(
+                            "No '{}' or 'other' case for plural argument '{}'",
+                            category, name
+                        ))
+                    })?
+            }
+        };
+        self.render(message, Some(&shown.to_string()))
+    }
+
+    fn render_printf(&mut self, spec: &PrintfSpec, source: &str) -> Result<String, Error> {
+        if spec.conversion == '%' {
+            return Ok("%".to_string());
+        }
+        let index = match spec.position {
+            Some(position) => position.checked_sub(1).ok_or_else(|| {
+                Error::DataMismatch(format!("Invalid argument position in '{}'", source))
+            })?,
+            None => {
+                let index = self.next_positional;
+                self.next_positional += 1;
+                index
+            }
+        };
+        let value = self.args.positional.get(index).ok_or_else(|| {
+            Error::DataMismatch(format!("Missing argument {} for '{}'", index + 1, source))
+        })?;
+        spec.render(value, source)
+    }
+}
+
+/// A printf conversion specification: `%[n$][flags][width][.precision][length]conversion`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PrintfSpec {
+    position: Option<usize>,
+    left_align: bool,
+    plus_sign: bool,
+    alternate: bool,
+    zero_pad: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    conversion: char,
+}
+
+impl PrintfSpec {
+    /// Parses a specification at the start of `input` (which begins with `%`)
+    /// and returns it with its byte length.
+    fn parse(input: &str) -> Option<(Self, usize)> {
+        if input.starts_with("%%") {
+            return Some((Self::literal_percent(), 2));
+        }
+        let parsed = parse_placeholder(input)?;
+        let token = &parsed.token;
+        let spec = PrintfSpec {
+            position: token.index,
+            left_align: token.flags.contains('-'),
+            plus_sign: token.flags.contains('+'),
+            alternate: token.flags.contains('#'),
+            zero_pad: token.flags.contains('0'),
+            width: token.width,
+            precision: token.precision,
+            conversion: parsed.conversion,
+        };
+        Some((spec, parsed.len))
+    }
+
+    fn literal_percent() -> Self {
+        PrintfSpec {
+            position: None,
+            left_align: false,
+            plus_sign: false,
+            alternate: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            conversion: '%',
+        }
+    }
+
+    fn render(&self, value: &FormatArg, source: &str) -> Result<String, Error> {
+        let not_a_number = || {
+            Error::DataMismatch(format!(
+                "Argument '{}' for '{}' is not a number",
+                value, source
+            ))
+        };
+        let (sign, body, numeric) = match self.conversion {
+            'd' | 'i' | 'u' => {
+                let int = value.as_i64().ok_or_else(not_a_number)?;
+                let mut digits = int.unsigned_abs().to_string();
+                if let Some(precision) = self.precision {
+                    digits = format!("{:0>width$}", digits, width = precision);
+                }
+                (self.sign(int < 0), digits, true)
+            }
+            'o' | 'x' | 'X' => {
+                let int = value.as_i64().ok_or_else(not_a_number)? as u64;
+                let body = match (self.conversion, self.alternate) {
+                    ('o', false) => format!("{:o}", int),
+                    ('o', true) => format!("{:#o}", int).replacen("0o", "0", 1),
+                    ('x', false) => format!("{:x}", int),
+                    ('x', true) => format!("{:#x}", int),
+                    (_, false) => format!("{:X}", int),
+                    (_, true) => format!("{:#X}", int).replacen("0x", "0X", 1),
+                };
+                (String::new(), body, true)
+            }
+            'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A' => {
+                let float = value.as_f64().ok_or_else(not_a_number)?;
+                let precision = self.precision.unwrap_or(6);
+                let body = match self.conversion {
+                    'f' | 'F' => format!("{:.*}", precision, float.abs()),
+                    'e' | 'E' => exponential(float.abs(), precision, self.conversion == 'E'),
+                    'g' | 'G' => general(
+                        float.abs(),
+                        precision,
+                        self.alternate,
+                        self.conversion == 'G',
+                    ),
+                    _ => float.abs().to_string(),
+                };
+                (
+                    self.sign(float.is_sign_negative() && float != 0.0),
+                    body,
+                    true,
+                )
+            }
+            'c' => {
+                let ch = match value {
+                    FormatArg::Int(code) => u32::try_from(*code)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .map(String::from)
+                        .unwrap_or_default(),
+                    other => other.to_string().chars().take(1).collect(),
+                };
+                (String::new(), ch, false)
+            }
+            // s, S, @, p
+            _ => {
+                let text = value.to_string();
+                let text = match self.precision {
+                    Some(precision) => text.chars().take(precision).collect(),
+                    None => text,
+                };
+                (String::new(), text, false)
+            }
+        };
+        Ok(self.pad(&sign, &body, numeric))
+    }
+
+    fn sign(&self, negative: bool) -> String {
+        if negative {
+            "-".to_string()
+        } else if self.plus_sign {
+            "+".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    fn pad(&self, sign: &str, body: &str, numeric: bool) -> String {
+        let len = sign.chars().count() + body.chars().count();
+        let fill = self.width.unwrap_or(0).saturating_sub(len);
+        if fill == 0 {
+            format!("{}{}", sign, body)
+        } else if self.left_align {
+            format!("{}{}{}", sign, body, " ".repeat(fill))
+        } else if self.zero_pad && numeric {
+            format!("{}{}{}", sign, "0".repeat(fill), body)
+        } else {
+            format!("{}{}{}", " ".repeat(fill), sign, body)
+        }
+    }
+}
+
+/// C-style `%e`: mantissa with `precision` digits and an exponent of at least two digits.
+fn exponential(value: f64, precision: usize, upper: bool) -> String {
+    let rendered = format!("{:.*e}", precision, value);
+    let (mantissa, exponent) = rendered.split_once('e').unwrap_or((&rendered, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let out = format!(
+        "{}e{}{:02}",
+        mantissa,
+        if exponent < 0 { '-' } else { '+' },
+        exponent.abs()
+    );
+    if upper { out.to_uppercase() } else { out }
+}
+
+/// C-style `%g`: the shorter of `%e` and `%f` for `precision` significant digits.
+fn general(value: f64, precision: usize, alternate: bool, upper: bool) -> String {
+    let precision = precision.max(1);
+    if value == 0.0 {
+        return if alternate {
+            format!("{:.*}", precision - 1, 0.0)
+        } else {
+            "0".to_string()
+        };
+    }
+    let exponent = format!("{:.*e}", precision - 1, value)
+        .split_once('e')
+        .and_then(|(_, e)| e.parse::<i32>().ok())
+        .unwrap_or(0);
+    if exponent < -4 || exponent >= precision as i32 {
+        let rendered = exponential(value, precision - 1, upper);
+        if alternate {
+            rendered
+        } else {
+            let (mantissa, exp) = rendered.split_once(['e', 'E']).unwrap_or((&rendered, ""));
+            let marker = if upper { 'E' } else { 'e' };
+            format!("{}{}{}", trim_fraction(mantissa), marker, exp)
+        }
+    } else {
+        let decimals = (precision as i32 - 1 - exponent).max(0) as usize;
+        let rendered = format!("{:.*}", decimals, value);
+        if alternate {
+            rendered
+        } else {
+            trim_fraction(&rendered).to_string()
+        }
+    }
+}
+
+fn trim_fraction(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
+
+fn number_arg(value: &FormatArg, name: &str) -> Result<f64, Error> {
+    value.as_f64().ok_or_else(|| {
+        Error::DataMismatch(format!("Argument '{}' = '{}' is not a number", name, value))
+    })
+}
+
+fn category_name(category: &PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+/// Byte index of the `}` that closes the `{` at the start of `input`.
+fn matching_brace(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (idx, ch) in input.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses `key {message} key {message} …` of a plural or select argument.
+fn parse_cases(style: &str, name: &str) -> Result<Vec<(String, String)>, Error> {
+    let invalid = || Error::DataMismatch(format!("Malformed cases for argument '{}'", name));
+    let mut cases = Vec::new();
+    let mut rest = style.trim_start();
+    while !rest.is_empty() {
+        let open = rest.find('{').ok_or_else(invalid)?;
+        let key = rest[..open].trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(invalid());
+        }
+        let close = matching_brace(&rest[open..]).ok_or_else(invalid)? + open;
+        cases.push((key.to_string(), rest[open + 1..close].to_string()));
+        rest = rest[close + 1..].trim_start();
+    }
+    Ok(cases)
+}
+
+fn find_case<'c>(cases: &'c [(String, String)], key: &str) -> Option<&'c str> {
+    cases
+        .iter()
+        .find(|(case, _)| case == key)
+        .map(|(_, message)| message.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EntryStatus, Plural};
+
+    fn render(message: &str, args: &FormatArgs) -> String {
+        format_message(message, "en", args).unwrap()
+    }
+
+    #[test]
+    fn test_printf_conversions() {
+        let args = FormatArgs::new().arg(42).arg(-12.3456).arg("Anna");
+        assert_eq!(render("%d|%.2f|%@", &args), "42|-12.35|Anna");
+        assert_eq!(render("%3$s %1$ld%%", &args), "Anna 42%");
+        assert_eq!(
+            render(
+                "[%5d][%-5d][%05d][%+d]",
+                &FormatArgs::new().arg(42).arg(42).arg(42).arg(42)
+            ),
+            "[   42][42   ][00042][+42]"
+        );
+        assert_eq!(
+            render("%x %#X %o", &FormatArgs::new().arg(255).arg(255).arg(8)),
+            "ff 0XFF 10"
+        );
+        assert_eq!(render("%e", &FormatArgs::new().arg(1234.5)), "1.234500e+03");
+        assert_eq!(
+            render("%g %g", &FormatArgs::new().arg(0.0001).arg(123456789.0)),
+            "0.0001 1.23457e+08"
+        );
+        assert_eq!(
+            render("%.3s|%c", &FormatArgs::new().arg("abcdef").arg(65)),
+            "abc|A"
+        );
+        assert_eq!(render("100% sure", &FormatArgs::new()), "100% sure");
+    }
+
+    #[test]
+    fn test_icu_arguments() {
+        let args = FormatArgs::new()
+            .arg("zero-based")
+            .named("name", "Anna")
+            .named("gender", "female")
+            .named("count", 1)
+            .named("place", 3);
+        assert_eq!(render("{0} {name}", &args), "zero-based Anna");
+        assert_eq!(
+            render(
+                "{gender, select, female {She} other {They}} has {count, plural, =0 {no files} one {# file} other {# files}}",
+                &args
+            ),
+            "She has 1 file"
+        );
+        assert_eq!(
+            render(
+                "{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}",
+                &args
+            ),
+            "3rd"
+        );
+        assert_eq!(
+            render(
+                "{count, plural, offset:1 =1 {Just you} other {You and # others}}",
+                &FormatArgs::new().named("count", 4)
+            ),
+            "You and 3 others"
+        );
+        assert_eq!(render("It''s '{literal}' {}", &args), "It's {literal} {}");
+        assert!(format_message("{missing}", "en", &args).is_err());
+    }
+
+    #[test]
+    fn test_format_entry_picks_plural_form_by_language() {
+        let entry = Entry {
+            id: "files".into(),
+            value: Translation::Plural(Plural {
+                id: "files".into(),
+                forms: [
+                    (PluralCategory::One, "%d файл".to_string()),
+                    (PluralCategory::Few, "%d файла".to_string()),
+                    (PluralCategory::Many, "%d файлов".to_string()),
+                    (PluralCategory::Other, "%.1f файла".to_string()),
+                ]
+                .into_iter()
+                .collect(),
+                kind: PluralKind::Cardinal,
+            }),
+            comment: None,
+            status: EntryStatus::Translated,
+            custom: Default::default(),
+        };
+        let ru = |arg: FormatArg| format_entry(&entry, "ru", &FormatArgs::new().arg(arg)).unwrap();
+        assert_eq!(ru(1.into()), "1 файл");
+        assert_eq!(ru(3.into()), "3 файла");
+        assert_eq!(ru(11.into()), "11 файлов");
+        assert_eq!(ru(FormatArg::infer("1.5")), "1.5 файла");
+        assert!(format_entry(&entry, "ru", &FormatArgs::new()).is_err());
+    }
+
+    #[test]
+    fn test_format_entry_select_and_infer() {
+        let entry = Entry {
+            id: "replied".into(),
+            value: Translation::Select(
+                Select::new(
+                    "gender",
+                    [
+                        ("female".to_string(), "%@ a répondu (elle)".to_string()),
+                        ("other".to_string(), "%@ a répondu".to_string()),
+                    ],
+                )
+                .unwrap(),
+            ),
+            comment: None,
+            status: EntryStatus::Translated,
+            custom: Default::default(),
+        };
+        let args = FormatArgs::new().arg("Anna").named("gender", "female");
+        assert_eq!(
+            format_entry(&entry, "fr", &args).unwrap(),
+            "Anna a répondu (elle)"
+        );
+        let args = FormatArgs::new().arg("Sam").named("gender", "unknown");
+        assert_eq!(format_entry(&entry, "fr", &args).unwrap(), "Sam a répondu");
+
+        assert_eq!(FormatArg::infer("5"), FormatArg::Int(5));
+        assert_eq!(FormatArg::infer("2.50"), FormatArg::Float(2.5));
+        assert_eq!(FormatArg::infer("inf"), FormatArg::Str("inf".into()));
+    }
+}
@@ -4,11 +4,14 @@
 //! the [`FormatType`] enum for generic format handling across the crate.
 
 pub mod android_strings;
+pub mod android_text;
+mod cst;
 pub mod csv;
 pub mod strings;
 pub mod tsv;
 pub mod xcstrings;
 pub mod xliff;
+pub mod xlsx;
 
 use std::{
     fmt::{Display, Formatter},
@@ -18,10 +21,11 @@ use std::{
 // Reexporting the formats for easier access
 pub use android_strings::Format as AndroidStringsFormat;
 pub use csv::{Format as CSVFormat, MultiLanguageCSVRecord};
-pub use strings::Format as StringsFormat;
+pub use strings::{Format as StringsFormat, StringsEncoding};
 pub use tsv::{Format as TSVFormat, MultiLanguageTSVRecord};
 pub use xcstrings::Format as XcstringsFormat;
 pub use xliff::Format as XliffFormat;
+pub use xlsx::{Format as XLSXFormat, MultiLanguageXLSXRecord};
 
 use crate::Error;
 
@@ -42,6 +46,8 @@ pub enum FormatType {
     CSV,
     /// TSV format (multi-language support built-in).
     TSV,
+    /// Excel `.xlsx` workbook (multi-language support built-in).
+    XLSX,
 }
 
 /// Implements [`std::fmt::Display`] for [`FormatType`].
@@ -68,6 +74,7 @@ impl Display for FormatType {
             FormatType::Xliff(_) => write!(f, "xliff"),
             FormatType::CSV => write!(f, "csv"),
             FormatType::TSV => write!(f, "tsv"),
+            FormatType::XLSX => write!(f, "xlsx"),
         }
     }
 }
@@ -101,6 +108,7 @@ impl FromStr for FormatType {
             "xliff" => Ok(FormatType::Xliff(None)),
             "csv" => Ok(FormatType::CSV),
             "tsv" => Ok(FormatType::TSV),
+            "xlsx" | "excel" => Ok(FormatType::XLSX),
             other => Err(Error::UnknownFormat(other.to_string())),
         }
     }
@@ -116,6 +124,7 @@ impl FormatType {
             FormatType::Xliff(_) => "xliff",
             FormatType::CSV => "csv",
             FormatType::TSV => "tsv",
+            FormatType::XLSX => "xlsx",
         }
     }
 
@@ -128,6 +137,7 @@ impl FormatType {
             FormatType::Xliff(lang) => lang.as_ref(),
             FormatType::CSV => None,
             FormatType::TSV => None,
+            FormatType::XLSX => None,
         }
     }
 
@@ -140,6 +150,7 @@ impl FormatType {
             FormatType::Xliff(_) => FormatType::Xliff(lang),
             FormatType::CSV => FormatType::CSV,
             FormatType::TSV => FormatType::TSV,
+            FormatType::XLSX => FormatType::XLSX,
         }
     }
 
@@ -174,6 +185,7 @@ impl FormatType {
             (FormatType::Xliff(_), _) | (_, FormatType::Xliff(_)) => true,
             (FormatType::CSV, _) | (_, FormatType::CSV) => true,
             (FormatType::TSV, _) | (_, FormatType::TSV) => true,
+            (FormatType::XLSX, _) | (_, FormatType::XLSX) => true,
             _ => self.language() == other.language(),
         }
     }
@@ -191,6 +203,7 @@ mod tests {
         assert_eq!(FormatType::Xliff(None).to_string(), "xliff");
         assert_eq!(FormatType::CSV.to_string(), "csv");
         assert_eq!(FormatType::TSV.to_string(), "tsv");
+        assert_eq!(FormatType::XLSX.to_string(), "xlsx");
     }
 
     #[test]
@@ -249,6 +262,10 @@ mod tests {
         // TSV format
         assert_eq!(FormatType::from_str("tsv").unwrap(), FormatType::TSV);
         assert_eq!(FormatType::from_str("TSV").unwrap(), FormatType::TSV);
+
+        // XLSX format
+        assert_eq!(FormatType::from_str("xlsx").unwrap(), FormatType::XLSX);
+        assert_eq!(FormatType::from_str("excel").unwrap(), FormatType::XLSX);
     }
 
     #[test]
@@ -277,6 +294,7 @@ mod tests {
         assert_eq!(FormatType::Xcstrings.extension(), "xcstrings");
         assert_eq!(FormatType::CSV.extension(), "csv");
         assert_eq!(FormatType::TSV.extension(), "tsv");
+        assert_eq!(FormatType::XLSX.extension(), "xlsx");
     }
 
     #[test]
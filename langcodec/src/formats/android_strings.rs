@@ -2,9 +2,20 @@
 //!
 //! Supports singular `<string>` and plural `<plurals>` elements.
 //! Provides parsing, serialization, and conversion to/from the internal `Resource` model.
+//!
+//! Attributes other than `name` and `translatable` (such as `formatted`,
+//! `product` or `tools:ignore`) are kept in [`Entry::custom`] under
+//! [`ANDROID_ATTRIBUTE_PREFIX`], and attributes of the `<resources>` root,
+//! including namespace declarations, in [`Metadata::custom`] under
+//! [`ANDROID_RESOURCES_ATTRIBUTE_PREFIX`]. Their source order is recorded
+//! under `android.attr_order` and `android.resources_order` so they are
+//! written back in the same order. Elements with a `product`
+//! attribute become separate entries whose id is `name[product]`, so product
+//! variants of the same string do not collide.
 
 use quick_xml::{
     Reader, Writer,
+    escape::escape,
     events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event},
 };
 use serde::Serialize;
@@ -12,71 +23,69 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     io::{BufRead, Write},
+    ops::Range,
     str::FromStr,
 };
 
 use crate::{
     error::Error,
+    formats::{
+        android_text,
+        cst::{Layout, Node, apply_resource},
+    },
+    provenance::{EntryPosition, LineIndex, SourcePosition},
     traits::Parser,
-    types::{Entry, EntryStatus, Metadata, Plural, PluralCategory, Resource, Translation},
+    types::{
+        Entry, EntryStatus, Metadata, Plural, PluralCategory, PluralKind, Resource, Translation,
+    },
 };
 
+/// Prefix of [`Entry::custom`] keys holding extra `<string>`/`<plurals>` attributes.
+pub const ANDROID_ATTRIBUTE_PREFIX: &str = "android.attr.";
+/// Prefix of [`Metadata::custom`] keys holding `<resources>` attributes.
+pub const ANDROID_RESOURCES_ATTRIBUTE_PREFIX: &str = "android.resources.";
+
+const TOOLS_NAMESPACE: &str = "http://schemas.android.com/tools";
+const XLIFF_NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:1.2";
+
 #[derive(Debug, Serialize)]
 pub struct Format {
     pub language: String,
     pub strings: Vec<StringResource>,
     pub plurals: Vec<PluralsResource>,
+    /// Attributes and namespace declarations of the `<resources>` root, in source order.
+    pub resources_attributes: Vec<(String, String)>,
 }
 
 impl Parser for Format {
     /// Parse from any reader.
     fn from_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
-        let mut xml_reader = Reader::from_reader(reader);
-        // Preserve whitespace inside text nodes so multi-line strings and
-        // indentation are kept exactly as authored in XML.
-        xml_reader.config_mut().trim_text(false);
-
-        let mut buf = Vec::new();
-        let mut string_resources = Vec::new();
-        let mut plural_resources: Vec<PluralsResource> = Vec::new();
-        let mut pending_comment: Option<String> = None;
-
-        loop {
-            match xml_reader.read_event_into(&mut buf) {
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"string" => {
-                    let mut sr = parse_string_resource(e, &mut xml_reader)?;
-                    sr.comment = pending_comment.take();
-                    string_resources.push(sr);
-                }
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"plurals" => {
-                    let mut pr = parse_plurals_resource(e, &mut xml_reader)?;
-                    pr.comment = pending_comment.take();
-                    plural_resources.push(pr);
-                }
-                Ok(Event::Comment(comment)) => {
-                    pending_comment = Some(parse_xml_comment(comment.as_ref()));
-                }
-                Ok(Event::Eof) => break,
-                Ok(_) => {}
-                Err(e) => return Err(Error::XmlParse(e)),
-            }
-            buf.clear();
-        }
-        Ok(Format {
-            language: String::new(), // strings.xml does not contain language metadata
-            strings: string_resources,
-            plurals: plural_resources,
-        })
+        Self::from_reader_with_positions(reader).map(|(format, _positions)| format)
     }
 
     /// Write to any writer (file, memory, etc.).
     fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        // Reject before writing anything so no truncated document is left behind.
+        if let Some(ordinal) = self
+            .plurals
+            .iter()
+            .find(|plural| plural.kind == PluralKind::Ordinal)
+        {
+            return Err(ordinal_plural_error(&ordinal.name));
+        }
+
         let mut xml_writer = Writer::new(&mut writer);
 
         xml_writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
         xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
 
-        let resources_start = BytesStart::new("resources");
+        let mut resources_start = BytesStart::new("resources");
+        for (key, value) in &self.resources_attributes {
+            resources_start.push_attribute((key.as_str(), value.as_str()));
+        }
+        for (key, value) in self.missing_namespaces() {
+            resources_start.push_attribute((key, value));
+        }
         xml_writer.write_event(Event::Start(resources_start))?;
         xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
 
@@ -87,9 +96,14 @@ impl Parser for Format {
             if let Some(trans) = sr.translatable {
                 elem.push_attribute(("translatable", if trans { "true" } else { "false" }));
             }
+            for (key, value) in &sr.attributes {
+                elem.push_attribute((key.as_str(), value.as_str()));
+            }
 
             xml_writer.write_event(Event::Start(elem))?;
-            xml_writer.write_event(Event::Text(BytesText::new(&sr.value)))?;
+            xml_writer.write_event(Event::Text(BytesText::from_escaped(android_text::encode(
+                &sr.value,
+            ))))?;
             xml_writer.write_event(Event::End(BytesEnd::new("string")))?;
             xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
         }
@@ -102,6 +116,9 @@ impl Parser for Format {
             if let Some(trans) = pr.translatable {
                 elem.push_attribute(("translatable", if trans { "true" } else { "false" }));
             }
+            for (key, value) in &pr.attributes {
+                elem.push_attribute((key.as_str(), value.as_str()));
+            }
             xml_writer.write_event(Event::Start(elem))?;
             xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
 
@@ -110,19 +127,11 @@ impl Parser for Format {
             items.sort_by(|a, b| a.quantity.cmp(&b.quantity));
             for item in &items {
                 let mut it = BytesStart::new("item");
-                it.push_attribute((
-                    "quantity",
-                    match item.quantity {
-                        PluralCategory::Zero => "zero",
-                        PluralCategory::One => "one",
-                        PluralCategory::Two => "two",
-                        PluralCategory::Few => "few",
-                        PluralCategory::Many => "many",
-                        PluralCategory::Other => "other",
-                    },
-                ));
+                it.push_attribute(("quantity", quantity_name(&item.quantity)));
                 xml_writer.write_event(Event::Start(it))?;
-                xml_writer.write_event(Event::Text(BytesText::new(&item.value)))?;
+                xml_writer.write_event(Event::Text(BytesText::from_escaped(
+                    android_text::encode(&item.value),
+                )))?;
                 xml_writer.write_event(Event::End(BytesEnd::new("item")))?;
                 xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
             }
@@ -137,6 +146,62 @@ impl Parser for Format {
     }
 }
 
+impl Format {
+    /// Parses `strings.xml` and reports where each `<string>` and `<plurals>` starts.
+    pub fn from_reader_with_positions<R: BufRead>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<EntryPosition>), Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).map_err(Error::Io)?;
+        let (format, spans) = parse_with_spans(&source)?;
+        let lines = LineIndex::new(&source);
+        let positions = spans
+            .iter()
+            .map(|span| {
+                let (name, attributes) = match span.kind {
+                    ElementKind::String(idx) => {
+                        (&format.strings[idx].name, &format.strings[idx].attributes)
+                    }
+                    ElementKind::Plurals(idx) => {
+                        (&format.plurals[idx].name, &format.plurals[idx].attributes)
+                    }
+                };
+                EntryPosition {
+                    key: entry_id(name.clone(), attributes),
+                    language: None,
+                    position: lines.position(&source, span.body.start),
+                }
+            })
+            .collect();
+        Ok((format, positions))
+    }
+
+    /// Namespace declarations that entries rely on but `<resources>` does not declare.
+    fn missing_namespaces(&self) -> Vec<(&'static str, &'static str)> {
+        let declared = |key: &str| self.resources_attributes.iter().any(|(k, _)| k == key);
+        let attributes = self
+            .strings
+            .iter()
+            .flat_map(|sr| &sr.attributes)
+            .chain(self.plurals.iter().flat_map(|pr| &pr.attributes));
+        let values = self.strings.iter().map(|sr| sr.value.as_str()).chain(
+            self.plurals
+                .iter()
+                .flat_map(|pr| pr.items.iter().map(|item| item.value.as_str())),
+        );
+
+        let mut missing = Vec::new();
+        if !declared("xmlns:tools") && attributes.clone().any(|(key, _)| key.starts_with("tools:"))
+        {
+            missing.push(("xmlns:tools", TOOLS_NAMESPACE));
+        }
+        if !declared("xmlns:xliff") && values.clone().any(|value| value.contains("<xliff:")) {
+            missing.push(("xmlns:xliff", XLIFF_NAMESPACE));
+        }
+        missing
+    }
+}
+
 impl From<Resource> for Format {
     fn from(value: Resource) -> Self {
         let mut strings = Vec::new();
@@ -144,29 +209,10 @@ impl From<Resource> for Format {
         for entry in value.entries {
             match entry.value {
                 Translation::Empty => {} // Do nothing
-                Translation::Singular(_) => strings.push(StringResource::from_entry(&entry)),
-                Translation::Plural(p) => {
-                    let mut items: Vec<PluralItem> = p
-                        .forms
-                        .into_iter()
-                        .map(|(cat, v)| PluralItem {
-                            quantity: cat,
-                            value: v,
-                        })
-                        .collect();
-                    // Ensure stable order later
-                    items.sort_by(|a, b| a.quantity.cmp(&b.quantity));
-                    plurals.push(PluralsResource {
-                        name: entry.id,
-                        items,
-                        comment: entry.comment,
-                        translatable: match entry.status {
-                            EntryStatus::Translated => Some(true),
-                            EntryStatus::DoNotTranslate => Some(false),
-                            _ => None,
-                        },
-                    });
+                Translation::Singular(_) | Translation::Select(_) => {
+                    strings.push(StringResource::from_entry(&entry))
                 }
+                Translation::Plural(_) => plurals.push(PluralsResource::from_entry(&entry)),
             }
         }
 
@@ -174,6 +220,10 @@ impl From<Resource> for Format {
             language: value.metadata.language,
             strings,
             plurals,
+            resources_attributes: prefixed_attributes(
+                &value.metadata.custom,
+                ANDROID_RESOURCES_ATTRIBUTE_PREFIX,
+            ),
         }
     }
 }
@@ -187,50 +237,347 @@ impl From<Format> for Resource {
             .collect();
 
         // Convert plurals to entries
-        for pr in value.plurals {
-            let mut forms = std::collections::BTreeMap::new();
-            for item in pr.items {
-                let PluralItem { quantity, value } = item;
-                forms.insert(quantity, value);
-            }
-            let all_empty = forms.values().all(|v| v.is_empty());
-            let status = match pr.translatable {
-                Some(true) => EntryStatus::Translated,
-                Some(false) => EntryStatus::DoNotTranslate,
-                None => {
-                    if all_empty {
-                        EntryStatus::New
-                    } else {
-                        EntryStatus::Translated
-                    }
-                }
-            };
-            entries.push(Entry {
-                id: pr.name.clone(),
-                value: Translation::Plural(Plural { id: pr.name, forms }),
-                comment: pr.comment,
-                status,
-                custom: HashMap::new(),
-            });
-        }
+        entries.extend(value.plurals.into_iter().map(PluralsResource::into_entry));
+
+        let custom = attributes_to_custom(
+            value.resources_attributes,
+            ANDROID_RESOURCES_ATTRIBUTE_PREFIX,
+        );
 
         Resource {
             metadata: Metadata {
                 language: value.language,
                 domain: String::new(), // strings.xml does not have a domain
-                custom: HashMap::new(),
+                custom,
             },
             entries,
         }
     }
 }
 
-#[derive(Debug, Serialize)]
+/// An Android `strings.xml` file kept as source text so edits can preserve its layout.
+///
+/// Unlike [`Format`], which rebuilds the file from its resources, a `Document`
+/// applies entry-level changes in place: XML comments, blank-line grouping,
+/// `tools:` and other attributes, and elements langcodec does not model (such
+/// as `<dimen>` or `<string-array>`) are kept byte-for-byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    source: String,
+}
+
+impl Document {
+    /// Returns the current source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Updates the document so it contains exactly the entries of `resource`.
+    ///
+    /// Unchanged `<string>`/`<plurals>` elements keep their original text;
+    /// changed values are rewritten inside the existing element (keeping its
+    /// attributes), removed keys are deleted with their comment, and new keys
+    /// are inserted after the preceding key in `resource` order.
+    pub fn apply(&mut self, resource: &Resource) -> Result<(), Error> {
+        if let Some(entry) = resource.entries.iter().find(
+            |entry| matches!(&entry.value, Translation::Plural(plural) if plural.is_ordinal()),
+        ) {
+            return Err(ordinal_plural_error(&entry.id));
+        }
+        let Some(closing) = self.source.rfind("</resources>") else {
+            // Nothing to anchor edits to (e.g. `<resources/>`); rebuild the file.
+            let mut out = Vec::new();
+            Format::from(resource.clone()).to_writer(&mut out)?;
+            self.source = String::from_utf8(out)
+                .map_err(|e| Error::InvalidResource(format!("Invalid UTF-8 output: {}", e)))?;
+            return Ok(());
+        };
+
+        let (format, spans) = parse_with_spans(&self.source)?;
+        let nodes: Vec<Node> = spans
+            .into_iter()
+            .map(|span| Node {
+                entry: match span.kind {
+                    ElementKind::String(idx) => format.strings[idx].clone().into_entry(),
+                    ElementKind::Plurals(idx) => format.plurals[idx].clone().into_entry(),
+                },
+                comment: span.comment,
+                body: span.body,
+            })
+            .collect();
+
+        let line_start = self.source[..closing].rfind('\n').map_or(0, |i| i + 1);
+        let fallback_insert = if self.source[line_start..closing].trim().is_empty() {
+            line_start
+        } else {
+            closing
+        };
+
+        self.source = apply_resource(
+            &AndroidLayout,
+            &self.source,
+            &nodes,
+            resource,
+            fallback_insert,
+            "    ",
+        )?;
+        Ok(())
+    }
+}
+
+impl Parser for Document {
+    fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).map_err(Error::Io)?;
+        Ok(Document { source })
+    }
+
+    fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(self.source.as_bytes()).map_err(Error::Io)
+    }
+}
+
+struct AndroidLayout;
+
+impl Layout for AndroidLayout {
+    fn patch_body(
+        &self,
+        body: &str,
+        old: &Entry,
+        new: &Entry,
+        indent: &str,
+    ) -> Result<String, Error> {
+        let Some(tag_end) = start_tag_end(body) else {
+            return self.render_entry(new, indent);
+        };
+        let self_closing = body[..tag_end].ends_with('/');
+        let start_tag =
+            set_translatable_attr(&body[..tag_end], new.status == EntryStatus::DoNotTranslate);
+
+        match (&old.value, &new.value) {
+            (Translation::Singular(_), Translation::Singular(value)) => {
+                let start_tag = start_tag.trim_end_matches('/').trim_end();
+                if old.value == new.value && !self_closing {
+                    let close = body.rfind("</").unwrap_or(body.len());
+                    return Ok(format!("{}{}", start_tag, &body[tag_end..close]) + &body[close..]);
+                }
+                Ok(format!(
+                    "{}>{}</string>",
+                    start_tag,
+                    android_text::encode(value)
+                ))
+            }
+            (Translation::Plural(old_plural), Translation::Plural(new_plural))
+                if !self_closing && old_plural.forms.keys().eq(new_plural.forms.keys()) =>
+            {
+                // Same quantities: rewrite only the items whose text changed.
+                let mut out = format!("{}>", start_tag);
+                let mut cursor = tag_end + 1;
+                for (quantity, inner) in plural_item_ranges(body)? {
+                    let (Some(before), Some(after)) = (
+                        old_plural.forms.get(&quantity),
+                        new_plural.forms.get(&quantity),
+                    ) else {
+                        continue;
+                    };
+                    if before != after {
+                        out.push_str(&body[cursor..inner.start]);
+                        out.push_str(&android_text::encode(after));
+                        cursor = inner.end;
+                    }
+                }
+                out.push_str(&body[cursor..]);
+                Ok(out)
+            }
+            (Translation::Plural(_), Translation::Plural(plural)) if !self_closing => {
+                let item_indent = body
+                    .find("<item")
+                    .map(|pos| {
+                        let line_start = body[..pos].rfind('\n').map_or(0, |i| i + 1);
+                        body[line_start..pos].to_string()
+                    })
+                    .filter(|prefix| prefix.trim().is_empty())
+                    .unwrap_or_else(|| format!("{}    ", indent));
+                Ok(format!(
+                    "{}>\n{}{}</plurals>",
+                    start_tag,
+                    render_plural_items(plural, &item_indent),
+                    indent
+                ))
+            }
+            _ => self.render_entry(new, indent),
+        }
+    }
+
+    fn rename_body(&self, body: &str, new: &Entry, indent: &str) -> Result<String, Error> {
+        lazy_static::lazy_static! {
+            static ref NAME_ATTR: regex::Regex =
+                regex::Regex::new(r#"\sname\s*=\s*("[^"]*"|'[^']*')"#).unwrap();
+        }
+
+        let tag_end = start_tag_end(body).unwrap_or(body.len());
+        let Some(value) = NAME_ATTR
+            .captures(&body[..tag_end])
+            .and_then(|caps| caps.get(1))
+        else {
+            return self.render_entry(new, indent);
+        };
+        let attributes = prefixed_attributes(&new.custom, ANDROID_ATTRIBUTE_PREFIX);
+        let quote = &body[value.start()..value.start() + 1];
+        Ok(format!(
+            "{}{quote}{}{quote}{}",
+            &body[..value.start()],
+            escape(element_name(&new.id, &attributes)),
+            &body[value.end()..]
+        ))
+    }
+
+    fn render_comment(&self, comment: &str) -> String {
+        format!("<!--{}-->", sanitize_xml_comment(comment.trim()))
+    }
+
+    fn render_entry(&self, entry: &Entry, indent: &str) -> Result<String, Error> {
+        let attributes = prefixed_attributes(&entry.custom, ANDROID_ATTRIBUTE_PREFIX);
+        let mut start_attrs = format!(" name=\"{}\"", escape(element_name(&entry.id, &attributes)));
+        if entry.status == EntryStatus::DoNotTranslate {
+            start_attrs.push_str(" translatable=\"false\"");
+        }
+        for (key, value) in &attributes {
+            start_attrs.push_str(&format!(" {}=\"{}\"", key, escape(value)));
+        }
+        match &entry.value {
+            Translation::Plural(plural) => Ok(format!(
+                "<plurals{}>\n{}{}</plurals>",
+                start_attrs,
+                render_plural_items(plural, &format!("{}    ", indent)),
+                indent
+            )),
+            Translation::Singular(value) => Ok(format!(
+                "<string{}>{}</string>",
+                start_attrs,
+                android_text::encode(value)
+            )),
+            Translation::Select(select) => Ok(format!(
+                "<string{}>{}</string>",
+                start_attrs,
+                android_text::encode(&select.to_icu())
+            )),
+            Translation::Empty => Ok(format!("<string{}></string>", start_attrs)),
+        }
+    }
+
+    fn skips_entry(&self, entry: &Entry) -> bool {
+        matches!(entry.value, Translation::Empty)
+    }
+}
+
+fn render_plural_items(plural: &Plural, indent: &str) -> String {
+    plural
+        .forms
+        .iter()
+        .map(|(quantity, value)| {
+            format!(
+                "{}<item quantity=\"{}\">{}</item>\n",
+                indent,
+                quantity_name(quantity),
+                android_text::encode(value)
+            )
+        })
+        .collect()
+}
+
+/// Byte offset of the `>` closing the first tag in `element`, skipping quoted attribute values.
+fn start_tag_end(element: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (idx, ch) in element.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Adds, updates or drops `translatable="false"` on a start tag (without its closing `>`).
+fn set_translatable_attr(start_tag: &str, do_not_translate: bool) -> String {
+    lazy_static::lazy_static! {
+        static ref TRANSLATABLE_ATTR: regex::Regex =
+            regex::Regex::new(r#"\s+translatable\s*=\s*("[^"]*"|'[^']*')"#).unwrap();
+    }
+
+    let existing = TRANSLATABLE_ATTR.captures(start_tag);
+    let is_false = existing
+        .as_ref()
+        .is_some_and(|caps| caps[1].trim_matches(['"', '\'']) == "false");
+    match (existing, do_not_translate) {
+        (Some(_), true) if is_false => start_tag.to_string(),
+        (Some(caps), true) => start_tag.replace(&caps[0], " translatable=\"false\""),
+        (None, true) => {
+            let (head, tail) = match start_tag.strip_suffix('/') {
+                Some(head) => (head.trim_end(), "/"),
+                None => (start_tag, ""),
+            };
+            format!("{} translatable=\"false\"{}", head, tail)
+        }
+        (Some(caps), false) if is_false => start_tag.replace(&caps[0], ""),
+        (_, false) => start_tag.to_string(),
+    }
+}
+
+/// Locates the inner text range of each `<item>` inside a `<plurals>` element.
+fn plural_item_ranges(element: &str) -> Result<Vec<(PluralCategory, Range<usize>)>, Error> {
+    let mut reader = Reader::from_str(element);
+    reader.config_mut().trim_text(false);
+    let mut items = Vec::new();
+    let mut open: Option<(PluralCategory, usize)> = None;
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"item" => {
+                let mut quantity = None;
+                for attr in e.attributes().with_checks(false) {
+                    let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
+                    if attr.key.as_ref() == b"quantity" {
+                        quantity = PluralCategory::from_str(&attr.unescape_value()?).ok();
+                    }
+                }
+                open = quantity.map(|q| (q, reader.buffer_position() as usize));
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"item" => {
+                if let Some((quantity, inner_start)) = open.take() {
+                    items.push((quantity, inner_start..start));
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+    }
+    Ok(items)
+}
+
+fn quantity_name(category: &PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
 pub struct StringResource {
     pub name: String,
     pub value: String,
     pub translatable: Option<bool>,
     pub comment: Option<String>,
+    /// Attributes other than `name` and `translatable`, in source order.
+    pub attributes: Vec<(String, String)>,
 }
 
 impl StringResource {
@@ -240,12 +587,13 @@ impl StringResource {
             value,
             translatable,
             comment,
+            attributes,
         } = self;
 
         let is_value_empty = value.is_empty();
 
         Entry {
-            id: name,
+            id: entry_id(name, &attributes),
             value: Translation::Singular(value),
             comment,
             status: match translatable {
@@ -254,16 +602,19 @@ impl StringResource {
                 None if is_value_empty => EntryStatus::New,
                 None => EntryStatus::Translated,
             },
-            custom: HashMap::new(),
+            custom: attributes_to_custom(attributes, ANDROID_ATTRIBUTE_PREFIX),
         }
     }
 
     fn from_entry(entry: &Entry) -> Self {
+        let attributes = prefixed_attributes(&entry.custom, ANDROID_ATTRIBUTE_PREFIX);
         StringResource {
-            name: entry.id.clone(),
+            name: element_name(&entry.id, &attributes),
             value: match &entry.value {
                 Translation::Empty => String::new(),
                 Translation::Singular(v) => v.clone(),
+                // Android reads ICU messages through `MessageFormat`
+                Translation::Select(s) => s.to_icu(),
                 Translation::Plural(_) => String::new(), // Plurals not supported in strings.xml
             },
             comment: entry.comment.clone(),
@@ -273,6 +624,7 @@ impl StringResource {
                 EntryStatus::New => None,
                 _ => None, // Other statuses not applicable
             },
+            attributes,
         }
     }
 }
@@ -283,50 +635,275 @@ pub struct PluralItem {
     pub value: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct PluralsResource {
     pub name: String,
     pub items: Vec<PluralItem>,
     pub translatable: Option<bool>,
     pub comment: Option<String>,
+    /// Attributes other than `name` and `translatable`, in source order.
+    pub attributes: Vec<(String, String)>,
+    /// Always cardinal when parsed; ordinal plurals are rejected on write.
+    pub kind: PluralKind,
 }
 
-fn parse_string_resource<R: BufRead>(
-    e: &BytesStart,
-    xml_reader: &mut Reader<R>,
-) -> Result<StringResource, Error> {
-    let mut name = None;
-    let mut translatable = None;
+impl PluralsResource {
+    fn from_entry(entry: &Entry) -> Self {
+        let attributes = prefixed_attributes(&entry.custom, ANDROID_ATTRIBUTE_PREFIX);
+        let (mut items, kind): (Vec<PluralItem>, PluralKind) = match &entry.value {
+            Translation::Plural(plural) => (
+                plural
+                    .forms
+                    .iter()
+                    .map(|(quantity, value)| PluralItem {
+                        quantity: quantity.clone(),
+                        value: value.clone(),
+                    })
+                    .collect(),
+                plural.kind,
+            ),
+            _ => (Vec::new(), PluralKind::Cardinal),
+        };
+        // Ensure stable order later
+        items.sort_by(|a, b| a.quantity.cmp(&b.quantity));
+        PluralsResource {
+            name: element_name(&entry.id, &attributes),
+            items,
+            comment: entry.comment.clone(),
+            translatable: match entry.status {
+                EntryStatus::Translated => Some(true),
+                EntryStatus::DoNotTranslate => Some(false),
+                _ => None,
+            },
+            attributes,
+            kind,
+        }
+    }
 
-    for attr in e.attributes().with_checks(false) {
-        let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
-        match attr.key.as_ref() {
-            b"name" => name = Some(attr.unescape_value()?.to_string()),
-            b"translatable" => {
-                let v = attr.unescape_value()?.to_string();
-                translatable = Some(v == "true");
+    fn into_entry(self) -> Entry {
+        let mut forms = std::collections::BTreeMap::new();
+        for item in self.items {
+            let PluralItem { quantity, value } = item;
+            forms.insert(quantity, value);
+        }
+        let all_empty = forms.values().all(|v| v.is_empty());
+        let status = match self.translatable {
+            Some(true) => EntryStatus::Translated,
+            Some(false) => EntryStatus::DoNotTranslate,
+            None => {
+                if all_empty {
+                    EntryStatus::New
+                } else {
+                    EntryStatus::Translated
+                }
             }
-            _ => {}
+        };
+        Entry {
+            id: entry_id(self.name.clone(), &self.attributes),
+            value: Translation::Plural(Plural {
+                id: self.name,
+                forms,
+                kind: PluralKind::Cardinal,
+            }),
+            comment: self.comment,
+            status,
+            custom: attributes_to_custom(self.attributes, ANDROID_ATTRIBUTE_PREFIX),
         }
     }
-    let name =
-        name.ok_or_else(|| Error::InvalidResource("string tag missing 'name'".to_string()))?;
+}
+
+/// Android `<plurals>` only select by quantity, so ordinal plurals cannot be written.
+fn ordinal_plural_error(name: &str) -> Error {
+    Error::UnsupportedFormat(format!(
+        "Ordinal plural '{}' cannot be represented in Android strings.xml: <plurals> only supports cardinal quantities",
+        name
+    ))
+}
+
+/// Entry id for an element: its `name`, suffixed with `[product]` for product variants.
+fn entry_id(name: String, attributes: &[(String, String)]) -> String {
+    match product(attributes) {
+        Some(product) => format!("{}[{}]", name, product),
+        None => name,
+    }
+}
+
+/// Inverse of [`entry_id`]: the `name` attribute to write for an entry.
+fn element_name(id: &str, attributes: &[(String, String)]) -> String {
+    product(attributes)
+        .and_then(|product| id.strip_suffix(&format!("[{}]", product)))
+        .unwrap_or(id)
+        .to_string()
+}
+
+fn product(attributes: &[(String, String)]) -> Option<&str> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == "product")
+        .map(|(_, value)| value.as_str())
+}
+
+/// Key of the comma-separated attribute names, in source order, stored next
+/// to the attributes under `prefix` (e.g. `android.attr_order`).
+fn attribute_order_key(prefix: &str) -> String {
+    format!("{}_order", prefix.trim_end_matches('.'))
+}
+
+/// Stores `attributes` under `prefix`, recording their source order.
+fn attributes_to_custom(
+    attributes: Vec<(String, String)>,
+    prefix: &str,
+) -> HashMap<String, String> {
+    let mut custom = HashMap::new();
+    if attributes.is_empty() {
+        return custom;
+    }
+    let order: Vec<&str> = attributes.iter().map(|(key, _)| key.as_str()).collect();
+    custom.insert(attribute_order_key(prefix), order.join(","));
+    for (key, value) in attributes {
+        custom.insert(format!("{prefix}{key}"), value);
+    }
+    custom
+}
+
+/// Attributes stored in `custom` under `prefix`, in their recorded source
+/// order. Attributes without a recorded position follow, ordered by name.
+fn prefixed_attributes(custom: &HashMap<String, String>, prefix: &str) -> Vec<(String, String)> {
+    let mut attributes: Vec<(String, String)> = custom
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(prefix)
+                .filter(|name| !name.is_empty())
+                .map(|name| (name.to_string(), value.clone()))
+        })
+        .collect();
+    attributes.sort();
+    if let Some(order) = custom.get(&attribute_order_key(prefix)) {
+        let order: Vec<&str> = order.split(',').collect();
+        attributes.sort_by_key(|(name, _)| {
+            order
+                .iter()
+                .position(|known| known == name)
+                .unwrap_or(order.len())
+        });
+    }
+    attributes
+}
+
+/// Which resource a parsed element produced, and where it sits in the source.
+struct ElementSpan {
+    kind: ElementKind,
+    comment: Option<Range<usize>>,
+    body: Range<usize>,
+}
+
+enum ElementKind {
+    String(usize),
+    Plurals(usize),
+}
+
+/// Parses `source`, attaching the line and column to any error.
+fn parse_with_spans(source: &str) -> Result<(Format, Vec<ElementSpan>), Error> {
+    let mut xml_reader = Reader::from_str(source);
+    let at = |offset: u64| SourcePosition::from_offset(source, offset as usize);
+    // Preserve whitespace inside text nodes so multi-line strings and
+    // indentation are kept exactly as authored in XML.
+    xml_reader.config_mut().trim_text(false);
 
     let mut buf = Vec::new();
-    // Read and accumulate all text nodes until we reach the end of this <string> element
-    let mut value = String::new();
+    let mut string_resources = Vec::new();
+    let mut plural_resources: Vec<PluralsResource> = Vec::new();
+    let mut spans = Vec::new();
+    let mut pending_comment: Option<(String, Range<usize>)> = None;
+    let mut resources_attributes = Vec::new();
+
     loop {
+        let start = xml_reader.buffer_position() as usize;
         match xml_reader.read_event_into(&mut buf) {
-            Ok(Event::Text(e)) => {
-                value.push_str(e.unescape().map_err(Error::XmlParse)?.as_ref());
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"resources" => {
+                resources_attributes =
+                    parse_attributes(e).map_err(|err| err.located(at(start as u64)))?;
             }
-            Ok(Event::End(ref end)) if end.name().as_ref() == b"string" => break,
-            Ok(Event::Eof) => return Err(Error::InvalidResource("Unexpected EOF".to_string())),
-            Ok(_) => (),
-            Err(e) => return Err(Error::XmlParse(e)),
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"string" => {
+                let mut sr = parse_string_resource(e, &mut xml_reader)
+                    .map_err(|err| locate_xml_error(err, &xml_reader, start, &at))?;
+                let (comment, comment_range) = pending_comment.take().unzip();
+                sr.comment = comment;
+                spans.push(ElementSpan {
+                    kind: ElementKind::String(string_resources.len()),
+                    comment: comment_range,
+                    body: start..xml_reader.buffer_position() as usize,
+                });
+                string_resources.push(sr);
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"plurals" => {
+                let mut pr = parse_plurals_resource(e, &mut xml_reader)
+                    .map_err(|err| locate_xml_error(err, &xml_reader, start, &at))?;
+                let (comment, comment_range) = pending_comment.take().unzip();
+                pr.comment = comment;
+                spans.push(ElementSpan {
+                    kind: ElementKind::Plurals(plural_resources.len()),
+                    comment: comment_range,
+                    body: start..xml_reader.buffer_position() as usize,
+                });
+                plural_resources.push(pr);
+            }
+            Ok(Event::Comment(comment)) => {
+                pending_comment = Some((
+                    parse_xml_comment(comment.as_ref()),
+                    start..xml_reader.buffer_position() as usize,
+                ));
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e).located(at(xml_reader.error_position()))),
         }
         buf.clear();
     }
+    let format = Format {
+        language: String::new(), // strings.xml does not contain language metadata
+        strings: string_resources,
+        plurals: plural_resources,
+        resources_attributes,
+    };
+    Ok((format, spans))
+}
+
+/// Positions an error raised inside an element: XML syntax errors at the
+/// failing byte, everything else at the element's start tag.
+fn locate_xml_error<R>(
+    error: Error,
+    xml_reader: &Reader<R>,
+    element_start: usize,
+    at: &impl Fn(u64) -> SourcePosition,
+) -> Error {
+    let offset = match error {
+        Error::XmlParse(_) => xml_reader.error_position(),
+        _ => element_start as u64,
+    };
+    error.located(at(offset))
+}
+
+fn parse_string_resource<R: BufRead>(
+    e: &BytesStart,
+    xml_reader: &mut Reader<R>,
+) -> Result<StringResource, Error> {
+    let mut name = None;
+    let mut translatable = None;
+    let mut attributes = Vec::new();
+
+    for (key, value) in parse_attributes(e)? {
+        match key.as_str() {
+            "name" => name = Some(value),
+            "translatable" => translatable = Some(value == "true"),
+            _ => attributes.push((key, value)),
+        }
+    }
+    let name =
+        name.ok_or_else(|| Error::InvalidResource("string tag missing 'name'".to_string()))?;
+
+    let raw = read_inner_xml(xml_reader, b"string", "Unexpected EOF")?;
+    let mut value = android_text::decode(&raw)?;
 
     // Normalize: if the content ends with a newline followed only by indentation
     // spaces, collapse that trailing indentation to 4 spaces to avoid
@@ -348,6 +925,7 @@ fn parse_string_resource<R: BufRead>(
         value,
         translatable,
         comment: None,
+        attributes,
     })
 }
 
@@ -357,16 +935,13 @@ fn parse_plurals_resource<R: BufRead>(
 ) -> Result<PluralsResource, Error> {
     let mut name: Option<String> = None;
     let mut translatable: Option<bool> = None;
+    let mut attributes = Vec::new();
 
-    for attr in e.attributes().with_checks(false) {
-        let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
-        match attr.key.as_ref() {
-            b"name" => name = Some(attr.unescape_value()?.to_string()),
-            b"translatable" => {
-                let v = attr.unescape_value()?.to_string();
-                translatable = Some(v == "true");
-            }
-            _ => {}
+    for (key, value) in parse_attributes(e)? {
+        match key.as_str() {
+            "name" => name = Some(value),
+            "translatable" => translatable = Some(value == "true"),
+            _ => attributes.push((key, value)),
         }
     }
     let name =
@@ -388,25 +963,8 @@ fn parse_plurals_resource<R: BufRead>(
                 }
                 let quantity = quantity
                     .ok_or_else(|| Error::InvalidResource("item missing 'quantity'".to_string()))?;
-                // Read text content until End(item)
-                let mut value = String::new();
-                let mut local_buf = Vec::new();
-                loop {
-                    match xml_reader.read_event_into(&mut local_buf) {
-                        Ok(Event::Text(e)) => {
-                            value.push_str(e.unescape().map_err(Error::XmlParse)?.as_ref());
-                        }
-                        Ok(Event::End(ref end)) if end.name().as_ref() == b"item" => break,
-                        Ok(Event::Eof) => {
-                            return Err(Error::InvalidResource(
-                                "Unexpected EOF inside <item>".to_string(),
-                            ));
-                        }
-                        Ok(_) => {}
-                        Err(e) => return Err(Error::XmlParse(e)),
-                    }
-                    local_buf.clear();
-                }
+                let raw = read_inner_xml(xml_reader, b"item", "Unexpected EOF inside <item>")?;
+                let value = android_text::decode(&raw)?;
                 items.push(PluralItem { quantity, value });
             }
             Ok(Event::End(ref end)) if end.name().as_ref() == b"plurals" => break,
@@ -426,9 +984,68 @@ fn parse_plurals_resource<R: BufRead>(
         items,
         translatable,
         comment: None,
+        attributes,
+        kind: PluralKind::Cardinal,
     })
 }
 
+fn parse_attributes(e: &BytesStart) -> Result<Vec<(String, String)>, Error> {
+    let mut attributes = Vec::new();
+    for attr in e.attributes().with_checks(false) {
+        let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
+        attributes.push((
+            String::from_utf8_lossy(attr.key.as_ref()).to_string(),
+            attr.unescape_value()?.to_string(),
+        ));
+    }
+    Ok(attributes)
+}
+
+/// Collects the raw inner XML of the element whose start tag was just read,
+/// keeping CDATA sections and nested markup for [`android_text::decode`].
+fn read_inner_xml<R: BufRead>(
+    xml_reader: &mut Reader<R>,
+    element: &[u8],
+    eof_message: &str,
+) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    let mut raw = String::new();
+    let mut depth = 0usize;
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Text(e)) => raw.push_str(&String::from_utf8_lossy(&e)),
+            Ok(Event::CData(e)) => {
+                raw.push_str("<![CDATA[");
+                raw.push_str(&String::from_utf8_lossy(&e));
+                raw.push_str("]]>");
+            }
+            Ok(Event::Start(e)) => {
+                depth += 1;
+                raw.push('<');
+                raw.push_str(&String::from_utf8_lossy(&e));
+                raw.push('>');
+            }
+            Ok(Event::Empty(e)) => {
+                raw.push('<');
+                raw.push_str(&String::from_utf8_lossy(&e));
+                raw.push_str("/>");
+            }
+            Ok(Event::End(ref end)) if depth == 0 && end.name().as_ref() == element => break,
+            Ok(Event::End(e)) => {
+                depth = depth.saturating_sub(1);
+                raw.push_str("</");
+                raw.push_str(&String::from_utf8_lossy(&e));
+                raw.push('>');
+            }
+            Ok(Event::Eof) => return Err(Error::InvalidResource(eof_message.to_string())),
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+        buf.clear();
+    }
+    Ok(raw)
+}
+
 fn parse_xml_comment(raw: &[u8]) -> String {
     String::from_utf8_lossy(raw).trim().to_string()
 }
@@ -590,6 +1207,245 @@ World
         }
     }
 
+    #[test]
+    fn test_parse_aapt_escapes_cdata_and_inline_markup() {
+        let xml = r#"
+        <resources>
+            <string name="apostrophe" translatable="true">Don\'t stop</string>
+            <string name="reference">\@home</string>
+            <string name="padded" translatable="true">"  two  spaces "</string>
+            <string name="styled" translatable="true">Tap <b>here</b> for <xliff:g id="count">%d</xliff:g> items</string>
+            <string name="cdata"><![CDATA[<a href="https://example.com">Link</a>]]></string>
+            <plurals name="files">
+                <item quantity="one"><i>One</i> file isn\'t here</item>
+                <item quantity="other">%d files</item>
+            </plurals>
+        </resources>
+        "#;
+        let resource = Resource::from(Format::from_str(xml).unwrap());
+        let value = |id: &str| match &resource.find_entry(id).unwrap().value {
+            Translation::Singular(value) => value.clone(),
+            other => panic!("expected singular value for {id}, got {other:?}"),
+        };
+        assert_eq!(value("apostrophe"), "Don't stop");
+        assert_eq!(value("reference"), "@home");
+        assert_eq!(value("padded"), "  two  spaces ");
+        assert_eq!(
+            value("styled"),
+            r#"Tap <b>here</b> for <xliff:g id="count">%d</xliff:g> items"#
+        );
+        assert_eq!(value("cdata"), r#"<a href="https://example.com">Link</a>"#);
+        match &resource.find_entry("files").unwrap().value {
+            Translation::Plural(plural) => assert_eq!(
+                plural.forms.get(&PluralCategory::One).unwrap(),
+                "<i>One</i> file isn't here"
+            ),
+            other => panic!("expected plural, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_write_escapes_values_for_aapt() {
+        let mut resource = Resource {
+            metadata: Metadata {
+                language: "en".to_string(),
+                domain: String::new(),
+                custom: HashMap::new(),
+            },
+            entries: Vec::new(),
+        };
+        for (id, value) in [
+            ("apostrophe", "Don't stop"),
+            ("handle", "@username"),
+            ("styled", "Tap <b>here</b> & save"),
+            ("padded", " leading"),
+        ] {
+            resource.add_entry(Entry {
+                id: id.to_string(),
+                value: Translation::Singular(value.to_string()),
+                comment: None,
+                status: EntryStatus::Translated,
+                custom: HashMap::new(),
+            });
+        }
+        let mut out = Vec::new();
+        Format::from(resource.clone()).to_writer(&mut out).unwrap();
+        let xml = String::from_utf8(out).unwrap();
+        assert!(
+            xml.contains(r#"<string name="apostrophe" translatable="true">Don\'t stop</string>"#)
+        );
+        assert!(xml.contains(r#"<string name="handle" translatable="true">\@username</string>"#));
+        assert!(xml.contains(
+            r#"<string name="styled" translatable="true">Tap <b>here</b> &amp; save</string>"#
+        ));
+        assert!(xml.contains(r#"<string name="padded" translatable="true">" leading"</string>"#));
+
+        let reparsed = Resource::from(Format::from_str(&xml).unwrap());
+        assert_eq!(reparsed.entries, resource.entries);
+    }
+
+    #[test]
+    fn test_preserves_extra_attributes_and_namespaces() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:tools="http://schemas.android.com/tools" tools:locale="en">
+    <string name="pattern" tools:ignore="MissingTranslation" formatted="false">%d of %s</string>
+    <plurals name="songs" tools:ignore="UnusedQuantity">
+        <item quantity="one">%d song</item>
+        <item quantity="other">%d songs</item>
+    </plurals>
+</resources>
+"#;
+        let resource = Resource::from(Format::from_str(xml).unwrap());
+        assert_eq!(
+            resource
+                .metadata
+                .custom
+                .get("android.resources.xmlns:tools")
+                .map(String::as_str),
+            Some("http://schemas.android.com/tools")
+        );
+        assert_eq!(
+            resource
+                .metadata
+                .custom
+                .get("android.resources.tools:locale")
+                .map(String::as_str),
+            Some("en")
+        );
+        let pattern = resource.find_entry("pattern").unwrap();
+        assert_eq!(
+            pattern
+                .custom
+                .get("android.attr.formatted")
+                .map(String::as_str),
+            Some("false")
+        );
+        assert_eq!(
+            pattern
+                .custom
+                .get("android.attr.tools:ignore")
+                .map(String::as_str),
+            Some("MissingTranslation")
+        );
+        let songs = resource.find_entry("songs").unwrap();
+        assert_eq!(
+            songs
+                .custom
+                .get("android.attr.tools:ignore")
+                .map(String::as_str),
+            Some("UnusedQuantity")
+        );
+
+        let mut out = Vec::new();
+        Format::from(resource.clone()).to_writer(&mut out).unwrap();
+        let written = String::from_utf8(out).unwrap();
+        // Attributes are written back in source order.
+        assert!(written.contains(
+            r#"<resources xmlns:tools="http://schemas.android.com/tools" tools:locale="en">"#
+        ));
+        assert!(written.contains(
+            r#"<string name="pattern" translatable="true" tools:ignore="MissingTranslation" formatted="false">"#
+        ));
+        assert!(written.contains(r#"tools:ignore="UnusedQuantity""#));
+        assert_eq!(
+            Resource::from(Format::from_str(&written).unwrap()),
+            resource
+        );
+    }
+
+    #[test]
+    fn test_product_variants_are_separate_entries() {
+        let xml = r#"
+        <resources>
+            <string name="device">phone</string>
+            <string name="device" product="tablet">tablet</string>
+            <string name="device" product="tv">TV</string>
+        </resources>
+        "#;
+        let resource = Resource::from(Format::from_str(xml).unwrap());
+        let ids: Vec<&str> = resource.entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["device", "device[tablet]", "device[tv]"]);
+        assert_eq!(
+            resource.find_entry("device[tablet]").unwrap().value,
+            Translation::Singular("tablet".to_string())
+        );
+
+        let format = Format::from(resource);
+        let names: Vec<&str> = format.strings.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["device", "device", "device"]);
+        let mut out = Vec::new();
+        format.to_writer(&mut out).unwrap();
+        let written = String::from_utf8(out).unwrap();
+        assert!(
+            written
+                .contains(r#"<string name="device" translatable="true" product="tv">TV</string>"#)
+        );
+    }
+
+    #[test]
+    fn test_writer_declares_namespaces_used_by_entries() {
+        let mut entry = Entry {
+            id: "count".to_string(),
+            value: Translation::Singular(r#"<xliff:g id="n">%d</xliff:g> items"#.to_string()),
+            comment: None,
+            status: EntryStatus::Translated,
+            custom: HashMap::new(),
+        };
+        entry
+            .custom
+            .insert("android.attr.tools:ignore".to_string(), "Typos".to_string());
+        let resource = Resource {
+            metadata: Metadata {
+                language: "en".to_string(),
+                domain: String::new(),
+                custom: HashMap::new(),
+            },
+            entries: vec![entry],
+        };
+        let mut out = Vec::new();
+        Format::from(resource).to_writer(&mut out).unwrap();
+        let written = String::from_utf8(out).unwrap();
+        assert!(written.contains(r#"xmlns:tools="http://schemas.android.com/tools""#));
+        assert!(written.contains(r#"xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2""#));
+    }
+
+    #[test]
+    fn test_writer_rejects_ordinal_plurals() {
+        let mut plural = Plural::new(
+            "place",
+            vec![(PluralCategory::Other, "%dth".to_string())].into_iter(),
+        )
+        .unwrap();
+        plural.kind = PluralKind::Ordinal;
+        let resource = Resource {
+            metadata: Metadata {
+                language: "en".to_string(),
+                domain: String::new(),
+                custom: HashMap::new(),
+            },
+            entries: vec![Entry {
+                id: "place".to_string(),
+                value: Translation::Plural(plural),
+                comment: None,
+                status: EntryStatus::Translated,
+                custom: HashMap::new(),
+            }],
+        };
+        let mut format = Format::from(resource);
+        format.strings.push(StringResource {
+            name: "title".to_string(),
+            value: "Title".to_string(),
+            translatable: None,
+            comment: None,
+            attributes: Vec::new(),
+        });
+        let mut out = Vec::new();
+        let err = format.to_writer(&mut out).unwrap_err();
+        assert!(out.is_empty());
+        assert!(matches!(err, Error::UnsupportedFormat(_)));
+        assert!(err.to_string().contains("Ordinal plural 'place'"));
+    }
+
     #[test]
     fn test_parse_and_round_trip_entry_comments() {
         let xml = r#"
@@ -674,6 +1530,7 @@ World
                 value: Translation::Plural(Plural {
                     id: "files".into(),
                     forms,
+                    kind: PluralKind::Cardinal,
                 }),
                 comment: None,
                 status: EntryStatus::Translated,
@@ -697,4 +1554,167 @@ World
                 .any(|i| matches!(i.quantity, PluralCategory::Other) && i.value == "%d files")
         );
     }
+
+    const LAYOUT_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:tools="http://schemas.android.com/tools">
+    <!-- Onboarding -->
+    <string name="welcome" tools:ignore="MissingTranslation">Welcome</string>
+    <string name="skip">Skip</string>
+
+    <dimen name="padding">16dp</dimen>
+    <!-- Shown in the inbox -->
+    <plurals name="messages">
+        <item quantity="one">%d message</item>
+        <item quantity="other">%d messages</item>
+    </plurals>
+</resources>
+"#;
+
+    fn layout_resource() -> Resource {
+        Resource::from(Format::from_str(LAYOUT_XML).unwrap())
+    }
+
+    #[test]
+    fn test_document_without_changes_is_byte_identical() {
+        let mut document = Document::from_str(LAYOUT_XML).unwrap();
+        document.apply(&layout_resource()).unwrap();
+        assert_eq!(document.source(), LAYOUT_XML);
+    }
+
+    #[test]
+    fn test_document_updates_single_value_in_place() {
+        let mut resource = layout_resource();
+        let entry = resource
+            .entries
+            .iter_mut()
+            .find(|e| e.id == "skip")
+            .unwrap();
+        entry.value = Translation::Singular("Not now".to_string());
+
+        let mut document = Document::from_str(LAYOUT_XML).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(document.source(), LAYOUT_XML.replace(">Skip<", ">Not now<"));
+    }
+
+    #[test]
+    fn test_document_keeps_attributes_and_updates_plural_item() {
+        let mut resource = layout_resource();
+        for entry in &mut resource.entries {
+            match entry.id.as_str() {
+                "welcome" => entry.value = Translation::Singular("Hi & hello".to_string()),
+                "messages" => {
+                    if let Translation::Plural(plural) = &mut entry.value {
+                        plural
+                            .forms
+                            .insert(PluralCategory::One, "%d new message".to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut document = Document::from_str(LAYOUT_XML).unwrap();
+        document.apply(&resource).unwrap();
+        let expected = LAYOUT_XML
+            .replace(
+                r#"tools:ignore="MissingTranslation">Welcome<"#,
+                r#"tools:ignore="MissingTranslation">Hi &amp; hello<"#,
+            )
+            .replace(">%d message<", ">%d new message<");
+        assert_eq!(document.source(), expected);
+    }
+
+    #[test]
+    fn test_document_inserts_after_previous_key_and_removes_with_comment() {
+        let mut resource = layout_resource();
+        resource.entries.retain(|e| e.id != "messages");
+        let position = resource
+            .entries
+            .iter()
+            .position(|e| e.id == "skip")
+            .unwrap();
+        resource.entries.insert(
+            position + 1,
+            Entry {
+                id: "later".to_string(),
+                value: Translation::Singular("Later".to_string()),
+                comment: Some("Secondary button".to_string()),
+                status: EntryStatus::DoNotTranslate,
+                custom: HashMap::new(),
+            },
+        );
+
+        let mut document = Document::from_str(LAYOUT_XML).unwrap();
+        document.apply(&resource).unwrap();
+        let source = document.source();
+        assert!(source.contains(
+            "    <string name=\"skip\">Skip</string>\n    <!--Secondary button-->\n    <string name=\"later\" translatable=\"false\">Later</string>\n\n    <dimen"
+        ));
+        assert!(!source.contains("messages"));
+        assert!(!source.contains("Shown in the inbox"));
+        assert!(source.contains("<!-- Onboarding -->"));
+        assert!(source.ends_with("<dimen name=\"padding\">16dp</dimen>\n</resources>\n"));
+    }
+
+    #[test]
+    fn test_document_marks_entry_do_not_translate() {
+        let mut resource = layout_resource();
+        let entry = resource
+            .entries
+            .iter_mut()
+            .find(|e| e.id == "skip")
+            .unwrap();
+        entry.status = EntryStatus::DoNotTranslate;
+
+        let mut document = Document::from_str(LAYOUT_XML).unwrap();
+        document.apply(&resource).unwrap();
+        assert!(
+            document
+                .source()
+                .contains(r#"<string name="skip" translatable="false">Skip</string>"#)
+        );
+    }
+
+    #[test]
+    fn test_document_renames_keys_in_place() {
+        let source = "<resources>\n    <string name=\"title\">Title</string>\n\n    <!-- Okay   button -->\n    <string name=\"ok\" tools:ignore=\"x\">OK</string>\n    <plurals name='files'>\n        <item quantity=\"other\">%d files</item>\n    </plurals>\n</resources>\n";
+        let mut resources = vec![Resource::from(Format::from_str(source).unwrap())];
+        let mapping =
+            crate::KeyMapping::pairs([("ok", "confirm_button"), ("files", "file_count")]).unwrap();
+        crate::rename_keys(&mut resources, &mapping).unwrap();
+
+        let mut document = Document::from_str(source).unwrap();
+        document.apply(&resources[0]).unwrap();
+        assert_eq!(
+            document.source(),
+            source
+                .replace("\"ok\"", "\"confirm_button\"")
+                .replace("'files'", "'file_count'")
+        );
+    }
+
+    #[test]
+    fn test_document_inserts_into_empty_resources() {
+        let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n</resources>\n";
+        let resource = Resource {
+            metadata: Metadata {
+                language: "en".to_string(),
+                domain: String::new(),
+                custom: HashMap::new(),
+            },
+            entries: vec![Entry {
+                id: "hello".to_string(),
+                value: Translation::Singular("Hello".to_string()),
+                comment: None,
+                status: EntryStatus::Translated,
+                custom: HashMap::new(),
+            }],
+        };
+        let mut document = Document::from_str(xml).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(
+            document.source(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n    <string name=\"hello\">Hello</string>\n</resources>\n"
+        );
+    }
 }
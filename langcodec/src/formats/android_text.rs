@@ -0,0 +1,365 @@
+//! Text codec for the inner content of Android `<string>` and `<item>` elements.
+//!
+//! Android resources are compiled by aapt, which applies its own escaping on
+//! top of XML: apostrophes and double quotes must be backslash-escaped, a
+//! leading `@` or `?` would be read as a resource reference, and whitespace
+//! is collapsed unless the value is wrapped in double quotes.
+//!
+//! [`decode`] turns the raw inner XML of an element into the value stored in
+//! the [`crate::types::Resource`] model and [`encode`] does the reverse. Like
+//! the `.strings` model, `\n`, `\t` and `\\` are kept as escape sequences so
+//! values convert between Apple and Android formats unchanged. Inline style
+//! markup (`<b>`, `<i>`, `<u>`, `<xliff:g>`, ...) is kept as markup in the
+//! value rather than being flattened into text.
+
+use lazy_static::lazy_static;
+use quick_xml::escape::unescape;
+use regex::Regex;
+
+use crate::error::Error;
+
+/// Inline elements that [`encode`] writes as markup instead of escaped text.
+pub const INLINE_TAGS: &[&str] = &[
+    "b",
+    "i",
+    "u",
+    "a",
+    "annotation",
+    "big",
+    "font",
+    "small",
+    "strike",
+    "sub",
+    "sup",
+    "tt",
+    "xliff:g",
+];
+
+lazy_static! {
+    static ref INLINE_TAG_REGEX: Regex = Regex::new(&format!(
+        r#"</?(?:{})(?:\s+[^<>]*?)?\s*/?>"#,
+        INLINE_TAGS.join("|")
+    ))
+    .unwrap();
+}
+
+enum Piece {
+    Text(String),
+    Markup(String),
+}
+
+/// Decodes the raw inner XML of a `<string>` or `<item>` element.
+///
+/// XML entities and `<![CDATA[...]]>` sections become plain text, aapt
+/// escapes (`\'`, `\"`, `\@`, `\?`, `\uXXXX`) are resolved, a value wrapped
+/// in double quotes loses its quotes, and nested elements are kept verbatim
+/// as markup. XML comments inside the element are dropped.
+pub fn decode(raw: &str) -> Result<String, Error> {
+    let mut pieces = tokenize(raw)?;
+    strip_outer_quotes(&mut pieces);
+
+    let mut out = String::with_capacity(raw.len());
+    for piece in &pieces {
+        match piece {
+            Piece::Text(text) => unescape_aapt(text, &mut out),
+            Piece::Markup(markup) => out.push_str(markup),
+        }
+    }
+    Ok(out)
+}
+
+/// Encodes a model value as the inner XML of a `<string>` or `<item>` element.
+///
+/// Apostrophes and double quotes are backslash-escaped, a leading `@` or `?`
+/// is escaped so it is not read as a reference, and values whose leading,
+/// trailing or repeated spaces aapt would collapse are wrapped in double
+/// quotes. Existing escape sequences are kept as-is, and recognized inline
+/// tags (see [`INLINE_TAGS`]) are written as markup.
+pub fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    let quoted = needs_quotes(value);
+    if quoted {
+        out.push('"');
+    }
+    let mut last = 0;
+    for tag in INLINE_TAG_REGEX.find_iter(value) {
+        escape_text(&value[last..tag.start()], last == 0, &mut out);
+        out.push_str(tag.as_str());
+        last = tag.end();
+    }
+    escape_text(&value[last..], last == 0, &mut out);
+    if quoted {
+        out.push('"');
+    }
+    out
+}
+
+fn tokenize(raw: &str) -> Result<Vec<Piece>, Error> {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let push_text = |pieces: &mut Vec<Piece>, text: &str| match pieces.last_mut() {
+        Some(Piece::Text(last)) => last.push_str(text),
+        _ => pieces.push(Piece::Text(text.to_string())),
+    };
+
+    let mut rest = raw;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after
+                .find("]]>")
+                .ok_or_else(|| Error::InvalidResource("Unterminated CDATA section".to_string()))?;
+            push_text(&mut pieces, &after[..end]);
+            rest = &after[end + 3..];
+        } else if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after
+                .find("-->")
+                .ok_or_else(|| Error::InvalidResource("Unterminated XML comment".to_string()))?;
+            rest = &after[end + 3..];
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest)
+                .ok_or_else(|| Error::InvalidResource(format!("Unterminated tag in '{}'", raw)))?;
+            pieces.push(Piece::Markup(rest[..=end].to_string()));
+            rest = &rest[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = unescape(&rest[..end]).map_err(|e| Error::DataMismatch(e.to_string()))?;
+            push_text(&mut pieces, &text);
+            rest = &rest[end..];
+        }
+    }
+    Ok(pieces)
+}
+
+/// Byte offset of the `>` closing the tag at the start of `s`, skipping quoted attribute values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (idx, ch) in s.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Removes the double quotes aapt uses to preserve whitespace when they wrap
+/// the whole value. Stray quotes elsewhere are kept as literal text.
+fn strip_outer_quotes(pieces: &mut [Piece]) {
+    let unescaped_quotes = |text: &str| {
+        let mut positions = Vec::new();
+        let mut escaped = false;
+        for (idx, ch) in text.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                positions.push(idx);
+            }
+        }
+        positions
+    };
+
+    let last_idx = pieces.len().saturating_sub(1);
+    let (Some(Piece::Text(first)), Some(Piece::Text(last))) = (pieces.first(), pieces.last())
+    else {
+        return;
+    };
+    let first_trimmed = first.trim_start();
+    let last_trimmed = last.trim_end();
+    if !first_trimmed.starts_with('"') || !last_trimmed.ends_with('"') {
+        return;
+    }
+
+    let open = first.len() - first_trimmed.len();
+    let close = last_trimmed.len() - 1;
+    if last_idx == 0 && open >= close {
+        return;
+    }
+    // Any other unescaped quote means the value is not simply wrapped.
+    let total: usize = pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => unescaped_quotes(text).len(),
+            Piece::Markup(_) => 0,
+        })
+        .sum();
+    if total != 2 || unescaped_quotes(last).last() != Some(&close) {
+        return;
+    }
+
+    if last_idx == 0 {
+        if let Some(Piece::Text(text)) = pieces.first_mut() {
+            *text = text[open + 1..close].to_string();
+        }
+        return;
+    }
+    if let Some(Piece::Text(text)) = pieces.first_mut() {
+        *text = text[open + 1..].to_string();
+    }
+    if let Some(Piece::Text(text)) = pieces.last_mut() {
+        text.truncate(close);
+    }
+}
+
+fn unescape_aapt(text: &str, out: &mut String) {
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.peek().copied() {
+            Some(c @ ('\'' | '"' | '@' | '?')) => {
+                chars.next();
+                out.push(c);
+            }
+            Some('u') => {
+                let hex: String = chars.clone().skip(1).take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) if hex.len() == 4 => {
+                        for _ in 0..5 {
+                            chars.next();
+                        }
+                        out.push(decoded);
+                    }
+                    _ => out.push('\\'),
+                }
+            }
+            Some(c) => {
+                // `\n`, `\t`, `\\` and unknown escapes stay as escape sequences.
+                chars.next();
+                out.push('\\');
+                out.push(c);
+            }
+            None => out.push('\\'),
+        }
+    }
+}
+
+fn escape_text(text: &str, at_value_start: bool, out: &mut String) {
+    let mut chars = text.chars();
+    let mut first = at_value_start;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some(next) => {
+                    out.push('\\');
+                    push_xml_char(next, out);
+                }
+                None => out.push_str("\\\\"),
+            },
+            '\'' => out.push_str("\\'"),
+            '"' => out.push_str("\\\""),
+            '@' | '?' if first => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            _ => push_xml_char(ch, out),
+        }
+        first = false;
+    }
+}
+
+fn push_xml_char(ch: char, out: &mut String) {
+    match ch {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        _ => out.push(ch),
+    }
+}
+
+/// aapt collapses leading, trailing and repeated whitespace outside quotes.
+fn needs_quotes(value: &str) -> bool {
+    value.starts_with(char::is_whitespace)
+        || value.ends_with(char::is_whitespace)
+        || value.contains("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_resolves_aapt_escapes() {
+        assert_eq!(decode(r"Don\'t stop").unwrap(), "Don't stop");
+        assert_eq!(decode(r#"He said \"Hi\""#).unwrap(), r#"He said "Hi""#);
+        assert_eq!(decode(r"\@string/name").unwrap(), "@string/name");
+        assert_eq!(decode(r"\?attr").unwrap(), "?attr");
+        assert_eq!(decode(r"caf\u00e9").unwrap(), "café");
+        assert_eq!(decode(r"Line1\nLine2").unwrap(), r"Line1\nLine2");
+        assert_eq!(decode(r"C:\\Temp").unwrap(), r"C:\\Temp");
+        assert_eq!(
+            decode("Use &lt;tag&gt; &amp; value").unwrap(),
+            "Use <tag> & value"
+        );
+    }
+
+    #[test]
+    fn test_decode_strips_wrapping_quotes_only() {
+        assert_eq!(decode(r#""  padded  ""#).unwrap(), "  padded  ");
+        assert_eq!(decode("\n    \"It's\"\n").unwrap(), "It's");
+        assert_eq!(decode(r#""a" and "b""#).unwrap(), r#""a" and "b""#);
+        assert_eq!(decode(r#"He said "Hello""#).unwrap(), r#"He said "Hello""#);
+        assert_eq!(decode(r#""<b>bold</b> ""#).unwrap(), "<b>bold</b> ");
+    }
+
+    #[test]
+    fn test_decode_cdata_and_markup() {
+        assert_eq!(
+            decode("<![CDATA[<a href=\"x\">link</a>]]> &amp; more").unwrap(),
+            "<a href=\"x\">link</a> & more"
+        );
+        assert_eq!(
+            decode(r#"Hello <b>%1$s</b>, <xliff:g id="count" example="3">%2$d</xliff:g> left"#)
+                .unwrap(),
+            r#"Hello <b>%1$s</b>, <xliff:g id="count" example="3">%2$d</xliff:g> left"#
+        );
+        assert_eq!(decode("a<!-- note -->b").unwrap(), "ab");
+        assert!(decode("<![CDATA[open").is_err());
+    }
+
+    #[test]
+    fn test_encode_produces_lint_valid_text() {
+        assert_eq!(encode("Don't stop"), r"Don\'t stop");
+        assert_eq!(encode(r#"He said "Hi""#), r#"He said \"Hi\""#);
+        assert_eq!(encode("@home"), r"\@home");
+        assert_eq!(encode("?really"), r"\?really");
+        assert_eq!(encode("mail@example.com?"), "mail@example.com?");
+        assert_eq!(encode("Use <tag> & value"), "Use &lt;tag&gt; &amp; value");
+        assert_eq!(encode("  padded"), r#""  padded""#);
+        assert_eq!(encode("a  b"), r#""a  b""#);
+        assert_eq!(encode("two\nlines"), r"two\nlines");
+    }
+
+    #[test]
+    fn test_encode_keeps_escapes_and_inline_markup() {
+        assert_eq!(encode(r#"He said \"Hi\""#), r#"He said \"Hi\""#);
+        assert_eq!(encode(r"Don\'t"), r"Don\'t");
+        assert_eq!(encode(r"C:\\Temp\n"), r"C:\\Temp\n");
+        assert_eq!(
+            encode(r#"<b>Bold</b> <xliff:g id="n">%d</xliff:g> isn't <blink>"#),
+            r#"<b>Bold</b> <xliff:g id="n">%d</xliff:g> isn\'t &lt;blink&gt;"#
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [
+            "Don't stop",
+            r#"He said "Hello""#,
+            "@handle",
+            "?",
+            "  spaced  out ",
+            "Tap <b>here</b> & <i>there</i>",
+            r"Line1\nLine2 \\ done",
+            "Unicode ✓ 你好",
+        ] {
+            assert_eq!(decode(&encode(value)).unwrap(), value, "value: {value}");
+        }
+    }
+}
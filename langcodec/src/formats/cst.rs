@@ -0,0 +1,419 @@
+//! Layout-preserving edits for hand-maintained single-language files.
+//!
+//! Formats such as Apple `.strings` and Android `strings.xml` often carry
+//! content the [`Resource`] model does not represent: free-standing comments,
+//! blank-line grouping, extra attributes, or unrelated elements. Instead of
+//! re-serializing the whole file, the helpers here splice entry-level changes
+//! into the original text so that touching one key changes one line.
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::{
+    error::Error,
+    provenance::entry_provenance,
+    types::{Entry, Resource},
+};
+
+/// A localization entry located in the original source text.
+#[derive(Debug, Clone)]
+pub(crate) struct Node {
+    /// The entry exactly as the regular parser reads it from this file.
+    pub entry: Entry,
+    /// Byte range of the comment the parser attached to this entry, if any.
+    pub comment: Option<Range<usize>>,
+    /// Byte range of the entry itself (e.g. `"key" = "value";` or `<string>…</string>`).
+    pub body: Range<usize>,
+}
+
+/// Format-specific rendering hooks used by [`apply_resource`].
+pub(crate) trait Layout {
+    /// Re-renders an existing entry whose value or status changed, keeping as
+    /// much of the original `body` text (attributes, quoting style) as possible.
+    fn patch_body(
+        &self,
+        body: &str,
+        old: &Entry,
+        new: &Entry,
+        indent: &str,
+    ) -> Result<String, Error>;
+
+    /// Rewrites only the key of an existing entry that was renamed to `new.id`,
+    /// leaving the rest of `body` as written.
+    fn rename_body(&self, body: &str, new: &Entry, indent: &str) -> Result<String, Error>;
+
+    /// Renders a comment in the format's syntax.
+    fn render_comment(&self, comment: &str) -> String;
+
+    /// Renders a new entry (without its comment) on a single logical line.
+    fn render_entry(&self, entry: &Entry, indent: &str) -> Result<String, Error>;
+
+    /// Whether the format drops this entry when writing (e.g. empty values).
+    fn skips_entry(&self, _entry: &Entry) -> bool {
+        false
+    }
+
+    /// Whether text following an entry on the same line can stay attached to
+    /// that line when a new entry is inserted after it.
+    fn is_line_trivia(&self, tail: &str) -> bool {
+        tail.trim().is_empty()
+    }
+}
+
+struct Edit {
+    range: Range<usize>,
+    text: String,
+}
+
+/// Splices the entries of `resource` into `source`.
+///
+/// Existing entries keep their position, comment and formatting unless their
+/// value, status or comment changed. Entries missing from `resource` are
+/// removed together with their attached comment, and new entries are inserted
+/// after the preceding key in `resource` order. An entry whose provenance
+/// records a `previous_key` takes over the block of that key when the old key
+/// is gone, so a rename only rewrites the key. When `resource` orders the
+/// existing keys differently, their blocks are permuted among the existing
+/// positions so surrounding content stays where it is. `fallback_insert` is
+/// used when the document has no entries to anchor insertions to.
+pub(crate) fn apply_resource<L: Layout>(
+    layout: &L,
+    source: &str,
+    nodes: &[Node],
+    resource: &Resource,
+    fallback_insert: usize,
+    default_indent: &str,
+) -> Result<String, Error> {
+    // Desired entries in resource order, first occurrence of each key wins.
+    let mut wanted: Vec<&Entry> = Vec::new();
+    let mut wanted_by_key: HashMap<&str, &Entry> = HashMap::new();
+    for entry in &resource.entries {
+        if layout.skips_entry(entry) || wanted_by_key.contains_key(entry.id.as_str()) {
+            continue;
+        }
+        wanted_by_key.insert(entry.id.as_str(), entry);
+        wanted.push(entry);
+    }
+
+    // Only the first node of a duplicated key is managed; later ones are left as-is.
+    let mut node_by_key: HashMap<&str, usize> = HashMap::new();
+    for (idx, node) in nodes.iter().enumerate() {
+        node_by_key.entry(node.entry.id.as_str()).or_insert(idx);
+    }
+    let managed: Vec<usize> = (0..nodes.len())
+        .filter(|idx| node_by_key.get(nodes[*idx].entry.id.as_str()) == Some(idx))
+        .collect();
+
+    // Wanted entries matched to the node they update: by key, or for renamed
+    // entries by their previous key when nothing else still uses it.
+    let mut node_of: HashMap<&str, usize> = HashMap::new();
+    for entry in &wanted {
+        if let Some(&idx) = node_by_key.get(entry.id.as_str()) {
+            node_of.insert(entry.id.as_str(), idx);
+        }
+    }
+    for entry in &wanted {
+        if node_of.contains_key(entry.id.as_str()) {
+            continue;
+        }
+        let Some(previous) = entry_provenance(entry).and_then(|p| p.previous_key) else {
+            continue;
+        };
+        if wanted_by_key.contains_key(previous.as_str()) {
+            continue;
+        }
+        if let Some(&idx) = node_by_key.get(previous.as_str())
+            && !node_of.values().any(|claimed| *claimed == idx)
+        {
+            node_of.insert(entry.id.as_str(), idx);
+        }
+    }
+    let entry_of: HashMap<usize, &Entry> = wanted
+        .iter()
+        .filter_map(|entry| Some((*node_of.get(entry.id.as_str())?, *entry)))
+        .collect();
+
+    let slots: Vec<usize> = managed
+        .iter()
+        .copied()
+        .filter(|idx| entry_of.contains_key(idx))
+        .collect();
+    let desired: Vec<usize> = wanted
+        .iter()
+        .filter_map(|entry| node_of.get(entry.id.as_str()).copied())
+        .collect();
+
+    // New entries are anchored after the previous existing entry in resource order.
+    let mut before_first: Vec<&Entry> = Vec::new();
+    let mut after_slot: HashMap<usize, Vec<&Entry>> = HashMap::new();
+    let mut anchor: Option<usize> = None;
+    for entry in &wanted {
+        match node_of.get(entry.id.as_str()) {
+            Some(node_idx) => {
+                anchor = desired.iter().position(|idx| idx == node_idx);
+            }
+            None => match anchor {
+                Some(slot) => after_slot.entry(slot).or_default().push(entry),
+                None => before_first.push(entry),
+            },
+        }
+    }
+
+    let mut edits: Vec<Edit> = Vec::new();
+
+    for node_idx in &managed {
+        if !slots.contains(node_idx) {
+            let block = block_range(source, &nodes[*node_idx]);
+            let range = expand_to_lines(source, with_line_trivia(layout, source, nodes, block));
+            edits.push(Edit {
+                range,
+                text: String::new(),
+            });
+        }
+    }
+
+    for (slot, (&slot_node, &placed_node)) in slots.iter().zip(desired.iter()).enumerate() {
+        let slot_range = block_range(source, &nodes[slot_node]);
+        let indent = line_indent(source, slot_range.start);
+        let placed = &nodes[placed_node];
+        let new_entry = entry_of[&placed_node];
+
+        let block = render_block(layout, source, placed, new_entry, indent, &mut edits)?;
+        if block != source[slot_range.clone()] {
+            edits.push(Edit {
+                range: slot_range.clone(),
+                text: block,
+            });
+        }
+
+        if let Some(entries) = after_slot.get(&slot) {
+            let rendered = render_entries(layout, entries, indent)?;
+            let tail_end = line_end(source, slot_range.end);
+            if layout.is_line_trivia(&source[slot_range.end..tail_end]) {
+                let (at, prefix) = if tail_end < source.len() {
+                    (tail_end + 1, "")
+                } else {
+                    (tail_end, "\n")
+                };
+                edits.push(Edit {
+                    range: at..at,
+                    text: format!("{}{}", prefix, join_lines(&rendered, indent, true)),
+                });
+            } else {
+                edits.push(Edit {
+                    range: slot_range.end..slot_range.end,
+                    text: format!("\n{}", join_lines(&rendered, indent, false)),
+                });
+            }
+        }
+    }
+
+    if !before_first.is_empty() {
+        match slots.first() {
+            Some(&first) => {
+                let start = block_range(source, &nodes[first]).start;
+                let indent = line_indent(source, start);
+                let rendered = render_entries(layout, &before_first, indent)?;
+                let line_start = start - indent.len();
+                if source[..line_start].is_empty() || source[..line_start].ends_with('\n') {
+                    edits.push(Edit {
+                        range: line_start..line_start,
+                        text: join_lines(&rendered, indent, true),
+                    });
+                } else {
+                    edits.push(Edit {
+                        range: start..start,
+                        text: format!("{}\n", join_lines(&rendered, "", false)),
+                    });
+                }
+            }
+            None => {
+                let rendered = render_entries(layout, &before_first, default_indent)?;
+                let at = fallback_insert.min(source.len());
+                let needs_newline = at > 0 && !source[..at].ends_with('\n');
+                edits.push(Edit {
+                    range: at..at,
+                    text: format!(
+                        "{}{}",
+                        if needs_newline { "\n" } else { "" },
+                        join_lines(&rendered, default_indent, true)
+                    ),
+                });
+            }
+        }
+    }
+
+    splice(source, edits)
+}
+
+/// Renders the comment and body of an existing node for its (possibly new) position.
+fn render_block<L: Layout>(
+    layout: &L,
+    source: &str,
+    node: &Node,
+    new: &Entry,
+    indent: &str,
+    edits: &mut Vec<Edit>,
+) -> Result<String, Error> {
+    let old = &node.entry;
+    let body_text = &source[node.body.clone()];
+    let body_text = if old.id == new.id {
+        body_text.to_string()
+    } else {
+        layout.rename_body(body_text, new, indent)?
+    };
+    let body = if old.value == new.value && old.status == new.status {
+        body_text
+    } else {
+        layout.patch_body(&body_text, old, new, indent)?
+    };
+
+    let comment_changed = old.comment != new.comment;
+    let rendered_comment = new
+        .comment
+        .as_deref()
+        .filter(|c| !c.trim().is_empty())
+        .map(|c| layout.render_comment(c));
+
+    match &node.comment {
+        Some(comment) if is_attached(source, comment, &node.body) => {
+            let gap = &source[comment.end..node.body.start];
+            Ok(match (comment_changed, rendered_comment) {
+                (false, _) => format!("{}{}", &source[comment.start..node.body.start], body),
+                (true, Some(rendered)) => format!("{}{}{}", rendered, gap, body),
+                (true, None) => body,
+            })
+        }
+        detached => {
+            if comment_changed {
+                if let Some(comment) = detached {
+                    // The comment is separated from its entry; edit it where it stands.
+                    let (range, text) = match rendered_comment {
+                        Some(rendered) => (comment.clone(), rendered),
+                        None => (expand_to_lines(source, comment.clone()), String::new()),
+                    };
+                    edits.push(Edit { range, text });
+                    return Ok(body);
+                }
+                if let Some(rendered) = rendered_comment {
+                    return Ok(format!("{}\n{}{}", rendered, indent, body));
+                }
+            }
+            Ok(body)
+        }
+    }
+}
+
+fn render_entries<L: Layout>(
+    layout: &L,
+    entries: &[&Entry],
+    indent: &str,
+) -> Result<Vec<String>, Error> {
+    let mut lines = Vec::new();
+    for entry in entries {
+        if let Some(comment) = entry.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+            lines.push(layout.render_comment(comment));
+        }
+        lines.push(layout.render_entry(entry, indent)?);
+    }
+    Ok(lines)
+}
+
+/// Joins rendered lines with `indent`; `leading` also indents the first line
+/// and terminates the last one with a newline.
+fn join_lines(lines: &[String], indent: &str, leading: bool) -> String {
+    let mut out = String::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > 0 || leading {
+            out.push_str(indent);
+        }
+        out.push_str(line);
+        if idx + 1 < lines.len() || leading {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// A comment belongs to the entry's block when it starts its own line and
+/// only whitespace separates it from the entry.
+fn is_attached(source: &str, comment: &Range<usize>, body: &Range<usize>) -> bool {
+    let line_start = source[..comment.start].rfind('\n').map_or(0, |i| i + 1);
+    comment.end <= body.start
+        && source[line_start..comment.start].trim().is_empty()
+        && source[comment.end..body.start].trim().is_empty()
+}
+
+fn block_range(source: &str, node: &Node) -> Range<usize> {
+    match &node.comment {
+        Some(comment) if is_attached(source, comment, &node.body) => comment.start..node.body.end,
+        _ => node.body.clone(),
+    }
+}
+
+/// Whitespace between the start of the line and `pos`, or `""` when other
+/// content precedes `pos` on that line.
+fn line_indent(source: &str, pos: usize) -> &str {
+    let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let prefix = &source[line_start..pos];
+    if prefix.chars().all(|c| c == ' ' || c == '\t') {
+        prefix
+    } else {
+        ""
+    }
+}
+
+/// Position of the newline ending the line that contains `pos` (or the end of input).
+fn line_end(source: &str, pos: usize) -> usize {
+    source[pos..].find('\n').map_or(source.len(), |i| pos + i)
+}
+
+/// Extends a removed block over trivia such as `// note` that follows it on
+/// its last line, unless another entry starts there.
+fn with_line_trivia<L: Layout>(
+    layout: &L,
+    source: &str,
+    nodes: &[Node],
+    range: Range<usize>,
+) -> Range<usize> {
+    let end = line_end(source, range.end);
+    let tail = range.end..end;
+    if !layout.is_line_trivia(&source[tail.clone()])
+        || nodes.iter().any(|node| tail.contains(&node.body.start))
+    {
+        return range;
+    }
+    range.start..end
+}
+
+/// Widens `range` to whole lines when nothing else shares those lines.
+fn expand_to_lines(source: &str, range: Range<usize>) -> Range<usize> {
+    let line_start = source[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = line_end(source, range.end);
+    let prefix_blank = source[line_start..range.start].trim().is_empty();
+    let suffix_blank = source[range.end..end].trim().is_empty();
+    if prefix_blank && suffix_blank {
+        let end = if end < source.len() { end + 1 } else { end };
+        line_start..end
+    } else {
+        range
+    }
+}
+
+fn splice(source: &str, mut edits: Vec<Edit>) -> Result<String, Error> {
+    // Insertions at a position go before a replacement starting there.
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end > edit.range.start));
+    let mut out = String::with_capacity(source.len());
+    let mut cursor = 0usize;
+    for edit in edits {
+        if edit.range.start < cursor {
+            return Err(Error::InvalidResource(
+                "Overlapping edits while preserving file layout".to_string(),
+            ));
+        }
+        out.push_str(&source[cursor..edit.range.start]);
+        out.push_str(&edit.text);
+        cursor = edit.range.end;
+    }
+    out.push_str(&source[cursor..]);
+    Ok(out)
+}
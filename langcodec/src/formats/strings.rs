@@ -3,18 +3,100 @@
 //! Provides parsing, serialization, and conversion to/from the internal `Resource` model.
 
 use std::collections::HashMap;
-use std::fs::File;
-// keep imports minimal; actual Read trait is used via fully qualified call above
-use std::path::Path;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
 
 use indoc::indoc;
 
 use crate::{
     error::Error,
+    formats::cst::{Layout, Node, apply_resource},
+    provenance::{EntryPosition, LineIndex},
     traits::Parser,
     types::{Entry, EntryStatus, Metadata, Resource, Translation},
 };
 
+/// Metadata key recording the text encoding a `.strings` file was read with.
+pub const STRINGS_ENCODING_KEY: &str = "strings.encoding";
+/// Metadata key recording whether a `.strings` file started with a byte order mark.
+pub const STRINGS_BOM_KEY: &str = "strings.bom";
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// Text encoding used when reading or writing a `.strings` file.
+///
+/// Older Xcode projects commonly store `.strings` files as UTF-16 with a BOM;
+/// the detected encoding is kept so that writes do not silently re-encode them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringsEncoding {
+    /// UTF-8, optionally preceded by a BOM.
+    #[default]
+    Utf8,
+    /// UTF-16 little-endian.
+    Utf16Le,
+    /// UTF-16 big-endian.
+    Utf16Be,
+}
+
+impl StringsEncoding {
+    /// Returns the canonical name stored in metadata (`utf8`, `utf16le`, `utf16be`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StringsEncoding::Utf8 => "utf8",
+            StringsEncoding::Utf16Le => "utf16le",
+            StringsEncoding::Utf16Be => "utf16be",
+        }
+    }
+
+    /// Whether a BOM is written by default for this encoding.
+    ///
+    /// UTF-16 is ambiguous without one, so it always gets a BOM; UTF-8 does not.
+    pub fn default_bom(&self) -> bool {
+        !matches!(self, StringsEncoding::Utf8)
+    }
+
+    /// Records this encoding (and its default BOM setting) in resource metadata,
+    /// overriding whatever encoding was detected when the resource was read.
+    pub fn apply_to(&self, metadata: &mut Metadata) {
+        metadata
+            .custom
+            .insert(STRINGS_ENCODING_KEY.to_string(), self.as_str().to_string());
+        metadata
+            .custom
+            .insert(STRINGS_BOM_KEY.to_string(), self.default_bom().to_string());
+    }
+}
+
+impl fmt::Display for StringsEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StringsEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_ascii_lowercase()
+            .replace(['-', '_'], "")
+            .as_str()
+        {
+            "utf8" => Ok(StringsEncoding::Utf8),
+            "utf16" | "utf16le" => Ok(StringsEncoding::Utf16Le),
+            "utf16be" => Ok(StringsEncoding::Utf16Be),
+            _ => Err(format!(
+                "Unknown .strings encoding: {} (expected utf8, utf16le, or utf16be)",
+                s
+            )),
+        }
+    }
+}
+
 /// Represents an Apple `.strings` localization file.
 ///
 /// The format consists of a set of key-value pairs, with optional comments.
@@ -24,6 +106,10 @@ pub struct Format {
     pub language: String,
     /// All key-value pairs (and optional comments) in the file.
     pub pairs: Vec<Pair>,
+    /// Encoding the file was read with, and will be written with.
+    pub encoding: StringsEncoding,
+    /// Whether the file starts with a byte order mark.
+    pub bom: bool,
 }
 
 impl Parser for Format {
@@ -32,20 +118,7 @@ impl Parser for Format {
     /// The `language` parameter would be empty, since the .strings format does
     /// not contain any metadata about the language.
     fn from_reader<R: std::io::BufRead>(reader: R) -> Result<Self, Error> {
-        // Read entire input into a string (UTF-8 expected here; UTF-16 handled in read_from)
-        let mut reader = reader;
-        let mut bytes = Vec::new();
-        std::io::Read::read_to_end(&mut reader, &mut bytes).map_err(Error::Io)?;
-        let content = String::from_utf8(bytes)
-            .map_err(|_| Error::InvalidResource("Invalid UTF-8 in .strings file".to_string()))?;
-
-        // Parse content
-        let header_language = extract_header_language(&content).unwrap_or_default();
-        let (pairs, _warnings) = parse_strings_content(&content);
-        Ok(Format {
-            language: header_language,
-            pairs,
-        })
+        Self::from_reader_with_positions(reader).map(|(format, _positions)| format)
     }
 
     fn to_writer<W: std::io::Write>(&self, mut writer: W) -> Result<(), Error> {
@@ -80,35 +153,61 @@ impl Parser for Format {
             content.push_str(&format!("\"{}\" = \"{}\";\n", key, value));
         }
 
-        writer.write_all(content.as_bytes()).map_err(Error::Io)
+        writer
+            .write_all(&encode_strings_content(&content, self.encoding, self.bom))
+            .map_err(Error::Io)
     }
+}
 
-    /// Override default file reading to support BOM-aware decoding (e.g., UTF-16 Apple .strings)
-    fn read_from<P: AsRef<Path>>(path: P) -> Result<Self, Error>
-    where
-        Self: Sized,
-    {
-        let file = File::open(path).map_err(Error::Io)?;
-        // Auto-detect BOM, decode to UTF-8; passthrough UTF-8
-        let mut decoder = encoding_rs_io::DecodeReaderBytesBuilder::new()
-            .bom_override(true)
-            .build(file);
+impl Format {
+    /// Parses a `.strings` file and reports where each pair starts.
+    ///
+    /// Offsets refer to the decoded text, so UTF-16 files report UTF-8 offsets.
+    pub fn from_reader_with_positions<R: std::io::BufRead>(
+        reader: R,
+    ) -> Result<(Self, Vec<EntryPosition>), Error> {
+        // Read raw bytes; the BOM (if any) decides between UTF-8 and UTF-16
+        let mut reader = reader;
+        let mut bytes = Vec::new();
+        std::io::Read::read_to_end(&mut reader, &mut bytes).map_err(Error::Io)?;
+        let (content, encoding, bom) = decode_strings_bytes(&bytes)?;
 
-        let mut decoded_bytes = Vec::new();
-        std::io::Read::read_to_end(&mut decoder, &mut decoded_bytes).map_err(Error::Io)?;
-        let decoded = String::from_utf8(decoded_bytes)
-            .map_err(|_| Error::InvalidResource("Invalid UTF-8 in .strings file".to_string()))?;
-        Self::from_str(&decoded)
+        // Parse content
+        let header_language = extract_header_language(&content).unwrap_or_default();
+        let (spans, _warnings) = parse_strings_spans(&content);
+        let lines = LineIndex::new(&content);
+        let positions = spans
+            .iter()
+            .map(|span| EntryPosition {
+                key: span.pair.key.clone(),
+                language: None,
+                position: lines.position(&content, span.body.start),
+            })
+            .collect();
+        let format = Format {
+            language: header_language,
+            pairs: spans.into_iter().map(|span| span.pair).collect(),
+            encoding,
+            bom,
+        };
+        Ok((format, positions))
     }
 }
 
 impl From<Format> for Resource {
     fn from(value: Format) -> Self {
+        let mut custom = HashMap::new();
+        custom.insert(
+            STRINGS_ENCODING_KEY.to_string(),
+            value.encoding.as_str().to_string(),
+        );
+        custom.insert(STRINGS_BOM_KEY.to_string(), value.bom.to_string());
+
         Resource {
             metadata: Metadata {
                 language: value.language,
                 domain: String::from(""),
-                custom: HashMap::new(),
+                custom,
             },
             entries: value.pairs.into_iter().map(Pair::into_entry).collect(),
         }
@@ -120,13 +219,235 @@ impl TryFrom<Resource> for Format {
 
     fn try_from(value: Resource) -> Result<Self, Self::Error> {
         let Resource { metadata, entries } = value;
+        let (encoding, bom) = encoding_from_metadata(&metadata)?;
         let language = metadata.language;
         let pairs = entries
             .into_iter()
             .map(Pair::try_from)
             .collect::<Result<Vec<_>, _>>()?;
-        Ok(Format { language, pairs })
+        Ok(Format {
+            language,
+            pairs,
+            encoding,
+            bom,
+        })
+    }
+}
+
+/// A `.strings` file kept as source text so edits can preserve its layout.
+///
+/// Unlike [`Format`], which rebuilds the file from its pairs, a `Document`
+/// applies entry-level changes in place: free-standing comments, blank lines,
+/// ordering, and formatting of untouched pairs are kept byte-for-byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    source: String,
+    encoding: StringsEncoding,
+    bom: bool,
+}
+
+impl Document {
+    /// Returns the current (decoded) source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Parses the current source into a [`Format`].
+    pub fn to_format(&self) -> Format {
+        let (pairs, _warnings) = parse_strings_content(&self.source);
+        Format {
+            language: extract_header_language(&self.source).unwrap_or_default(),
+            pairs,
+            encoding: self.encoding,
+            bom: self.bom,
+        }
+    }
+
+    /// Updates the document so it contains exactly the entries of `resource`.
+    ///
+    /// Unchanged pairs keep their original text; changed values and comments
+    /// are rewritten in place, removed keys are deleted with their comment,
+    /// and new keys are inserted after the preceding key in `resource` order.
+    /// Encoding metadata on `resource` (see [`STRINGS_ENCODING_KEY`]) is honored.
+    pub fn apply(&mut self, resource: &Resource) -> Result<(), Error> {
+        if resource.metadata.custom.contains_key(STRINGS_ENCODING_KEY) {
+            (self.encoding, self.bom) = encoding_from_metadata(&resource.metadata)?;
+        }
+
+        let (spans, _warnings) = parse_strings_spans(&self.source);
+        let nodes: Vec<Node> = spans
+            .into_iter()
+            .map(|span| Node {
+                entry: span.pair.into_entry(),
+                comment: span.comment,
+                body: span.body,
+            })
+            .collect();
+
+        self.source = apply_resource(
+            &StringsLayout,
+            &self.source,
+            &nodes,
+            resource,
+            self.source.len(),
+            "",
+        )?;
+        Ok(())
+    }
+}
+
+impl Parser for Document {
+    fn from_reader<R: std::io::BufRead>(reader: R) -> Result<Self, Error> {
+        let mut reader = reader;
+        let mut bytes = Vec::new();
+        std::io::Read::read_to_end(&mut reader, &mut bytes).map_err(Error::Io)?;
+        let (source, encoding, bom) = decode_strings_bytes(&bytes)?;
+        Ok(Document {
+            source,
+            encoding,
+            bom,
+        })
+    }
+
+    fn to_writer<W: std::io::Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer
+            .write_all(&encode_strings_content(
+                &self.source,
+                self.encoding,
+                self.bom,
+            ))
+            .map_err(Error::Io)
+    }
+}
+
+struct StringsLayout;
+
+impl Layout for StringsLayout {
+    fn patch_body(
+        &self,
+        _body: &str,
+        _old: &Entry,
+        new: &Entry,
+        indent: &str,
+    ) -> Result<String, Error> {
+        self.render_entry(new, indent)
+    }
+
+    fn rename_body(&self, body: &str, new: &Entry, indent: &str) -> Result<String, Error> {
+        let Some((key_end, _)) = parse_quoted_utf8(body, body.as_bytes(), 0) else {
+            return self.render_entry(new, indent);
+        };
+        let pair = Pair::try_from(new.clone())?;
+        Ok(format!(
+            "\"{}\"{}",
+            escape_strings_token(&pair.key),
+            &body[key_end..]
+        ))
+    }
+
+    fn render_comment(&self, comment: &str) -> String {
+        let comment = comment.trim();
+        if comment.starts_with("//") || comment.starts_with("/*") {
+            comment.to_string()
+        } else {
+            format!("/* {} */", comment)
+        }
     }
+
+    fn render_entry(&self, entry: &Entry, _indent: &str) -> Result<String, Error> {
+        let pair = Pair::try_from(entry.clone())?;
+        Ok(format!(
+            "\"{}\" = \"{}\";",
+            escape_strings_token(&pair.key),
+            escape_strings_token(&pair.value)
+        ))
+    }
+
+    fn is_line_trivia(&self, tail: &str) -> bool {
+        let tail = tail.trim();
+        tail.is_empty() || tail.starts_with("//") || tail.starts_with("/*")
+    }
+}
+
+fn encoding_from_metadata(metadata: &Metadata) -> Result<(StringsEncoding, bool), Error> {
+    let encoding = match metadata.custom.get(STRINGS_ENCODING_KEY) {
+        Some(raw) => raw
+            .parse::<StringsEncoding>()
+            .map_err(Error::DataMismatch)?,
+        None => StringsEncoding::default(),
+    };
+    let bom = match metadata.custom.get(STRINGS_BOM_KEY) {
+        Some(raw) => raw.parse::<bool>().map_err(|_| {
+            Error::DataMismatch(format!("Invalid {} value: {}", STRINGS_BOM_KEY, raw))
+        })?,
+        None => encoding.default_bom(),
+    };
+    Ok((encoding, bom))
+}
+
+/// Decodes raw `.strings` bytes, detecting UTF-8/UTF-16 from the BOM.
+///
+/// Files without a BOM are treated as UTF-8.
+fn decode_strings_bytes(bytes: &[u8]) -> Result<(String, StringsEncoding, bool), Error> {
+    if let Some(rest) = bytes.strip_prefix(UTF16LE_BOM) {
+        Ok((
+            decode_utf16(rest, u16::from_le_bytes)?,
+            StringsEncoding::Utf16Le,
+            true,
+        ))
+    } else if let Some(rest) = bytes.strip_prefix(UTF16BE_BOM) {
+        Ok((
+            decode_utf16(rest, u16::from_be_bytes)?,
+            StringsEncoding::Utf16Be,
+            true,
+        ))
+    } else {
+        let (rest, bom) = match bytes.strip_prefix(UTF8_BOM) {
+            Some(rest) => (rest, true),
+            None => (bytes, false),
+        };
+        let content = String::from_utf8(rest.to_vec())
+            .map_err(|_| Error::InvalidResource("Invalid UTF-8 in .strings file".to_string()))?;
+        Ok((content, StringsEncoding::Utf8, bom))
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Result<String, Error> {
+    let invalid = || Error::InvalidResource("Invalid UTF-16 in .strings file".to_string());
+    let chunks = bytes.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(invalid());
+    }
+    let units = chunks.map(|pair| to_unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| invalid())
+}
+
+/// Encodes serialized `.strings` content with the requested encoding and BOM.
+fn encode_strings_content(content: &str, encoding: StringsEncoding, bom: bool) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(content.len() * 2 + 3);
+    match encoding {
+        StringsEncoding::Utf8 => {
+            if bom {
+                bytes.extend_from_slice(UTF8_BOM);
+            }
+            bytes.extend_from_slice(content.as_bytes());
+        }
+        StringsEncoding::Utf16Le => {
+            if bom {
+                bytes.extend_from_slice(UTF16LE_BOM);
+            }
+            bytes.extend(content.encode_utf16().flat_map(u16::to_le_bytes));
+        }
+        StringsEncoding::Utf16Be => {
+            if bom {
+                bytes.extend_from_slice(UTF16BE_BOM);
+            }
+            bytes.extend(content.encode_utf16().flat_map(u16::to_be_bytes));
+        }
+    }
+    bytes
 }
 
 /// A single key-value pair in a `.strings` file, possibly with an associated comment.
@@ -176,12 +497,24 @@ impl Pair {
 // ----------------------
 
 fn parse_strings_content(content: &str) -> (Vec<Pair>, Vec<String>) {
+    let (spans, warnings) = parse_strings_spans(content);
+    (spans.into_iter().map(|span| span.pair).collect(), warnings)
+}
+
+/// A parsed pair together with where it (and its attached comment) sits in the source.
+struct PairSpan {
+    pair: Pair,
+    comment: Option<Range<usize>>,
+    body: Range<usize>,
+}
+
+fn parse_strings_spans(content: &str) -> (Vec<PairSpan>, Vec<String>) {
     let bytes = content.as_bytes();
     let mut i = 0usize;
     let len = bytes.len();
-    let mut pairs: Vec<Pair> = Vec::new();
+    let mut pairs: Vec<PairSpan> = Vec::new();
     let warnings: Vec<String> = Vec::new();
-    let mut pending_comment: Option<String> = None;
+    let mut pending_comment: Option<(String, Range<usize>)> = None;
     let mut have_seen_pair = false;
 
     while i < len {
@@ -201,18 +534,19 @@ fn parse_strings_content(content: &str) -> (Vec<Pair>, Vec<String>) {
         // Comments
         if starts_with(bytes, i, b"//") {
             let (nj, comment) = parse_line_comment(bytes, i);
-            pending_comment = Some(comment);
+            pending_comment = Some((comment, i..nj));
             i = nj;
             continue;
         }
         if starts_with(bytes, i, b"/*") {
             let (nj, comment) = parse_block_comment(bytes, i);
-            pending_comment = Some(comment);
+            pending_comment = Some((comment, i..nj.min(len)));
             i = nj;
             continue;
         }
 
         // Key-Value pair: "key" = "value";
+        let body_start = i;
         if let Some((j, key)) = parse_quoted_utf8(content, bytes, i) {
             i = j;
             let (ni2, _) = skip_inline_ws(bytes, i);
@@ -240,12 +574,16 @@ fn parse_strings_content(content: &str) -> (Vec<Pair>, Vec<String>) {
                     }
 
                     let value = normalize_value_newlines(&value_raw);
-                    let pair = Pair {
-                        key,
-                        value,
-                        comment: pending_comment.take(),
-                    };
-                    pairs.push(pair);
+                    let (comment, comment_range) = pending_comment.take().unzip();
+                    pairs.push(PairSpan {
+                        pair: Pair {
+                            key,
+                            value,
+                            comment,
+                        },
+                        comment: comment_range,
+                        body: body_start..i,
+                    });
                     have_seen_pair = true;
                     continue;
                 }
@@ -512,6 +850,11 @@ impl TryFrom<Entry> for Pair {
                 value: crate::placeholder::to_ios_placeholders(&value),
                 comment: entry.comment,
             }),
+            Translation::Select(select) => Ok(Pair {
+                key: entry.id,
+                value: crate::placeholder::to_ios_placeholders(&select.to_icu()),
+                comment: entry.comment,
+            }),
             Translation::Plural(_) => Err(Error::DataMismatch(
                 "Plural translations are not supported in .strings format".to_string(),
             )),
@@ -605,6 +948,8 @@ mod tests {
                 value: "He said: \"hi\"\\and newline\n".to_string(),
                 comment: None,
             }],
+            encoding: StringsEncoding::Utf8,
+            bom: false,
         };
         let mut out = Vec::new();
         format.to_writer(&mut out).unwrap();
@@ -778,4 +1123,217 @@ mod tests {
         let parsed = Format::from_str(content).unwrap();
         assert_eq!(parsed.pairs.len(), 3);
     }
+
+    fn utf16le_bytes(content: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(content.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    #[test]
+    fn test_utf16le_with_bom_round_trips_encoding() {
+        let input = utf16le_bytes("\"hello\" = \"Hallo Welt\";\n");
+        let parsed = Format::from_bytes(&input).unwrap();
+        assert_eq!(parsed.encoding, StringsEncoding::Utf16Le);
+        assert!(parsed.bom);
+        assert_eq!(parsed.pairs[0].value, "Hallo Welt");
+
+        let resource = Resource::from(parsed);
+        assert_eq!(
+            resource.metadata.custom.get(STRINGS_ENCODING_KEY),
+            Some(&"utf16le".to_string())
+        );
+        assert_eq!(
+            resource.metadata.custom.get(STRINGS_BOM_KEY),
+            Some(&"true".to_string())
+        );
+
+        let mut out = Vec::new();
+        Format::try_from(resource)
+            .unwrap()
+            .to_writer(&mut out)
+            .unwrap();
+        assert!(out.starts_with(&[0xFF, 0xFE]));
+        let reparsed = Format::from_bytes(&out).unwrap();
+        assert_eq!(reparsed.encoding, StringsEncoding::Utf16Le);
+        assert_eq!(reparsed.pairs[0].value, "Hallo Welt");
+    }
+
+    #[test]
+    fn test_utf8_bom_is_preserved_and_plain_utf8_has_none() {
+        let mut input = vec![0xEF, 0xBB, 0xBF];
+        input.extend_from_slice("\"a\" = \"b\";\n".as_bytes());
+        let parsed = Format::from_bytes(&input).unwrap();
+        assert_eq!(parsed.encoding, StringsEncoding::Utf8);
+        assert!(parsed.bom);
+        let mut out = Vec::new();
+        parsed.to_writer(&mut out).unwrap();
+        assert!(out.starts_with(&[0xEF, 0xBB, 0xBF]));
+
+        let plain = Format::from_str("\"a\" = \"b\";\n").unwrap();
+        assert!(!plain.bom);
+        let mut out = Vec::new();
+        plain.to_writer(&mut out).unwrap();
+        assert!(out.starts_with(b"//"));
+    }
+
+    #[test]
+    fn test_encoding_override_via_metadata() {
+        let mut resource = Resource::from(Format::from_str("\"a\" = \"b\";\n").unwrap());
+        StringsEncoding::Utf16Be.apply_to(&mut resource.metadata);
+        let mut out = Vec::new();
+        Format::try_from(resource)
+            .unwrap()
+            .to_writer(&mut out)
+            .unwrap();
+        assert!(out.starts_with(&[0xFE, 0xFF]));
+        let reparsed = Format::from_bytes(&out).unwrap();
+        assert_eq!(reparsed.encoding, StringsEncoding::Utf16Be);
+        assert_eq!(reparsed.pairs[0].value, "b");
+    }
+
+    #[test]
+    fn test_strings_encoding_from_str() {
+        assert_eq!(
+            "UTF-8".parse::<StringsEncoding>().unwrap(),
+            StringsEncoding::Utf8
+        );
+        assert_eq!(
+            "utf16le".parse::<StringsEncoding>().unwrap(),
+            StringsEncoding::Utf16Le
+        );
+        assert_eq!(
+            "utf-16be".parse::<StringsEncoding>().unwrap(),
+            StringsEncoding::Utf16Be
+        );
+        assert!("latin1".parse::<StringsEncoding>().is_err());
+    }
+
+    const LAYOUT_STRINGS: &str = r#"/* Onboarding */
+"welcome" = "Welcome";
+"skip" = "Skip"; // secondary button
+
+// MARK: - Inbox
+/* Shown above the list */
+"inbox_title" = "Inbox";
+"#;
+
+    fn layout_resource() -> Resource {
+        Resource::from(Format::from_str(LAYOUT_STRINGS).unwrap())
+    }
+
+    #[test]
+    fn test_document_without_changes_is_byte_identical() {
+        let mut document = Document::from_str(LAYOUT_STRINGS).unwrap();
+        document.apply(&layout_resource()).unwrap();
+        assert_eq!(document.source(), LAYOUT_STRINGS);
+    }
+
+    #[test]
+    fn test_document_changes_only_edited_line() {
+        let mut resource = layout_resource();
+        let entry = resource
+            .entries
+            .iter_mut()
+            .find(|e| e.id == "inbox_title")
+            .unwrap();
+        entry.value = Translation::Singular("Your \"Inbox\"".to_string());
+
+        let mut document = Document::from_str(LAYOUT_STRINGS).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(
+            document.source(),
+            LAYOUT_STRINGS.replace(
+                "\"inbox_title\" = \"Inbox\";",
+                "\"inbox_title\" = \"Your \\\"Inbox\\\"\";"
+            )
+        );
+    }
+
+    #[test]
+    fn test_document_adds_and_removes_pairs() {
+        let mut resource = layout_resource();
+        resource.entries.retain(|e| e.id != "welcome");
+        resource.entries.push(Entry {
+            id: "archive".to_string(),
+            value: Translation::Singular("Archive".to_string()),
+            comment: Some("Toolbar action".to_string()),
+            status: EntryStatus::Translated,
+            custom: HashMap::new(),
+        });
+
+        let mut document = Document::from_str(LAYOUT_STRINGS).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(
+            document.source(),
+            r#""skip" = "Skip"; // secondary button
+
+// MARK: - Inbox
+/* Shown above the list */
+"inbox_title" = "Inbox";
+/* Toolbar action */
+"archive" = "Archive";
+"#
+        );
+    }
+
+    #[test]
+    fn test_document_removes_trailing_comment_with_pair() {
+        let source = "\"a\" = \"A\";\n\n/* Okay */\n\"ok\" = \"OK\"; // trailing\n\"z\" = \"Z\";\n";
+        let mut resource = Resource::from(Format::from_str(source).unwrap());
+        resource.entries.retain(|e| e.id != "ok");
+
+        let mut document = Document::from_str(source).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(document.source(), "\"a\" = \"A\";\n\n\"z\" = \"Z\";\n");
+    }
+
+    #[test]
+    fn test_document_renames_keys_in_place() {
+        let source =
+            "\"a\" = \"A\";\n\n/* Okay   button */\n\"ok\" = \"OK\"; // trailing\n\"z\" = \"Z\";\n";
+        let mut resources = vec![Resource::from(Format::from_str(source).unwrap())];
+        let mapping = crate::KeyMapping::pairs([("ok", "confirm \"button\"")]).unwrap();
+        crate::rename_keys(&mut resources, &mapping).unwrap();
+
+        let mut document = Document::from_str(source).unwrap();
+        document.apply(&resources[0]).unwrap();
+        assert_eq!(
+            document.source(),
+            "\"a\" = \"A\";\n\n/* Okay   button */\n\"confirm \\\"button\\\"\" = \"OK\"; // trailing\n\"z\" = \"Z\";\n"
+        );
+    }
+
+    #[test]
+    fn test_document_reorders_blocks_with_their_comments() {
+        let mut resource = layout_resource();
+        resource.entries.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut document = Document::from_str(LAYOUT_STRINGS).unwrap();
+        document.apply(&resource).unwrap();
+        assert_eq!(
+            document.source(),
+            r#"/* Shown above the list */
+"inbox_title" = "Inbox";
+"skip" = "Skip"; // secondary button
+
+// MARK: - Inbox
+/* Onboarding */
+"welcome" = "Welcome";
+"#
+        );
+    }
+
+    #[test]
+    fn test_document_keeps_utf16_encoding() {
+        let input = utf16le_bytes("\"a\" = \"b\";\n");
+        let mut document = Document::from_bytes(&input).unwrap();
+        let mut resource = Resource::from(Format::from_bytes(&input).unwrap());
+        resource.entries[0].value = Translation::Singular("c".to_string());
+        document.apply(&resource).unwrap();
+
+        let mut out = Vec::new();
+        document.to_writer(&mut out).unwrap();
+        assert_eq!(out, utf16le_bytes("\"a\" = \"c\";\n"));
+    }
 }
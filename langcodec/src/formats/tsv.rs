@@ -7,16 +7,23 @@ use std::{collections::HashMap, io::BufRead};
 
 use crate::{
     error::Error,
+    length::{LengthLimit, LengthUnit, entry_length_limit, set_entry_length_limit},
+    provenance::{EntryPosition, SourcePosition},
     traits::Parser,
-    types::{Entry, EntryStatus, Metadata, Resource, Translation},
+    types::{Entry, EntryStatus, Metadata, PluralKind, Resource, Translation},
 };
 
+/// Header of the optional column holding per-key maximum lengths.
+const MAX_LENGTH_COLUMN: &str = "max_length";
+
 /// Represents a multi-language TSV record where the first column is the key
 /// and subsequent columns are translations for different languages.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MultiLanguageTSVRecord {
     pub key: String,
     pub translations: HashMap<String, String>,
+    /// Maximum length in characters, from the optional `max_length` column.
+    pub max_length: Option<usize>,
 }
 
 impl MultiLanguageTSVRecord {
@@ -25,6 +32,7 @@ impl MultiLanguageTSVRecord {
         Self {
             key,
             translations: HashMap::new(),
+            max_length: None,
         }
     }
 
@@ -72,23 +80,18 @@ impl Format {
     pub fn get_records_mut(&mut self) -> &mut [MultiLanguageTSVRecord] {
         &mut self.records
     }
-}
-
-impl Default for Format {
-    fn default() -> Self {
-        Self::new()
-    }
-}
 
-impl Parser for Format {
-    /// Parse from any reader, automatically detecting single vs multi-language format.
-    fn from_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+    /// Parses like [`Parser::from_reader`] and reports the line each row starts on.
+    pub fn from_reader_with_positions<R: BufRead>(
+        reader: R,
+    ) -> Result<(Self, Vec<EntryPosition>), Error> {
         let mut rdr = csv::ReaderBuilder::new()
             .has_headers(false)
             .delimiter(b'\t')
             .from_reader(reader);
 
         let mut records = Vec::new();
+        let mut positions = Vec::new();
         let mut lines = rdr.records();
 
         // Read the first line to determine the format
@@ -109,12 +112,14 @@ impl Parser for Format {
                         if line.len() == 2 {
                             let mut record = MultiLanguageTSVRecord::new(line[0].to_string());
                             record.add_translation(language.clone(), line[1].to_string());
+                            positions.extend(row_position(&line, &record.key));
                             records.push(record);
                         }
                     }
                 } else {
                     // Single language data form: key, value
                     // First line is data, not header
+                    positions.extend(row_position(&first_line, &first_line[0]));
                     records.push(MultiLanguageTSVRecord {
                         key: first_line[0].to_string(),
                         translations: {
@@ -122,6 +127,7 @@ impl Parser for Format {
                             map.insert("default".to_string(), first_line[1].to_string());
                             map
                         },
+                        max_length: None,
                     });
 
                     // Process remaining lines
@@ -130,14 +136,19 @@ impl Parser for Format {
                         if line.len() == 2 {
                             let mut record = MultiLanguageTSVRecord::new(line[0].to_string());
                             record.add_translation("default".to_string(), line[1].to_string());
+                            positions.extend(row_position(&line, &record.key));
                             records.push(record);
                         }
                     }
                 }
             } else if first_line.len() >= 3 {
-                // Multi-language format: key, lang1, lang2, ...
+                // Multi-language format: key, lang1, lang2, ..., with an
+                // optional max_length column anywhere after the key
                 let languages: Vec<String> =
                     first_line.iter().skip(1).map(|s| s.to_string()).collect();
+                let max_length_column = languages
+                    .iter()
+                    .position(|column| column.trim().eq_ignore_ascii_case(MAX_LENGTH_COLUMN));
 
                 // First line is header, process remaining lines as data
                 for line in lines {
@@ -145,10 +156,16 @@ impl Parser for Format {
                     if line.len() >= 2 {
                         let mut record = MultiLanguageTSVRecord::new(line[0].to_string());
                         for (i, lang) in languages.iter().enumerate() {
-                            if i + 1 < line.len() {
+                            if i + 1 >= line.len() {
+                                continue;
+                            }
+                            if Some(i) == max_length_column {
+                                record.max_length = parse_max_length(&line[i + 1], &record.key)?;
+                            } else {
                                 record.add_translation(lang.clone(), line[i + 1].to_string());
                             }
                         }
+                        positions.extend(row_position(&line, &record.key));
                         records.push(record);
                     }
                 }
@@ -159,7 +176,44 @@ impl Parser for Format {
             }
         }
 
-        Ok(Format { records })
+        Ok((Format { records }, positions))
+    }
+}
+
+/// Parses a `max_length` cell; blank cells mean no limit.
+fn parse_max_length(cell: &str, key: &str) -> Result<Option<usize>, Error> {
+    let cell = cell.trim();
+    if cell.is_empty() {
+        return Ok(None);
+    }
+    cell.parse().map(Some).map_err(|_| {
+        Error::DataMismatch(format!("Invalid max_length '{}' for key '{}'", cell, key))
+    })
+}
+
+/// Where the row holding `key` starts; rows always start at column 1.
+fn row_position(row: &csv::StringRecord, key: &str) -> Option<EntryPosition> {
+    row.position().map(|position| EntryPosition {
+        key: key.to_string(),
+        language: None,
+        position: SourcePosition {
+            offset: position.byte() as usize,
+            line: position.line() as usize,
+            column: 1,
+        },
+    })
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for Format {
+    /// Parse from any reader, automatically detecting single vs multi-language format.
+    fn from_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+        Self::from_reader_with_positions(reader).map(|(format, _positions)| format)
     }
 
     /// Write to any writer (file, memory, etc.).
@@ -187,6 +241,10 @@ impl Parser for Format {
         // Write header row
         let mut header = vec!["key".to_string()];
         header.extend(sorted_languages.clone());
+        let has_max_length = self.records.iter().any(|r| r.max_length.is_some());
+        if has_max_length {
+            header.push(MAX_LENGTH_COLUMN.to_string());
+        }
         wtr.write_record(&header).map_err(Error::CsvParse)?;
 
         // Write data rows
@@ -197,6 +255,14 @@ impl Parser for Format {
                 let value = record.translations.get(lang).unwrap_or(&empty_string);
                 row.push(value.clone());
             }
+            if has_max_length {
+                row.push(
+                    record
+                        .max_length
+                        .map(|max| max.to_string())
+                        .unwrap_or_default(),
+                );
+            }
             wtr.write_record(&row).map_err(Error::CsvParse)?;
         }
 
@@ -209,6 +275,15 @@ impl TryFrom<Vec<Resource>> for Format {
     type Error = Error;
 
     fn try_from(resources: Vec<Resource>) -> Result<Self, Self::Error> {
+        if let Some(entry) = resources.iter().flat_map(|res| &res.entries).find(
+            |entry| matches!(&entry.value, Translation::Plural(plural) if plural.kind == PluralKind::Ordinal),
+        ) {
+            return Err(Error::UnsupportedFormat(format!(
+                "Ordinal plural '{}' cannot be represented in TSV: only singular values are written",
+                entry.id
+            )));
+        }
+
         if resources.is_empty() {
             return Ok(Format::new());
         }
@@ -231,9 +306,17 @@ impl TryFrom<Vec<Resource>> for Format {
                     let value = match &entry.value {
                         Translation::Empty => String::new(),
                         Translation::Singular(v) => v.clone(),
+                        Translation::Select(s) => s.to_icu(),
                         Translation::Plural(_) => String::new(), // Plurals not supported
                     };
                     record.add_translation(resource.metadata.language.clone(), value);
+                    // The column only holds character counts.
+                    if let Some(limit) = entry_length_limit(entry)
+                        && limit.unit == LengthUnit::Graphemes
+                        && record.max_length.is_none()
+                    {
+                        record.max_length = Some(limit.max);
+                    }
                 }
             }
 
@@ -286,13 +369,20 @@ impl TryFrom<Format> for Vec<Resource> {
 
             for record in &format.records {
                 if let Some(translation) = record.translations.get(&language) {
-                    resource.entries.push(Entry {
+                    let mut entry = Entry {
                         id: record.key.clone(),
                         value: Translation::Singular(translation.clone()),
                         comment: None,
                         status: EntryStatus::Translated,
                         custom: HashMap::new(),
-                    });
+                    };
+                    set_entry_length_limit(
+                        &mut entry,
+                        record
+                            .max_length
+                            .map(|max| LengthLimit::new(max, LengthUnit::Graphemes)),
+                    );
+                    resource.entries.push(entry);
                 }
             }
 
@@ -856,4 +946,24 @@ mod tests {
             assert_eq!(original.translations, round_trip.translations);
         }
     }
+
+    #[test]
+    fn test_rejects_ordinal_plurals() {
+        let mut plural = crate::types::Plural::new(
+            "place",
+            vec![(crate::types::PluralCategory::Other, "%dth".to_string())].into_iter(),
+        )
+        .unwrap();
+        plural.kind = PluralKind::Ordinal;
+        let resources = vec![crate::test_support::resource(
+            "en",
+            &[
+                ("title", Translation::Singular("Title".to_string())),
+                ("place", Translation::Plural(plural)),
+            ],
+        )];
+        let err = Format::try_from(resources).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedFormat(_)));
+        assert!(err.to_string().contains("Ordinal plural 'place'"));
+    }
 }
@@ -7,8 +7,11 @@ use std::{
 
 use crate::{
     error::Error,
+    provenance::{EntryPosition, LineIndex},
     traits::Parser,
-    types::{Entry, EntryStatus, Metadata, Plural, PluralCategory, Resource, Translation},
+    types::{
+        Entry, EntryStatus, Metadata, Plural, PluralCategory, PluralKind, Resource, Translation,
+    },
 };
 
 fn serialize_sorted_map<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
@@ -47,6 +50,135 @@ impl Parser for Format {
     }
 }
 
+impl Format {
+    /// Parses a string catalog and reports where each key and each of its
+    /// localizations start.
+    pub fn from_reader_with_positions<R: BufRead>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<EntryPosition>), Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).map_err(Error::Io)?;
+        let format: Format = serde_json::from_str(&source).map_err(Error::Parse)?;
+
+        let lines = LineIndex::new(&source);
+        let mut positions = Vec::new();
+        for (field, _, strings) in json_object_members(&source, 0) {
+            if field != "strings" {
+                continue;
+            }
+            for (key, key_offset, item) in json_object_members(&source, strings) {
+                positions.push(EntryPosition {
+                    key: key.clone(),
+                    language: None,
+                    position: lines.position(&source, key_offset),
+                });
+                for (field, _, localizations) in json_object_members(&source, item) {
+                    if field != "localizations" {
+                        continue;
+                    }
+                    for (language, offset, _) in json_object_members(&source, localizations) {
+                        positions.push(EntryPosition {
+                            key: key.clone(),
+                            language: Some(language),
+                            position: lines.position(&source, offset),
+                        });
+                    }
+                }
+            }
+        }
+        Ok((format, positions))
+    }
+}
+
+/// Members of the JSON object starting at byte `start`, as
+/// `(key, key_offset, value_offset)`. Stops quietly at malformed input.
+fn json_object_members(source: &str, start: usize) -> Vec<(String, usize, usize)> {
+    let bytes = source.as_bytes();
+    let mut members = Vec::new();
+    let mut i = skip_json_whitespace(bytes, start);
+    if bytes.get(i) != Some(&b'{') {
+        return members;
+    }
+    i += 1;
+    loop {
+        i = skip_json_whitespace(bytes, i);
+        let key_start = i;
+        let Some(key_end) = json_string_end(bytes, key_start) else {
+            break;
+        };
+        let Ok(key) = serde_json::from_str::<String>(&source[key_start..key_end]) else {
+            break;
+        };
+        i = skip_json_whitespace(bytes, key_end);
+        if bytes.get(i) != Some(&b':') {
+            break;
+        }
+        let value_start = skip_json_whitespace(bytes, i + 1);
+        members.push((key, key_start, value_start));
+        let Some(value_end) = json_value_end(bytes, value_start) else {
+            break;
+        };
+        i = skip_json_whitespace(bytes, value_end);
+        if bytes.get(i) != Some(&b',') {
+            break;
+        }
+        i += 1;
+    }
+    members
+}
+
+fn skip_json_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while matches!(bytes.get(i), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+        i += 1;
+    }
+    i
+}
+
+/// Index just past the string literal opening at `start`.
+fn json_string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes.get(start) != Some(&b'"') {
+        return None;
+    }
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Index just past the JSON value starting at `start`.
+fn json_value_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = json_string_end(bytes, i)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+                continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return Some(i),
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    Some(i)
+}
+
 impl TryFrom<Vec<Resource>> for Format {
     type Error = Error;
 
@@ -58,6 +190,15 @@ impl TryFrom<Vec<Resource>> for Format {
         let mut version = String::new();
 
         for mut resource in resources {
+            if let Some(entry) = resource.entries.iter().find(
+                |entry| matches!(&entry.value, Translation::Plural(plural) if plural.kind == PluralKind::Ordinal),
+            ) {
+                return Err(Error::UnsupportedFormat(format!(
+                    "Ordinal plural '{}' cannot be represented in .xcstrings: String Catalogs only support cardinal plural variations",
+                    entry.id
+                )));
+            }
+
             // source_language
             if source_language.is_empty() {
                 if let Some(v) = resource.metadata.custom.remove("source_language") {
@@ -314,6 +455,13 @@ impl Item {
                     Localization::from(StringUnit::new(entry.status, &value)),
                 );
             }
+            // String Catalogs have no select variations; keep the ICU message text.
+            Translation::Select(select) => {
+                localizations.insert(
+                    language,
+                    Localization::from(StringUnit::new(entry.status, &select.to_icu())),
+                );
+            }
             Translation::Plural(plural) => {
                 localizations.insert(
                     language,
@@ -594,6 +742,7 @@ mod tests {
                             f.insert(PluralCategory::Other, "%1$s files".into());
                             f
                         },
+                        kind: PluralKind::Cardinal,
                     }),
                     comment: None,
                     status: EntryStatus::Translated,
@@ -22,6 +22,8 @@ use std::{
 
 use crate::{
     error::Error,
+    length::{MAX_LENGTH_KEY, MAX_LENGTH_UNIT_KEY},
+    provenance::{EntryPosition, LineIndex},
     traits::Parser,
     types::{Entry, EntryStatus, Metadata, Resource, Translation},
 };
@@ -55,85 +57,15 @@ pub struct TransUnit {
     pub source: String,
     pub target: Option<String>,
     pub notes: Vec<String>,
+    /// `maxwidth` attribute, counted in `size_unit`.
+    pub max_width: Option<usize>,
+    /// `size-unit` attribute (`char`, `col`, `pixel`, ...).
+    pub size_unit: Option<String>,
 }
 
 impl Parser for Format {
     fn from_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
-        let mut xml_reader = Reader::from_reader(reader);
-        xml_reader.config_mut().trim_text(false);
-
-        let mut buf = Vec::new();
-        let mut files = Vec::new();
-        let mut saw_root = false;
-        let mut current_file: Option<FileGroup> = None;
-
-        loop {
-            match xml_reader.read_event_into(&mut buf) {
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"xliff" => {
-                    saw_root = true;
-                    let version = required_attr(e, b"version", "<xliff>")?;
-                    if version != DEFAULT_VERSION {
-                        return Err(Error::UnsupportedFormat(format!(
-                            "Unsupported XLIFF version '{}'. Only XLIFF 1.2 is supported.",
-                            version
-                        )));
-                    }
-                }
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"file" => {
-                    if current_file.is_some() {
-                        return Err(Error::InvalidResource(
-                            "Nested <file> elements are not supported".to_string(),
-                        ));
-                    }
-                    current_file = Some(parse_file_group_start(e)?);
-                }
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"body" => {}
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"trans-unit" => {
-                    let file = current_file.as_mut().ok_or_else(|| {
-                        Error::InvalidResource(
-                            "<trans-unit> encountered outside of a <file> group".to_string(),
-                        )
-                    })?;
-                    let unit = parse_trans_unit(e, &mut xml_reader, &file.target_language)?;
-                    file.units.push(unit);
-                }
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"group" => {
-                    return Err(Error::UnsupportedFormat(
-                        "Plural/group XLIFF payloads are not supported in v1".to_string(),
-                    ));
-                }
-                Ok(Event::Start(ref e)) if e.name().as_ref() == b"bin-unit" => {
-                    return Err(Error::UnsupportedFormat(
-                        "Binary XLIFF units are not supported in v1".to_string(),
-                    ));
-                }
-                Ok(Event::End(ref e)) if e.name().as_ref() == b"file" => {
-                    let file = current_file.take().ok_or_else(|| {
-                        Error::InvalidResource("Unexpected </file> without matching <file>".into())
-                    })?;
-                    validate_file_group(&file)?;
-                    files.push(file);
-                }
-                Ok(Event::Eof) => break,
-                Ok(_) => {}
-                Err(e) => return Err(Error::XmlParse(e)),
-            }
-            buf.clear();
-        }
-
-        if !saw_root {
-            return Err(Error::InvalidResource(
-                "Missing <xliff> root element".to_string(),
-            ));
-        }
-
-        if current_file.is_some() {
-            return Err(Error::InvalidResource(
-                "Unexpected EOF before closing </file>".to_string(),
-            ));
-        }
-
-        Ok(Self { files })
+        Self::from_reader_with_positions(reader).map(|(format, _positions)| format)
     }
 
     fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
@@ -185,6 +117,12 @@ impl Parser for Format {
                 if let Some(resname) = &unit.resname {
                     unit_start.push_attribute(("resname", resname.as_str()));
                 }
+                if let Some(max_width) = unit.max_width {
+                    unit_start.push_attribute(("maxwidth", max_width.to_string().as_str()));
+                }
+                if let Some(size_unit) = &unit.size_unit {
+                    unit_start.push_attribute(("size-unit", size_unit.as_str()));
+                }
                 xml_writer.write_event(Event::Start(unit_start))?;
                 xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
 
@@ -222,6 +160,29 @@ impl Parser for Format {
 }
 
 impl Format {
+    /// Parses an XLIFF document and reports where each trans-unit starts, once
+    /// for the source language and once for the target language.
+    pub fn from_reader_with_positions<R: BufRead>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<EntryPosition>), Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).map_err(Error::Io)?;
+        let lines = LineIndex::new(&source);
+        let mut xml_reader = Reader::from_str(&source);
+        xml_reader.config_mut().trim_text(false);
+
+        let mut positions = Vec::new();
+        let format =
+            parse_document(&mut xml_reader, &source, &lines, &mut positions).map_err(|err| {
+                let offset = match err {
+                    Error::XmlParse(_) => xml_reader.error_position(),
+                    _ => xml_reader.buffer_position(),
+                };
+                err.located(lines.position(&source, offset as usize))
+            })?;
+        Ok((format, positions))
+    }
+
     pub fn from_resources(
         resources: Vec<Resource>,
         source_language_hint: Option<&str>,
@@ -331,12 +292,21 @@ impl Format {
                     .or_else(|| target_entry.and_then(|entry| entry.comment.clone()));
                 let target = target_entry.map(|entry| entry.value.clone());
 
+                let (max_width, size_unit) = match source_entry.max_width {
+                    Some(_) => (source_entry.max_width, source_entry.size_unit.clone()),
+                    None => target_entry
+                        .map(|entry| (entry.max_width, entry.size_unit.clone()))
+                        .unwrap_or_default(),
+                };
+
                 units.push(TransUnit {
                     id,
                     resname: source_entry.resname.clone(),
                     source: source_entry.value,
                     target,
                     notes: split_comment_into_notes(comment.as_deref()),
+                    max_width,
+                    size_unit,
                 });
             }
 
@@ -408,6 +378,16 @@ impl TryFrom<Format> for Vec<Resource> {
                 {
                     source_custom.insert(XLIFF_RESNAME_KEY.to_string(), resname.clone());
                 }
+                if let Some(max_width) = unit.max_width {
+                    // `size-unit` defaults to `pixel` in the spec, but tools
+                    // that set `maxwidth` alone almost always mean characters,
+                    // so a missing unit is left for `entry_length_limit` to
+                    // measure as characters and is not written back.
+                    source_custom.insert(MAX_LENGTH_KEY.to_string(), max_width.to_string());
+                    if let Some(size_unit) = &unit.size_unit {
+                        source_custom.insert(MAX_LENGTH_UNIT_KEY.to_string(), size_unit.clone());
+                    }
+                }
 
                 source_entries.push(Entry {
                     id: unit.id.clone(),
@@ -475,6 +455,8 @@ struct PreparedEntry {
     value: String,
     comment: Option<String>,
     resname: Option<String>,
+    max_width: Option<usize>,
+    size_unit: Option<String>,
 }
 
 impl PreparedEntry {
@@ -482,6 +464,7 @@ impl PreparedEntry {
         let value = match entry.value {
             Translation::Empty => String::new(),
             Translation::Singular(value) => value,
+            Translation::Select(select) => select.to_icu(),
             Translation::Plural(_) => {
                 return Err(Error::UnsupportedFormat(format!(
                     "Plural entry '{}' cannot be represented in XLIFF v1 output",
@@ -496,11 +479,19 @@ impl PreparedEntry {
             .cloned()
             .filter(|resname| resname != &entry.id);
 
+        let max_width = entry
+            .custom
+            .get(MAX_LENGTH_KEY)
+            .and_then(|max| max.trim().parse().ok());
+        let size_unit = max_width.and(entry.custom.get(MAX_LENGTH_UNIT_KEY).cloned());
+
         Ok(Self {
             id: entry.id,
             value,
             comment: entry.comment,
             resname,
+            max_width,
+            size_unit,
         })
     }
 }
@@ -513,6 +504,98 @@ struct GroupAccumulator {
     target_entries: BTreeMap<String, PreparedEntry>,
 }
 
+/// Reads the document, recording each trans-unit's position in `positions`.
+fn parse_document(
+    xml_reader: &mut Reader<&[u8]>,
+    source: &str,
+    lines: &LineIndex,
+    positions: &mut Vec<EntryPosition>,
+) -> Result<Format, Error> {
+    let mut buf = Vec::new();
+    let mut files = Vec::new();
+    let mut saw_root = false;
+    let mut current_file: Option<FileGroup> = None;
+
+    loop {
+        let start = xml_reader.buffer_position() as usize;
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"xliff" => {
+                saw_root = true;
+                let version = required_attr(e, b"version", "<xliff>")?;
+                if version != DEFAULT_VERSION {
+                    return Err(Error::UnsupportedFormat(format!(
+                        "Unsupported XLIFF version '{}'. Only XLIFF 1.2 is supported.",
+                        version
+                    )));
+                }
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"file" => {
+                if current_file.is_some() {
+                    return Err(Error::InvalidResource(
+                        "Nested <file> elements are not supported".to_string(),
+                    ));
+                }
+                current_file = Some(parse_file_group_start(e)?);
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"body" => {}
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"trans-unit" => {
+                let file = current_file.as_mut().ok_or_else(|| {
+                    Error::InvalidResource(
+                        "<trans-unit> encountered outside of a <file> group".to_string(),
+                    )
+                })?;
+                let unit = parse_trans_unit(e, xml_reader, &file.target_language)?;
+                let position = lines.position(source, start);
+                for language in
+                    std::iter::once(&file.source_language).chain(file.target_language.as_ref())
+                {
+                    positions.push(EntryPosition {
+                        key: unit.id.clone(),
+                        language: Some(language.clone()),
+                        position,
+                    });
+                }
+                file.units.push(unit);
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"group" => {
+                return Err(Error::UnsupportedFormat(
+                    "Plural/group XLIFF payloads are not supported in v1".to_string(),
+                ));
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"bin-unit" => {
+                return Err(Error::UnsupportedFormat(
+                    "Binary XLIFF units are not supported in v1".to_string(),
+                ));
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"file" => {
+                let file = current_file.take().ok_or_else(|| {
+                    Error::InvalidResource("Unexpected </file> without matching <file>".into())
+                })?;
+                validate_file_group(&file)?;
+                files.push(file);
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+        buf.clear();
+    }
+
+    if !saw_root {
+        return Err(Error::InvalidResource(
+            "Missing <xliff> root element".to_string(),
+        ));
+    }
+
+    if current_file.is_some() {
+        return Err(Error::InvalidResource(
+            "Unexpected EOF before closing </file>".to_string(),
+        ));
+    }
+
+    Ok(Format { files })
+}
+
 fn parse_file_group_start(e: &BytesStart<'_>) -> Result<FileGroup, Error> {
     let source_language = required_attr(e, b"source-language", "<file>")?;
     let target_language = optional_attr(e, b"target-language")?;
@@ -537,6 +620,17 @@ fn parse_trans_unit<R: BufRead>(
 ) -> Result<TransUnit, Error> {
     let id = required_attr(e, b"id", "<trans-unit>")?;
     let resname = optional_attr(e, b"resname")?;
+    let max_width = optional_attr(e, b"maxwidth")?
+        .map(|value| {
+            value.trim().parse::<usize>().map_err(|_| {
+                Error::InvalidResource(format!(
+                    "Invalid maxwidth '{}' on trans-unit '{}'",
+                    value, id
+                ))
+            })
+        })
+        .transpose()?;
+    let size_unit = optional_attr(e, b"size-unit")?;
 
     let mut buf = Vec::new();
     let mut source = None;
@@ -607,6 +701,8 @@ fn parse_trans_unit<R: BufRead>(
         source,
         target,
         notes,
+        max_width,
+        size_unit,
     })
 }
 
@@ -938,6 +1034,7 @@ fn write_optional_text_element<W: Write>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::length::{LengthLimit, LengthUnit, entry_length_limit};
 
     fn parse_xliff(input: &str) -> Format {
         Format::from_str(input).unwrap()
@@ -1202,4 +1299,62 @@ mod tests {
         assert_eq!(format.files[0].target_language.as_deref(), Some("fr"));
         assert_eq!(format.files[0].units[0].target, None);
     }
+
+    #[test]
+    fn maxwidth_round_trips_as_entry_length_limit() {
+        let xliff = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="Localizable.strings" source-language="en" target-language="de" datatype="plaintext">
+    <body>
+      <trans-unit id="title" maxwidth="12" size-unit="col">
+        <source>Title</source>
+        <target>Titel</target>
+      </trans-unit>
+      <trans-unit id="body" maxwidth="40">
+        <source>Body</source>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+
+        let resources = Vec::<Resource>::try_from(parse_xliff(xliff)).unwrap();
+        let title = |language: &str| {
+            resources
+                .iter()
+                .find(|resource| resource.metadata.language == language)
+                .and_then(|resource| resource.entries.iter().find(|e| e.id == "title"))
+                .and_then(entry_length_limit)
+        };
+        let expected = LengthLimit::new(12, LengthUnit::DisplayWidth);
+        assert_eq!(title("en"), Some(expected));
+        assert_eq!(title("de"), Some(expected));
+        let body = resources[0]
+            .entries
+            .iter()
+            .find(|e| e.id == "body")
+            .unwrap();
+        assert_eq!(
+            entry_length_limit(body),
+            Some(LengthLimit::new(40, LengthUnit::Graphemes))
+        );
+
+        let mut output = Vec::new();
+        Format::try_from(resources)
+            .unwrap()
+            .to_writer(&mut output)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(r#"maxwidth="12" size-unit="col""#));
+        assert!(output.contains(r#"maxwidth="40">"#));
+        assert!(!output.contains(r#"size-unit="char""#));
+    }
+
+    #[test]
+    fn rejects_non_numeric_maxwidth() {
+        let xliff = r#"<xliff version="1.2"><file original="a" source-language="en"><body>
+<trans-unit id="k" maxwidth="wide"><source>A</source></trans-unit>
+</body></file></xliff>"#;
+        let error = Format::from_str(xliff).unwrap_err();
+        assert!(error.to_string().contains("Invalid maxwidth 'wide'"));
+    }
 }
@@ -0,0 +1,669 @@
+//! Support for Excel `.xlsx` localization workbooks.
+//!
+//! Uses the same multi-language layout as [`crate::formats::csv`]: the first sheet
+//! has a `key` column followed by one column per language. Two optional sheets,
+//! `Comments` and `Status`, mirror that layout for per-language comments and entry
+//! statuses. When writing, untranslated cells and cells that need review are
+//! highlighted so translators can find them quickly.
+//!
+//! Only singular key-value pairs are supported; plurals will be dropped during conversion.
+//! Reading and writing are pure Rust and do not require an office installation.
+use std::{
+    collections::{HashMap, HashSet},
+    io::{BufRead, Cursor},
+    str::FromStr,
+};
+
+use calamine::{Data, Reader, Xlsx, open_workbook_from_rs};
+use rust_xlsxwriter::{Color, Format as CellFormat, Workbook, Worksheet, XlsxError};
+
+use crate::{
+    error::Error,
+    traits::Parser,
+    types::{Entry, EntryStatus, Metadata, PluralKind, Resource, Translation},
+};
+
+/// Name of the sheet holding translations.
+pub const TRANSLATIONS_SHEET: &str = "Translations";
+/// Name of the optional sheet holding per-language comments.
+pub const COMMENTS_SHEET: &str = "Comments";
+/// Name of the optional sheet holding per-language entry statuses.
+pub const STATUS_SHEET: &str = "Status";
+
+// Fill colors used to flag rows that still need attention.
+const UNTRANSLATED_FILL: u32 = 0xFFC7CE;
+const NEEDS_REVIEW_FILL: u32 = 0xFFEB9C;
+
+/// Represents a multi-language XLSX record where the first column is the key
+/// and subsequent columns are translations for different languages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiLanguageXLSXRecord {
+    pub key: String,
+    pub translations: HashMap<String, String>,
+    /// Per-language comments, stored in the `Comments` sheet.
+    pub comments: HashMap<String, String>,
+    /// Per-language statuses, stored in the `Status` sheet.
+    pub statuses: HashMap<String, EntryStatus>,
+}
+
+impl MultiLanguageXLSXRecord {
+    /// Creates a new multi-language XLSX record.
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            translations: HashMap::new(),
+            comments: HashMap::new(),
+            statuses: HashMap::new(),
+        }
+    }
+
+    /// Adds a translation for a specific language.
+    pub fn add_translation(&mut self, language: String, value: String) {
+        self.translations.insert(language, value);
+    }
+
+    /// Gets a translation for a specific language.
+    pub fn get_translation(&self, language: &str) -> Option<&String> {
+        self.translations.get(language)
+    }
+
+    /// Returns the status for a language, defaulting to `Translated`.
+    pub fn status_for(&self, language: &str) -> EntryStatus {
+        self.statuses
+            .get(language)
+            .cloned()
+            .unwrap_or(EntryStatus::Translated)
+    }
+}
+
+/// Represents the XLSX format containing all records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    /// Language columns in display order.
+    pub languages: Vec<String>,
+    pub records: Vec<MultiLanguageXLSXRecord>,
+}
+
+impl Format {
+    /// Creates a new XLSX format with empty records.
+    pub fn new() -> Self {
+        Self {
+            languages: Vec::new(),
+            records: Vec::new(),
+        }
+    }
+
+    /// Creates a new XLSX format with the given records.
+    ///
+    /// Language columns are derived from the records and sorted.
+    pub fn with_records(records: Vec<MultiLanguageXLSXRecord>) -> Self {
+        let mut format = Self {
+            languages: Vec::new(),
+            records,
+        };
+        format.languages = format.column_languages();
+        format
+    }
+
+    /// Adds a record to the format.
+    pub fn add_record(&mut self, record: MultiLanguageXLSXRecord) {
+        self.records.push(record);
+    }
+
+    /// Gets all records.
+    pub fn get_records(&self) -> &[MultiLanguageXLSXRecord] {
+        &self.records
+    }
+
+    /// Gets all records as mutable.
+    pub fn get_records_mut(&mut self) -> &mut [MultiLanguageXLSXRecord] {
+        &mut self.records
+    }
+
+    /// Languages used as columns: the declared order first, then any extra
+    /// languages found in records (sorted for consistent output).
+    fn column_languages(&self) -> Vec<String> {
+        let mut languages = self.languages.clone();
+        let mut seen: HashSet<String> = languages.iter().cloned().collect();
+        let mut extra = Vec::new();
+        for record in &self.records {
+            for lang in record
+                .translations
+                .keys()
+                .chain(record.comments.keys())
+                .chain(record.statuses.keys())
+            {
+                if seen.insert(lang.clone()) {
+                    extra.push(lang.clone());
+                }
+            }
+        }
+        extra.sort();
+        languages.extend(extra);
+        languages
+    }
+
+    fn build_workbook(&self) -> Result<Workbook, XlsxError> {
+        let languages = self.column_languages();
+        let header_format = CellFormat::new().set_bold();
+        let untranslated_format =
+            CellFormat::new().set_background_color(Color::RGB(UNTRANSLATED_FILL));
+        let needs_review_format =
+            CellFormat::new().set_background_color(Color::RGB(NEEDS_REVIEW_FILL));
+
+        let mut workbook = Workbook::new();
+
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(TRANSLATIONS_SHEET)?;
+        write_header(sheet, &languages, &header_format)?;
+        for (index, record) in self.records.iter().enumerate() {
+            let row = index as u32 + 1;
+            sheet.write_string(row, 0, &record.key)?;
+            for (col, lang) in languages.iter().enumerate() {
+                let col = col as u16 + 1;
+                let value = record
+                    .translations
+                    .get(lang)
+                    .map(String::as_str)
+                    .unwrap_or("");
+                if value.is_empty() {
+                    sheet.write_string_with_format(row, col, value, &untranslated_format)?;
+                } else if matches!(
+                    record.statuses.get(lang),
+                    Some(EntryStatus::NeedsReview | EntryStatus::Stale)
+                ) {
+                    sheet.write_string_with_format(row, col, value, &needs_review_format)?;
+                } else {
+                    sheet.write_string(row, col, value)?;
+                }
+            }
+        }
+
+        if self.records.iter().any(|r| !r.comments.is_empty()) {
+            let sheet = workbook.add_worksheet();
+            sheet.set_name(COMMENTS_SHEET)?;
+            write_header(sheet, &languages, &header_format)?;
+            for (index, record) in self.records.iter().enumerate() {
+                let row = index as u32 + 1;
+                sheet.write_string(row, 0, &record.key)?;
+                for (col, lang) in languages.iter().enumerate() {
+                    if let Some(comment) = record.comments.get(lang) {
+                        sheet.write_string(row, col as u16 + 1, comment)?;
+                    }
+                }
+            }
+        }
+
+        if self.records.iter().any(|r| !r.statuses.is_empty()) {
+            let sheet = workbook.add_worksheet();
+            sheet.set_name(STATUS_SHEET)?;
+            write_header(sheet, &languages, &header_format)?;
+            for (index, record) in self.records.iter().enumerate() {
+                let row = index as u32 + 1;
+                sheet.write_string(row, 0, &record.key)?;
+                for (col, lang) in languages.iter().enumerate() {
+                    if let Some(status) = record.statuses.get(lang) {
+                        sheet.write_string(row, col as u16 + 1, status_label(status))?;
+                    }
+                }
+            }
+        }
+
+        Ok(workbook)
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_header(
+    sheet: &mut Worksheet,
+    languages: &[String],
+    header_format: &CellFormat,
+) -> Result<(), XlsxError> {
+    sheet.write_string_with_format(0, 0, "key", header_format)?;
+    sheet.set_column_width(0, 32)?;
+    for (col, lang) in languages.iter().enumerate() {
+        let col = col as u16 + 1;
+        sheet.write_string_with_format(0, col, lang, header_format)?;
+        sheet.set_column_width(col, 40)?;
+    }
+    sheet.set_freeze_panes(1, 1)?;
+    Ok(())
+}
+
+fn status_label(status: &EntryStatus) -> &'static str {
+    match status {
+        EntryStatus::DoNotTranslate => "do_not_translate",
+        EntryStatus::New => "new",
+        EntryStatus::Stale => "stale",
+        EntryStatus::NeedsReview => "needs_review",
+        EntryStatus::Translated => "translated",
+    }
+}
+
+/// A sheet decoded into its header languages and `key -> (language -> value)` rows.
+struct SheetTable {
+    languages: Vec<String>,
+    rows: Vec<(String, HashMap<String, String>)>,
+}
+
+fn cell_text(range: &calamine::Range<Data>, row: u32, col: u32) -> String {
+    match range.get_value((row, col)) {
+        None | Some(Data::Empty) => String::new(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn read_sheet_table(range: &calamine::Range<Data>) -> SheetTable {
+    let Some((end_row, end_col)) = range.end() else {
+        return SheetTable {
+            languages: Vec::new(),
+            rows: Vec::new(),
+        };
+    };
+
+    let languages: Vec<String> = (1..=end_col)
+        .map(|col| cell_text(range, 0, col).trim().to_string())
+        .collect();
+
+    let mut rows = Vec::new();
+    for row in 1..=end_row {
+        let key = cell_text(range, row, 0);
+        if key.trim().is_empty() {
+            continue;
+        }
+        let mut values = HashMap::new();
+        for (offset, lang) in languages.iter().enumerate() {
+            if lang.is_empty() {
+                continue;
+            }
+            let value = cell_text(range, row, offset as u32 + 1);
+            if !value.is_empty() {
+                values.insert(lang.clone(), value);
+            }
+        }
+        rows.push((key, values));
+    }
+
+    SheetTable {
+        languages: languages.into_iter().filter(|l| !l.is_empty()).collect(),
+        rows,
+    }
+}
+
+fn find_sheet(names: &[String], wanted: &str) -> Option<String> {
+    names
+        .iter()
+        .find(|name| name.trim().eq_ignore_ascii_case(wanted))
+        .cloned()
+}
+
+impl Parser for Format {
+    /// Parse an `.xlsx` workbook from any reader.
+    ///
+    /// The first sheet is treated as the translations sheet; `Comments` and
+    /// `Status` sheets are picked up by name when present.
+    fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(Error::Io)?;
+
+        let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(bytes))
+            .map_err(|e| Error::DataMismatch(format!("Invalid XLSX workbook: {}", e)))?;
+        let sheet_names = workbook.sheet_names();
+
+        let Some(first_sheet) = sheet_names.first().cloned() else {
+            return Ok(Format::new());
+        };
+        let translations_sheet =
+            find_sheet(&sheet_names, TRANSLATIONS_SHEET).unwrap_or(first_sheet);
+
+        let mut read_table = |name: &str| -> Result<SheetTable, Error> {
+            let range = workbook.worksheet_range(name).map_err(|e| {
+                Error::DataMismatch(format!("Invalid XLSX sheet '{}': {}", name, e))
+            })?;
+            Ok(read_sheet_table(&range))
+        };
+
+        let translations = read_table(&translations_sheet)?;
+        let comments = match find_sheet(&sheet_names, COMMENTS_SHEET) {
+            Some(name) => Some(read_table(&name)?),
+            None => None,
+        };
+        let statuses = match find_sheet(&sheet_names, STATUS_SHEET) {
+            Some(name) => Some(read_table(&name)?),
+            None => None,
+        };
+
+        let mut records: Vec<MultiLanguageXLSXRecord> = translations
+            .rows
+            .into_iter()
+            .map(|(key, values)| MultiLanguageXLSXRecord {
+                key,
+                translations: values,
+                comments: HashMap::new(),
+                statuses: HashMap::new(),
+            })
+            .collect();
+        let index: HashMap<String, usize> = records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| (record.key.clone(), i))
+            .collect();
+
+        if let Some(comments) = comments {
+            for (key, values) in comments.rows {
+                if let Some(&i) = index.get(&key) {
+                    records[i].comments = values;
+                }
+            }
+        }
+
+        if let Some(statuses) = statuses {
+            for (key, values) in statuses.rows {
+                let Some(&i) = index.get(&key) else {
+                    continue;
+                };
+                for (lang, raw) in values {
+                    let status = EntryStatus::from_str(raw.trim()).map_err(|e| {
+                        Error::DataMismatch(format!(
+                            "Invalid status for key '{}' ({}) in XLSX: {}",
+                            key, lang, e
+                        ))
+                    })?;
+                    records[i].statuses.insert(lang, status);
+                }
+            }
+        }
+
+        Ok(Format {
+            languages: translations.languages,
+            records,
+        })
+    }
+
+    /// Write the workbook to any writer (file, memory, etc.).
+    fn to_writer<W: std::io::Write>(&self, mut writer: W) -> Result<(), Error> {
+        let bytes = self
+            .build_workbook()
+            .and_then(|mut workbook| workbook.save_to_buffer())
+            .map_err(|e| {
+                Error::conversion_error(
+                    format!("Error building XLSX workbook: {}", e),
+                    Some(Box::new(e)),
+                )
+            })?;
+        writer.write_all(&bytes).map_err(Error::Io)?;
+        writer.flush().map_err(Error::Io)?;
+        Ok(())
+    }
+}
+
+impl TryFrom<Vec<Resource>> for Format {
+    type Error = Error;
+
+    fn try_from(resources: Vec<Resource>) -> Result<Self, Self::Error> {
+        if let Some(entry) = resources.iter().flat_map(|res| &res.entries).find(
+            |entry| matches!(&entry.value, Translation::Plural(plural) if plural.kind == PluralKind::Ordinal),
+        ) {
+            return Err(Error::UnsupportedFormat(format!(
+                "Ordinal plural '{}' cannot be represented in XLSX: only singular values are written",
+                entry.id
+            )));
+        }
+
+        if resources.is_empty() {
+            return Ok(Format::new());
+        }
+
+        // Source language first so translators read left to right.
+        let source_language = resources
+            .iter()
+            .find_map(|r| r.metadata.custom.get("source_language").cloned());
+        let mut languages: Vec<String> = Vec::new();
+        for resource in &resources {
+            if !languages.contains(&resource.metadata.language) {
+                languages.push(resource.metadata.language.clone());
+            }
+        }
+        if let Some(source) = source_language
+            && let Some(pos) = languages.iter().position(|l| *l == source)
+        {
+            let source = languages.remove(pos);
+            languages.insert(0, source);
+        }
+
+        let mut records: Vec<MultiLanguageXLSXRecord> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for resource in &resources {
+            let language = &resource.metadata.language;
+            for entry in &resource.entries {
+                let i = *index.entry(entry.id.clone()).or_insert_with(|| {
+                    records.push(MultiLanguageXLSXRecord::new(entry.id.clone()));
+                    records.len() - 1
+                });
+                let record = &mut records[i];
+
+                let value = match &entry.value {
+                    Translation::Empty => String::new(),
+                    Translation::Singular(v) => v.clone(),
+                    Translation::Select(s) => s.to_icu(),
+                    Translation::Plural(_) => String::new(), // Plurals not supported
+                };
+                record.add_translation(language.clone(), value);
+                if let Some(comment) = &entry.comment {
+                    record.comments.insert(language.clone(), comment.clone());
+                }
+                if entry.status != EntryStatus::Translated {
+                    record
+                        .statuses
+                        .insert(language.clone(), entry.status.clone());
+                }
+            }
+        }
+
+        Ok(Format { languages, records })
+    }
+}
+
+impl TryFrom<Format> for Vec<Resource> {
+    type Error = Error;
+
+    fn try_from(format: Format) -> Result<Self, Self::Error> {
+        if format.records.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let languages = format.column_languages();
+
+        // The first language column is treated as the source language.
+        let mut custom_metadata = HashMap::new();
+        custom_metadata.insert(
+            "source_language".to_string(),
+            languages
+                .first()
+                .cloned()
+                .unwrap_or_else(|| "en".to_string()),
+        );
+        custom_metadata.insert("version".to_string(), "1.0".to_string());
+
+        let mut resources = Vec::new();
+        for language in languages {
+            let mut resource = Resource {
+                metadata: Metadata {
+                    language: language.clone(),
+                    domain: String::from(""),
+                    custom: custom_metadata.clone(),
+                },
+                entries: Vec::new(),
+            };
+
+            for record in &format.records {
+                let translation = record.translations.get(&language);
+                let status = record.statuses.get(&language);
+                if translation.is_none() && status.is_none() {
+                    continue;
+                }
+                resource.entries.push(Entry {
+                    id: record.key.clone(),
+                    value: translation
+                        .map(|v| Translation::Singular(v.clone()))
+                        .unwrap_or(Translation::Empty),
+                    comment: record.comments.get(&language).cloned(),
+                    status: record.status_for(&language),
+                    custom: HashMap::new(),
+                });
+            }
+
+            if !resource.entries.is_empty() {
+                resources.push(resource);
+            }
+        }
+
+        Ok(resources)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Parser;
+
+    fn sample_resources() -> Vec<Resource> {
+        let mut custom = HashMap::new();
+        custom.insert("source_language".to_string(), "en".to_string());
+        vec![
+            Resource {
+                metadata: Metadata {
+                    language: "fr".to_string(),
+                    domain: String::new(),
+                    custom: custom.clone(),
+                },
+                entries: vec![Entry {
+                    id: "hello".to_string(),
+                    value: Translation::Singular("Bonjour".to_string()),
+                    comment: None,
+                    status: EntryStatus::NeedsReview,
+                    custom: HashMap::new(),
+                }],
+            },
+            Resource {
+                metadata: Metadata {
+                    language: "en".to_string(),
+                    domain: String::new(),
+                    custom,
+                },
+                entries: vec![
+                    Entry {
+                        id: "hello".to_string(),
+                        value: Translation::Singular("Hello,\nworld".to_string()),
+                        comment: Some("Greeting on the home screen".to_string()),
+                        status: EntryStatus::Translated,
+                        custom: HashMap::new(),
+                    },
+                    Entry {
+                        id: "bye".to_string(),
+                        value: Translation::Singular("Goodbye".to_string()),
+                        comment: None,
+                        status: EntryStatus::Translated,
+                        custom: HashMap::new(),
+                    },
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_source_language_is_first_column() {
+        let format = Format::try_from(sample_resources()).unwrap();
+        assert_eq!(format.languages, vec!["en".to_string(), "fr".to_string()]);
+        assert_eq!(format.records.len(), 2);
+        assert_eq!(format.records[0].key, "hello");
+        assert_eq!(
+            format.records[0].statuses.get("fr"),
+            Some(&EntryStatus::NeedsReview)
+        );
+    }
+
+    #[test]
+    fn test_xlsx_round_trip_preserves_comments_status_and_newlines() {
+        let format = Format::try_from(sample_resources()).unwrap();
+        let mut bytes = Vec::new();
+        format.to_writer(&mut bytes).unwrap();
+
+        let parsed = Format::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.languages, vec!["en".to_string(), "fr".to_string()]);
+        assert_eq!(parsed.records.len(), 2);
+
+        let hello = &parsed.records[0];
+        assert_eq!(
+            hello.get_translation("en"),
+            Some(&"Hello,\nworld".to_string())
+        );
+        assert_eq!(
+            hello.comments.get("en"),
+            Some(&"Greeting on the home screen".to_string())
+        );
+        assert_eq!(hello.status_for("fr"), EntryStatus::NeedsReview);
+        assert_eq!(hello.status_for("en"), EntryStatus::Translated);
+
+        // "bye" has no French translation; it must not produce a French entry.
+        let resources = Vec::<Resource>::try_from(parsed).unwrap();
+        let fr = resources
+            .iter()
+            .find(|r| r.metadata.language == "fr")
+            .unwrap();
+        assert_eq!(fr.entries.len(), 1);
+        assert_eq!(fr.entries[0].status, EntryStatus::NeedsReview);
+        assert_eq!(
+            resources[0].metadata.custom.get("source_language"),
+            Some(&"en".to_string())
+        );
+    }
+
+    #[test]
+    fn test_workbook_without_optional_sheets() {
+        let mut record = MultiLanguageXLSXRecord::new("hello".to_string());
+        record.add_translation("en".to_string(), "Hello".to_string());
+        record.add_translation("de".to_string(), "Hallo".to_string());
+
+        let mut bytes = Vec::new();
+        Format::with_records(vec![record])
+            .to_writer(&mut bytes)
+            .unwrap();
+
+        let parsed = Format::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.languages, vec!["de".to_string(), "en".to_string()]);
+        assert!(parsed.records[0].comments.is_empty());
+        assert!(parsed.records[0].statuses.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_workbook_is_rejected() {
+        let err = Format::from_bytes(b"key,en\nhello,Hello\n").unwrap_err();
+        assert!(err.to_string().contains("Invalid XLSX workbook"));
+    }
+
+    #[test]
+    fn test_rejects_ordinal_plurals() {
+        let mut plural = crate::types::Plural::new(
+            "place",
+            vec![(crate::types::PluralCategory::Other, "%dth".to_string())].into_iter(),
+        )
+        .unwrap();
+        plural.kind = PluralKind::Ordinal;
+        let resources = vec![crate::test_support::resource(
+            "en",
+            &[
+                ("title", Translation::Singular("Title".to_string())),
+                ("place", Translation::Plural(plural)),
+            ],
+        )];
+        let err = Format::try_from(resources).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedFormat(_)));
+        assert!(err.to_string().contains("Ordinal plural 'place'"));
+    }
+}
@@ -0,0 +1,723 @@
+//! Glossaries of required term translations and do-not-translate terms.
+//!
+//! A [`Glossary`] is loaded from CSV or TBX (or built in code) and used to
+//! check that translations use the agreed terminology: when a source form
+//! contains a term, the translated form must contain the term's required
+//! translation, and do-not-translate terms such as brand names must appear
+//! unchanged.
+
+use std::{collections::BTreeMap, fmt, io::BufRead, path::Path};
+
+use quick_xml::{Reader, events::Event};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    operations::{lang_base, normalize_lang},
+    placeholder::{category_label, forms_of, source_text_for},
+    types::{EntryStatus, PluralCategory, Resource},
+};
+
+/// One source term with its required translations.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GlossaryTerm {
+    pub source: String,
+    /// Required translation by language code.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub translations: BTreeMap<String, String>,
+    /// The term must appear unchanged in every translation.
+    #[serde(default)]
+    pub do_not_translate: bool,
+    /// Match the term case-sensitively in source and translated text.
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl GlossaryTerm {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            ..Self::default()
+        }
+    }
+
+    /// A term that must not be translated, such as a brand name.
+    pub fn protected(source: impl Into<String>) -> Self {
+        Self {
+            do_not_translate: true,
+            ..Self::new(source)
+        }
+    }
+
+    pub fn with_translation(
+        mut self,
+        language: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        self.translations.insert(language.into(), text.into());
+        self
+    }
+
+    /// Returns the text a translation into `language` must contain: the term
+    /// itself for do-not-translate terms, otherwise the translation for the
+    /// language or its base language (`fr` for `fr-CA`).
+    pub fn translation_for(&self, language: &str) -> Option<&str> {
+        if self.do_not_translate {
+            return Some(&self.source);
+        }
+        let wanted = normalize_lang(language);
+        self.translations
+            .iter()
+            .find(|(lang, _)| normalize_lang(lang) == wanted)
+            .or_else(|| {
+                self.translations
+                    .iter()
+                    .find(|(lang, _)| normalize_lang(lang) == lang_base(&wanted))
+            })
+            .map(|(_, text)| text.as_str())
+    }
+
+    /// Whether the term occurs in `text` as a whole word.
+    pub fn occurs_in(&self, text: &str) -> bool {
+        contains(text, &self.source, !self.case_sensitive, true)
+    }
+}
+
+/// A set of glossary terms.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Glossary {
+    pub terms: Vec<GlossaryTerm>,
+}
+
+impl Glossary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Adds a term, merging translations into an existing term with the same
+    /// source text.
+    pub fn add(&mut self, term: GlossaryTerm) {
+        match self
+            .terms
+            .iter_mut()
+            .find(|existing| existing.source == term.source)
+        {
+            Some(existing) => {
+                existing.translations.extend(term.translations);
+                existing.do_not_translate |= term.do_not_translate;
+                existing.case_sensitive |= term.case_sensitive;
+                if term.note.is_some() {
+                    existing.note = term.note;
+                }
+            }
+            None => self.terms.push(term),
+        }
+    }
+
+    /// Adds every term of `other`.
+    pub fn extend(&mut self, other: Glossary) {
+        for term in other.terms {
+            self.add(term);
+        }
+    }
+
+    /// Terms occurring in `text`, in glossary order.
+    pub fn terms_in(&self, text: &str) -> Vec<&GlossaryTerm> {
+        self.terms
+            .iter()
+            .filter(|term| term.occurs_in(text))
+            .collect()
+    }
+
+    /// Reads a CSV glossary with a header row.
+    ///
+    /// The `term` (or `source`) column holds the source term, or the column
+    /// named after `source_language` when there is none. Optional
+    /// `do_not_translate`, `case_sensitive` (`true`/`yes`/`1`) and `note`
+    /// columns are recognized; every other column is a language code holding
+    /// required translations.
+    pub fn from_csv_reader<R: BufRead>(
+        reader: R,
+        source_language: Option<&str>,
+    ) -> Result<Self, Error> {
+        let mut rdr = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = rdr.headers().map_err(Error::CsvParse)?.clone();
+
+        let mut source_column = None;
+        let mut dnt_column = None;
+        let mut case_column = None;
+        let mut note_column = None;
+        let mut language_columns = Vec::new();
+        for (index, header) in headers.iter().enumerate() {
+            match header
+                .to_ascii_lowercase()
+                .replace(['-', ' '], "_")
+                .as_str()
+            {
+                "term" | "source" => source_column = Some(index),
+                "do_not_translate" | "dnt" => dnt_column = Some(index),
+                "case_sensitive" => case_column = Some(index),
+                "note" | "notes" | "comment" | "description" => note_column = Some(index),
+                "" => {}
+                _ => language_columns.push((index, header.to_string())),
+            }
+        }
+        if source_column.is_none()
+            && let Some(source_language) = source_language
+        {
+            let wanted = normalize_lang(source_language);
+            if let Some(position) = language_columns
+                .iter()
+                .position(|(_, lang)| normalize_lang(lang) == wanted)
+            {
+                source_column = Some(language_columns.remove(position).0);
+            }
+        }
+        let source_column = source_column.ok_or_else(|| {
+            Error::InvalidResource(
+                "Glossary CSV needs a 'term' column or a column for the source language"
+                    .to_string(),
+            )
+        })?;
+
+        let mut glossary = Glossary::new();
+        for record in rdr.records() {
+            let record = record.map_err(Error::CsvParse)?;
+            let Some(source) = record.get(source_column).filter(|s| !s.is_empty()) else {
+                continue;
+            };
+            let flag = |column: Option<usize>| {
+                column
+                    .and_then(|index| record.get(index))
+                    .is_some_and(parse_flag)
+            };
+            let mut term = GlossaryTerm {
+                do_not_translate: flag(dnt_column),
+                case_sensitive: flag(case_column),
+                note: note_column
+                    .and_then(|index| record.get(index))
+                    .filter(|note| !note.is_empty())
+                    .map(str::to_string),
+                ..GlossaryTerm::new(source)
+            };
+            for (index, language) in &language_columns {
+                if let Some(text) = record.get(*index).filter(|text| !text.is_empty()) {
+                    term.translations.insert(language.clone(), text.to_string());
+                }
+            }
+            glossary.add(term);
+        }
+        Ok(glossary)
+    }
+
+    /// Reads a TBX (v2 `martif` or v3 `tbx`) termbase.
+    ///
+    /// Each `termEntry`/`conceptEntry` becomes a term: the first term in
+    /// `source_language` (default: the root `xml:lang`, else the first
+    /// language) is the source, and the first term of every other language
+    /// its required translation. `<termNote type="translatable">no</termNote>`
+    /// marks do-not-translate terms, `<descrip type="definition">` or
+    /// `<note>` becomes the note.
+    pub fn from_tbx_reader<R: BufRead>(
+        reader: R,
+        source_language: Option<&str>,
+    ) -> Result<Self, Error> {
+        let mut xml_reader = Reader::from_reader(reader);
+        xml_reader.config_mut().trim_text(false);
+
+        let mut glossary = Glossary::new();
+        let mut buf = Vec::new();
+        let mut default_source = source_language.map(str::to_string);
+        let mut saw_root = false;
+        let mut concept: Option<TbxConcept> = None;
+        let mut language: Option<String> = None;
+
+        loop {
+            match xml_reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                    b"martif" | b"tbx" => {
+                        saw_root = true;
+                        if default_source.is_none() {
+                            default_source = xml_lang(e)?;
+                        }
+                    }
+                    b"termEntry" | b"conceptEntry" => concept = Some(TbxConcept::default()),
+                    b"langSet" | b"langSec" => language = xml_lang(e)?,
+                    b"term" => {
+                        let text = read_element_text(&mut xml_reader, b"term")?;
+                        if let (Some(concept), Some(language)) = (concept.as_mut(), &language) {
+                            concept.terms.push((language.clone(), text));
+                        }
+                    }
+                    name @ (b"termNote" | b"descrip" | b"note") => {
+                        let name = name.to_vec();
+                        let kind = attr(e, b"type")?.unwrap_or_default();
+                        let text = read_element_text(&mut xml_reader, &name)?;
+                        if let Some(concept) = concept.as_mut() {
+                            match (name.as_slice(), kind.as_str()) {
+                                (b"termNote", "translatable") => {
+                                    concept.do_not_translate |=
+                                        matches!(text.trim(), "no" | "false");
+                                }
+                                (b"descrip", "definition") | (b"note", _) => {
+                                    concept.note.get_or_insert(text.trim().to_string());
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::End(ref e)) => match e.name().as_ref() {
+                    b"langSet" | b"langSec" => language = None,
+                    b"termEntry" | b"conceptEntry" => {
+                        if let Some(term) = concept
+                            .take()
+                            .and_then(|concept| concept.into_term(default_source.as_deref()))
+                        {
+                            glossary.add(term);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => return Err(Error::XmlParse(e)),
+            }
+            buf.clear();
+        }
+
+        if !saw_root {
+            return Err(Error::InvalidResource(
+                "TBX document is missing the <martif> or <tbx> root element".to_string(),
+            ));
+        }
+        Ok(glossary)
+    }
+
+    /// Reads a `.csv` or `.tbx` glossary file.
+    pub fn read_file<P: AsRef<Path>>(
+        path: P,
+        source_language: Option<&str>,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        match extension.as_deref() {
+            Some("csv") => Self::from_csv_reader(reader, source_language),
+            Some("tbx") | Some("xml") => Self::from_tbx_reader(reader, source_language),
+            _ => Err(Error::UnknownFormat(format!(
+                "Unsupported glossary file '{}'; expected .csv or .tbx",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Checks one translated text against the terms found in `source`.
+    pub fn check_translation(
+        &self,
+        key: &str,
+        language: &str,
+        source: &str,
+        translation: &str,
+    ) -> Vec<GlossaryIssue> {
+        self.check_form(key, language, None, None, source, translation)
+    }
+
+    fn check_form(
+        &self,
+        key: &str,
+        language: &str,
+        plural_category: Option<&PluralCategory>,
+        select_case: Option<&String>,
+        source: &str,
+        translation: &str,
+    ) -> Vec<GlossaryIssue> {
+        let mut issues = Vec::new();
+        for term in self.terms_in(source) {
+            let Some(expected) = term.translation_for(language) else {
+                continue;
+            };
+            let kind = if term.do_not_translate {
+                if contains(translation, expected, false, false) {
+                    continue;
+                }
+                GlossaryIssueKind::TranslatedProtectedTerm
+            } else {
+                if contains(translation, expected, !term.case_sensitive, false) {
+                    continue;
+                }
+                GlossaryIssueKind::MissingTerm
+            };
+            issues.push(GlossaryIssue {
+                key: key.to_string(),
+                language: language.to_string(),
+                plural_category: plural_category.cloned(),
+                select_case: select_case.cloned(),
+                kind,
+                term: term.source.clone(),
+                expected: expected.to_string(),
+            });
+        }
+        issues
+    }
+}
+
+/// What is wrong with the terminology of a translated form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlossaryIssueKind {
+    /// The source uses a term whose required translation is absent.
+    MissingTerm,
+    /// A do-not-translate term was translated or altered.
+    TranslatedProtectedTerm,
+}
+
+impl fmt::Display for GlossaryIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingTerm => "missing glossary term",
+            Self::TranslatedProtectedTerm => "translated do-not-translate term",
+        })
+    }
+}
+
+/// A terminology problem in one form of a translated entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlossaryIssue {
+    pub key: String,
+    pub language: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plural_category: Option<PluralCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select_case: Option<String>,
+    pub kind: GlossaryIssueKind,
+    /// The source term.
+    pub term: String,
+    /// The text the translation should contain.
+    pub expected: String,
+}
+
+impl fmt::Display for GlossaryIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lang='{}' key='{}'", self.language, self.key)?;
+        if let Some(category) = &self.plural_category {
+            write!(f, " [{}]", category_label(category))?;
+        }
+        if let Some(case) = &self.select_case {
+            write!(f, " [{}]", case)?;
+        }
+        write!(f, ": {} '{}'", self.kind, self.term)?;
+        if self.kind == GlossaryIssueKind::MissingTerm {
+            write!(f, ", expected '{}'", self.expected)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks every translation against the glossary, form by form.
+///
+/// Each plural form or select case is compared with the matching source
+/// form. Empty, missing and do-not-translate entries are skipped.
+pub fn collect_glossary_issues(
+    resources: &[Resource],
+    glossary: &Glossary,
+    source_language: &str,
+) -> Vec<GlossaryIssue> {
+    let Some(source) = resources
+        .iter()
+        .find(|res| res.metadata.language == source_language)
+    else {
+        return Vec::new();
+    };
+
+    let mut issues = Vec::new();
+    for target in resources
+        .iter()
+        .filter(|res| res.metadata.language != source_language)
+    {
+        for entry in &target.entries {
+            if entry.status == EntryStatus::DoNotTranslate {
+                continue;
+            }
+            let Some(source_entry) = source.find_entry(&entry.id) else {
+                continue;
+            };
+            for form in forms_of(&entry.value) {
+                if form.text.trim().is_empty() {
+                    continue;
+                }
+                let Some(source_text) = source_text_for(&source_entry.value, &form) else {
+                    continue;
+                };
+                issues.extend(glossary.check_form(
+                    &entry.id,
+                    &target.metadata.language,
+                    form.plural_category.as_ref(),
+                    form.select_case.as_ref(),
+                    source_text,
+                    form.text,
+                ));
+            }
+        }
+    }
+    issues
+}
+
+#[derive(Default)]
+struct TbxConcept {
+    terms: Vec<(String, String)>,
+    do_not_translate: bool,
+    note: Option<String>,
+}
+
+impl TbxConcept {
+    fn into_term(self, source_language: Option<&str>) -> Option<GlossaryTerm> {
+        let source_index = match source_language {
+            Some(language) => {
+                let wanted = normalize_lang(language);
+                self.terms
+                    .iter()
+                    .position(|(lang, _)| normalize_lang(lang) == wanted)
+                    .or_else(|| {
+                        self.terms.iter().position(|(lang, _)| {
+                            lang_base(&normalize_lang(lang)) == lang_base(&wanted)
+                        })
+                    })?
+            }
+            None => 0,
+        };
+        let (source_lang, source) = self.terms.get(source_index)?.clone();
+        let mut term = GlossaryTerm {
+            do_not_translate: self.do_not_translate,
+            note: self.note,
+            ..GlossaryTerm::new(source.trim())
+        };
+        for (lang, text) in self.terms {
+            if normalize_lang(&lang) == normalize_lang(&source_lang) {
+                continue;
+            }
+            term.translations
+                .entry(lang)
+                .or_insert_with(|| text.trim().to_string());
+        }
+        Some(term)
+    }
+}
+
+fn read_element_text<R: BufRead>(
+    xml_reader: &mut Reader<R>,
+    element: &[u8],
+) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    let mut text = String::new();
+    let mut depth = 0usize;
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Text(e)) => text.push_str(e.unescape().map_err(Error::XmlParse)?.as_ref()),
+            Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(e.as_ref())),
+            Ok(Event::Start(_)) => depth += 1,
+            Ok(Event::End(ref e)) if depth == 0 && e.name().as_ref() == element => break,
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) => {
+                return Err(Error::InvalidResource(format!(
+                    "Unexpected EOF inside <{}>",
+                    String::from_utf8_lossy(element)
+                )));
+            }
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+        buf.clear();
+    }
+    Ok(text)
+}
+
+fn attr(e: &quick_xml::events::BytesStart<'_>, name: &[u8]) -> Result<Option<String>, Error> {
+    for attr in e.attributes().with_checks(false) {
+        let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
+        if attr.key.as_ref() == name {
+            return Ok(Some(attr.unescape_value()?.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+fn xml_lang(e: &quick_xml::events::BytesStart<'_>) -> Result<Option<String>, Error> {
+    match attr(e, b"xml:lang")? {
+        Some(lang) => Ok(Some(lang)),
+        None => attr(e, b"lang"),
+    }
+}
+
+fn parse_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "true" | "yes" | "y" | "1" | "x"
+    )
+}
+
+/// Substring search, optionally case-insensitive and limited to whole words.
+fn contains(haystack: &str, needle: &str, ignore_case: bool, whole_word: bool) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+    let (haystack, needle) = if ignore_case {
+        (haystack.to_lowercase(), needle.to_lowercase())
+    } else {
+        (haystack.to_string(), needle.to_string())
+    };
+    if !whole_word {
+        return haystack.contains(&needle);
+    }
+    haystack.match_indices(&needle).any(|(start, matched)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + matched.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::resource;
+    use crate::types::{Plural, Translation};
+
+    fn glossary() -> Glossary {
+        let mut glossary = Glossary::new();
+        glossary.add(GlossaryTerm::new("sign in").with_translation("fr", "se connecter"));
+        glossary.add(GlossaryTerm::protected("Acme"));
+        glossary
+    }
+
+    #[test]
+    fn test_terms_match_whole_words_case_insensitively() {
+        let glossary = glossary();
+        assert_eq!(glossary.terms_in("Sign in to Acme").len(), 2);
+        assert!(glossary.terms_in("Design insights").is_empty());
+        assert!(glossary.terms_in("Acmes").is_empty());
+    }
+
+    #[test]
+    fn test_check_translation_flags_missing_and_protected_terms() {
+        let glossary = glossary();
+        assert!(
+            glossary
+                .check_translation("k", "fr", "Sign in to Acme", "Se connecter à Acme")
+                .is_empty()
+        );
+
+        let issues =
+            glossary.check_translation("k", "fr-CA", "Sign in to Acme", "Connexion à ACME");
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, GlossaryIssueKind::MissingTerm);
+        assert_eq!(issues[0].expected, "se connecter");
+        assert_eq!(issues[1].kind, GlossaryIssueKind::TranslatedProtectedTerm);
+        assert_eq!(
+            issues[1].to_string(),
+            "lang='fr-CA' key='k': translated do-not-translate term 'Acme'"
+        );
+
+        // Languages without a required translation are only checked for protected terms.
+        assert!(
+            glossary
+                .check_translation("k", "de", "Sign in to Acme", "Bei Acme anmelden")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_collect_glossary_issues_per_plural_form() {
+        let plural = |one: &str, other: &str| {
+            Translation::Plural(
+                Plural::new(
+                    "n",
+                    [
+                        (PluralCategory::One, one.to_string()),
+                        (PluralCategory::Other, other.to_string()),
+                    ]
+                    .into_iter(),
+                )
+                .unwrap(),
+            )
+        };
+        let resources = vec![
+            resource(
+                "en",
+                &[("devices", plural("%d Acme device", "%d Acme devices"))],
+            ),
+            resource(
+                "fr",
+                &[("devices", plural("%d appareil Acme", "%d appareils Acmé"))],
+            ),
+        ];
+        let issues = collect_glossary_issues(&resources, &glossary(), "en");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].plural_category, Some(PluralCategory::Other));
+        assert_eq!(issues[0].term, "Acme");
+    }
+
+    #[test]
+    fn test_from_csv_reader() {
+        let csv = "term,fr,de,do_not_translate,note\nSign in,Se connecter,Anmelden,,Login button\niCloud,,,yes,\n";
+        let glossary = Glossary::from_csv_reader(csv.as_bytes(), None).unwrap();
+        assert_eq!(glossary.len(), 2);
+        assert_eq!(glossary.terms[0].translation_for("de"), Some("Anmelden"));
+        assert_eq!(glossary.terms[0].note.as_deref(), Some("Login button"));
+        assert!(glossary.terms[1].do_not_translate);
+
+        let csv = "en,fr\nSign in,Se connecter\n";
+        assert!(Glossary::from_csv_reader(csv.as_bytes(), None).is_err());
+        let glossary = Glossary::from_csv_reader(csv.as_bytes(), Some("en")).unwrap();
+        assert_eq!(glossary.terms[0].source, "Sign in");
+        assert_eq!(
+            glossary.terms[0].translation_for("fr"),
+            Some("Se connecter")
+        );
+    }
+
+    #[test]
+    fn test_from_tbx_reader() {
+        let tbx = r#"<?xml version="1.0" encoding="UTF-8"?>
+<martif type="TBX" xml:lang="en">
+  <text><body>
+    <termEntry id="c1">
+      <descrip type="definition">Button that opens the login form</descrip>
+      <langSet xml:lang="en"><tig><term>Sign in</term></tig></langSet>
+      <langSet xml:lang="fr"><tig><term>Se connecter</term></tig></langSet>
+    </termEntry>
+    <termEntry id="c2">
+      <langSet xml:lang="en"><tig><term>Acme</term><termNote type="translatable">no</termNote></tig></langSet>
+    </termEntry>
+  </body></text>
+</martif>"#;
+        let glossary = Glossary::from_tbx_reader(tbx.as_bytes(), None).unwrap();
+        assert_eq!(glossary.len(), 2);
+        assert_eq!(glossary.terms[0].source, "Sign in");
+        assert_eq!(
+            glossary.terms[0].translation_for("fr"),
+            Some("Se connecter")
+        );
+        assert_eq!(
+            glossary.terms[0].note.as_deref(),
+            Some("Button that opens the login form")
+        );
+        assert!(glossary.terms[1].do_not_translate);
+    }
+}
@@ -0,0 +1,358 @@
+//! Maximum-length constraints for translations.
+//!
+//! Limits are read from source files (XLIFF `maxwidth`/`size-unit`, a CSV or
+//! TSV `max_length` column) into [`Entry::custom`] under [`MAX_LENGTH_KEY`]
+//! and [`MAX_LENGTH_UNIT_KEY`], or assigned to keys by pattern with
+//! [`LengthLimits`]. Text is measured in grapheme clusters or in display
+//! columns, so combining marks count once and wide CJK characters count
+//! twice, the way they render.
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    placeholder::{category_label, forms_of},
+    types::{Entry, PluralCategory, Resource},
+};
+
+/// [`Entry::custom`] key holding the maximum length of every form.
+pub const MAX_LENGTH_KEY: &str = "langcodec.max_length";
+/// [`Entry::custom`] key holding the unit of [`MAX_LENGTH_KEY`], using the
+/// XLIFF `size-unit` names (`char`, `col`); `char` when absent.
+pub const MAX_LENGTH_UNIT_KEY: &str = "langcodec.max_length_unit";
+
+/// How the length of a translation is counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthUnit {
+    /// User-perceived characters (extended grapheme clusters).
+    #[default]
+    Graphemes,
+    /// Terminal/display columns; East Asian wide characters take two.
+    DisplayWidth,
+}
+
+impl LengthUnit {
+    /// Length of `text` in this unit.
+    pub fn measure(self, text: &str) -> usize {
+        match self {
+            LengthUnit::Graphemes => text.graphemes(true).count(),
+            LengthUnit::DisplayWidth => text.width(),
+        }
+    }
+
+    /// The XLIFF `size-unit` spelling of this unit.
+    pub fn xliff_name(self) -> &'static str {
+        match self {
+            LengthUnit::Graphemes => "char",
+            LengthUnit::DisplayWidth => "col",
+        }
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LengthUnit::Graphemes => "characters",
+            LengthUnit::DisplayWidth => "columns",
+        })
+    }
+}
+
+impl FromStr for LengthUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "char" | "chars" | "characters" | "grapheme" | "graphemes" => Ok(Self::Graphemes),
+            "col" | "cols" | "columns" | "width" | "display_width" => Ok(Self::DisplayWidth),
+            _ => Err(format!("Unknown length unit: {}", s)),
+        }
+    }
+}
+
+/// A maximum length for every form of an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LengthLimit {
+    pub max: usize,
+    pub unit: LengthUnit,
+}
+
+impl LengthLimit {
+    pub fn new(max: usize, unit: LengthUnit) -> Self {
+        Self { max, unit }
+    }
+
+    /// Length of `text`, or `None` when it fits.
+    pub fn overrun(&self, text: &str) -> Option<usize> {
+        let length = self.unit.measure(text);
+        (length > self.max).then_some(length)
+    }
+}
+
+impl fmt::Display for LengthLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.max, self.unit)
+    }
+}
+
+/// Reads the limit stored on `entry`.
+///
+/// Units that cannot be measured from text (such as XLIFF `pixel`) yield `None`.
+pub fn entry_length_limit(entry: &Entry) -> Option<LengthLimit> {
+    let max = entry.custom.get(MAX_LENGTH_KEY)?.trim().parse().ok()?;
+    let unit = match entry.custom.get(MAX_LENGTH_UNIT_KEY) {
+        Some(unit) => unit.parse().ok()?,
+        None => LengthUnit::default(),
+    };
+    Some(LengthLimit { max, unit })
+}
+
+/// Stores `limit` on `entry`, or removes the stored limit.
+pub fn set_entry_length_limit(entry: &mut Entry, limit: Option<LengthLimit>) {
+    match limit {
+        Some(limit) => {
+            entry
+                .custom
+                .insert(MAX_LENGTH_KEY.to_string(), limit.max.to_string());
+            entry.custom.insert(
+                MAX_LENGTH_UNIT_KEY.to_string(),
+                limit.unit.xliff_name().to_string(),
+            );
+        }
+        None => {
+            entry.custom.remove(MAX_LENGTH_KEY);
+            entry.custom.remove(MAX_LENGTH_UNIT_KEY);
+        }
+    }
+}
+
+/// Length limits assigned to keys by pattern, e.g. from configuration.
+///
+/// Patterns match whole keys; `*` matches any run of characters and `?` a
+/// single one. The first matching pattern wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LengthLimits {
+    patterns: Vec<(String, LengthLimit)>,
+}
+
+impl LengthLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pattern after the existing ones.
+    pub fn add_pattern(&mut self, pattern: impl Into<String>, limit: LengthLimit) {
+        self.patterns.push((pattern.into(), limit));
+    }
+
+    pub fn with_pattern(mut self, pattern: impl Into<String>, limit: LengthLimit) -> Self {
+        self.add_pattern(pattern, limit);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// The limit of the first pattern matching `key`.
+    pub fn limit_for(&self, key: &str) -> Option<LengthLimit> {
+        self.patterns
+            .iter()
+            .find(|(pattern, _)| wildcard_match(pattern, key))
+            .map(|(_, limit)| *limit)
+    }
+
+    /// Stores pattern limits on entries that have no limit of their own.
+    /// Returns the number of entries updated.
+    pub fn apply(&self, resources: &mut [Resource]) -> usize {
+        let mut applied = 0;
+        for entry in resources.iter_mut().flat_map(|res| res.entries.iter_mut()) {
+            if entry.custom.contains_key(MAX_LENGTH_KEY) {
+                continue;
+            }
+            if let Some(limit) = self.limit_for(&entry.id) {
+                set_entry_length_limit(entry, Some(limit));
+                applied += 1;
+            }
+        }
+        applied
+    }
+}
+
+/// Limits per key, taken from whichever resource defines one first.
+///
+/// Source files usually carry the limit; translations inherit it.
+pub fn limits_by_key(resources: &[Resource]) -> HashMap<&str, LengthLimit> {
+    let mut limits = HashMap::new();
+    for entry in resources.iter().flat_map(|res| &res.entries) {
+        if let Some(limit) = entry_length_limit(entry) {
+            limits.entry(entry.id.as_str()).or_insert(limit);
+        }
+    }
+    limits
+}
+
+/// A form that is longer than its entry allows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LengthOverrun {
+    pub key: String,
+    pub language: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plural_category: Option<PluralCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select_case: Option<String>,
+    pub length: usize,
+    pub limit: LengthLimit,
+}
+
+impl fmt::Display for LengthOverrun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lang='{}' key='{}'", self.language, self.key)?;
+        if let Some(category) = &self.plural_category {
+            write!(f, " [{}]", category_label(category))?;
+        } else if let Some(case) = &self.select_case {
+            write!(f, " [{}]", case)?;
+        }
+        write!(
+            f,
+            ": length {} exceeds the limit of {}",
+            self.length, self.limit
+        )
+    }
+}
+
+/// Checks every form of every entry against its limit.
+///
+/// An entry without a limit of its own uses the limit of the same key in
+/// another resource. Overruns are ordered by language, then key.
+pub fn check_lengths(resources: &[Resource]) -> Vec<LengthOverrun> {
+    let shared = limits_by_key(resources);
+    let mut overruns = Vec::new();
+    for resource in resources {
+        for entry in &resource.entries {
+            let Some(limit) =
+                entry_length_limit(entry).or_else(|| shared.get(entry.id.as_str()).copied())
+            else {
+                continue;
+            };
+            for form in forms_of(&entry.value) {
+                if let Some(length) = limit.overrun(form.text) {
+                    overruns.push(LengthOverrun {
+                        key: entry.id.clone(),
+                        language: resource.metadata.language.clone(),
+                        plural_category: form.plural_category.clone(),
+                        select_case: form.select_case.clone(),
+                        length,
+                        limit,
+                    });
+                }
+            }
+        }
+    }
+    overruns.sort_by(|a, b| (&a.language, &a.key).cmp(&(&b.language, &b.key)));
+    overruns
+}
+
+/// Whole-string match with `*` (any run) and `?` (one character).
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, from)) => {
+                    p = star + 1;
+                    t = from + 1;
+                    backtrack = Some((star, from + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::resource;
+    use crate::types::{Plural, Translation};
+
+    #[test]
+    fn units_count_graphemes_and_columns() {
+        assert_eq!(LengthUnit::Graphemes.measure("e\u{301}te\u{301}"), 3);
+        assert_eq!(LengthUnit::Graphemes.measure("👩‍👩‍👧"), 1);
+        assert_eq!(LengthUnit::DisplayWidth.measure("設定"), 4);
+        assert_eq!("col".parse(), Ok(LengthUnit::DisplayWidth));
+        assert!("pixel".parse::<LengthUnit>().is_err());
+    }
+
+    #[test]
+    fn wildcard_patterns_match_whole_keys() {
+        assert!(wildcard_match("watch_*", "watch_title"));
+        assert!(wildcard_match("*.title", "widget.small.title"));
+        assert!(wildcard_match("tab_?", "tab_1"));
+        assert!(!wildcard_match("tab_?", "tab_10"));
+        assert!(!wildcard_match("watch_*", "stopwatch_title"));
+    }
+
+    #[test]
+    fn overruns_use_shared_and_pattern_limits_per_form() {
+        let plural = Plural::new(
+            "widget_files",
+            [
+                (PluralCategory::One, "%d Datei".to_string()),
+                (PluralCategory::Other, "%d Dateien insgesamt".to_string()),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        let mut resources = vec![
+            resource(
+                "en",
+                &[("watch_title", Translation::Singular("Steps".to_string()))],
+            ),
+            resource(
+                "de",
+                &[
+                    (
+                        "watch_title",
+                        Translation::Singular("Schrittzähler".to_string()),
+                    ),
+                    ("widget_files", Translation::Plural(plural)),
+                ],
+            ),
+        ];
+        set_entry_length_limit(
+            &mut resources[0].entries[0],
+            Some(LengthLimit::new(10, LengthUnit::Graphemes)),
+        );
+        let limits = LengthLimits::new()
+            .with_pattern("widget_*", LengthLimit::new(12, LengthUnit::Graphemes));
+        assert_eq!(limits.apply(&mut resources), 1);
+
+        let overruns = check_lengths(&resources);
+        let described: Vec<String> = overruns.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            described,
+            vec![
+                "lang='de' key='watch_title': length 13 exceeds the limit of 10 characters",
+                "lang='de' key='widget_files' [other]: length 20 exceeds the limit of 12 characters",
+            ]
+        );
+    }
+}
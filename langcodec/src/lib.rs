@@ -2,7 +2,7 @@
 //! Universal localization file toolkit for Rust.
 //!
 //! Supports parsing, writing, and converting between Apple `.strings`, `.xcstrings`, `.xliff`,
-//! Android `strings.xml`, CSV, and TSV files.
+//! Android `strings.xml`, CSV, TSV, and Excel `.xlsx` files.
 //! All conversion happens through the unified `Resource` model.
 //!
 //! # Quick Start
@@ -36,6 +36,7 @@
 //! - **Android `strings.xml`**: Android resource files
 //! - **CSV**: Comma-separated values for simple key-value pairs
 //! - **TSV**: Tab-separated values for simple key-value pairs
+//! - **XLSX**: Excel workbooks with optional comment and status sheets
 //!
 //! # Features
 //!
@@ -140,22 +141,37 @@
 //! # Ok::<(), langcodec::Error>(())
 //! ```
 
+pub mod bidi;
 pub mod builder;
 pub mod codec;
 pub mod converter;
 pub mod error;
+pub mod fallback;
+pub mod format;
 pub mod formats;
+pub mod glossary;
+pub mod length;
+pub mod lint;
 pub mod normalize;
 pub mod operations;
 pub mod placeholder;
 pub mod plural_rules;
 pub mod provenance;
+pub mod pseudo;
 pub mod read_options;
+pub mod rename;
+pub mod report;
+pub mod select;
+pub mod tm;
 pub mod traits;
 pub mod types;
 
+#[cfg(test)]
+mod test_support;
+
 // Re-export most used types for easy consumption
 pub use crate::{
+    bidi::{BidiIssue, check_bidi, is_rtl_language, isolate_ltr_runs, isolate_resource_ltr_runs},
     builder::CodecBuilder,
     codec::Codec,
     converter::{
@@ -164,24 +180,53 @@ pub use crate::{
         infer_language_from_path, merge_resources,
     },
     error::{Error, ErrorCode, ErrorContext, StructuredError},
+    fallback::{FallbackChain, ResolvedEntry},
+    format::{FormatArg, FormatArgs, format_entry, format_message},
     formats::FormatType,
-    normalize::{KeyStyle, NormalizeOptions, NormalizeReport, normalize_codec},
+    glossary::{Glossary, GlossaryIssue, GlossaryIssueKind, GlossaryTerm, collect_glossary_issues},
+    length::{
+        LengthLimit, LengthLimits, LengthOverrun, LengthUnit, MAX_LENGTH_KEY, MAX_LENGTH_UNIT_KEY,
+        check_lengths, entry_length_limit, set_entry_length_limit,
+    },
+    lint::{
+        LintEntry, LintFinding, LintPair, LintReport, LintRule, LintSeverity, Linter, builtin_rules,
+    },
+    normalize::{
+        KeyCollision, KeyRename, KeyStyle, NormalizeChange, NormalizeOptions, NormalizeReport,
+        NormalizeRule, UnicodeForm, ValueChange, normalize_codec, preview_normalize,
+    },
     operations::{
-        DiffChangedItem, DiffOptions, DiffReport, DiffSummary, LanguageDiff, SyncIssue,
-        SyncIssueKind, SyncOptions, SyncReport, diff_resources, sync_existing_entries,
+        DiffChangedItem, DiffOptions, DiffReport, DiffSummary, LanguageDiff, RefreshAction,
+        RefreshStatusChange, RefreshStatusOptions, RefreshStatusReport, SyncIssue, SyncIssueKind,
+        SyncOptions, SyncReport, diff_resources, refresh_stale_status, sync_existing_entries,
+    },
+    placeholder::{
+        PlaceholderDialect, PlaceholderFix, PlaceholderIssue, PlaceholderIssueKind,
+        PlaceholderNames, convert_entry_placeholders, convert_placeholders, extract_placeholders,
+        normalize_placeholders, signature,
     },
-    placeholder::{extract_placeholders, normalize_placeholders, signature},
     plural_rules::{
-        PluralValidationReport, autofix_fill_missing_from_other_resource,
-        collect_resource_plural_issues, required_categories_for_str, validate_resource_plurals,
+        PluralOperands, PluralValidationReport, autofix_fill_missing_from_other_resource,
+        category_for_kind, collect_resource_plural_issues, ordinal_category_for,
+        plural_category_for, required_categories_for_kind, required_categories_for_str,
+        validate_resource_plurals,
     },
     provenance::{
-        PROVENANCE_PREFIX, ProvenanceRecord, entry_provenance, resource_provenance,
-        set_entry_provenance, set_resource_provenance,
+        EntryPosition, PROVENANCE_PREFIX, ProvenanceRecord, SourcePosition, entry_provenance,
+        resource_provenance, set_entry_provenance, set_resource_provenance,
     },
+    pseudo::{PseudoOptions, pseudolocalize, pseudolocalize_resource},
     read_options::ReadOptions,
+    rename::{
+        KeyMapping, RenameCollision, RenameReport, find_rename_collisions, map_values, rename_keys,
+    },
+    report::{Diagnostic, ReportFormat, to_github_annotations, to_junit, to_sarif},
+    select::{SelectValidationReport, collect_select_issues, validate_selects},
+    tm::{
+        TmFill, TmFillOptions, TmFillReport, TmMatch, TmUnit, TranslationMemory, fill_from_memory,
+    },
     types::{
-        ConflictStrategy, Entry, EntryStatus, Metadata, Plural, PluralCategory, Resource,
-        Translation,
+        ConflictStrategy, Entry, EntryStatus, Metadata, Plural, PluralCategory, PluralKind,
+        Resource, Select, Translation,
     },
 };
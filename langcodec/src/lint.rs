@@ -0,0 +1,1200 @@
+//! Pluggable translation quality checks.
+//!
+//! A [`Linter`] pairs every translated form with the matching source form and
+//! runs a set of [`LintRule`]s over each pair. Built-in rules cover
+//! whitespace, punctuation, line breaks, markup, URLs, ellipses, length
+//! limits, bidi safety and placeholders; each reports with a [`LintSeverity`]
+//! that can be changed or turned off per rule, and custom rules implement the
+//! same trait.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bidi::check_bidi,
+    error::Error,
+    length::{LengthLimit, entry_length_limit, limits_by_key},
+    operations::{lang_base, normalize_lang},
+    placeholder::{category_label, compare_placeholders, forms_of, signature, source_text_for},
+    plural_rules::missing_categories_for_plural,
+    select::compare_selects,
+    types::{EntryStatus, PluralCategory, PluralKind, Resource, Translation},
+};
+
+lazy_static! {
+    static ref TAG_REGEX: Regex =
+        Regex::new(r"<(/?)([A-Za-z][\w:.-]*)(?:\s[^<>]*?)?(/?)>").unwrap();
+    static ref LINK_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:https?|ftp)://[^\s<>{}\x22'`]+|\b[\w.+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)+"
+    )
+    .unwrap();
+}
+
+/// Elements that never take a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// How serious a lint finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The rule is disabled.
+    Off,
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Off => "off",
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+impl FromStr for LintSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" | "allow" => Ok(Self::Off),
+            "info" | "note" => Ok(Self::Info),
+            "warning" | "warn" => Ok(Self::Warning),
+            "error" | "deny" => Ok(Self::Error),
+            _ => Err(format!("Unknown lint severity: {}", s)),
+        }
+    }
+}
+
+/// One translated form and the source form it is checked against.
+#[derive(Debug, Clone, Copy)]
+pub struct LintPair<'a> {
+    pub key: &'a str,
+    pub source_language: &'a str,
+    pub language: &'a str,
+    pub plural_category: Option<&'a PluralCategory>,
+    pub select_case: Option<&'a str>,
+    pub source: &'a str,
+    pub translation: &'a str,
+}
+
+/// A whole entry and the source entry it is checked against.
+///
+/// For entries of the source language, `source` and `translation` are the
+/// same value.
+#[derive(Debug, Clone, Copy)]
+pub struct LintEntry<'a> {
+    pub key: &'a str,
+    pub source_language: &'a str,
+    pub language: &'a str,
+    pub source: &'a Translation,
+    pub translation: &'a Translation,
+    /// Length limit of the entry, its own or one set for the key elsewhere.
+    pub max_length: Option<LengthLimit>,
+}
+
+/// A translation quality check.
+pub trait LintRule: Send + Sync {
+    /// Stable identifier used in configuration and reports, e.g. `double_space`.
+    fn id(&self) -> &str;
+
+    /// One-line description of what the rule checks.
+    fn description(&self) -> &str;
+
+    /// Severity used unless the [`Linter`] is configured otherwise.
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Warning
+    }
+
+    /// Returns one message per problem found in `pair`.
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String>;
+
+    /// Checks an entry as a whole, e.g. which plural forms it has. Runs once
+    /// per entry in every language, including the source language.
+    fn check_entry(&self, _entry: &LintEntry<'_>) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// A problem reported by a lint rule in one form of a translated entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule: String,
+    pub severity: LintSeverity,
+    pub key: String,
+    pub language: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plural_category: Option<PluralCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select_case: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lang='{}' key='{}'", self.language, self.key)?;
+        if let Some(category) = &self.plural_category {
+            write!(f, " [{}]", category_label(category))?;
+        }
+        if let Some(case) = &self.select_case {
+            write!(f, " [{}]", case)?;
+        }
+        write!(f, ": {} ({})", self.message, self.rule)
+    }
+}
+
+/// Findings of one [`Linter::lint`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintReport {
+    pub source_language: String,
+    /// Number of translated forms checked.
+    pub checked: usize,
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    /// Number of findings with exactly `severity`.
+    pub fn count(&self, severity: LintSeverity) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    }
+
+    /// The most serious severity reported, if any.
+    pub fn max_severity(&self) -> Option<LintSeverity> {
+        self.findings.iter().map(|finding| finding.severity).max()
+    }
+}
+
+/// Runs lint rules with per-rule severities.
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    severities: BTreeMap<String, LintSeverity>,
+}
+
+impl fmt::Debug for Linter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Linter")
+            .field(
+                "rules",
+                &self.rules.iter().map(|rule| rule.id()).collect::<Vec<_>>(),
+            )
+            .field("severities", &self.severities)
+            .finish()
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Linter {
+    /// Creates a linter with all [`builtin_rules`].
+    pub fn new() -> Self {
+        Self {
+            rules: builtin_rules(),
+            severities: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a linter without any rules.
+    pub fn empty() -> Self {
+        Self {
+            rules: Vec::new(),
+            severities: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a rule, replacing any rule with the same id.
+    pub fn add_rule(&mut self, rule: Box<dyn LintRule>) {
+        self.rules.retain(|existing| existing.id() != rule.id());
+        self.rules.push(rule);
+    }
+
+    /// Builder-style [`Linter::add_rule`].
+    pub fn with_rule(mut self, rule: impl LintRule + 'static) -> Self {
+        self.add_rule(Box::new(rule));
+        self
+    }
+
+    /// Overrides the severity of a rule; [`LintSeverity::Off`] disables it.
+    pub fn set_severity(&mut self, rule: &str, severity: LintSeverity) -> Result<(), Error> {
+        if !self.rules.iter().any(|existing| existing.id() == rule) {
+            let known: Vec<&str> = self.rules.iter().map(|rule| rule.id()).collect();
+            return Err(Error::InvalidResource(format!(
+                "unknown lint rule '{}' (known rules: {})",
+                rule,
+                known.join(", ")
+            )));
+        }
+        self.severities.insert(rule.to_string(), severity);
+        Ok(())
+    }
+
+    /// The effective severity of a rule, or `None` for an unknown rule.
+    pub fn severity(&self, rule: &str) -> Option<LintSeverity> {
+        let found = self.rules.iter().find(|existing| existing.id() == rule)?;
+        Some(
+            self.severities
+                .get(rule)
+                .copied()
+                .unwrap_or_else(|| found.default_severity()),
+        )
+    }
+
+    /// The configured rules in the order they run.
+    pub fn rules(&self) -> impl Iterator<Item = &dyn LintRule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Checks every translation against the same key in `source_language`.
+    ///
+    /// Each plural form or select case is compared with the matching source
+    /// form; [`LintRule::check_entry`] also sees source-language entries.
+    /// Empty, missing and do-not-translate entries are skipped.
+    pub fn lint(&self, resources: &[Resource], source_language: &str) -> LintReport {
+        let mut report = LintReport {
+            source_language: source_language.to_string(),
+            ..LintReport::default()
+        };
+        let Some(source) = resources
+            .iter()
+            .find(|res| res.metadata.language == source_language)
+        else {
+            return report;
+        };
+        let active: Vec<(&dyn LintRule, LintSeverity)> = self
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                let severity = self.severity(rule.id())?;
+                (severity != LintSeverity::Off).then_some((rule.as_ref(), severity))
+            })
+            .collect();
+        let limits = limits_by_key(resources);
+
+        for target in resources {
+            let is_source = target.metadata.language == source_language;
+            for entry in &target.entries {
+                if entry.status == EntryStatus::DoNotTranslate {
+                    continue;
+                }
+                let source_entry = if is_source {
+                    Some(entry)
+                } else {
+                    source.find_entry(&entry.id)
+                };
+                let Some(source_entry) = source_entry else {
+                    continue;
+                };
+                let whole = LintEntry {
+                    key: &entry.id,
+                    source_language,
+                    language: &target.metadata.language,
+                    source: &source_entry.value,
+                    translation: &entry.value,
+                    max_length: entry_length_limit(entry)
+                        .or_else(|| limits.get(entry.id.as_str()).copied()),
+                };
+                for (rule, severity) in &active {
+                    for message in rule.check_entry(&whole) {
+                        report.findings.push(LintFinding {
+                            rule: rule.id().to_string(),
+                            severity: *severity,
+                            key: entry.id.clone(),
+                            language: target.metadata.language.clone(),
+                            plural_category: None,
+                            select_case: None,
+                            message,
+                        });
+                    }
+                }
+                if is_source {
+                    continue;
+                }
+                for form in forms_of(&entry.value) {
+                    if form.text.trim().is_empty() {
+                        continue;
+                    }
+                    let Some(source_text) = source_text_for(&source_entry.value, &form) else {
+                        continue;
+                    };
+                    report.checked += 1;
+                    let pair = LintPair {
+                        key: &entry.id,
+                        source_language,
+                        language: &target.metadata.language,
+                        plural_category: form.plural_category.as_ref(),
+                        select_case: form.select_case.as_deref(),
+                        source: source_text,
+                        translation: form.text,
+                    };
+                    for (rule, severity) in &active {
+                        for message in rule.check(&pair) {
+                            report.findings.push(LintFinding {
+                                rule: rule.id().to_string(),
+                                severity: *severity,
+                                key: entry.id.clone(),
+                                language: target.metadata.language.clone(),
+                                plural_category: form.plural_category.clone(),
+                                select_case: form.select_case.clone(),
+                                message,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        // Entry order of some formats is not stable; rule order is kept per form.
+        report
+            .findings
+            .sort_by(|a, b| (&a.language, &a.key).cmp(&(&b.language, &b.key)));
+        report
+    }
+}
+
+/// All built-in rules, in the order they run.
+pub fn builtin_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(PluralFormsRule),
+        Box::new(SelectCasesRule),
+        Box::new(PlaceholderRule),
+        Box::new(WhitespaceRule),
+        Box::new(TerminalPunctuationRule),
+        Box::new(NewlineCountRule),
+        Box::new(TagBalanceRule),
+        Box::new(IdenticalToSourceRule),
+        Box::new(DoubleSpaceRule),
+        Box::new(LinkRule),
+        Box::new(EllipsisRule),
+        Box::new(MaxLengthRule),
+        Box::new(BidiRule),
+    ]
+}
+
+/// Plurals must have every CLDR category their language requires.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PluralFormsRule;
+
+impl LintRule for PluralFormsRule {
+    fn id(&self) -> &str {
+        "plural_forms"
+    }
+
+    fn description(&self) -> &str {
+        "plurals have every CLDR category the language requires"
+    }
+
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Error
+    }
+
+    fn check(&self, _pair: &LintPair<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn check_entry(&self, entry: &LintEntry<'_>) -> Vec<String> {
+        let Translation::Plural(plural) = entry.translation else {
+            return Vec::new();
+        };
+        let Ok(language) = entry.language.parse() else {
+            return Vec::new();
+        };
+        let missing = missing_categories_for_plural(&language, plural);
+        if missing.is_empty() {
+            return Vec::new();
+        }
+        let kind = match plural.kind {
+            PluralKind::Cardinal => "",
+            PluralKind::Ordinal => "ordinal ",
+        };
+        let missing: Vec<&str> = missing.iter().map(category_label).collect();
+        vec![format!(
+            "missing {}plural categories: {}",
+            kind,
+            missing.join(", ")
+        )]
+    }
+}
+
+/// Select messages must offer the same cases as the source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SelectCasesRule;
+
+impl LintRule for SelectCasesRule {
+    fn id(&self) -> &str {
+        "select_cases"
+    }
+
+    fn description(&self) -> &str {
+        "select messages cover the same selector cases as the source"
+    }
+
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Error
+    }
+
+    fn check(&self, _pair: &LintPair<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn check_entry(&self, entry: &LintEntry<'_>) -> Vec<String> {
+        if entry.language == entry.source_language {
+            return Vec::new();
+        }
+        compare_selects(entry.source, entry.translation, entry.language, entry.key)
+            .map(|report| report.problems())
+            .unwrap_or_default()
+    }
+}
+
+/// Placeholders must match the source by position and type.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlaceholderRule;
+
+impl LintRule for PlaceholderRule {
+    fn id(&self) -> &str {
+        "placeholders"
+    }
+
+    fn description(&self) -> &str {
+        "placeholders match the source by position and type"
+    }
+
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Error
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        compare_placeholders(pair.source, pair.translation)
+            .into_iter()
+            .map(|(kind, _)| {
+                format!(
+                    "{}: expected [{}], found [{}]",
+                    kind,
+                    signature(pair.source).join(", "),
+                    signature(pair.translation).join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+/// Leading and trailing whitespace must match the source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceRule;
+
+impl LintRule for WhitespaceRule {
+    fn id(&self) -> &str {
+        "whitespace"
+    }
+
+    fn description(&self) -> &str {
+        "leading and trailing whitespace matches the source"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let leading = |text: &str| text.len() - text.trim_start().len();
+        let trailing = |text: &str| text.len() - text.trim_end().len();
+        let mut messages = Vec::new();
+        if pair.source[..leading(pair.source)] != pair.translation[..leading(pair.translation)] {
+            messages.push("leading whitespace differs from the source".to_string());
+        }
+        if pair.source[pair.source.len() - trailing(pair.source)..]
+            != pair.translation[pair.translation.len() - trailing(pair.translation)..]
+        {
+            messages.push("trailing whitespace differs from the source".to_string());
+        }
+        messages
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terminal {
+    Period,
+    Question,
+    Exclamation,
+    Colon,
+    Semicolon,
+    Ellipsis,
+}
+
+/// Classifies the sentence-final punctuation of `text`, looking through
+/// closing quotes and brackets.
+fn terminal_punctuation(text: &str, language: &str) -> Option<(Terminal, String)> {
+    let trimmed = text
+        .trim_end()
+        .trim_end_matches(['"', '\'', '»', '”', '’', ')', ']', '」', '』', '）'])
+        .trim_end();
+    if trimmed.ends_with("...") {
+        return Some((Terminal::Ellipsis, "...".to_string()));
+    }
+    let last = trimmed.chars().next_back()?;
+    let greek = lang_base(&normalize_lang(language)) == "el";
+    let class = match last {
+        '…' => Terminal::Ellipsis,
+        '.' | '。' | '｡' | '।' | '۔' | '။' | '។' | '։' => Terminal::Period,
+        // Greek uses `;` as its question mark.
+        ';' if greek => Terminal::Question,
+        '?' | '？' | '؟' => Terminal::Question,
+        '!' | '！' => Terminal::Exclamation,
+        ':' | '：' => Terminal::Colon,
+        ';' | '；' | '؛' => Terminal::Semicolon,
+        _ => return None,
+    };
+    Some((class, last.to_string()))
+}
+
+/// Sentence-final punctuation must match the source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TerminalPunctuationRule;
+
+impl LintRule for TerminalPunctuationRule {
+    fn id(&self) -> &str {
+        "terminal_punctuation"
+    }
+
+    fn description(&self) -> &str {
+        "sentence-final punctuation matches the source"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let source = terminal_punctuation(pair.source, pair.source_language);
+        let translation = terminal_punctuation(pair.translation, pair.language);
+        let message = match (source, translation) {
+            (Some((expected, _)), Some((actual, _))) if expected == actual => return Vec::new(),
+            (None, None) => return Vec::new(),
+            // Thai, Lao, Khmer and Burmese often omit the final period.
+            (Some((Terminal::Period, _)), None)
+                if ["th", "lo", "km", "my"].iter().any(|lang| {
+                    lang_base(&normalize_lang(pair.language)).eq_ignore_ascii_case(lang)
+                }) =>
+            {
+                return Vec::new();
+            }
+            (Some((_, expected)), Some((_, actual))) => format!(
+                "source ends with '{}' but the translation ends with '{}'",
+                expected, actual
+            ),
+            (Some((_, expected)), None) => {
+                format!("translation is missing the final '{}'", expected)
+            }
+            (None, Some((_, actual))) => {
+                format!("translation adds a final '{}' not in the source", actual)
+            }
+        };
+        vec![message]
+    }
+}
+
+/// The number of line breaks must match the source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NewlineCountRule;
+
+impl LintRule for NewlineCountRule {
+    fn id(&self) -> &str {
+        "newline_count"
+    }
+
+    fn description(&self) -> &str {
+        "the number of line breaks matches the source"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let expected = pair.source.matches('\n').count();
+        let actual = pair.translation.matches('\n').count();
+        if expected == actual {
+            return Vec::new();
+        }
+        vec![format!(
+            "source has {} line break(s), translation has {}",
+            expected, actual
+        )]
+    }
+}
+
+/// Tag names of `text` in order, and balance problems found on the way.
+fn scan_tags(text: &str) -> (Vec<String>, Vec<String>) {
+    let mut names = Vec::new();
+    let mut problems = Vec::new();
+    let mut open: Vec<String> = Vec::new();
+    for captures in TAG_REGEX.captures_iter(text) {
+        let closing = !captures[1].is_empty();
+        let self_closing = !captures[3].is_empty();
+        let name = captures[2].to_string();
+        let void = VOID_ELEMENTS
+            .iter()
+            .any(|element| element.eq_ignore_ascii_case(&name));
+        if closing {
+            if void {
+                continue;
+            }
+            match open.iter().rposition(|tag| *tag == name) {
+                Some(index) => {
+                    for unclosed in open.drain(index..).skip(1) {
+                        problems.push(format!("<{}> is not closed", unclosed));
+                    }
+                }
+                None => problems.push(format!("</{}> has no opening tag", name)),
+            }
+            continue;
+        }
+        names.push(name.clone());
+        if !self_closing && !void {
+            open.push(name);
+        }
+    }
+    problems.extend(
+        open.into_iter()
+            .map(|tag| format!("<{}> is not closed", tag)),
+    );
+    (names, problems)
+}
+
+/// HTML/XML tags must be balanced and match the source's tags.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TagBalanceRule;
+
+impl LintRule for TagBalanceRule {
+    fn id(&self) -> &str {
+        "unbalanced_tags"
+    }
+
+    fn description(&self) -> &str {
+        "HTML/XML tags are balanced and match the source"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let (mut actual, problems) = scan_tags(pair.translation);
+        if !problems.is_empty() {
+            return problems;
+        }
+        let (mut expected, _) = scan_tags(pair.source);
+        expected.sort();
+        actual.sort();
+        if expected == actual {
+            return Vec::new();
+        }
+        let render = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| format!("<{}>", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        vec![format!(
+            "tags differ from the source: expected [{}], found [{}]",
+            render(&expected),
+            render(&actual)
+        )]
+    }
+}
+
+/// The translation should not be a copy of the source text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdenticalToSourceRule;
+
+impl LintRule for IdenticalToSourceRule {
+    fn id(&self) -> &str {
+        "identical_to_source"
+    }
+
+    fn description(&self) -> &str {
+        "the translation differs from the source text"
+    }
+
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Info
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let same_language = lang_base(&normalize_lang(pair.source_language))
+            == lang_base(&normalize_lang(pair.language));
+        let has_words = pair
+            .source
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .nth(1)
+            .is_some();
+        if same_language || !has_words || pair.source.trim() != pair.translation.trim() {
+            return Vec::new();
+        }
+        vec!["translation is identical to the source".to_string()]
+    }
+}
+
+/// The translation should not contain double spaces the source lacks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DoubleSpaceRule;
+
+impl LintRule for DoubleSpaceRule {
+    fn id(&self) -> &str {
+        "double_space"
+    }
+
+    fn description(&self) -> &str {
+        "no double spaces unless the source has them"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        if pair.translation.trim().contains("  ") && !pair.source.trim().contains("  ") {
+            vec!["translation contains a double space".to_string()]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+fn links(text: &str) -> Vec<&str> {
+    let mut found: Vec<&str> = LINK_REGEX
+        .find_iter(text)
+        .map(|link| {
+            link.as_str()
+                .trim_end_matches(['.', ',', ';', ':', '!', '?', ')'])
+        })
+        .collect();
+    found.sort_unstable();
+    found
+}
+
+/// URLs and email addresses must be copied unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkRule;
+
+impl LintRule for LinkRule {
+    fn id(&self) -> &str {
+        "url_mismatch"
+    }
+
+    fn description(&self) -> &str {
+        "URLs and email addresses are copied unchanged"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let expected = links(pair.source);
+        let mut actual = links(pair.translation);
+        let mut messages = Vec::new();
+        for link in expected {
+            match actual.iter().position(|found| *found == link) {
+                Some(index) => {
+                    actual.remove(index);
+                }
+                None => messages.push(format!("'{}' is missing or changed", link)),
+            }
+        }
+        messages.extend(
+            actual
+                .into_iter()
+                .map(|link| format!("'{}' is not in the source", link)),
+        );
+        messages
+    }
+}
+
+/// The translation should use the same ellipsis style as the source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EllipsisRule;
+
+impl LintRule for EllipsisRule {
+    fn id(&self) -> &str {
+        "ellipsis_style"
+    }
+
+    fn description(&self) -> &str {
+        "ellipses use the same style ('…' or '...') as the source"
+    }
+
+    fn default_severity(&self) -> LintSeverity {
+        LintSeverity::Info
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        let (expected, unexpected) = if pair.source.contains('…') && !pair.source.contains("...")
+        {
+            ("…", "...")
+        } else if pair.source.contains("...") && !pair.source.contains('…') {
+            ("...", "…")
+        } else {
+            return Vec::new();
+        };
+        if pair.translation.contains(unexpected) {
+            vec![format!(
+                "translation uses '{}' where the source uses '{}'",
+                unexpected, expected
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Every form must fit the entry's length limit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxLengthRule;
+
+impl LintRule for MaxLengthRule {
+    fn id(&self) -> &str {
+        "max_length"
+    }
+
+    fn description(&self) -> &str {
+        "every form fits the entry's maximum length"
+    }
+
+    fn check(&self, _pair: &LintPair<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn check_entry(&self, entry: &LintEntry<'_>) -> Vec<String> {
+        let Some(limit) = entry.max_length else {
+            return Vec::new();
+        };
+        forms_of(entry.translation)
+            .into_iter()
+            .filter_map(|form| {
+                let length = limit.overrun(form.text)?;
+                let prefix = match (&form.plural_category, &form.select_case) {
+                    (Some(category), _) => format!("[{}] ", category_label(category)),
+                    (None, Some(case)) => format!("[{}] ", case),
+                    (None, None) => String::new(),
+                };
+                Some(format!(
+                    "{}length {} exceeds the limit of {}",
+                    prefix, length, limit
+                ))
+            })
+            .collect()
+    }
+}
+
+/// RTL translations isolate placeholders and keep direction controls,
+/// brackets and punctuation consistent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BidiRule;
+
+impl LintRule for BidiRule {
+    fn id(&self) -> &str {
+        "bidi"
+    }
+
+    fn description(&self) -> &str {
+        "RTL translations isolate placeholders and use consistent direction controls, brackets and punctuation"
+    }
+
+    fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+        check_bidi(pair.source, pair.translation, pair.language)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::resource;
+    use crate::types::{Plural, Translation};
+
+    fn check(rule: &dyn LintRule, language: &str, source: &str, translation: &str) -> Vec<String> {
+        rule.check(&LintPair {
+            key: "key",
+            source_language: "en",
+            language,
+            plural_category: None,
+            select_case: None,
+            source,
+            translation,
+        })
+    }
+
+    fn singular(text: &str) -> Translation {
+        Translation::Singular(text.to_string())
+    }
+
+    #[test]
+    fn linter_reports_builtin_findings_per_form() {
+        let resources = vec![
+            resource(
+                "en",
+                &[
+                    ("greeting", singular("Hello, %@!")),
+                    (
+                        "files",
+                        Translation::Plural(
+                            Plural::new(
+                                "files",
+                                [
+                                    (PluralCategory::One, "One file.".to_string()),
+                                    (PluralCategory::Other, "%d files.".to_string()),
+                                ]
+                                .into_iter(),
+                            )
+                            .unwrap(),
+                        ),
+                    ),
+                ],
+            ),
+            resource(
+                "fr",
+                &[
+                    ("greeting", singular("Bonjour  !")),
+                    (
+                        "files",
+                        Translation::Plural(
+                            Plural::new(
+                                "files",
+                                [
+                                    (PluralCategory::One, "Un fichier.".to_string()),
+                                    (PluralCategory::Other, "%d fichiers".to_string()),
+                                ]
+                                .into_iter(),
+                            )
+                            .unwrap(),
+                        ),
+                    ),
+                ],
+            ),
+        ];
+
+        let report = Linter::new().lint(&resources, "en");
+        assert_eq!(report.checked, 3);
+        let rules: Vec<(&str, &str)> = report
+            .findings
+            .iter()
+            .map(|finding| (finding.key.as_str(), finding.rule.as_str()))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                ("files", "plural_forms"),
+                ("files", "terminal_punctuation"),
+                ("greeting", "placeholders"),
+                ("greeting", "double_space"),
+            ]
+        );
+        assert_eq!(
+            report.findings[0].message,
+            "missing plural categories: many"
+        );
+        assert_eq!(report.findings[2].severity, LintSeverity::Error);
+        assert_eq!(
+            report.findings[1].plural_category,
+            Some(PluralCategory::Other)
+        );
+        assert_eq!(report.max_severity(), Some(LintSeverity::Error));
+        assert_eq!(
+            report.findings[1].to_string(),
+            "lang='fr' key='files' [other]: translation is missing the final '.' (terminal_punctuation)"
+        );
+    }
+
+    #[test]
+    fn severities_can_be_overridden_or_disabled() {
+        let resources = vec![
+            resource("en", &[("ok", singular("OK"))]),
+            resource("de", &[("ok", singular("OK"))]),
+        ];
+        let mut linter = Linter::new();
+        assert_eq!(
+            linter.severity("identical_to_source"),
+            Some(LintSeverity::Info)
+        );
+        assert_eq!(linter.lint(&resources, "en").count(LintSeverity::Info), 1);
+
+        linter
+            .set_severity("identical_to_source", LintSeverity::Error)
+            .unwrap();
+        assert_eq!(linter.lint(&resources, "en").count(LintSeverity::Error), 1);
+
+        linter
+            .set_severity("identical_to_source", LintSeverity::Off)
+            .unwrap();
+        assert!(linter.lint(&resources, "en").findings.is_empty());
+
+        assert!(
+            linter
+                .set_severity("no_such_rule", LintSeverity::Off)
+                .is_err()
+        );
+        assert_eq!("warn".parse::<LintSeverity>(), Ok(LintSeverity::Warning));
+    }
+
+    #[test]
+    fn custom_rules_run_alongside_builtins() {
+        struct NoExclamation;
+        impl LintRule for NoExclamation {
+            fn id(&self) -> &str {
+                "no_exclamation"
+            }
+            fn description(&self) -> &str {
+                "avoid exclamation marks"
+            }
+            fn check(&self, pair: &LintPair<'_>) -> Vec<String> {
+                if pair.translation.contains('!') {
+                    vec!["avoid exclamation marks".to_string()]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+
+        let resources = vec![
+            resource("en", &[("done", singular("Done!"))]),
+            resource("de", &[("done", singular("Fertig!"))]),
+        ];
+        let report = Linter::empty()
+            .with_rule(NoExclamation)
+            .lint(&resources, "en");
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].rule, "no_exclamation");
+        assert_eq!(report.findings[0].severity, LintSeverity::Warning);
+    }
+
+    #[test]
+    fn punctuation_whitespace_and_newlines() {
+        let rule = TerminalPunctuationRule;
+        assert!(check(&rule, "fr", "Delete?", "Supprimer ?").is_empty());
+        assert!(check(&rule, "ja", "Saved.", "保存しました。").is_empty());
+        assert!(check(&rule, "el", "Delete?", "Διαγραφή;").is_empty());
+        assert!(check(&rule, "th", "Saved.", "บันทึกแล้ว").is_empty());
+        assert!(check(&rule, "fr", "Tap \"OK\".", "Touchez « OK ».").is_empty());
+        assert_eq!(
+            check(&rule, "de", "Delete?", "Löschen!"),
+            vec!["source ends with '?' but the translation ends with '!'"]
+        );
+
+        assert_eq!(
+            check(&WhitespaceRule, "de", " Name: ", "Name:"),
+            vec![
+                "leading whitespace differs from the source",
+                "trailing whitespace differs from the source"
+            ]
+        );
+        assert_eq!(
+            check(&NewlineCountRule, "de", "One\nTwo", "Eins Zwei"),
+            vec!["source has 1 line break(s), translation has 0"]
+        );
+    }
+
+    #[test]
+    fn tags_links_and_ellipses() {
+        let rule = TagBalanceRule;
+        assert!(check(&rule, "de", "<b>Bold</b><br/>", "<br><b>Fett</b>").is_empty());
+        assert_eq!(
+            check(&rule, "de", "<b>Bold</b>", "<b>Fett"),
+            vec!["<b> is not closed"]
+        );
+        assert_eq!(
+            check(&rule, "de", "<b>Bold</b>", "Fett</b>"),
+            vec!["</b> has no opening tag"]
+        );
+        assert_eq!(
+            check(&rule, "de", "<b>Bold</b>", "<i>Fett</i>"),
+            vec!["tags differ from the source: expected [<b>], found [<i>]"]
+        );
+
+        assert!(
+            check(
+                &LinkRule,
+                "de",
+                "See https://example.com/help.",
+                "Siehe https://example.com/help."
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            check(
+                &LinkRule,
+                "de",
+                "Write to help@example.com",
+                "Schreiben Sie an hilfe@example.com"
+            ),
+            vec![
+                "'help@example.com' is missing or changed",
+                "'hilfe@example.com' is not in the source"
+            ]
+        );
+        assert!(check(&LinkRule, "de", "Hello %1$@", "Hallo %1$@").is_empty());
+
+        assert_eq!(
+            check(&EllipsisRule, "de", "Loading…", "Laden..."),
+            vec!["translation uses '...' where the source uses '…'"]
+        );
+        assert!(check(&EllipsisRule, "de", "Loading...", "Laden...").is_empty());
+    }
+
+    #[test]
+    fn max_length_uses_the_source_entry_limit() {
+        let mut resources = vec![
+            resource("en", &[("watch_title", singular("Steps"))]),
+            resource("fi", &[("watch_title", singular("Askelmittari"))]),
+        ];
+        crate::length::set_entry_length_limit(
+            &mut resources[0].entries[0],
+            Some(LengthLimit::new(10, crate::length::LengthUnit::Graphemes)),
+        );
+
+        let report = Linter::empty()
+            .with_rule(MaxLengthRule)
+            .lint(&resources, "en");
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(
+            report.findings[0].to_string(),
+            "lang='fi' key='watch_title': length 12 exceeds the limit of 10 characters (max_length)"
+        );
+    }
+
+    #[test]
+    fn select_cases_reports_missing_and_unknown_cases() {
+        let resources = vec![
+            resource(
+                "en",
+                &[(
+                    "invite",
+                    singular("{gender, select, male {He} female {She} other {They}}"),
+                )],
+            ),
+            resource(
+                "de",
+                &[(
+                    "invite",
+                    singular("{gender, select, male {Er} neuter {Es} other {Sie}}"),
+                )],
+            ),
+        ];
+
+        let report = Linter::empty()
+            .with_rule(SelectCasesRule)
+            .lint(&resources, "en");
+        let messages: Vec<String> = report.findings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "lang='de' key='invite': missing select cases: [female] (select_cases)",
+                "lang='de' key='invite': unknown select cases: [neuter] (select_cases)",
+            ]
+        );
+    }
+
+    #[test]
+    fn bidi_flags_unisolated_placeholders_in_rtl_only() {
+        assert_eq!(
+            check(&BidiRule, "ar", "%@ items", "%@ عناصر"),
+            vec!["'%@' is not wrapped in bidi isolates (FSI/PDI)"]
+        );
+        assert!(check(&BidiRule, "ar", "%@ items", "\u{2068}%@\u{2069} عناصر").is_empty());
+        assert!(check(&BidiRule, "de", "%@ items", "%@ Elemente").is_empty());
+    }
+}
@@ -1,10 +1,38 @@
-use crate::{Codec, Error};
-use std::collections::HashMap;
+use crate::{
+    Codec, Error,
+    bidi::{is_rtl_language, isolate_ltr_runs},
+    operations::{lang_base, normalize_lang},
+    placeholder::{category_label, forms_of, forms_of_mut, normalize_placeholders},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+use unicode_normalization::UnicodeNormalization;
 
+/// Which normalizations [`normalize_codec`] applies.
+///
+/// Placeholder normalization and sorting are on by default; the typography
+/// rules are opt-in because they change what users see.
 #[derive(Debug, Clone)]
 pub struct NormalizeOptions {
     pub normalize_placeholders: bool,
     pub key_style: KeyStyle,
+    /// Wrap placeholders in RTL translations in FSI/PDI isolates
+    /// (see [`crate::bidi::isolate_ltr_runs`]).
+    pub isolate_bidi: bool,
+    /// Unicode normalization form for every value.
+    pub unicode_form: Option<UnicodeForm>,
+    /// Use no-break spaces before `;`, `:`, `!` and `?` and inside `« »` in
+    /// French.
+    pub french_spacing: bool,
+    /// Replace paired straight double quotes with the quotation marks of the
+    /// language (`“ ”`, `« »`, `„ “`, `「 」`, ...).
+    pub smart_quotes: bool,
+    /// Replace `...` with `…`.
+    pub ellipsis: bool,
+    /// Remove spaces and tabs at the end of every line.
+    pub trim_trailing_whitespace: bool,
+    /// Convert `\r\n` and `\r` line breaks to `\n`.
+    pub line_endings: bool,
 }
 
 impl Default for NormalizeOptions {
@@ -12,10 +40,39 @@ impl Default for NormalizeOptions {
         Self {
             normalize_placeholders: true,
             key_style: KeyStyle::None,
+            isolate_bidi: false,
+            unicode_form: None,
+            french_spacing: false,
+            smart_quotes: false,
+            ellipsis: false,
+            trim_trailing_whitespace: false,
+            line_endings: false,
         }
     }
 }
 
+impl NormalizeOptions {
+    /// Enabled value rules, in the order they run.
+    fn text_rules(&self) -> Vec<NormalizeRule> {
+        [
+            (self.line_endings, NormalizeRule::LineEndings),
+            (self.unicode_form.is_some(), NormalizeRule::UnicodeForm),
+            (
+                self.trim_trailing_whitespace,
+                NormalizeRule::TrailingWhitespace,
+            ),
+            (self.normalize_placeholders, NormalizeRule::Placeholders),
+            (self.ellipsis, NormalizeRule::Ellipsis),
+            (self.smart_quotes, NormalizeRule::Quotes),
+            (self.french_spacing, NormalizeRule::FrenchSpacing),
+            (self.isolate_bidi, NormalizeRule::BidiIsolation),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, rule)| enabled.then_some(rule))
+        .collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum KeyStyle {
     #[default]
@@ -25,62 +82,295 @@ pub enum KeyStyle {
     Camel,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Unicode normalization form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnicodeForm {
+    /// Canonical composition: `e` + U+0301 becomes `é`.
+    Nfc,
+    /// Compatibility composition, which also folds ligatures, full-width
+    /// forms and similar variants (`ﬁ` becomes `fi`).
+    Nfkc,
+}
+
+impl std::str::FromStr for UnicodeForm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "nfc" => Ok(Self::Nfc),
+            "nfkc" => Ok(Self::Nfkc),
+            _ => Err(format!(
+                "Unknown Unicode form: {} (expected nfc or nfkc)",
+                s
+            )),
+        }
+    }
+}
+
+/// A normalization that changed an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizeRule {
+    KeyStyle,
+    LineEndings,
+    UnicodeForm,
+    TrailingWhitespace,
+    Placeholders,
+    Ellipsis,
+    Quotes,
+    FrenchSpacing,
+    BidiIsolation,
+}
+
+impl NormalizeRule {
+    /// Stable identifier, e.g. `french_spacing`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::KeyStyle => "key_style",
+            Self::LineEndings => "line_endings",
+            Self::UnicodeForm => "unicode_form",
+            Self::TrailingWhitespace => "trailing_whitespace",
+            Self::Placeholders => "placeholders",
+            Self::Ellipsis => "ellipsis",
+            Self::Quotes => "quotes",
+            Self::FrenchSpacing => "french_spacing",
+            Self::BidiIsolation => "bidi_isolation",
+        }
+    }
+
+    fn apply(self, text: &str, language: &str, options: &NormalizeOptions) -> String {
+        match self {
+            Self::KeyStyle => text.to_string(),
+            Self::LineEndings => text.replace("\r\n", "\n").replace('\r', "\n"),
+            Self::UnicodeForm => match options.unicode_form {
+                Some(UnicodeForm::Nfc) => text.nfc().collect(),
+                Some(UnicodeForm::Nfkc) => text.nfkc().collect(),
+                None => text.to_string(),
+            },
+            Self::TrailingWhitespace => trim_trailing_whitespace(text),
+            Self::Placeholders => normalize_placeholders(text),
+            Self::Ellipsis => replace_ellipses(text),
+            Self::Quotes => smart_quotes(text, language),
+            Self::FrenchSpacing if lang_base(&normalize_lang(language)) == "fr" => {
+                french_spacing(text)
+            }
+            Self::FrenchSpacing => text.to_string(),
+            Self::BidiIsolation if is_rtl_language(language) => isolate_ltr_runs(text),
+            Self::BidiIsolation => text.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for NormalizeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// One entry changed by one rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizeChange {
+    pub language: String,
+    /// The key after normalization.
+    pub key: String,
+    pub rule: NormalizeRule,
+}
+
+/// A value before and after normalization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueChange {
+    pub language: String,
+    /// The key after normalization.
+    pub key: String,
+    /// Plural category or select case, for plural and select values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<String>,
+    pub before: String,
+    pub after: String,
+    /// Rules that changed this value, in the order they ran.
+    pub rules: Vec<NormalizeRule>,
+}
+
+/// A key renamed by [`KeyStyle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyRename {
+    pub language: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Keys of one resource that [`KeyStyle`] would turn into the same id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyCollision {
+    pub language: String,
+    pub domain: String,
+    /// The colliding keys, in file order.
+    pub keys: Vec<String>,
+    /// The id they all normalize to.
+    pub normalized: String,
+}
+
+impl fmt::Display for KeyCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: Vec<String> = self.keys.iter().map(|key| format!("'{}'", key)).collect();
+        let keys = match keys.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => keys.join(""),
+        };
+        write!(
+            f,
+            "key-style collision in language '{}' (domain '{}'): {} {} normalize to '{}'",
+            self.language,
+            self.domain,
+            keys,
+            if self.keys.len() == 2 { "both" } else { "all" },
+            self.normalized
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct NormalizeReport {
     pub changed: bool,
+    /// Every entry a rule changed, in file order; reordering entries sets
+    /// `changed` without a change of its own.
+    pub changes: Vec<NormalizeChange>,
+    /// Every changed value with its text before and after.
+    pub values: Vec<ValueChange>,
+    pub renamed_keys: Vec<KeyRename>,
+    /// Key-style collisions; [`normalize_codec`] fails when there are any.
+    pub collisions: Vec<KeyCollision>,
+    /// Languages whose entries were sorted by key.
+    pub reordered: Vec<String>,
+}
+
+impl NormalizeReport {
+    /// Changes made by `rule`.
+    pub fn changes_by(&self, rule: NormalizeRule) -> impl Iterator<Item = &NormalizeChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.rule == rule)
+    }
+
+    fn record(&mut self, language: &str, key: &str, rule: NormalizeRule) {
+        self.changed = true;
+        self.changes.push(NormalizeChange {
+            language: language.to_string(),
+            key: key.to_string(),
+            rule,
+        });
+    }
 }
 
+/// Normalizes `codec` in place.
+///
+/// Fails without touching `codec` when [`KeyStyle`] would give two keys the
+/// same id; [`preview_normalize`] reports such collisions instead.
 pub fn normalize_codec(
     codec: &mut Codec,
     options: &NormalizeOptions,
 ) -> Result<NormalizeReport, Error> {
     let mut normalized = codec.clone();
-    let report = normalize_codec_in_place(&mut normalized, options)?;
+    let report = normalize_codec_in_place(&mut normalized, options);
+    if let Some(collision) = report.collisions.first() {
+        return Err(Error::validation_error(collision.to_string()));
+    }
     *codec = normalized;
     Ok(report)
 }
 
-fn normalize_codec_in_place(
-    codec: &mut Codec,
-    options: &NormalizeOptions,
-) -> Result<NormalizeReport, Error> {
-    let mut changed = false;
+/// Reports what [`normalize_codec`] would change without changing `codec`.
+///
+/// Unlike [`normalize_codec`] this does not fail on key-style collisions: they
+/// are listed in [`NormalizeReport::collisions`] and the colliding keys are
+/// left as they are.
+pub fn preview_normalize(codec: &Codec, options: &NormalizeOptions) -> NormalizeReport {
+    normalize_codec_in_place(&mut codec.clone(), options)
+}
+
+fn normalize_codec_in_place(codec: &mut Codec, options: &NormalizeOptions) -> NormalizeReport {
+    let mut report = NormalizeReport::default();
+    let text_rules = options.text_rules();
 
     for resource in &mut codec.resources {
+        let language = resource.metadata.language.clone();
         if options.key_style != KeyStyle::None {
-            let mut transformed_ids = Vec::with_capacity(resource.entries.len());
-            let mut seen: HashMap<String, String> = HashMap::new();
-
-            for entry in &resource.entries {
-                let transformed = transform_key_style(&entry.id, options.key_style);
-                if let Some(existing) = seen.get(&transformed) {
-                    return Err(Error::validation_error(format!(
-                        "key-style collision in language '{}' (domain '{}'): '{}' and '{}' both normalize to '{}'",
-                        resource.metadata.language,
-                        resource.metadata.domain,
-                        existing,
-                        entry.id,
-                        transformed
-                    )));
+            let transformed_ids: Vec<String> = resource
+                .entries
+                .iter()
+                .map(|entry| transform_key_style(&entry.id, options.key_style))
+                .collect();
+            let mut groups: HashMap<&str, Vec<&str>> = HashMap::new();
+            for (entry, transformed) in resource.entries.iter().zip(&transformed_ids) {
+                groups.entry(transformed).or_default().push(&entry.id);
+            }
+            let mut colliding: Vec<&str> = Vec::new();
+            for transformed in &transformed_ids {
+                let keys = &groups[transformed.as_str()];
+                if keys.len() > 1 && !colliding.contains(&transformed.as_str()) {
+                    colliding.push(transformed);
+                    report.collisions.push(KeyCollision {
+                        language: language.clone(),
+                        domain: resource.metadata.domain.clone(),
+                        keys: keys.iter().map(|key| key.to_string()).collect(),
+                        normalized: transformed.clone(),
+                    });
                 }
-                seen.insert(transformed.clone(), entry.id.clone());
-                transformed_ids.push(transformed);
             }
 
-            for (entry, transformed_id) in
-                resource.entries.iter_mut().zip(transformed_ids.into_iter())
-            {
-                if entry.id != transformed_id {
-                    entry.id = transformed_id;
-                    changed = true;
+            for (entry, transformed_id) in resource.entries.iter_mut().zip(&transformed_ids) {
+                if entry.id != *transformed_id && !colliding.contains(&transformed_id.as_str()) {
+                    report.renamed_keys.push(KeyRename {
+                        language: language.clone(),
+                        from: std::mem::replace(&mut entry.id, transformed_id.clone()),
+                        to: transformed_id.clone(),
+                    });
+                    report.record(&language, &entry.id, NormalizeRule::KeyStyle);
                 }
             }
         }
 
-        if options.normalize_placeholders {
-            for entry in &mut resource.entries {
-                if normalize_entry_placeholders(entry) {
-                    changed = true;
+        for entry in &mut resource.entries {
+            let labels: Vec<Option<String>> = forms_of(&entry.value)
+                .into_iter()
+                .map(|form| {
+                    form.plural_category
+                        .as_ref()
+                        .map(|category| category_label(category).to_string())
+                        .or(form.select_case)
+                })
+                .collect();
+            let before: Vec<String> = forms_of_mut(&mut entry.value)
+                .into_iter()
+                .map(|text| text.clone())
+                .collect();
+            let mut form_rules: Vec<Vec<NormalizeRule>> = vec![Vec::new(); before.len()];
+            for rule in &text_rules {
+                let mut entry_changed = false;
+                for (index, text) in forms_of_mut(&mut entry.value).into_iter().enumerate() {
+                    let normalized = rule.apply(text, &language, options);
+                    if *text != normalized {
+                        *text = normalized;
+                        entry_changed = true;
+                        form_rules[index].push(*rule);
+                    }
+                }
+                if entry_changed {
+                    report.record(&language, &entry.id, *rule);
+                }
+            }
+            let after = forms_of_mut(&mut entry.value);
+            for (index, (before, after)) in before.into_iter().zip(after).enumerate() {
+                if before != *after {
+                    report.values.push(ValueChange {
+                        language: language.clone(),
+                        key: entry.id.clone(),
+                        form: labels[index].clone(),
+                        before,
+                        after: after.clone(),
+                        rules: std::mem::take(&mut form_rules[index]),
+                    });
                 }
             }
         }
@@ -93,40 +383,128 @@ fn normalize_codec_in_place(
             resource
                 .entries
                 .sort_by(|left, right| left.id.cmp(&right.id));
-            changed = true;
+            report.changed = true;
+            report.reordered.push(language);
         }
     }
 
-    Ok(NormalizeReport { changed })
+    report
 }
 
-fn normalize_entry_placeholders(entry: &mut crate::types::Entry) -> bool {
-    use crate::placeholder::normalize_placeholders;
-    use crate::types::Translation;
+fn trim_trailing_whitespace(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let (line, cr) = match line.strip_suffix('\r') {
+                Some(line) => (line, "\r"),
+                None => (line, ""),
+            };
+            format!("{}{}", line.trim_end_matches([' ', '\t']), cr)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    match &mut entry.value {
-        Translation::Empty => false,
-        Translation::Singular(value) => {
-            let normalized = normalize_placeholders(value);
-            if *value != normalized {
-                *value = normalized;
-                true
-            } else {
-                false
-            }
+/// Replaces runs of exactly three dots; longer runs are left alone.
+fn replace_ellipses(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut dots = 0;
+    let flush = |out: &mut String, dots: usize| {
+        if dots == 3 {
+            out.push('…');
+        } else {
+            out.extend(std::iter::repeat_n('.', dots));
         }
-        Translation::Plural(plural) => {
-            let mut changed = false;
-            for value in plural.forms.values_mut() {
-                let normalized = normalize_placeholders(value);
-                if *value != normalized {
-                    *value = normalized;
-                    changed = true;
-                }
-            }
-            changed
+    };
+    for c in text.chars() {
+        if c == '.' {
+            dots += 1;
+            continue;
         }
+        flush(&mut out, dots);
+        dots = 0;
+        out.push(c);
     }
+    flush(&mut out, dots);
+    out
+}
+
+/// Opening and closing quotation marks used by `language`.
+fn quote_marks(language: &str) -> (char, char) {
+    let lower = language.to_ascii_lowercase().replace('_', "-");
+    match lang_base(&normalize_lang(language)) {
+        "fr" | "es" | "it" | "ca" | "ru" | "uk" | "be" | "el" | "nb" | "no" | "nn" | "fa" => {
+            ('«', '»')
+        }
+        "de" | "cs" | "sk" | "sl" | "bg" | "lt" | "et" | "is" => ('„', '“'),
+        "pl" | "hu" | "ro" | "hr" => ('„', '”'),
+        "ja" => ('「', '」'),
+        "zh" if ["hant", "-tw", "-hk", "-mo"]
+            .iter()
+            .any(|tag| lower.contains(tag)) =>
+        {
+            ('「', '」')
+        }
+        _ => ('“', '”'),
+    }
+}
+
+/// Converts straight double quotes outside markup tags into the language's
+/// quotation marks. Escaped quotes (`\"`, as kept by `.strings` and Android
+/// values) are replaced with their backslash. Text with an odd number of
+/// quotes is left alone, since they cannot be paired reliably.
+fn smart_quotes(text: &str, language: &str) -> String {
+    let mut in_tag = false;
+    // (byte offset, byte length) of every quote
+    let mut quotes = Vec::new();
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            '"' if !in_tag => match text[..i].strip_suffix('\\') {
+                Some(_) => quotes.push((i - 1, 2)),
+                None => quotes.push((i, 1)),
+            },
+            _ => {}
+        }
+    }
+    if quotes.is_empty() || quotes.len() % 2 != 0 {
+        return text.to_string();
+    }
+    let (open, close) = quote_marks(language);
+    let mut out = String::with_capacity(text.len() + quotes.len() * 2);
+    let mut last = 0;
+    for (n, (i, len)) in quotes.into_iter().enumerate() {
+        out.push_str(&text[last..i]);
+        out.push(if n % 2 == 0 { open } else { close });
+        last = i + len;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Turns the ordinary space before `;`, `:`, `!`, `?` and `»` and after `«`
+/// into a no-break space (U+00A0), and adds one inside guillemets that have
+/// none.
+fn french_spacing(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).copied();
+        let previous = i.checked_sub(1).map(|i| chars[i]);
+        if *c == ' ' && (matches!(next, Some(';' | ':' | '!' | '?' | '»')) || previous == Some('«'))
+        {
+            out.push('\u{00A0}');
+            continue;
+        }
+        if *c == '»' && previous.is_some_and(|p| !p.is_whitespace()) {
+            out.push('\u{00A0}');
+        }
+        out.push(*c);
+        if *c == '«' && next.is_some_and(|n| !n.is_whitespace()) {
+            out.push('\u{00A0}');
+        }
+    }
+    out
 }
 
 fn transform_key_style(input: &str, key_style: KeyStyle) -> String {
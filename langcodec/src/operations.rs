@@ -1,4 +1,4 @@
-//! High-level resource operations (sync/diff) reusable by CLI and library users.
+//! High-level resource operations (sync/diff/stale detection) reusable by CLI and library users.
 
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     Error,
     provenance::{ProvenanceRecord, set_entry_provenance},
-    types::{Resource, Translation},
+    types::{Entry, EntryStatus, Resource, Translation},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -179,6 +179,12 @@ pub fn sync_existing_entries(
                         updated = true;
                     }
                 }
+                (Translation::Select(current), Translation::Select(new_value)) => {
+                    if current != new_value {
+                        entry.value = Translation::Select(new_value.clone());
+                        updated = true;
+                    }
+                }
                 (Translation::Empty, Translation::Empty) => {}
                 _ => {
                     report.skipped_type_mismatch += 1;
@@ -340,15 +346,267 @@ pub fn diff_resources(
     }
 }
 
-fn normalize_lang(lang: &str) -> String {
+/// `Entry.custom` key holding the fingerprint of the source text a translation was made from.
+pub const SOURCE_FINGERPRINT_KEY: &str = "langcodec.source_fingerprint";
+/// `Entry.custom` key holding the fingerprint of the translation when it was last checked,
+/// used to notice that a stale translation has since been updated.
+pub const TRANSLATION_FINGERPRINT_KEY: &str = "langcodec.translation_fingerprint";
+
+/// Returns a stable fingerprint of a value, or `None` for empty values.
+///
+/// The fingerprint is a 64-bit FNV-1a hash in hex; it covers every plural
+/// form and select case.
+pub fn fingerprint(value: &Translation) -> Option<String> {
+    let canonical = match value {
+        Translation::Empty => return None,
+        Translation::Singular(text) if text.is_empty() => return None,
+        Translation::Singular(text) => format!("s\u{1f}{}", text),
+        Translation::Plural(plural) => {
+            let mut out = format!("p\u{1f}{}", plural.kind);
+            for (category, text) in &plural.forms {
+                out.push_str(&format!("\u{1f}{:?}={}", category, text));
+            }
+            out
+        }
+        Translation::Select(select) => {
+            let mut out = format!("x\u{1f}{}", select.selector);
+            for (case, text) in &select.cases {
+                out.push_str(&format!("\u{1f}{}={}", case, text));
+            }
+            out
+        }
+    };
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in canonical.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    Some(format!("{:016x}", hash))
+}
+
+/// Records that `entry` was translated from `source`.
+pub fn stamp_source_fingerprint(entry: &mut Entry, source: &Translation) {
+    match fingerprint(source) {
+        Some(source_fingerprint) => {
+            entry
+                .custom
+                .insert(SOURCE_FINGERPRINT_KEY.to_string(), source_fingerprint);
+        }
+        None => {
+            entry.custom.remove(SOURCE_FINGERPRINT_KEY);
+        }
+    }
+    match fingerprint(&entry.value) {
+        Some(translation_fingerprint) => {
+            entry.custom.insert(
+                TRANSLATION_FINGERPRINT_KEY.to_string(),
+                translation_fingerprint,
+            );
+        }
+        None => {
+            entry.custom.remove(TRANSLATION_FINGERPRINT_KEY);
+        }
+    }
+}
+
+/// Options controlling [`refresh_stale_status`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RefreshStatusOptions {
+    /// Source language; defaults to the `source_language` metadata, or the
+    /// only resource when there is just one.
+    pub source_language: Option<String>,
+    pub language_filter: Option<String>,
+    /// Record the current source for every translation that is not stale
+    /// instead of marking it stale, e.g. after reviewing minor source edits.
+    pub accept: bool,
+}
+
+/// What [`refresh_stale_status`] did to a translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshAction {
+    /// The translation had no fingerprint; the current source was recorded.
+    Tracked,
+    /// The source changed since the translation was made.
+    MarkedStale,
+    /// The source changed, but the translation was updated afterwards or the
+    /// change was accepted; the current source was recorded.
+    Restamped,
+}
+
+/// One translation whose status or fingerprint changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshStatusChange {
+    pub language: String,
+    pub key: String,
+    pub action: RefreshAction,
+    pub previous_status: EntryStatus,
+}
+
+/// Report returned by [`refresh_stale_status`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RefreshStatusReport {
+    pub source_language: String,
+    /// Translations compared with their source.
+    pub checked: usize,
+    pub up_to_date: usize,
+    pub tracked: usize,
+    pub marked_stale: usize,
+    /// Outdated translations that were already marked stale.
+    pub already_stale: usize,
+    pub restamped: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub changes: Vec<RefreshStatusChange>,
+}
+
+/// Marks translations whose source text changed since they were made as
+/// [`EntryStatus::Stale`].
+///
+/// Each translation carries the fingerprint of its source under
+/// [`SOURCE_FINGERPRINT_KEY`]. Translations without one start being tracked
+/// from the current source. When the source fingerprint no longer matches:
+/// - a translation that was edited since the last check is considered
+///   updated for the new source, restamped, and no longer stale;
+/// - otherwise it is marked stale and keeps its old fingerprint, so it stays
+///   outdated until it is re-translated.
+///
+/// Do-not-translate entries, empty translations and keys missing from the
+/// source are skipped.
+pub fn refresh_stale_status(
+    resources: &mut [Resource],
+    options: &RefreshStatusOptions,
+) -> Result<RefreshStatusReport, Error> {
+    let source_language = match &options.source_language {
+        Some(language) => language.clone(),
+        None => resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language").cloned())
+            .or_else(|| match resources {
+                [resource] => Some(resource.metadata.language.clone()),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::InvalidResource(
+                    "Cannot determine the source language; set source_language".to_string(),
+                )
+            })?,
+    };
+
+    let source_values: HashMap<String, Translation> = resources
+        .iter()
+        .filter(|res| lang_matches(&res.metadata.language, &source_language))
+        .flat_map(|res| res.entries.iter())
+        .map(|entry| (entry.id.clone(), entry.value.clone()))
+        .collect();
+    if source_values.is_empty() {
+        return Err(Error::InvalidResource(format!(
+            "Source language '{}' not found",
+            source_language
+        )));
+    }
+
+    let mut report = RefreshStatusReport {
+        source_language: source_language.clone(),
+        ..RefreshStatusReport::default()
+    };
+
+    for resource in resources.iter_mut() {
+        let language = resource.metadata.language.clone();
+        if normalize_lang(&language) == normalize_lang(&source_language) {
+            continue;
+        }
+        if let Some(filter_lang) = options.language_filter.as_deref()
+            && !lang_matches(&language, filter_lang)
+        {
+            continue;
+        }
+
+        for entry in &mut resource.entries {
+            if entry.status == EntryStatus::DoNotTranslate {
+                continue;
+            }
+            let Some(current_translation) = fingerprint(&entry.value) else {
+                continue;
+            };
+            let Some(source) = source_values.get(&entry.id) else {
+                continue;
+            };
+            let Some(current_source) = fingerprint(source) else {
+                continue;
+            };
+            report.checked += 1;
+
+            let previous_status = entry.status.clone();
+            let action = match entry.custom.get(SOURCE_FINGERPRINT_KEY) {
+                None => Some(RefreshAction::Tracked),
+                Some(stamped) if *stamped == current_source => {
+                    report.up_to_date += 1;
+                    if entry.custom.get(TRANSLATION_FINGERPRINT_KEY) != Some(&current_translation) {
+                        stamp_source_fingerprint(entry, source);
+                    }
+                    None
+                }
+                Some(_) => {
+                    let edited = entry
+                        .custom
+                        .get(TRANSLATION_FINGERPRINT_KEY)
+                        .is_some_and(|stamped| *stamped != current_translation);
+                    let accepted = options.accept && entry.status != EntryStatus::Stale;
+                    if edited || accepted {
+                        Some(RefreshAction::Restamped)
+                    } else if entry.status == EntryStatus::Stale {
+                        report.already_stale += 1;
+                        None
+                    } else {
+                        Some(RefreshAction::MarkedStale)
+                    }
+                }
+            };
+
+            let Some(action) = action else {
+                continue;
+            };
+            match action {
+                RefreshAction::Tracked => {
+                    report.tracked += 1;
+                    stamp_source_fingerprint(entry, source);
+                }
+                RefreshAction::Restamped => {
+                    report.restamped += 1;
+                    if entry.status == EntryStatus::Stale {
+                        entry.status = EntryStatus::Translated;
+                    }
+                    stamp_source_fingerprint(entry, source);
+                }
+                RefreshAction::MarkedStale => {
+                    report.marked_stale += 1;
+                    entry.status = EntryStatus::Stale;
+                    entry
+                        .custom
+                        .insert(TRANSLATION_FINGERPRINT_KEY.to_string(), current_translation);
+                }
+            }
+            report.changes.push(RefreshStatusChange {
+                language: language.clone(),
+                key: entry.id.clone(),
+                action,
+                previous_status,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+pub(crate) fn normalize_lang(lang: &str) -> String {
     lang.trim().replace('_', "-").to_ascii_lowercase()
 }
 
-fn lang_base(lang: &str) -> &str {
+pub(crate) fn lang_base(lang: &str) -> &str {
     lang.split('-').next().unwrap_or(lang)
 }
 
-fn lang_matches(resource_lang: &str, requested_lang: &str) -> bool {
+pub(crate) fn lang_matches(resource_lang: &str, requested_lang: &str) -> bool {
     let res = normalize_lang(resource_lang);
     let req = normalize_lang(requested_lang);
     res == req || lang_base(&res) == lang_base(&req)
@@ -547,7 +805,10 @@ mod tests {
 
     use crate::types::{Entry, EntryStatus, Metadata};
 
-    use super::{DiffOptions, SyncIssueKind, SyncOptions, diff_resources, sync_existing_entries};
+    use super::{
+        DiffOptions, RefreshAction, RefreshStatusOptions, SOURCE_FINGERPRINT_KEY, SyncIssueKind,
+        SyncOptions, diff_resources, fingerprint, refresh_stale_status, sync_existing_entries,
+    };
 
     fn entry(id: &str, value: &str) -> Entry {
         Entry {
@@ -679,4 +940,110 @@ mod tests {
         let encoded = serde_json::to_string(&issue).unwrap();
         assert_eq!(encoded, "\"type_mismatch\"");
     }
+
+    #[test]
+    fn test_fingerprint_ignores_empty_values_and_tracks_text() {
+        assert_eq!(fingerprint(&crate::Translation::Empty), None);
+        assert_eq!(
+            fingerprint(&crate::Translation::Singular("Hello".into())),
+            fingerprint(&crate::Translation::Singular("Hello".into()))
+        );
+        assert_ne!(
+            fingerprint(&crate::Translation::Singular("Hello".into())),
+            fingerprint(&crate::Translation::Singular("Hello!".into()))
+        );
+    }
+
+    #[test]
+    fn test_refresh_stale_status_tracks_then_marks_changed_sources() {
+        let mut resources = vec![
+            resource("en", vec![entry("hello", "Hello"), entry("bye", "Bye")]),
+            resource(
+                "fr",
+                vec![entry("hello", "Bonjour"), entry("bye", "Au revoir")],
+            ),
+        ];
+        let options = RefreshStatusOptions {
+            source_language: Some("en".to_string()),
+            ..RefreshStatusOptions::default()
+        };
+
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.tracked, 2);
+        assert!(
+            resources[1].entries[0]
+                .custom
+                .contains_key(SOURCE_FINGERPRINT_KEY)
+        );
+
+        resources[0].entries[0].value = crate::Translation::Singular("Hello there".into());
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.marked_stale, 1);
+        assert_eq!(report.up_to_date, 1);
+        assert_eq!(report.changes[0].key, "hello");
+        assert_eq!(report.changes[0].action, RefreshAction::MarkedStale);
+        assert_eq!(resources[1].entries[0].status, EntryStatus::Stale);
+        assert_eq!(resources[1].entries[1].status, EntryStatus::Translated);
+
+        // Running again does not re-report the same stale entry.
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.marked_stale, 0);
+        assert_eq!(report.already_stale, 1);
+        assert_eq!(resources[1].entries[0].status, EntryStatus::Stale);
+    }
+
+    #[test]
+    fn test_refresh_stale_status_restamps_updated_translations() {
+        let mut resources = vec![
+            resource("en", vec![entry("hello", "Hello")]),
+            resource("fr", vec![entry("hello", "Bonjour")]),
+        ];
+        let options = RefreshStatusOptions {
+            source_language: Some("en".to_string()),
+            ..RefreshStatusOptions::default()
+        };
+        refresh_stale_status(&mut resources, &options).unwrap();
+
+        resources[0].entries[0].value = crate::Translation::Singular("Hello there".into());
+        refresh_stale_status(&mut resources, &options).unwrap();
+
+        resources[1].entries[0].value = crate::Translation::Singular("Bonjour à tous".into());
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.restamped, 1);
+        assert_eq!(resources[1].entries[0].status, EntryStatus::Translated);
+
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.up_to_date, 1);
+        assert!(report.changes.is_empty());
+    }
+
+    #[test]
+    fn test_refresh_stale_status_accept_restamps_without_marking() {
+        let mut resources = vec![
+            resource("en", vec![entry("hello", "Hello")]),
+            resource("fr", vec![entry("hello", "Bonjour")]),
+        ];
+        let mut options = RefreshStatusOptions {
+            source_language: Some("en".to_string()),
+            ..RefreshStatusOptions::default()
+        };
+        refresh_stale_status(&mut resources, &options).unwrap();
+
+        resources[0].entries[0].value = crate::Translation::Singular("Hello!".into());
+        options.accept = true;
+        let report = refresh_stale_status(&mut resources, &options).unwrap();
+        assert_eq!(report.restamped, 1);
+        assert_eq!(resources[1].entries[0].status, EntryStatus::Translated);
+    }
+
+    #[test]
+    fn test_refresh_stale_status_requires_source_language() {
+        let mut resources = vec![
+            resource("en", vec![entry("hello", "Hello")]),
+            resource("fr", vec![entry("hello", "Bonjour")]),
+        ];
+        let err = refresh_stale_status(&mut resources, &RefreshStatusOptions::default());
+        assert!(err.is_err());
+    }
 }
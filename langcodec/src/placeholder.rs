@@ -4,213 +4,222 @@
 //! - Normalize common iOS vs Android placeholder variants to a canonical form.
 //! - Extract a placeholder "signature" for comparison across languages.
 //! - Validate placeholder consistency per entry (across all languages and plural forms).
+//! - Convert placeholders between printf, ICU, i18next, Ruby, .NET and Chrome syntax.
 
+mod dialect;
+mod issues;
+
+pub(crate) use dialect::register_entry_placeholder_names;
+pub use dialect::{
+    PLACEHOLDER_NAMES_KEY, PlaceholderDialect, PlaceholderNames, convert_entry_placeholders,
+    convert_placeholders, register_placeholder_names,
+};
+pub use issues::{
+    PlaceholderFix, PlaceholderIssue, PlaceholderIssueKind, collect_placeholder_issues,
+};
+pub(crate) use issues::{
+    category_label, compare as compare_placeholders, forms_of, forms_of_mut, source_text_for,
+};
+
+/// A printf-style placeholder such as `%1$-10.2lf`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlaceholderToken {
+    /// Positional index (`%2$d`), 1-based.
     pub index: Option<usize>,
+    /// Flags in source order, any of `-`, `+`, `#` and `0`.
+    pub flags: String,
+    /// Minimum field width (`%5d`).
+    pub width: Option<usize>,
+    /// Precision (`%.2f`); a bare `.` is precision zero.
+    pub precision: Option<usize>,
+    /// Length modifier: `hh`, `h`, `l`, `ll`, `q`, `L`, `z`, `t` or `j`.
+    pub length: String,
     pub kind: char, // canonical kind: s, d, f, etc.
 }
 
 impl PlaceholderToken {
+    /// Compact form used to compare placeholders, e.g. `1$-10.2lf`.
+    ///
+    /// Flags are sorted so that `%-+d` and `%+-d` compare equal, and integer
+    /// size modifiers that differ between platforms (`l`, `ll`, `q`, `z`, `t`,
+    /// `j`) are left out so that `%lld` and `%d` compare equal.
     pub fn to_signature(&self) -> String {
-        match self.index {
-            Some(i) => format!("{}${}", i, self.kind),
-            None => format!("{}", self.kind),
+        let mut out = String::new();
+        if let Some(i) = self.index {
+            out.push_str(&format!("{}$", i));
+        }
+        let mut flags: Vec<char> = self.flags.chars().collect();
+        flags.sort_unstable();
+        flags.dedup();
+        out.extend(flags);
+        if let Some(width) = self.width {
+            out.push_str(&width.to_string());
+        }
+        if let Some(precision) = self.precision {
+            out.push_str(&format!(".{}", precision));
+        }
+        let platform_size = matches!(self.kind, 'd' | 'i' | 'u' | 'o' | 'x')
+            && matches!(self.length.as_str(), "l" | "ll" | "q" | "z" | "t" | "j");
+        if !platform_size {
+            out.push_str(&self.length);
         }
+        out.push(self.kind);
+        out
     }
 }
 
-/// Extracts placeholder tokens from a string and returns them in occurrence order.
-/// Handles iOS and Android variants and ignores escaped percent `%%`.
-pub fn extract_placeholders(input: &str) -> Vec<PlaceholderToken> {
+const LENGTH_MODIFIERS: [&str; 9] = ["hh", "ll", "h", "l", "q", "L", "z", "t", "j"];
+const CONVERSIONS: &str = "diouxXeEfFgGaAcsSp@";
+
+/// A placeholder found in source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedPlaceholder {
+    pub token: PlaceholderToken,
+    /// Conversion character as written (`@`, `X`, ...).
+    pub conversion: char,
+    /// Byte length of the placeholder text.
+    pub len: usize,
+    /// Byte offset of the length modifier (or the conversion when there is none).
+    pub length_start: usize,
+}
+
+/// Parses the placeholder at the start of `input`, which must begin with `%`.
+///
+/// Returns `None` for `%%` and for text that is not a complete placeholder.
+pub(crate) fn parse_placeholder(input: &str) -> Option<ParsedPlaceholder> {
     let bytes = input.as_bytes();
-    let mut i = 0;
-    let mut out = Vec::new();
+    if bytes.first() != Some(&b'%') || bytes.get(1) == Some(&b'%') {
+        return None;
+    }
 
-    while i < bytes.len() {
-        if bytes[i] != b'%' {
-            i += 1;
-            continue;
-        }
-        // Handle escaped percent
-        if i + 1 < bytes.len() && bytes[i + 1] == b'%' {
-            i += 2;
-            continue;
+    let digits = |i: &mut usize| -> Option<usize> {
+        let start = *i;
+        while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+            *i += 1;
         }
+        input[start..*i].parse().ok()
+    };
 
-        let mut j = i + 1;
+    let mut i = 1;
+    // Optional positional index: digits followed by '$'
+    let mut index = None;
+    if let Some(n) = digits(&mut i)
+        && bytes.get(i) == Some(&b'$')
+    {
+        index = Some(n);
+        i += 1;
+    } else {
+        i = 1;
+    }
 
-        // Optional positional index: digits followed by '$'
-        let mut index: Option<usize> = None;
-        let start_digits = j;
-        while j < bytes.len() && bytes[j].is_ascii_digit() {
-            j += 1;
-        }
-        if j < bytes.len() && j > start_digits && bytes[j] == b'$' {
-            // parse digits
-            if let Some(num) = std::str::from_utf8(&bytes[start_digits..j])
-                .ok()
-                .and_then(|s| s.parse::<usize>().ok())
-            {
-                index = Some(num);
-            }
-            j += 1; // skip '$'
-        } else {
-            // reset j if not actually positional
-            j = i + 1;
-        }
+    let flags_start = i;
+    while bytes
+        .get(i)
+        .is_some_and(|b| matches!(b, b'-' | b'+' | b'#' | b'0'))
+    {
+        i += 1;
+    }
+    let flags = input[flags_start..i].to_string();
+    let width = digits(&mut i);
+    let mut precision = None;
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        precision = Some(digits(&mut i).unwrap_or(0));
+    }
 
-        // Optional length modifiers (l/ll)
-        if j < bytes.len() && bytes[j] == b'l' {
-            j += 1;
-            if j < bytes.len() && bytes[j] == b'l' {
-                j += 1;
-            }
-        }
+    let length_start = i;
+    let length = LENGTH_MODIFIERS
+        .iter()
+        .find(|modifier| input[i..].starts_with(**modifier))
+        .map_or("", |modifier| *modifier);
+    i += length.len();
 
-        // Expect a type character
-        if j < bytes.len() {
-            let ch = bytes[j] as char;
-            if ch.is_ascii_alphabetic() || ch == '@' {
-                out.push(PlaceholderToken {
-                    index,
-                    kind: canonical_kind_char(ch),
-                });
-                i = j + 1;
-                continue;
-            }
-        }
+    let conversion = *bytes.get(i)? as char;
+    if !CONVERSIONS.contains(conversion) {
+        return None;
+    }
+    Some(ParsedPlaceholder {
+        token: PlaceholderToken {
+            index,
+            flags,
+            width,
+            precision,
+            length: length.to_string(),
+            kind: canonical_kind_char(conversion),
+        },
+        conversion,
+        len: i + 1,
+        length_start,
+    })
+}
 
-        // Not a recognized placeholder; skip this '%'
-        i += 1;
+/// Walks `input`, passing each placeholder and the text between them to `emit`.
+/// Escaped percents (`%%`) are passed through as text.
+fn rewrite_placeholders(
+    input: &str,
+    mut emit: impl FnMut(&ParsedPlaceholder, &str) -> String,
+) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find('%') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("%%") {
+            out.push_str("%%");
+            rest = &rest[2..];
+        } else if let Some(parsed) = parse_placeholder(rest) {
+            out.push_str(&emit(&parsed, &rest[..parsed.len]));
+            rest = &rest[parsed.len..];
+        } else {
+            out.push('%');
+            rest = &rest[1..];
+        }
     }
+    out.push_str(rest);
+    out
+}
 
+/// Extracts placeholder tokens from a string and returns them in occurrence order.
+/// Handles iOS and Android variants, including flags, width, precision and
+/// length modifiers, and ignores escaped percent `%%`.
+pub fn extract_placeholders(input: &str) -> Vec<PlaceholderToken> {
+    let mut out = Vec::new();
+    rewrite_placeholders(input, |parsed, _| {
+        out.push(parsed.token.clone());
+        String::new()
+    });
     out
 }
 
 /// Normalize a string by converting iOS-specific tokens to canonical ones.
 /// - %@  -> %s
 /// - %1$@ -> %1$s
-/// - %ld, %lu -> %d / %u
+///
+/// Everything else is kept as written, including flags, width, precision and
+/// length modifiers (`%-10@` -> `%-10s`, `%lld` stays `%lld`).
 pub fn normalize_placeholders(input: &str) -> String {
-    // Replace positional iOS object placeholders %<n>$@ -> %<n>$s
-    let bytes = input.as_bytes();
-    let mut i = 0;
-    let mut tmp = String::with_capacity(input.len());
-    while i < bytes.len() {
-        if bytes[i] == b'%' {
-            let mut j = i + 1;
-            let start_digits = j;
-            while j < bytes.len() && bytes[j].is_ascii_digit() {
-                j += 1;
-            }
-            if j > start_digits && j + 1 < bytes.len() && bytes[j] == b'$' && bytes[j + 1] == b'@' {
-                // Copy prefix, then normalized token
-                tmp.push('%');
-                tmp.push_str(&input[start_digits..j]); // digits
-                tmp.push('$');
-                tmp.push('s');
-                i = j + 2;
-                continue;
-            }
+    rewrite_placeholders(input, |parsed, text| {
+        if parsed.conversion == '@' {
+            format!("{}{}s", &text[..parsed.length_start], parsed.token.length)
+        } else {
+            text.to_string()
         }
-        // Copy the next full UTF-8 character, not just one byte
-        let ch = input[i..]
-            .chars()
-            .next()
-            .expect("valid UTF-8 slicing while scanning placeholders");
-        tmp.push(ch);
-        i += ch.len_utf8();
-    }
-
-    // Simple iOS object -> string
-    let out = tmp.replace("%@", "%s");
-    // Long ints to canonical
-    let out = out.replace("%ld", "%d");
-
-    out.replace("%lu", "%u")
+    })
 }
 
 /// Convert canonical/Android-style string placeholders to iOS-style.
 /// - %s   -> %@
 /// - %1$s -> %1$@
-///   Leaves numeric specifiers (e.g., %d, %u, %ld) unchanged.
+///   Flags, width and precision are kept (`%-10s` -> `%-10@`); numeric
+///   specifiers (e.g., %d, %u, %ld) and wide strings (`%ls`) are left unchanged.
 pub fn to_ios_placeholders(input: &str) -> String {
-    let bytes = input.as_bytes();
-    let mut i = 0usize;
-    let mut out = String::with_capacity(input.len());
-    while i < bytes.len() {
-        if bytes[i] != b'%' {
-            // Copy the next full UTF-8 character
-            let ch = input[i..]
-                .chars()
-                .next()
-                .expect("valid UTF-8 slicing while converting placeholders");
-            out.push(ch);
-            i += ch.len_utf8();
-            continue;
-        }
-        // Escaped percent '%%'
-        if i + 1 < bytes.len() && bytes[i + 1] == b'%' {
-            out.push('%');
-            out.push('%');
-            i += 2;
-            continue;
-        }
-
-        // Examine potential placeholder
-        let mut j = i + 1;
-        // Optional positional index digits+$
-        let start_digits = j;
-        while j < bytes.len() && bytes[j].is_ascii_digit() {
-            j += 1;
-        }
-        let mut had_positional = false;
-        if j > start_digits && j < bytes.len() && bytes[j] == b'$' {
-            had_positional = true;
-            j += 1; // skip '$'
+    rewrite_placeholders(input, |parsed, text| {
+        if parsed.conversion == 's' && parsed.token.length.is_empty() {
+            format!("{}@", &text[..parsed.length_start])
         } else {
-            // reset if not positional
-            j = i + 1;
-        }
-
-        // Optional length modifiers (l/ll). We will drop them when converting %s -> %@.
-        let mut k = j;
-        while k < bytes.len() && bytes[k] == b'l' {
-            k += 1;
-        }
-        if k >= bytes.len() {
-            // not a complete placeholder, copy '%' and advance
-            out.push('%');
-            i += 1;
-            continue;
+            text.to_string()
         }
-
-        let ty = bytes[k] as char;
-        if ty == 's' {
-            // Emit converted iOS placeholder
-            out.push('%');
-            if had_positional {
-                // copy the digits we saw
-                out.push_str(
-                    &input[start_digits..(if start_digits < j {
-                        j - 1
-                    } else {
-                        start_digits
-                    })],
-                );
-                out.push('$');
-            }
-            out.push('@');
-            i = k + 1;
-            continue;
-        }
-
-        // Not a string placeholder, emit '%' and advance one byte; the rest will be handled in next iterations
-        out.push('%');
-        i += 1;
-    }
-    out
+    })
 }
 
 /// Build a normalized signature (sequence of tokens) for comparison.
@@ -248,7 +257,7 @@ mod tests {
         let s = "Value: %@ and number %ld";
         let n = normalize_placeholders(s);
         assert!(n.contains("%s"));
-        assert!(n.contains("%d"));
+        assert!(n.contains("%ld"));
         assert_eq!(signature(s), vec!["s", "d"]);
     }
 
@@ -259,6 +268,41 @@ mod tests {
         assert!(n.contains("%1$s"));
     }
 
+    #[test]
+    fn test_flags_width_precision_and_length() {
+        let tokens = extract_placeholders("%.2f %5d %-10s %+d %03d %1$.1f %zd %#x %hhu");
+        let sigs: Vec<String> = tokens.iter().map(|t| t.to_signature()).collect();
+        assert_eq!(
+            sigs,
+            vec![".2f", "5d", "-10s", "+d", "03d", "1$.1f", "d", "#x", "hhu"]
+        );
+        assert_eq!(tokens[0].precision, Some(2));
+        assert_eq!(tokens[2].flags, "-");
+        assert_eq!(tokens[2].width, Some(10));
+        assert_eq!(tokens[6].length, "z");
+
+        // Different precision is a real mismatch; platform integer sizes are not.
+        assert_ne!(signature("%.1f"), signature("%.2f"));
+        assert_eq!(signature("%1$lld / %2$-5@"), signature("%1$d / %2$-5s"));
+        assert_eq!(signature("%-+d"), signature("%+-d"));
+        assert_eq!(signature("50% off, 100%"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_conversions_keep_flags_width_and_precision() {
+        let ios = "%1$-10@ %2$.1f %3$05ld %.3@ %%";
+        let normalized = normalize_placeholders(ios);
+        assert_eq!(normalized, "%1$-10s %2$.1f %3$05ld %.3s %%");
+        assert_eq!(to_ios_placeholders(&normalized), ios);
+        assert_eq!(normalize_placeholders("%zd of %llu"), "%zd of %llu");
+    }
+
+    #[test]
+    fn test_to_ios_keeps_wide_strings() {
+        assert_eq!(to_ios_placeholders("%ls and %5s"), "%ls and %5@");
+        assert_eq!(to_ios_placeholders("%1$ls"), "%1$ls");
+    }
+
     #[test]
     fn test_ignore_escaped_percent() {
         let s = "Discount: 50%% and value %d";
@@ -0,0 +1,900 @@
+//! Placeholder syntaxes and conversion between them.
+//!
+//! Mobile formats use printf placeholders (`%1$@`, `%d`) while web stacks
+//! use named ones (`{name}`, `{{name}}`, `%{name}`, `$NAME$`). Converting a
+//! message between the two needs to know which position a name stands for;
+//! that mapping is kept in a [`PlaceholderNames`] table, stored in
+//! `Entry.custom` under [`PLACEHOLDER_NAMES_KEY`] so that a later conversion
+//! back restores the original names and printf conversions.
+//!
+//! Only simple ICU arguments are converted. Complex ICU arguments (`plural`,
+//! `select`, `selectordinal`) are copied unchanged.
+
+use std::{fmt, str::FromStr};
+
+use crate::types::{Entry, Translation};
+
+use super::parse_placeholder;
+
+/// `Entry.custom` key holding the serialized [`PlaceholderNames`] of an entry.
+pub const PLACEHOLDER_NAMES_KEY: &str = "langcodec.placeholder_names";
+
+/// A placeholder syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaceholderDialect {
+    /// printf as used by Apple and Android: `%@`, `%1$s`, `%.2f`.
+    Printf,
+    /// ICU MessageFormat arguments: `{name}`, `{0}`.
+    Icu,
+    /// i18next interpolation: `{{name}}`.
+    I18next,
+    /// Ruby i18n interpolation: `%{name}`, `%<name>.2f`.
+    Ruby,
+    /// .NET and Java composite formatting: `{0}`, `{0:N2}`.
+    DotNet,
+    /// Chrome extension messages: `$NAME$`, `$1`.
+    Chrome,
+}
+
+impl PlaceholderDialect {
+    /// All dialects, in declaration order.
+    pub const ALL: [PlaceholderDialect; 6] = [
+        Self::Printf,
+        Self::Icu,
+        Self::I18next,
+        Self::Ruby,
+        Self::DotNet,
+        Self::Chrome,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Printf => "printf",
+            Self::Icu => "icu",
+            Self::I18next => "i18next",
+            Self::Ruby => "ruby",
+            Self::DotNet => "dotnet",
+            Self::Chrome => "chrome",
+        }
+    }
+}
+
+impl fmt::Display for PlaceholderDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PlaceholderDialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "printf" | "apple" | "ios" | "android" => Ok(Self::Printf),
+            "icu" => Ok(Self::Icu),
+            "i18next" => Ok(Self::I18next),
+            "ruby" | "rails" => Ok(Self::Ruby),
+            "dotnet" | ".net" | "java" => Ok(Self::DotNet),
+            "chrome" => Ok(Self::Chrome),
+            other => Err(format!(
+                "Unknown placeholder dialect '{}'. Expected one of: printf, icu, i18next, ruby, dotnet, chrome",
+                other
+            )),
+        }
+    }
+}
+
+/// One slot of a [`PlaceholderNames`] table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Slot {
+    name: Option<String>,
+    /// printf conversion without `%` and position, e.g. `.2f` or `@`.
+    spec: Option<String>,
+}
+
+/// Maps argument positions (1-based) to names and printf conversions.
+///
+/// Serialized as a comma-separated list in position order, each item being
+/// `name`, `name:spec` or `:spec`, e.g. `count:lld,user:@`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlaceholderNames {
+    slots: Vec<Slot>,
+}
+
+impl PlaceholderNames {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a table from names in position order.
+    pub fn from_names<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            slots: names
+                .into_iter()
+                .map(|name| Slot {
+                    name: Some(name.into()),
+                    spec: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Reads the table stored on `entry`, if any.
+    pub fn from_entry(entry: &Entry) -> Option<Self> {
+        entry
+            .custom
+            .get(PLACEHOLDER_NAMES_KEY)
+            .map(|value| Self::parse(value))
+    }
+
+    /// Stores the table on `entry`; an empty table removes the key.
+    pub fn store(&self, entry: &mut Entry) {
+        if self.is_empty() {
+            entry.custom.remove(PLACEHOLDER_NAMES_KEY);
+        } else {
+            entry
+                .custom
+                .insert(PLACEHOLDER_NAMES_KEY.to_string(), self.to_string());
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        let slots = value
+            .split(',')
+            .map(|item| {
+                let (name, spec) = match item.split_once(':') {
+                    Some((name, spec)) => (name.trim(), Some(spec.trim())),
+                    None => (item.trim(), None),
+                };
+                Slot {
+                    name: (!name.is_empty()).then(|| name.to_string()),
+                    spec: spec.filter(|s| !s.is_empty()).map(str::to_string),
+                }
+            })
+            .collect();
+        Self { slots }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Name of the argument at `position` (1-based).
+    pub fn name_of(&self, position: usize) -> Option<&str> {
+        self.slot(position)?.name.as_deref()
+    }
+
+    /// printf conversion recorded for `position` (1-based), e.g. `lld`.
+    pub fn spec_of(&self, position: usize) -> Option<&str> {
+        self.slot(position)?.spec.as_deref()
+    }
+
+    /// Position (1-based) of `name`. An exact match wins over a
+    /// case-insensitive one, since Chrome names ignore case.
+    pub fn position_of(&self, name: &str) -> Option<usize> {
+        let exact = self
+            .slots
+            .iter()
+            .position(|slot| slot.name.as_deref() == Some(name));
+        exact
+            .or_else(|| {
+                self.slots.iter().position(|slot| {
+                    slot.name
+                        .as_deref()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                })
+            })
+            .map(|i| i + 1)
+    }
+
+    fn slot(&self, position: usize) -> Option<&Slot> {
+        self.slots.get(position.checked_sub(1)?)
+    }
+
+    fn slot_mut(&mut self, position: usize) -> &mut Slot {
+        if self.slots.len() < position {
+            self.slots.resize_with(position, Slot::default);
+        }
+        &mut self.slots[position - 1]
+    }
+
+    /// Records `arg` and returns its position.
+    fn register(&mut self, arg: &Arg) -> usize {
+        let position = match (&arg.position, &arg.name) {
+            (Some(position), _) => *position,
+            (None, Some(name)) => match self.position_of(name) {
+                Some(position) => position,
+                None => self.slots.len() + 1,
+            },
+            (None, None) => self.slots.len() + 1,
+        };
+        let slot = self.slot_mut(position);
+        if slot.name.is_none() {
+            slot.name = arg.name.clone();
+        }
+        if slot.spec.is_none() {
+            slot.spec = arg.spec.clone();
+        }
+        position
+    }
+
+    /// Name for `position`, inventing `argN` for dialects that cannot refer
+    /// to arguments by position.
+    fn name_or_generate(&mut self, position: usize) -> String {
+        if let Some(name) = self.name_of(position) {
+            return name.to_string();
+        }
+        let mut name = format!("arg{}", position);
+        let mut suffix = 1;
+        while self.position_of(&name).is_some() {
+            suffix += 1;
+            name = format!("arg{}_{}", position, suffix);
+        }
+        self.slot_mut(position).name = Some(name.clone());
+        name
+    }
+}
+
+impl fmt::Display for PlaceholderNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, slot) in self.slots.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if let Some(name) = &slot.name {
+                f.write_str(name)?;
+            }
+            if let Some(spec) = &slot.spec {
+                write!(f, ":{}", spec)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Highest argument position accepted from source text. Larger positions,
+/// and position 0, are kept as literal text rather than sizing the name table.
+const MAX_POSITION: usize = 99;
+
+/// Returns `position` if it is a usable 1-based argument position.
+fn checked_position(position: usize) -> Option<usize> {
+    (1..=MAX_POSITION).contains(&position).then_some(position)
+}
+
+/// An argument reference found in a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Arg {
+    name: Option<String>,
+    /// 1-based position.
+    position: Option<usize>,
+    spec: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Literal text, unescaped.
+    Text(String),
+    /// Dialect-specific text copied as is, such as a complex ICU argument.
+    Raw(String),
+    Arg(Arg),
+}
+
+fn push_text(segments: &mut Vec<Segment>, text: &str) {
+    if let Some(Segment::Text(last)) = segments.last_mut() {
+        last.push_str(text);
+    } else {
+        segments.push(Segment::Text(text.to_string()));
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn tokenize(input: &str, dialect: PlaceholderDialect) -> Vec<Segment> {
+    match dialect {
+        PlaceholderDialect::Printf => tokenize_printf(input),
+        PlaceholderDialect::Icu => tokenize_icu(input),
+        PlaceholderDialect::I18next => tokenize_i18next(input),
+        PlaceholderDialect::Ruby => tokenize_ruby(input),
+        PlaceholderDialect::DotNet => tokenize_dotnet(input),
+        PlaceholderDialect::Chrome => tokenize_chrome(input),
+    }
+}
+
+fn tokenize_printf(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut next_position = 1;
+    let mut rest = input;
+    while let Some(pos) = rest.find('%') {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("%%") {
+            push_text(&mut segments, "%");
+            rest = &rest[2..];
+        } else if let Some(parsed) = parse_placeholder(rest) {
+            let Some(position) = checked_position(parsed.token.index.unwrap_or(next_position))
+            else {
+                push_text(&mut segments, &rest[..parsed.len]);
+                rest = &rest[parsed.len..];
+                continue;
+            };
+            next_position = position + 1;
+            let spec_start = rest[1..parsed.len].find('$').map_or(1, |i| i + 2);
+            let spec = &rest[spec_start..parsed.len];
+            segments.push(Segment::Arg(Arg {
+                name: None,
+                position: Some(position),
+                // `s` is what named arguments become anyway.
+                spec: (spec != "s").then(|| spec.to_string()),
+            }));
+            rest = &rest[parsed.len..];
+        } else {
+            push_text(&mut segments, "%");
+            rest = &rest[1..];
+        }
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+/// Returns the byte length of the balanced `{...}` group at the start of `input`.
+fn balanced_group_len(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, ch) in input.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tokenize_icu(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find(['\'', '{']) {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("''") {
+            push_text(&mut segments, "'");
+            rest = &rest[2..];
+        } else if rest.starts_with("'{") || rest.starts_with("'}") {
+            // Quoted literal up to the next lone apostrophe.
+            let body = &rest[1..];
+            let end = body.find('\'').unwrap_or(body.len());
+            push_text(&mut segments, &body[..end].replace("''", "'"));
+            rest = &body[(end + 1).min(body.len())..];
+        } else if rest.starts_with('\'') {
+            push_text(&mut segments, "'");
+            rest = &rest[1..];
+        } else {
+            let Some(len) = balanced_group_len(rest) else {
+                push_text(&mut segments, rest);
+                rest = "";
+                break;
+            };
+            let inner = &rest[1..len - 1];
+            let mut parts = inner.splitn(3, ',').map(str::trim);
+            let name = parts.next().unwrap_or_default();
+            let kind = parts.next();
+            let complex = matches!(kind, Some("plural" | "select" | "selectordinal"));
+            if let Some(arg) = icu_arg(name).filter(|_| !complex) {
+                segments.push(Segment::Arg(arg));
+            } else {
+                segments.push(Segment::Raw(rest[..len].to_string()));
+            }
+            rest = &rest[len..];
+        }
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+/// ICU numbers are 0-based argument indexes. Returns `None` for names that
+/// are not identifiers and for indexes out of range.
+fn icu_arg(name: &str) -> Option<Arg> {
+    if !is_identifier(name) {
+        return None;
+    }
+    if name.bytes().all(|b| b.is_ascii_digit()) {
+        let index = name.parse::<usize>().ok()?;
+        return Some(Arg {
+            name: None,
+            position: Some(checked_position(index.checked_add(1)?)?),
+            spec: None,
+        });
+    }
+    Some(Arg {
+        name: Some(name.to_string()),
+        position: None,
+        spec: None,
+    })
+}
+
+fn tokenize_i18next(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find("{{") {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        let Some(end) = rest.find("}}") else {
+            break;
+        };
+        let inner = rest[2..end].trim();
+        // `{{- name}}` disables escaping and `{{name, format}}` formats the value.
+        let name = inner.strip_prefix('-').unwrap_or(inner).trim();
+        let name = name.split(',').next().unwrap_or_default().trim();
+        if is_identifier(name) {
+            segments.push(Segment::Arg(Arg {
+                name: Some(name.to_string()),
+                position: None,
+                spec: None,
+            }));
+        } else {
+            push_text(&mut segments, &rest[..end + 2]);
+        }
+        rest = &rest[end + 2..];
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+fn tokenize_ruby(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find('%') {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("%%") {
+            push_text(&mut segments, "%");
+            rest = &rest[2..];
+            continue;
+        }
+        let parsed = match rest.as_bytes().get(1) {
+            Some(b'{') => rest.find('}').map(|end| (&rest[2..end], None, end + 1)),
+            Some(b'<') => rest.find('>').and_then(|end| {
+                // `%<name>.2f` carries a printf conversion after the name.
+                let conversion = parse_placeholder(&format!("%{}", &rest[end + 1..]))?;
+                let spec_len = conversion.len - 1;
+                Some((
+                    &rest[2..end],
+                    Some(rest[end + 1..end + 1 + spec_len].to_string()),
+                    end + 1 + spec_len,
+                ))
+            }),
+            _ => None,
+        };
+        match parsed {
+            Some((name, spec, len)) if is_identifier(name) => {
+                segments.push(Segment::Arg(Arg {
+                    name: Some(name.to_string()),
+                    position: None,
+                    spec,
+                }));
+                rest = &rest[len..];
+            }
+            _ => {
+                push_text(&mut segments, "%");
+                rest = &rest[1..];
+            }
+        }
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+fn tokenize_dotnet(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find(['{', '}']) {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            push_text(&mut segments, &rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let item = rest
+            .starts_with('{')
+            .then(|| rest.find('}'))
+            .flatten()
+            .and_then(|end| {
+                // `{index[,alignment][:format]}`
+                let inner = &rest[1..end];
+                let index = inner
+                    .split([',', ':'])
+                    .next()?
+                    .trim()
+                    .parse::<usize>()
+                    .ok()?;
+                Some((checked_position(index.checked_add(1)?)?, end + 1))
+            });
+        match item {
+            Some((position, len)) => {
+                segments.push(Segment::Arg(Arg {
+                    name: None,
+                    position: Some(position),
+                    spec: None,
+                }));
+                rest = &rest[len..];
+            }
+            None => {
+                push_text(&mut segments, &rest[..1]);
+                rest = &rest[1..];
+            }
+        }
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+fn tokenize_chrome(input: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find('$') {
+        push_text(&mut segments, &rest[..pos]);
+        rest = &rest[pos..];
+        let after = &rest[1..];
+        if after.starts_with('$') {
+            push_text(&mut segments, "$");
+            rest = &rest[2..];
+        } else if let Some(digit) = after.chars().next().filter(|c| matches!(c, '1'..='9')) {
+            segments.push(Segment::Arg(Arg {
+                name: None,
+                position: digit.to_digit(10).map(|d| d as usize),
+                spec: None,
+            }));
+            rest = &rest[2..];
+        } else if let Some(end) = after.find('$')
+            && !after[..end].is_empty()
+            && after[..end]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '@'))
+        {
+            segments.push(Segment::Arg(Arg {
+                name: Some(after[..end].to_string()),
+                position: None,
+                spec: None,
+            }));
+            rest = &after[end + 1..];
+        } else {
+            push_text(&mut segments, "$");
+            rest = after;
+        }
+    }
+    push_text(&mut segments, rest);
+    segments
+}
+
+fn escape_text(text: &str, dialect: PlaceholderDialect) -> String {
+    match dialect {
+        PlaceholderDialect::Printf | PlaceholderDialect::Ruby => text.replace('%', "%%"),
+        PlaceholderDialect::DotNet => text.replace('{', "{{").replace('}', "}}"),
+        PlaceholderDialect::Chrome => text.replace('$', "$$"),
+        PlaceholderDialect::I18next => text.to_string(),
+        PlaceholderDialect::Icu => {
+            let mut out = String::with_capacity(text.len());
+            let mut chars = text.chars().peekable();
+            while let Some(ch) = chars.next() {
+                match ch {
+                    '{' | '}' => {
+                        out.push('\'');
+                        out.push(ch);
+                        out.push('\'');
+                    }
+                    // A lone apostrophe is literal unless it precedes syntax.
+                    '\'' if matches!(chars.peek(), None | Some('{' | '}' | '\'')) => {
+                        out.push_str("''")
+                    }
+                    _ => out.push(ch),
+                }
+            }
+            out
+        }
+    }
+}
+
+fn render_arg(
+    position: usize,
+    dialect: PlaceholderDialect,
+    names: &mut PlaceholderNames,
+) -> String {
+    match dialect {
+        PlaceholderDialect::Printf => {
+            format!("%{}${}", position, names.spec_of(position).unwrap_or("s"))
+        }
+        PlaceholderDialect::Icu => match names.name_of(position) {
+            Some(name) => format!("{{{}}}", name),
+            None => format!("{{{}}}", position - 1),
+        },
+        PlaceholderDialect::I18next => format!("{{{{{}}}}}", names.name_or_generate(position)),
+        PlaceholderDialect::Ruby => {
+            let name = names.name_or_generate(position);
+            match names.spec_of(position) {
+                Some(spec) if !matches!(spec, "@" | "s") => format!("%<{}>{}", name, spec),
+                _ => format!("%{{{}}}", name),
+            }
+        }
+        PlaceholderDialect::DotNet => format!("{{{}}}", position - 1),
+        PlaceholderDialect::Chrome => match names.name_of(position) {
+            Some(name) => format!("${}$", name),
+            None if position <= 9 => format!("${}", position),
+            None => format!("${}$", names.name_or_generate(position)),
+        },
+    }
+}
+
+/// Records every argument of `input` in `names` without rewriting it.
+///
+/// Register all translations of a message before converting any of them, so
+/// that positions follow the source language rather than each translation's
+/// word order.
+pub fn register_placeholder_names(
+    input: &str,
+    dialect: PlaceholderDialect,
+    names: &mut PlaceholderNames,
+) {
+    for segment in tokenize(input, dialect) {
+        if let Segment::Arg(arg) = segment {
+            names.register(&arg);
+        }
+    }
+}
+
+/// Rewrites the placeholders of `input` from one dialect to another.
+///
+/// Named arguments are mapped to positions through `names`; arguments not in
+/// the table yet are appended in order of appearance. Dialects that can only
+/// refer to arguments by name get `argN` for unnamed positions, which is
+/// recorded in the table too.
+///
+/// ```rust
+/// use langcodec::placeholder::{PlaceholderDialect, PlaceholderNames, convert_placeholders};
+///
+/// let mut names = PlaceholderNames::new();
+/// let printf = convert_placeholders(
+///     "{{user}} has {{count}} files",
+///     PlaceholderDialect::I18next,
+///     PlaceholderDialect::Printf,
+///     &mut names,
+/// );
+/// assert_eq!(printf, "%1$s has %2$s files");
+/// assert_eq!(names.to_string(), "user,count");
+///
+/// let ruby = convert_placeholders("%2$s: %1$s", PlaceholderDialect::Printf, PlaceholderDialect::Ruby, &mut names);
+/// assert_eq!(ruby, "%{count}: %{user}");
+/// ```
+pub fn convert_placeholders(
+    input: &str,
+    from: PlaceholderDialect,
+    to: PlaceholderDialect,
+    names: &mut PlaceholderNames,
+) -> String {
+    let segments = tokenize(input, from);
+    if from == to {
+        for segment in &segments {
+            if let Segment::Arg(arg) = segment {
+                names.register(arg);
+            }
+        }
+        return input.to_string();
+    }
+
+    let mut out = String::with_capacity(input.len());
+    for segment in &segments {
+        match segment {
+            Segment::Text(text) => out.push_str(&escape_text(text, to)),
+            Segment::Raw(raw) => out.push_str(raw),
+            Segment::Arg(arg) => {
+                let position = names.register(arg);
+                out.push_str(&render_arg(position, to, names));
+            }
+        }
+    }
+    out
+}
+
+/// Rewrites every value of `entry` (all plural forms and select cases) and
+/// stores the updated naming table on it.
+///
+/// Returns `true` when any value changed.
+pub fn convert_entry_placeholders(
+    entry: &mut Entry,
+    from: PlaceholderDialect,
+    to: PlaceholderDialect,
+    names: &mut PlaceholderNames,
+) -> bool {
+    let mut changed = false;
+    let mut convert = |value: &mut String| {
+        let converted = convert_placeholders(value, from, to, names);
+        if converted != *value {
+            *value = converted;
+            changed = true;
+        }
+    };
+    match &mut entry.value {
+        Translation::Empty => {}
+        Translation::Singular(value) => convert(value),
+        Translation::Plural(plural) => plural.forms.values_mut().for_each(convert),
+        Translation::Select(select) => select.cases.values_mut().for_each(convert),
+    }
+    names.store(entry);
+    changed
+}
+
+/// Registers every value of `entry` in `names`; see [`register_placeholder_names`].
+pub(crate) fn register_entry_placeholder_names(
+    entry: &Entry,
+    dialect: PlaceholderDialect,
+    names: &mut PlaceholderNames,
+) {
+    match &entry.value {
+        Translation::Empty => {}
+        Translation::Singular(value) => register_placeholder_names(value, dialect, names),
+        Translation::Plural(plural) => plural
+            .forms
+            .values()
+            .for_each(|value| register_placeholder_names(value, dialect, names)),
+        Translation::Select(select) => select
+            .cases
+            .values()
+            .for_each(|value| register_placeholder_names(value, dialect, names)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EntryStatus;
+    use PlaceholderDialect::*;
+
+    fn convert(input: &str, from: PlaceholderDialect, to: PlaceholderDialect) -> String {
+        convert_placeholders(input, from, to, &mut PlaceholderNames::new())
+    }
+
+    #[test]
+    fn test_dialect_from_str_and_display() {
+        for dialect in PlaceholderDialect::ALL {
+            assert_eq!(
+                dialect.to_string().parse::<PlaceholderDialect>(),
+                Ok(dialect)
+            );
+        }
+        assert_eq!("Android".parse::<PlaceholderDialect>(), Ok(Printf));
+        assert!("gettext".parse::<PlaceholderDialect>().is_err());
+    }
+
+    #[test]
+    fn test_named_to_printf_and_back() {
+        let mut names = PlaceholderNames::new();
+        let printf = convert_placeholders("Hi {name}, 100% done", Icu, Printf, &mut names);
+        assert_eq!(printf, "Hi %1$s, 100%% done");
+
+        let icu = convert_placeholders(&printf, Printf, Icu, &mut names);
+        assert_eq!(icu, "Hi {name}, 100% done");
+    }
+
+    #[test]
+    fn test_printf_specs_survive_round_trip() {
+        let mut names = PlaceholderNames::new();
+        let chrome = convert_placeholders("%@ has %lld items", Printf, Chrome, &mut names);
+        assert_eq!(chrome, "$1 has $2 items");
+        assert_eq!(names.to_string(), ":@,:lld");
+
+        let printf = convert_placeholders(&chrome, Chrome, Printf, &mut names);
+        assert_eq!(printf, "%1$@ has %2$lld items");
+    }
+
+    #[test]
+    fn test_positional_to_name_only_dialect_generates_names() {
+        let mut names = PlaceholderNames::new();
+        let i18n = convert_placeholders("{0} and {1}", DotNet, I18next, &mut names);
+        assert_eq!(i18n, "{{arg1}} and {{arg2}}");
+        assert_eq!(names.name_of(2), Some("arg2"));
+    }
+
+    #[test]
+    fn test_ruby_format_specs() {
+        let mut names = PlaceholderNames::new();
+        let printf = convert_placeholders("%<price>.2f for %{item}", Ruby, Printf, &mut names);
+        assert_eq!(printf, "%1$.2f for %2$s");
+        assert_eq!(
+            convert_placeholders(&printf, Printf, Ruby, &mut names),
+            "%<price>.2f for %{item}"
+        );
+    }
+
+    #[test]
+    fn test_position_zero_is_literal() {
+        assert_eq!(convert("%0$d items", Printf, Icu), "%0$d items");
+        assert_eq!(convert("%0$d items", Printf, Chrome), "%0$$d items");
+    }
+
+    #[test]
+    fn test_out_of_range_positions_are_literal() {
+        let mut names = PlaceholderNames::new();
+        let icu = convert_placeholders("%99999999$d and %1$d", Printf, Icu, &mut names);
+        assert_eq!(icu, "%99999999$d and {0}");
+        assert_eq!(
+            convert("{99999999999} {0}", DotNet, Printf),
+            "{99999999999} %1$s"
+        );
+        assert_eq!(
+            convert("{18446744073709551615} {0}", Icu, DotNet),
+            "{18446744073709551615} {0}"
+        );
+        assert!(names.name_of(2).is_none());
+    }
+
+    #[test]
+    fn test_escapes_are_translated() {
+        assert_eq!(convert("{0} {{literal}}", DotNet, Icu), "{0} '{'literal'}'");
+        assert_eq!(convert("'{'x'}' {0}, it's", Icu, DotNet), "{{x}} {0}, it's");
+        assert_eq!(convert("$$5 for $ITEM$", Chrome, Ruby), "$5 for %{ITEM}");
+        assert_eq!(
+            convert("{{- html}} and {{n, number}}", I18next, Icu),
+            "{html} and {n}"
+        );
+    }
+
+    #[test]
+    fn test_complex_icu_arguments_are_kept() {
+        let input = "{count, plural, one {# file} other {# files}} by {user}";
+        assert_eq!(
+            convert(input, Icu, Printf),
+            "{count, plural, one {# file} other {# files}} by %1$s"
+        );
+    }
+
+    #[test]
+    fn test_chrome_names_match_case_insensitively() {
+        let mut names = PlaceholderNames::from_names(["userName"]);
+        assert_eq!(
+            convert_placeholders("Hello $USERNAME$", Chrome, Icu, &mut names),
+            "Hello {userName}"
+        );
+    }
+
+    #[test]
+    fn test_entry_conversion_stores_table() {
+        let mut entry = Entry {
+            id: "greeting".into(),
+            value: Translation::Singular("{{user}} sent {{count}}".into()),
+            comment: None,
+            status: EntryStatus::Translated,
+            custom: Default::default(),
+        };
+        let mut names = PlaceholderNames::new();
+        assert!(convert_entry_placeholders(
+            &mut entry, I18next, Printf, &mut names
+        ));
+        assert_eq!(entry.value, Translation::Singular("%1$s sent %2$s".into()));
+        assert_eq!(entry.custom[PLACEHOLDER_NAMES_KEY], "user,count");
+
+        let mut names = PlaceholderNames::from_entry(&entry).unwrap();
+        convert_entry_placeholders(&mut entry, Printf, I18next, &mut names);
+        assert_eq!(
+            entry.value,
+            Translation::Singular("{{user}} sent {{count}}".into())
+        );
+        assert_eq!(entry.custom[PLACEHOLDER_NAMES_KEY], "user,count");
+    }
+}
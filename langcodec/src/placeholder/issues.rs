@@ -0,0 +1,443 @@
+//! Structured placeholder validation.
+//!
+//! Every translated form is compared with the matching source form: the same
+//! plural category or select case, falling back to `other` and then to a
+//! singular source value. Placeholders are matched by argument position, so
+//! `%1$@` and `%s` agree while `%d` and `%s` at the same position do not.
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PluralCategory, Resource, Translation};
+
+use super::{
+    PlaceholderToken, extract_placeholders, normalize_placeholders, rewrite_placeholders, signature,
+};
+
+/// What is wrong with the placeholders of a translated form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceholderIssueKind {
+    /// The source has placeholders the translation lacks.
+    Missing,
+    /// The translation has placeholders the source does not.
+    Extra,
+    /// The same argument is formatted differently, e.g. `%d` vs `%s`.
+    TypeMismatch,
+    /// The translation reorders arguments without `%1$`-style positions, so
+    /// they would be filled in the wrong order.
+    ReorderedWithoutPositions,
+}
+
+impl fmt::Display for PlaceholderIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Missing => "missing placeholders",
+            Self::Extra => "extra placeholders",
+            Self::TypeMismatch => "placeholder type mismatch",
+            Self::ReorderedWithoutPositions => "placeholders reordered without positions",
+        })
+    }
+}
+
+/// A suggested correction for a [`PlaceholderIssue`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaceholderFix {
+    /// Human-readable summary of the change.
+    pub description: String,
+    /// The corrected text of the affected form.
+    pub value: String,
+}
+
+/// A placeholder problem in one form of a translated entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaceholderIssue {
+    pub key: String,
+    pub language: String,
+    /// Plural category of the affected form, for plural entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plural_category: Option<PluralCategory>,
+    /// Select case of the affected form, for select entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select_case: Option<String>,
+    pub kind: PlaceholderIssueKind,
+    /// Placeholder signature of the source form.
+    pub expected: Vec<String>,
+    /// Placeholder signature of the translated form.
+    pub actual: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<PlaceholderFix>,
+}
+
+impl fmt::Display for PlaceholderIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lang='{}' key='{}'", self.language, self.key)?;
+        if let Some(category) = &self.plural_category {
+            write!(f, " [{}]", category_label(category))?;
+        }
+        if let Some(case) = &self.select_case {
+            write!(f, " [{}]", case)?;
+        }
+        write!(
+            f,
+            ": {}: expected [{}], found [{}]",
+            self.kind,
+            self.expected.join(", "),
+            self.actual.join(", ")
+        )
+    }
+}
+
+pub(crate) fn category_label(category: &PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+/// One value of a translation with its plural category or select case.
+pub(crate) struct Form<'a> {
+    pub(crate) plural_category: Option<PluralCategory>,
+    pub(crate) select_case: Option<String>,
+    pub(crate) text: &'a str,
+}
+
+pub(crate) fn forms_of(translation: &Translation) -> Vec<Form<'_>> {
+    match translation {
+        Translation::Empty => Vec::new(),
+        Translation::Singular(text) => vec![Form {
+            plural_category: None,
+            select_case: None,
+            text,
+        }],
+        Translation::Plural(plural) => plural
+            .forms
+            .iter()
+            .map(|(category, text)| Form {
+                plural_category: Some(category.clone()),
+                select_case: None,
+                text,
+            })
+            .collect(),
+        Translation::Select(select) => select
+            .cases
+            .iter()
+            .map(|(case, text)| Form {
+                plural_category: None,
+                select_case: Some(case.clone()),
+                text,
+            })
+            .collect(),
+    }
+}
+
+/// Every value of a translation, for rewriting in place.
+pub(crate) fn forms_of_mut(translation: &mut Translation) -> Vec<&mut String> {
+    match translation {
+        Translation::Empty => Vec::new(),
+        Translation::Singular(text) => vec![text],
+        Translation::Plural(plural) => plural.forms.values_mut().collect(),
+        Translation::Select(select) => select.cases.values_mut().collect(),
+    }
+}
+
+/// Finds the source text a translated form should be compared with.
+pub(crate) fn source_text_for<'a>(source: &'a Translation, form: &Form<'_>) -> Option<&'a str> {
+    match source {
+        Translation::Empty => None,
+        Translation::Singular(text) => Some(text),
+        Translation::Plural(plural) => form
+            .plural_category
+            .as_ref()
+            .and_then(|category| plural.forms.get(category))
+            .or_else(|| plural.forms.get(&PluralCategory::Other))
+            .map(String::as_str),
+        Translation::Select(select) => form
+            .select_case
+            .as_ref()
+            .and_then(|case| select.cases.get(case))
+            .map(String::as_str)
+            .or_else(|| select.fallback()),
+    }
+}
+
+/// Placeholders keyed by the argument position they consume.
+fn by_position(tokens: &[PlaceholderToken]) -> BTreeMap<usize, String> {
+    let mut next = 1;
+    let mut out = BTreeMap::new();
+    for token in tokens {
+        let position = token.index.unwrap_or(next);
+        next = position + 1;
+        out.insert(position, type_signature(token));
+    }
+    out
+}
+
+/// Signature of a placeholder without its position.
+fn type_signature(token: &PlaceholderToken) -> String {
+    PlaceholderToken {
+        index: None,
+        ..token.clone()
+    }
+    .to_signature()
+}
+
+/// Compares a translated form with its source form.
+pub(crate) fn compare(
+    expected: &str,
+    actual: &str,
+) -> Vec<(PlaceholderIssueKind, Option<PlaceholderFix>)> {
+    let expected_tokens = extract_placeholders(&normalize_placeholders(expected));
+    let actual_tokens = extract_placeholders(&normalize_placeholders(actual));
+    let expected_map = by_position(&expected_tokens);
+    let actual_map = by_position(&actual_tokens);
+    if expected_map == actual_map {
+        return Vec::new();
+    }
+
+    let mut expected_types: Vec<&String> = expected_map.values().collect();
+    let mut actual_types: Vec<&String> = actual_map.values().collect();
+    expected_types.sort();
+    actual_types.sort();
+    if expected_types == actual_types && actual_tokens.iter().all(|t| t.index.is_none()) {
+        return vec![(
+            PlaceholderIssueKind::ReorderedWithoutPositions,
+            position_fix(actual, &expected_map),
+        )];
+    }
+
+    let mut issues = Vec::new();
+    if expected_map.keys().any(|p| !actual_map.contains_key(p)) {
+        issues.push((PlaceholderIssueKind::Missing, None));
+    }
+    if actual_map.keys().any(|p| !expected_map.contains_key(p)) {
+        issues.push((PlaceholderIssueKind::Extra, None));
+    }
+    let mismatched: BTreeMap<usize, &String> = expected_map
+        .iter()
+        .filter(|(position, ty)| actual_map.get(position).is_some_and(|a| a != *ty))
+        .map(|(position, ty)| (*position, ty))
+        .collect();
+    if !mismatched.is_empty() {
+        issues.push((
+            PlaceholderIssueKind::TypeMismatch,
+            type_fix(actual, &mismatched),
+        ));
+    }
+    issues
+}
+
+/// Adds explicit positions so each placeholder takes the source argument of its type.
+fn position_fix(actual: &str, expected: &BTreeMap<usize, String>) -> Option<PlaceholderFix> {
+    let mut unused: Vec<(usize, &String)> = expected.iter().map(|(p, t)| (*p, t)).collect();
+    let value = rewrite_placeholders(actual, |_, text| {
+        let Some(ty) = extract_placeholders(&normalize_placeholders(text))
+            .first()
+            .map(type_signature)
+        else {
+            return text.to_string();
+        };
+        match unused.iter().position(|(_, t)| **t == ty) {
+            Some(i) => {
+                let (position, _) = unused.remove(i);
+                format!("%{}${}", position, &text[1..])
+            }
+            None => text.to_string(),
+        }
+    });
+    Some(PlaceholderFix {
+        description: "add explicit argument positions".to_string(),
+        value,
+    })
+}
+
+/// Rewrites mismatched placeholders to the source formatting.
+fn type_fix(actual: &str, mismatched: &BTreeMap<usize, &String>) -> Option<PlaceholderFix> {
+    let mut next = 1;
+    let value = rewrite_placeholders(actual, |parsed, text| {
+        let position = parsed.token.index.unwrap_or(next);
+        next = position + 1;
+        match mismatched.get(&position) {
+            Some(ty) => match parsed.token.index {
+                Some(index) => format!("%{}${}", index, ty),
+                None => format!("%{}", ty),
+            },
+            None => text.to_string(),
+        }
+    });
+    let types: Vec<String> = mismatched
+        .iter()
+        .map(|(position, ty)| format!("%{}${}", position, ty))
+        .collect();
+    Some(PlaceholderFix {
+        description: format!("use the source placeholder types: {}", types.join(", ")),
+        value,
+    })
+}
+
+/// Compares placeholders of every translation with the same key in
+/// `source_language`.
+///
+/// Missing or empty entries are skipped; they are untranslated rather than
+/// wrong.
+pub fn collect_placeholder_issues(
+    resources: &[Resource],
+    source_language: &str,
+) -> Vec<PlaceholderIssue> {
+    let Some(source) = resources
+        .iter()
+        .find(|res| res.metadata.language == source_language)
+    else {
+        return Vec::new();
+    };
+
+    let mut issues = Vec::new();
+    for source_entry in &source.entries {
+        for target in resources
+            .iter()
+            .filter(|res| res.metadata.language != source_language)
+        {
+            let Some(entry) = target.find_entry(&source_entry.id) else {
+                continue;
+            };
+            for form in forms_of(&entry.value) {
+                let Some(expected) = source_text_for(&source_entry.value, &form) else {
+                    continue;
+                };
+                for (kind, fix) in compare(expected, form.text) {
+                    issues.push(PlaceholderIssue {
+                        key: entry.id.clone(),
+                        language: target.metadata.language.clone(),
+                        plural_category: form.plural_category.clone(),
+                        select_case: form.select_case.clone(),
+                        kind,
+                        expected: signature(expected),
+                        actual: signature(form.text),
+                        fix,
+                    });
+                }
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Entry, EntryStatus, Metadata, Plural};
+
+    fn resource(language: &str, value: Translation) -> Resource {
+        Resource {
+            metadata: Metadata {
+                language: language.into(),
+                domain: String::new(),
+                custom: Default::default(),
+            },
+            entries: vec![Entry {
+                id: "files".into(),
+                value,
+                comment: None,
+                status: EntryStatus::Translated,
+                custom: Default::default(),
+            }],
+        }
+    }
+
+    fn issues(source: &str, target: &str) -> Vec<PlaceholderIssue> {
+        collect_placeholder_issues(
+            &[
+                resource("en", Translation::Singular(source.into())),
+                resource("fr", Translation::Singular(target.into())),
+            ],
+            "en",
+        )
+    }
+
+    #[test]
+    fn test_positions_and_platform_variants_agree() {
+        assert!(issues("%@ has %lld", "%1$s a %2$d").is_empty());
+        assert!(issues("No placeholders", "Aucun").is_empty());
+    }
+
+    #[test]
+    fn test_missing_and_extra() {
+        let found = issues("%1$@ and %2$@", "%1$@ et %3$@");
+        let kinds: Vec<_> = found.iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            [PlaceholderIssueKind::Missing, PlaceholderIssueKind::Extra]
+        );
+        assert_eq!(found[0].expected, ["1$s", "2$s"]);
+        assert_eq!(found[0].actual, ["1$s", "3$s"]);
+        assert!(found[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_type_mismatch_fix_uses_source_type() {
+        let found = issues("%d files in %@", "%s fichiers dans %@");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, PlaceholderIssueKind::TypeMismatch);
+        let fix = found[0].fix.as_ref().unwrap();
+        assert_eq!(fix.value, "%d fichiers dans %@");
+    }
+
+    #[test]
+    fn test_reordered_without_positions_fix_adds_positions() {
+        let found = issues("%@ scored %d", "%d points pour %@");
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].kind,
+            PlaceholderIssueKind::ReorderedWithoutPositions
+        );
+        assert_eq!(
+            found[0].fix.as_ref().unwrap().value,
+            "%2$d points pour %1$@"
+        );
+    }
+
+    #[test]
+    fn test_plural_forms_compare_by_category() {
+        let source = Translation::Plural(
+            Plural::new(
+                "n",
+                [
+                    (PluralCategory::One, "One file".to_string()),
+                    (PluralCategory::Other, "%d files".to_string()),
+                ]
+                .into_iter(),
+            )
+            .unwrap(),
+        );
+        let target = Translation::Plural(
+            Plural::new(
+                "n",
+                [
+                    (PluralCategory::One, "Un fichier".to_string()),
+                    (PluralCategory::Many, "%s fichiers".to_string()),
+                    (PluralCategory::Other, "%d fichiers".to_string()),
+                ]
+                .into_iter(),
+            )
+            .unwrap(),
+        );
+        let found =
+            collect_placeholder_issues(&[resource("en", source), resource("fr", target)], "en");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].plural_category, Some(PluralCategory::Many));
+        assert_eq!(
+            found[0].to_string(),
+            "lang='fr' key='files' [many]: placeholder type mismatch: expected [d], found [s]"
+        );
+
+        let json = serde_json::to_value(&found[0]).unwrap();
+        assert_eq!(json["kind"], "type_mismatch");
+        assert_eq!(json["plural_category"], "many");
+        assert!(json.get("select_case").is_none());
+    }
+}
@@ -1,78 +1,147 @@
-use std::collections::{BTreeMap, BTreeSet};
+//! CLDR plural rules: category selection for numbers and plural validation.
+//!
+//! Rules come from the complete CLDR data set (see [`cldr`]) and are evaluated
+//! with the standard plural operands (`n`, `i`, `v`, `w`, `f`, `t`, `e`).
+//! Regional rule sets such as `pt-PT` take precedence over the base language.
+
+mod cldr;
+mod rule;
+
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
 
 use unic_langid::LanguageIdentifier;
 
 use crate::{
     error::Error,
-    types::{EntryStatus, Plural, PluralCategory, Resource, Translation},
+    types::{EntryStatus, Plural, PluralCategory, PluralKind, Resource, Translation},
 };
 
 use lazy_static::lazy_static;
 use serde::Serialize;
 
-lazy_static! {
-    /// Static mapping from base language subtag → required plural categories (CLDR‑style, cardinals).
-    static ref CATEGORY_TABLE: BTreeMap<&'static str, BTreeSet<PluralCategory>> = {
-        use PluralCategory::*;
-        let mut m: BTreeMap<&'static str, BTreeSet<PluralCategory>> = BTreeMap::new();
-
-        // Helper to build a set from a slice
-        fn s(items: &[PluralCategory]) -> BTreeSet<PluralCategory> {
-            items.iter().cloned().collect()
-        }
+pub use rule::{ParseOperandsError, PluralOperands};
 
-        // One/Other (most Indo‑European languages without complex plural rules)
-        for code in [
-            "en","de","nl","sv","da","nb","nn","no","is","fi","et","fa","hi","bn","gu",
-            "ta","te","kn","ml","mr","it","es","pt","mk","el","eu","gl","af","sw","ur",
-            "fil","tl","tr","id","ms","fr","hy","kab"
-        ] {
-            m.insert(code, s(&[One, Other]));
-        }
+/// Compiled rules for one group of locales, in CLDR category order.
+struct RuleSet {
+    rules: Vec<(PluralCategory, rule::Condition)>,
+}
 
-        // Only Other (East/Southeast Asian common cases)
-        for code in ["ja","zh","ko","th","vi","km","lo","my","yue"] {
-            m.insert(code, s(&[Other]));
-        }
+impl RuleSet {
+    fn select(&self, operands: &PluralOperands) -> PluralCategory {
+        self.rules
+            .iter()
+            .find(|(_, condition)| condition.matches(operands))
+            .map(|(category, _)| category.clone())
+            .unwrap_or(PluralCategory::Other)
+    }
 
-        // Slavic (Russian group): one, few, many, other
-        for code in ["ru","uk","be","sr","hr","bs","sh"] {
-            m.insert(code, s(&[One, Few, Many, Other]));
-        }
+    fn categories(&self) -> BTreeSet<PluralCategory> {
+        self.rules
+            .iter()
+            .map(|(category, _)| category.clone())
+            .chain(std::iter::once(PluralCategory::Other))
+            .collect()
+    }
+}
 
-        // Polish
-        m.insert("pl", s(&[One, Few, Many, Other]));
+/// Rule sets indexed by CLDR locale id (`pt-PT`, `en`, `root`, ...).
+struct RuleTable {
+    sets: Vec<RuleSet>,
+    by_locale: HashMap<String, usize>,
+}
 
-        // Czech/Slovak
-        for code in ["cs","sk"] {
-            m.insert(code, s(&[One, Few, Other]));
+impl RuleTable {
+    fn compile(groups: &[cldr::RuleGroup]) -> Self {
+        let mut sets = Vec::new();
+        let mut by_locale = HashMap::new();
+        for (locales, rules) in groups {
+            let rules = rules
+                .iter()
+                .map(|(category, condition)| {
+                    let category = PluralCategory::from_str(category)
+                        .unwrap_or_else(|e| panic!("invalid CLDR plural category: {e}"));
+                    let condition = rule::Condition::parse(condition)
+                        .unwrap_or_else(|e| panic!("invalid CLDR plural rule: {e}"));
+                    (category, condition)
+                })
+                .collect();
+            for locale in locales.split_whitespace() {
+                by_locale.insert(locale.replace('_', "-"), sets.len());
+            }
+            sets.push(RuleSet { rules });
         }
+        RuleTable { sets, by_locale }
+    }
 
-        // Slovenian
-        m.insert("sl", s(&[One, Two, Few, Other]));
-
-        // Lithuanian
-        m.insert("lt", s(&[One, Few, Other]));
+    /// Most specific rule set for `lang`: language-region, language-script,
+    /// then language, falling back to CLDR `root` (only `other`).
+    fn rules_for(&self, lang: &LanguageIdentifier) -> &RuleSet {
+        let language = lang.language.as_str();
+        let candidates = [
+            lang.region.map(|region| format!("{}-{}", language, region)),
+            lang.script.map(|script| format!("{}-{}", language, script)),
+            Some(language.to_string()),
+            Some("root".to_string()),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .find_map(|locale| self.by_locale.get(&locale))
+            .map(|idx| &self.sets[*idx])
+            .expect("CLDR plural data defines root")
+    }
+}
 
-        // Latvian
-        m.insert("lv", s(&[Zero, One, Other]));
+lazy_static! {
+    static ref CARDINAL_RULES: RuleTable = RuleTable::compile(cldr::CARDINAL);
+    static ref ORDINAL_RULES: RuleTable = RuleTable::compile(cldr::ORDINAL);
+}
 
-        // Irish Gaelic
-        m.insert("ga", s(&[One, Two, Few, Many, Other]));
+fn rule_table(kind: PluralKind) -> &'static RuleTable {
+    match kind {
+        PluralKind::Cardinal => &CARDINAL_RULES,
+        PluralKind::Ordinal => &ORDINAL_RULES,
+    }
+}
 
-        // Romanian
-        m.insert("ro", s(&[One, Few, Other]));
+/// Parses a language code, accepting underscores; invalid codes map to `und`.
+fn parse_language(lang: &str) -> LanguageIdentifier {
+    lang.replace('_', "-")
+        .parse()
+        .unwrap_or_else(|_| "und".parse().unwrap())
+}
 
-        // Arabic
-        m.insert("ar", s(&[Zero, One, Two, Few, Many, Other]));
+/// Returns the cardinal plural category of `number` in `lang`.
+///
+/// ```rust
+/// use langcodec::{PluralCategory, plural_category_for};
+///
+/// assert_eq!(plural_category_for("ru", 21), PluralCategory::One);
+/// assert_eq!(plural_category_for("ru", 5), PluralCategory::Many);
+/// // Visible fraction digits matter: "1.0 files" in English.
+/// let one_point_zero = "1.0".parse::<langcodec::PluralOperands>().unwrap();
+/// assert_eq!(plural_category_for("en", one_point_zero), PluralCategory::Other);
+/// ```
+pub fn plural_category_for(lang: &str, number: impl Into<PluralOperands>) -> PluralCategory {
+    category_for_kind(lang, number, PluralKind::Cardinal)
+}
 
-        // Hebrew (legacy code iw also maps here)
-        for code in ["he","iw"] {
-            m.insert(code, s(&[One, Two, Many, Other]));
-        }
+/// Returns the ordinal plural category of `number` in `lang` (e.g. English
+/// "1st" is `one`, "2nd" is `two`, "3rd" is `few`, "4th" is `other`).
+pub fn ordinal_category_for(lang: &str, number: impl Into<PluralOperands>) -> PluralCategory {
+    category_for_kind(lang, number, PluralKind::Ordinal)
+}
 
-        m
-    };
+/// Returns the plural category of `number` in `lang` under the rules for `kind`.
+pub fn category_for_kind(
+    lang: &str,
+    number: impl Into<PluralOperands>,
+    kind: PluralKind,
+) -> PluralCategory {
+    rule_table(kind)
+        .rules_for(&parse_language(lang))
+        .select(&number.into())
 }
 
 /// Non-fatal report describing missing plural categories for a key in a locale.
@@ -82,30 +151,33 @@ pub struct PluralValidationReport {
     pub key: String,
     pub missing: BTreeSet<PluralCategory>,
     pub have: BTreeSet<PluralCategory>,
+    #[serde(skip_serializing_if = "PluralKind::is_cardinal")]
+    pub kind: PluralKind,
 }
 
-/// Returns the required CLDR plural categories for a given language identifier.
+/// Returns the required CLDR cardinal plural categories for a given language identifier.
 ///
-/// This is a curated subset of CLDR rules covering common locales. For unknown
-/// or unsupported locales, falls back to {Other} to avoid false positives.
+/// Every locale in CLDR is covered; regional rule sets (e.g. `pt-PT`) are
+/// used when present. Unknown languages use the CLDR root rules, which only
+/// require `other`. Use [`required_categories_for_kind`] for ordinals.
 pub fn required_categories_for(lang: &LanguageIdentifier) -> BTreeSet<PluralCategory> {
-    // Base language subtag only for rule selection
-    let lang_str = lang.language.as_str();
-    CATEGORY_TABLE.get(lang_str).cloned().unwrap_or_else(|| {
-        // Conservative default to avoid noisy validation for unknown locales
-        let mut s = BTreeSet::new();
-        s.insert(PluralCategory::Other);
-        s
-    })
+    required_categories_for_kind(lang, PluralKind::Cardinal)
+}
+
+/// Returns the required CLDR plural categories for `lang` under the rules for `kind`.
+///
+/// English needs `one`/`other` for cardinals but `one`/`two`/`few`/`other`
+/// for ordinals ("1st", "2nd", "3rd", "4th").
+pub fn required_categories_for_kind(
+    lang: &LanguageIdentifier,
+    kind: PluralKind,
+) -> BTreeSet<PluralCategory> {
+    rule_table(kind).rules_for(lang).categories()
 }
 
 /// Helper for string language codes (accepts underscores, normalizes to hyphen).
 pub fn required_categories_for_str(lang: &str) -> BTreeSet<PluralCategory> {
-    let normalized = lang.replace('_', "-");
-    let parsed: LanguageIdentifier = normalized
-        .parse()
-        .unwrap_or_else(|_| "und".parse().unwrap());
-    required_categories_for(&parsed)
+    required_categories_for(&parse_language(lang))
 }
 
 /// Compute which required categories are missing for a given plural entry and language.
@@ -113,7 +185,7 @@ pub fn missing_categories_for_plural(
     lang: &LanguageIdentifier,
     plural: &Plural,
 ) -> BTreeSet<PluralCategory> {
-    let required = required_categories_for(lang);
+    let required = required_categories_for_kind(lang, plural.kind);
     let have: BTreeSet<PluralCategory> = plural.forms.keys().cloned().collect();
     &required - &have
 }
@@ -126,6 +198,7 @@ pub fn collect_resource_plural_issues(resource: &Resource) -> Vec<PluralValidati
             key: String::from("<resource>"),
             missing: [PluralCategory::Other].into_iter().collect(),
             have: BTreeSet::new(),
+            kind: PluralKind::Cardinal,
         }];
     };
 
@@ -140,6 +213,7 @@ pub fn collect_resource_plural_issues(resource: &Resource) -> Vec<PluralValidati
                     key: entry.id.clone(),
                     missing,
                     have,
+                    kind: plural.kind,
                 });
             }
         }
@@ -157,10 +231,15 @@ pub fn validate_resource_plurals(resource: &Resource) -> Result<(), Error> {
     for r in reports {
         let miss: Vec<String> = r.missing.iter().map(|k| format!("{:?}", k)).collect();
         let have: Vec<String> = r.have.iter().map(|k| format!("{:?}", k)).collect();
+        let kind = match r.kind {
+            PluralKind::Cardinal => "",
+            PluralKind::Ordinal => "ordinal ",
+        };
         lines.push(format!(
-            "lang='{}' key='{}': missing plural categories: [{}] (have: [{}])",
+            "lang='{}' key='{}': missing {}plural categories: [{}] (have: [{}])",
             r.language,
             r.key,
+            kind,
             miss.join(", "),
             have.join(", ")
         ));
@@ -239,6 +318,220 @@ mod tests {
         assert_eq!(ja_set.len(), 1);
     }
 
+    fn category(name: &str) -> PluralCategory {
+        PluralCategory::from_str(name).unwrap()
+    }
+
+    fn assert_cardinal(lang: &str, cases: &[(&str, &str)]) {
+        for (number, expected) in cases {
+            let operands: PluralOperands = number.parse().unwrap();
+            assert_eq!(
+                plural_category_for(lang, operands),
+                category(expected),
+                "{lang} cardinal {number}"
+            );
+        }
+    }
+
+    fn assert_ordinal(lang: &str, cases: &[(u64, &str)]) {
+        for (number, expected) in cases {
+            assert_eq!(
+                ordinal_category_for(lang, *number),
+                category(expected),
+                "{lang} ordinal {number}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_cldr_rules_compile() {
+        for table in [&*CARDINAL_RULES, &*ORDINAL_RULES] {
+            assert!(table.by_locale.contains_key("root"));
+            assert!(table.by_locale.contains_key("en"));
+        }
+        assert!(CARDINAL_RULES.by_locale.len() > 200);
+    }
+
+    #[test]
+    fn test_cardinal_categories_match_cldr_samples() {
+        assert_cardinal(
+            "en",
+            &[
+                ("1", "one"),
+                ("0", "other"),
+                ("2", "other"),
+                ("1.0", "other"),
+            ],
+        );
+        assert_cardinal(
+            "ru",
+            &[
+                ("1", "one"),
+                ("21", "one"),
+                ("11", "many"),
+                ("3", "few"),
+                ("24", "few"),
+                ("5", "many"),
+                ("1.5", "other"),
+            ],
+        );
+        assert_cardinal(
+            "pl",
+            &[
+                ("1", "one"),
+                ("22", "few"),
+                ("12", "many"),
+                ("0.5", "other"),
+            ],
+        );
+        assert_cardinal(
+            "cs",
+            &[("1", "one"), ("3", "few"), ("1.5", "many"), ("5", "other")],
+        );
+        assert_cardinal(
+            "ar",
+            &[
+                ("0", "zero"),
+                ("1", "one"),
+                ("2", "two"),
+                ("103", "few"),
+                ("111", "many"),
+                ("100", "other"),
+            ],
+        );
+        assert_cardinal(
+            "fr",
+            &[
+                ("0", "one"),
+                ("1.5", "one"),
+                ("2", "other"),
+                ("1000000", "many"),
+                ("1c6", "many"),
+            ],
+        );
+        assert_cardinal(
+            "lv",
+            &[("0", "zero"), ("21", "one"), ("2", "other"), ("0.1", "one")],
+        );
+        assert_cardinal("he", &[("1", "one"), ("2", "two"), ("20", "other")]);
+        assert_cardinal("ja", &[("1", "other")]);
+        assert_cardinal(
+            "sl",
+            &[
+                ("101", "one"),
+                ("102", "two"),
+                ("103", "few"),
+                ("105", "other"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_regional_override_pt_pt() {
+        // Brazilian Portuguese treats 0 as singular; European Portuguese does not.
+        assert_eq!(plural_category_for("pt", 0), PluralCategory::One);
+        assert_eq!(plural_category_for("pt-BR", 0), PluralCategory::One);
+        assert_eq!(plural_category_for("pt-PT", 0), PluralCategory::Other);
+        assert_eq!(plural_category_for("pt_PT", 1), PluralCategory::One);
+    }
+
+    #[test]
+    fn test_ordinal_categories_match_cldr_samples() {
+        assert_ordinal(
+            "en",
+            &[
+                (1, "one"),
+                (2, "two"),
+                (3, "few"),
+                (4, "other"),
+                (11, "other"),
+                (21, "one"),
+                (112, "other"),
+            ],
+        );
+        assert_ordinal("fr", &[(1, "one"), (2, "other")]);
+        assert_ordinal("sv", &[(1, "one"), (2, "one"), (12, "other")]);
+        assert_ordinal("it", &[(8, "many"), (11, "many"), (9, "other")]);
+        assert_ordinal(
+            "cy",
+            &[
+                (0, "zero"),
+                (1, "one"),
+                (2, "two"),
+                (3, "few"),
+                (5, "many"),
+                (10, "other"),
+            ],
+        );
+        assert_ordinal("de", &[(1, "other")]);
+    }
+
+    #[test]
+    fn test_unknown_language_uses_root_rules() {
+        assert_eq!(plural_category_for("xx", 1), PluralCategory::Other);
+        assert_eq!(
+            required_categories_for_str("not a language"),
+            [PluralCategory::Other].into_iter().collect()
+        );
+        let pt_pt = required_categories_for_str("pt-PT");
+        assert_eq!(
+            pt_pt,
+            [
+                PluralCategory::One,
+                PluralCategory::Many,
+                PluralCategory::Other
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+
+    #[test]
+    fn test_ordinal_plurals_validate_against_ordinal_rules() {
+        let mut ordinal = Plural::new(
+            "place",
+            vec![
+                (PluralCategory::One, "%dst".to_string()),
+                (PluralCategory::Other, "%dth".to_string()),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        ordinal.kind = PluralKind::Ordinal;
+        let mut resource = Resource {
+            metadata: Metadata {
+                language: "en".into(),
+                domain: String::new(),
+                custom: Default::default(),
+            },
+            entries: vec![Entry {
+                id: "place".into(),
+                value: Translation::Plural(ordinal),
+                comment: None,
+                status: EntryStatus::Translated,
+                custom: Default::default(),
+            }],
+        };
+
+        let reports = collect_resource_plural_issues(&resource);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].kind, PluralKind::Ordinal);
+        assert_eq!(
+            reports[0].missing,
+            [PluralCategory::Two, PluralCategory::Few]
+                .into_iter()
+                .collect()
+        );
+        let err = validate_resource_plurals(&resource).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("missing ordinal plural categories")
+        );
+
+        assert_eq!(autofix_fill_missing_from_other_resource(&mut resource), 2);
+        assert!(collect_resource_plural_issues(&resource).is_empty());
+    }
+
     #[test]
     fn test_validate_resource_plurals_missing() {
         // English requires one/other; missing 'one' should fail
@@ -0,0 +1,338 @@
+//! Operand extraction and evaluation of CLDR plural rule conditions.
+//!
+//! Rules use the syntax from [UTS #35](https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules),
+//! e.g. `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`. Sample lists
+//! (`@integer`, `@decimal`) are not part of the stored rules.
+
+use std::{fmt, str::FromStr};
+
+/// The plural operands of a number, as defined by CLDR.
+///
+/// Operands depend on how the number is written: `1` and `1.0` have the same
+/// value but different `v`, which matters for languages such as English
+/// ("1 file" but "1.0 files"). Parse a string to keep visible fraction
+/// digits, or convert from an integer type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {
+    /// Absolute value of the source number.
+    pub n: f64,
+    /// Integer digits of `n`.
+    pub i: u64,
+    /// Number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+    /// Number of visible fraction digits, without trailing zeros.
+    pub w: usize,
+    /// Visible fraction digits, with trailing zeros, as an integer.
+    pub f: u64,
+    /// Visible fraction digits, without trailing zeros, as an integer.
+    pub t: u64,
+    /// Compact decimal exponent (`c` and `e` are synonyms in CLDR).
+    pub e: usize,
+}
+
+impl PluralOperands {
+    fn from_parts(int_digits: &str, frac_digits: &str, exponent: usize) -> Option<Self> {
+        let i: u64 = if int_digits.is_empty() {
+            0
+        } else {
+            int_digits.parse().ok()?
+        };
+        let trimmed = frac_digits.trim_end_matches('0');
+        let f: u64 = if frac_digits.is_empty() {
+            0
+        } else {
+            frac_digits.parse().ok()?
+        };
+        let t: u64 = if trimmed.is_empty() {
+            0
+        } else {
+            trimmed.parse().ok()?
+        };
+        let n = format!(
+            "{}.{}",
+            if int_digits.is_empty() {
+                "0"
+            } else {
+                int_digits
+            },
+            if frac_digits.is_empty() {
+                "0"
+            } else {
+                frac_digits
+            }
+        )
+        .parse()
+        .ok()?;
+        Some(PluralOperands {
+            n,
+            i,
+            v: frac_digits.len(),
+            w: trimmed.len(),
+            f,
+            t,
+            e: exponent,
+        })
+    }
+}
+
+/// Error returned when a string is not a plain decimal number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOperandsError(String);
+
+impl fmt::Display for ParseOperandsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid number for plural selection: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseOperandsError {}
+
+impl FromStr for PluralOperands {
+    type Err = ParseOperandsError;
+
+    /// Parses numbers such as `5`, `-1.50`, or compact forms like `1.2c3` (1200).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseOperandsError(s.to_string());
+        let unsigned = s.trim().trim_start_matches(['-', '+']);
+        let (mantissa, exponent) = match unsigned.find(['c', 'e', 'C', 'E']) {
+            Some(pos) => (
+                &unsigned[..pos],
+                unsigned[pos + 1..].parse::<usize>().map_err(|_| err())?,
+            ),
+            None => (unsigned, 0),
+        };
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !int_part.chars().all(|c| c.is_ascii_digit())
+            || !frac_part.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(err());
+        }
+
+        // Shift the decimal point right by the exponent.
+        let shift = exponent.min(frac_part.len());
+        let mut int_digits = format!("{}{}", int_part, &frac_part[..shift]);
+        int_digits.extend(std::iter::repeat_n('0', exponent - shift));
+        let int_digits = int_digits.trim_start_matches('0');
+        PluralOperands::from_parts(int_digits, &frac_part[shift..], exponent).ok_or_else(err)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for PluralOperands {
+                fn from(value: $ty) -> Self {
+                    let i = value.unsigned_abs() as u64;
+                    PluralOperands { n: i as f64, i, v: 0, w: 0, f: 0, t: 0, e: 0 }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for PluralOperands {
+                fn from(value: $ty) -> Self {
+                    let i = value as u64;
+                    PluralOperands { n: i as f64, i, v: 0, w: 0, f: 0, t: 0, e: 0 }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    N,
+    I,
+    V,
+    W,
+    F,
+    T,
+    E,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Relation {
+    operand: Operand,
+    modulus: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+/// A parsed plural rule condition: an `or` of `and`-ed relations.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Condition(Vec<Vec<Relation>>);
+
+impl Condition {
+    /// Parses a condition such as `n % 10 = 1 and n % 100 != 11`.
+    pub(crate) fn parse(source: &str) -> Result<Self, String> {
+        let mut or_groups = Vec::new();
+        for and_group in source.split(" or ") {
+            let mut relations = Vec::new();
+            for relation in and_group.split(" and ") {
+                relations.push(parse_relation(relation.trim())?);
+            }
+            or_groups.push(relations);
+        }
+        Ok(Condition(or_groups))
+    }
+
+    pub(crate) fn matches(&self, operands: &PluralOperands) -> bool {
+        self.0
+            .iter()
+            .any(|relations| relations.iter().all(|r| r.matches(operands)))
+    }
+}
+
+fn parse_relation(source: &str) -> Result<Relation, String> {
+    let (lhs, negated, rhs) = if let Some((lhs, rhs)) = source.split_once("!=") {
+        (lhs, true, rhs)
+    } else if let Some((lhs, rhs)) = source.split_once('=') {
+        (lhs, false, rhs)
+    } else {
+        return Err(format!("missing '=' or '!=' in relation '{}'", source));
+    };
+
+    let (operand, modulus) = match lhs.split_once('%') {
+        Some((operand, modulus)) => (
+            operand.trim(),
+            Some(
+                modulus
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid modulus in '{}'", source))?,
+            ),
+        ),
+        None => (lhs.trim(), None),
+    };
+    let operand = match operand {
+        "n" => Operand::N,
+        "i" => Operand::I,
+        "v" => Operand::V,
+        "w" => Operand::W,
+        "f" => Operand::F,
+        "t" => Operand::T,
+        "c" | "e" => Operand::E,
+        other => return Err(format!("unknown operand '{}' in '{}'", other, source)),
+    };
+
+    let mut ranges = Vec::new();
+    for item in rhs.split(',') {
+        let item = item.trim();
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| format!("invalid value '{}' in '{}'", value, source))
+        };
+        let range = match item.split_once("..") {
+            Some((low, high)) => (parse(low)?, parse(high)?),
+            None => {
+                let value = parse(item)?;
+                (value, value)
+            }
+        };
+        ranges.push(range);
+    }
+
+    Ok(Relation {
+        operand,
+        modulus,
+        negated,
+        ranges,
+    })
+}
+
+impl Relation {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        let contained = match self.operand {
+            // `n` may be fractional; it only matches a range when it is an integer inside it.
+            Operand::N => {
+                let value = match self.modulus {
+                    Some(modulus) => operands.n % modulus as f64,
+                    None => operands.n,
+                };
+                value.fract() == 0.0
+                    && self
+                        .ranges
+                        .iter()
+                        .any(|(low, high)| value >= *low as f64 && value <= *high as f64)
+            }
+            operand => {
+                let raw = match operand {
+                    Operand::I => operands.i,
+                    Operand::V => operands.v as u64,
+                    Operand::W => operands.w as u64,
+                    Operand::F => operands.f,
+                    Operand::T => operands.t,
+                    Operand::E => operands.e as u64,
+                    Operand::N => unreachable!("handled above"),
+                };
+                let value = match self.modulus {
+                    Some(modulus) => raw % modulus,
+                    None => raw,
+                };
+                self.ranges
+                    .iter()
+                    .any(|(low, high)| (*low..=*high).contains(&value))
+            }
+        };
+        contained != self.negated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ops(s: &str) -> PluralOperands {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_operands_from_strings() {
+        let o = ops("1.50");
+        assert_eq!(
+            (o.n, o.i, o.v, o.w, o.f, o.t, o.e),
+            (1.5, 1, 2, 1, 50, 5, 0)
+        );
+        let o = ops("-12");
+        assert_eq!((o.n, o.i, o.v, o.f), (12.0, 12, 0, 0));
+        let o = ops("1.2c3");
+        assert_eq!((o.n, o.i, o.v, o.e), (1200.0, 1200, 0, 3));
+        let o = ops("0.05");
+        assert_eq!((o.i, o.v, o.f, o.t), (0, 2, 5, 5));
+        assert!("abc".parse::<PluralOperands>().is_err());
+        assert!("".parse::<PluralOperands>().is_err());
+        assert_eq!(PluralOperands::from(-3i32), ops("3"));
+    }
+
+    #[test]
+    fn test_condition_matching() {
+        let rule = Condition::parse("v = 0 and i % 10 = 2..4 and i % 100 != 12..14").unwrap();
+        assert!(rule.matches(&ops("22")));
+        assert!(!rule.matches(&ops("12")));
+        assert!(!rule.matches(&ops("2.0")));
+
+        let rule = Condition::parse("n % 10 = 1 and n % 100 != 11").unwrap();
+        assert!(rule.matches(&ops("21")));
+        assert!(!rule.matches(&ops("1.5")));
+
+        let rule = Condition::parse("e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5")
+            .unwrap();
+        assert!(rule.matches(&ops("1000000")));
+        assert!(rule.matches(&ops("1c6")));
+        assert!(!rule.matches(&ops("1c3")));
+
+        assert!(Condition::parse("x = 1").is_err());
+        assert!(Condition::parse("n is 1").is_err());
+    }
+}
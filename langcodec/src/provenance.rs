@@ -13,6 +13,73 @@ const SOURCE_FORMAT_KEY: &str = "langcodec.provenance.source_format";
 const SOURCE_LANGUAGE_KEY: &str = "langcodec.provenance.source_language";
 const MATCH_STRATEGY_KEY: &str = "langcodec.provenance.match_strategy";
 const SOURCE_KEY_KEY: &str = "langcodec.provenance.source_key";
+const PREVIOUS_KEY_KEY: &str = "langcodec.provenance.previous_key";
+const OFFSET_KEY: &str = "langcodec.provenance.offset";
+const LINE_KEY: &str = "langcodec.provenance.line";
+const COLUMN_KEY: &str = "langcodec.provenance.column";
+
+/// A location in parsed source text.
+///
+/// `offset` is a byte offset into the decoded text; `line` and `column` are
+/// 1-based, with columns counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SourcePosition {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourcePosition {
+    /// Computes the line and column of a byte offset in `text`.
+    pub fn from_offset(text: &str, offset: usize) -> Self {
+        LineIndex::new(text).position(text, offset)
+    }
+}
+
+impl std::fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Where a parsed entry starts in its source file.
+///
+/// `language` is set when the position belongs to one language only (a
+/// localization in a catalog, a target in an XLIFF file group); otherwise it
+/// applies to the entry in every language the file provides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPosition {
+    pub key: String,
+    pub language: Option<String>,
+    pub position: SourcePosition,
+}
+
+/// Line start offsets of a text, for repeated offset-to-position lookups.
+pub(crate) struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub(crate) fn new(text: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        LineIndex { starts }
+    }
+
+    pub(crate) fn position(&self, text: &str, offset: usize) -> SourcePosition {
+        let mut offset = offset.min(text.len());
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self.starts.partition_point(|start| *start <= offset);
+        let line_start = self.starts[line - 1];
+        SourcePosition {
+            offset,
+            line,
+            column: text[line_start..offset].chars().count() + 1,
+        }
+    }
+}
 
 /// Structured provenance information for resources and entries.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -22,6 +89,14 @@ pub struct ProvenanceRecord {
     pub source_language: Option<String>,
     pub match_strategy: Option<String>,
     pub source_key: Option<String>,
+    /// Key the entry had before it was last renamed.
+    pub previous_key: Option<String>,
+    /// Byte offset of the entry in its source text.
+    pub offset: Option<usize>,
+    /// 1-based line of the entry in its source text.
+    pub line: Option<usize>,
+    /// 1-based column of the entry in its source text.
+    pub column: Option<usize>,
 }
 
 impl ProvenanceRecord {
@@ -31,6 +106,27 @@ impl ProvenanceRecord {
             && self.source_language.is_none()
             && self.match_strategy.is_none()
             && self.source_key.is_none()
+            && self.previous_key.is_none()
+            && self.offset.is_none()
+            && self.line.is_none()
+            && self.column.is_none()
+    }
+
+    /// Returns the recorded position, when line and column are both known.
+    pub fn position(&self) -> Option<SourcePosition> {
+        Some(SourcePosition {
+            offset: self.offset.unwrap_or_default(),
+            line: self.line?,
+            column: self.column?,
+        })
+    }
+
+    /// Sets offset, line and column from `position`.
+    pub fn with_position(mut self, position: SourcePosition) -> Self {
+        self.offset = Some(position.offset);
+        self.line = Some(position.line);
+        self.column = Some(position.column);
+        self
     }
 }
 
@@ -63,6 +159,10 @@ fn apply_to_map(
     apply_opt(map, SOURCE_LANGUAGE_KEY, &provenance.source_language);
     apply_opt(map, MATCH_STRATEGY_KEY, &provenance.match_strategy);
     apply_opt(map, SOURCE_KEY_KEY, &provenance.source_key);
+    apply_opt(map, PREVIOUS_KEY_KEY, &provenance.previous_key);
+    apply_opt(map, OFFSET_KEY, &provenance.offset.map(|v| v.to_string()));
+    apply_opt(map, LINE_KEY, &provenance.line.map(|v| v.to_string()));
+    apply_opt(map, COLUMN_KEY, &provenance.column.map(|v| v.to_string()));
 }
 
 fn apply_opt(
@@ -84,6 +184,10 @@ fn from_map(map: &std::collections::HashMap<String, String>) -> Option<Provenanc
         source_language: map.get(SOURCE_LANGUAGE_KEY).cloned(),
         match_strategy: map.get(MATCH_STRATEGY_KEY).cloned(),
         source_key: map.get(SOURCE_KEY_KEY).cloned(),
+        previous_key: map.get(PREVIOUS_KEY_KEY).cloned(),
+        offset: map.get(OFFSET_KEY).and_then(|v| v.parse().ok()),
+        line: map.get(LINE_KEY).and_then(|v| v.parse().ok()),
+        column: map.get(COLUMN_KEY).and_then(|v| v.parse().ok()),
     };
     if record.is_empty() {
         None
@@ -99,8 +203,8 @@ mod tests {
     use crate::types::{Entry, EntryStatus, Metadata, Resource, Translation};
 
     use super::{
-        ProvenanceRecord, entry_provenance, resource_provenance, set_entry_provenance,
-        set_resource_provenance,
+        ProvenanceRecord, SourcePosition, entry_provenance, resource_provenance,
+        set_entry_provenance, set_resource_provenance,
     };
 
     #[test]
@@ -120,6 +224,7 @@ mod tests {
             source_language: Some("en".to_string()),
             match_strategy: None,
             source_key: None,
+            ..ProvenanceRecord::default()
         };
 
         set_resource_provenance(&mut resource, &record);
@@ -142,9 +247,31 @@ mod tests {
             source_language: Some("fr".to_string()),
             match_strategy: Some("fallback_translation".to_string()),
             source_key: Some("welcome_title".to_string()),
-        };
+            previous_key: Some("welcome".to_string()),
+            ..ProvenanceRecord::default()
+        }
+        .with_position(SourcePosition {
+            offset: 42,
+            line: 3,
+            column: 5,
+        });
 
         set_entry_provenance(&mut entry, &record);
         assert_eq!(entry_provenance(&entry), Some(record));
     }
+
+    #[test]
+    fn test_source_position_counts_lines_and_characters() {
+        let text = "first\nsé \"key\"\n";
+        let offset = text.find("\"key").unwrap();
+        assert_eq!(
+            SourcePosition::from_offset(text, offset),
+            SourcePosition {
+                offset,
+                line: 2,
+                column: 4,
+            }
+        );
+        assert_eq!(SourcePosition::from_offset(text, 0).to_string(), "1:1");
+    }
 }
@@ -0,0 +1,427 @@
+//! Pseudo-localization.
+//!
+//! Generates a fake translation from the source language so that layout and
+//! internationalization bugs show up before real translations arrive:
+//! accented letters reveal hard-coded strings and missing fonts, padding
+//! reveals truncation, brackets reveal clipped or concatenated strings, and
+//! RTL wrapping reveals mirroring problems.
+//!
+//! Placeholders (`%1$@`, `{name}`, `{{name}}`, `%{name}`), HTML/XML tags,
+//! character entities and backslash escapes are copied unchanged.
+
+use crate::{
+    placeholder::parse_placeholder,
+    plural_rules::required_categories_for_str,
+    types::{EntryStatus, Plural, PluralCategory, PluralKind, Resource, Translation},
+};
+
+const ACCENTED_LOWER: [char; 26] = [
+    'å', 'ƀ', 'ç', 'đ', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ', 'š',
+    'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+];
+const ACCENTED_UPPER: [char; 26] = [
+    'Å', 'Ɓ', 'Ç', 'Đ', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ', 'Š',
+    'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+];
+/// Words appended to reach the requested expansion.
+const PADDING_WORDS: [&str; 10] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+];
+
+/// Right-to-left mark, right-to-left override and pop directional formatting.
+const RLM: char = '\u{200F}';
+const RLO: char = '\u{202E}';
+const PDF: char = '\u{202C}';
+
+/// How pseudo-localized text is generated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudoOptions {
+    /// Replace ASCII letters with accented look-alikes.
+    pub accents: bool,
+    /// Extra length to add, as a fraction of the translatable text
+    /// (`0.4` makes a 10-character string 14 characters long).
+    pub expansion: f64,
+    /// Wrap each value in `[` and `]`.
+    pub brackets: bool,
+    /// Wrap each word in right-to-left override marks.
+    pub rtl: bool,
+}
+
+impl PseudoOptions {
+    /// Accented pseudo-locale, conventionally `en-XA`: `[Ĥéļļö one]`.
+    pub fn accented() -> Self {
+        Self {
+            accents: true,
+            expansion: 0.4,
+            brackets: true,
+            rtl: false,
+        }
+    }
+
+    /// Bidi pseudo-locale, conventionally `ar-XB`: words are shown right to left.
+    pub fn bidi() -> Self {
+        Self {
+            accents: false,
+            expansion: 0.0,
+            brackets: false,
+            rtl: true,
+        }
+    }
+
+    /// Options for a conventional pseudo-locale: `ar-XB` and other `-XB`
+    /// locales are bidi, everything else is accented.
+    pub fn for_locale(locale: &str) -> Self {
+        let region = locale.rsplit(['-', '_']).next().unwrap_or_default();
+        if region.eq_ignore_ascii_case("XB") {
+            Self::bidi()
+        } else {
+            Self::accented()
+        }
+    }
+}
+
+impl Default for PseudoOptions {
+    fn default() -> Self {
+        Self::accented()
+    }
+}
+
+/// Returns the byte length of the protected span at the start of `input`, if any.
+fn protected_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let closing = |open: &str, close: &str| {
+        input
+            .strip_prefix(open)?
+            .find(close)
+            .map(|end| open.len() + end + close.len())
+    };
+    match bytes.first()? {
+        b'%' => {
+            if bytes.get(1) == Some(&b'%') {
+                Some(2)
+            } else if let Some(parsed) = parse_placeholder(input) {
+                Some(parsed.len)
+            } else {
+                closing("%{", "}").or_else(|| closing("%<", ">"))
+            }
+        }
+        b'{' => {
+            let mut depth = 0usize;
+            for (i, ch) in input.char_indices() {
+                match ch {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            None
+        }
+        b'<' if bytes
+            .get(1)
+            .is_some_and(|b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!' | b'?')) =>
+        {
+            closing("<", ">")
+        }
+        b'&' => {
+            let end = input.find(';')?;
+            let name = &input[1..end];
+            let valid = !name.is_empty()
+                && name.len() <= 10
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '#');
+            valid.then_some(end + 1)
+        }
+        b'\\' => match bytes.get(1)? {
+            b'u' if input
+                .get(2..6)
+                .is_some_and(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit())) =>
+            {
+                Some(6)
+            }
+            _ => Some(1 + input[1..].chars().next()?.len_utf8()),
+        },
+        b'$' => {
+            // Chrome `$NAME$` placeholders.
+            let end = input[1..].find('$')?;
+            let name = &input[1..1 + end];
+            (!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+                .then_some(end + 2)
+        }
+        _ => None,
+    }
+}
+
+fn accent(ch: char) -> char {
+    match ch {
+        'a'..='z' => ACCENTED_LOWER[(ch as u8 - b'a') as usize],
+        'A'..='Z' => ACCENTED_UPPER[(ch as u8 - b'A') as usize],
+        _ => ch,
+    }
+}
+
+/// Appends `text`, wrapping each word in RTL override marks when requested.
+fn push_text(out: &mut String, text: &str, options: &PseudoOptions) {
+    let mut in_word = false;
+    for ch in text.chars() {
+        let ch = if options.accents { accent(ch) } else { ch };
+        if options.rtl {
+            if ch.is_whitespace() && in_word {
+                out.push(PDF);
+                out.push(RLM);
+                in_word = false;
+            } else if !ch.is_whitespace() && !in_word {
+                out.push(RLM);
+                out.push(RLO);
+                in_word = true;
+            }
+        }
+        out.push(ch);
+    }
+    if in_word {
+        out.push(PDF);
+        out.push(RLM);
+    }
+}
+
+/// Pseudo-localizes one string.
+///
+/// ```rust
+/// use langcodec::{PseudoOptions, pseudolocalize};
+///
+/// let text = pseudolocalize("Hello %1$@, <b>welcome</b>", &PseudoOptions::accented());
+/// assert_eq!(text, "[Ĥéļļö %1$@, <b>ŵéļçöɱé</b> one two]");
+/// ```
+pub fn pseudolocalize(text: &str, options: &PseudoOptions) -> String {
+    let mut out = String::with_capacity(text.len() * 2);
+    if options.brackets {
+        out.push('[');
+    }
+
+    let mut translatable = 0usize;
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        match protected_len(rest) {
+            Some(len) => {
+                push_text(&mut out, &plain, options);
+                plain.clear();
+                out.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            None => {
+                if !ch.is_whitespace() {
+                    translatable += 1;
+                }
+                plain.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    push_text(&mut out, &plain, options);
+
+    let extra = (translatable as f64 * options.expansion.max(0.0)).round() as usize;
+    if extra > 0 {
+        let mut padding = String::new();
+        for word in PADDING_WORDS.iter().cycle() {
+            if padding.chars().count() >= extra {
+                break;
+            }
+            padding.push(' ');
+            padding.push_str(word);
+        }
+        // Padding stays unaccented so it is easy to tell apart from the text.
+        let padding_options = PseudoOptions {
+            accents: false,
+            ..options.clone()
+        };
+        push_text(&mut out, &padding, &padding_options);
+    }
+
+    if options.brackets {
+        out.push(']');
+    }
+    out
+}
+
+/// Builds a pseudo-locale resource from `source`.
+///
+/// Every plural form and select case is pseudo-localized. Plural categories
+/// `locale` requires but the source lacks are filled from `other`, so the
+/// result passes plural validation (`ar-XB` needs six cardinal forms).
+/// Entries marked do-not-translate are copied unchanged.
+pub fn pseudolocalize_resource(
+    source: &Resource,
+    locale: &str,
+    options: &PseudoOptions,
+) -> Resource {
+    let mut resource = source.clone();
+    resource.metadata.language = locale.to_string();
+    resource
+        .metadata
+        .custom
+        .entry("source_language".to_string())
+        .or_insert_with(|| source.metadata.language.clone());
+
+    let required = required_categories_for_str(locale);
+    for entry in &mut resource.entries {
+        if entry.status == EntryStatus::DoNotTranslate {
+            continue;
+        }
+        match &mut entry.value {
+            Translation::Empty => continue,
+            Translation::Singular(value) => *value = pseudolocalize(value, options),
+            Translation::Plural(plural) => {
+                for value in plural.forms.values_mut() {
+                    *value = pseudolocalize(value, options);
+                }
+                if plural.kind == PluralKind::Cardinal {
+                    fill_required_forms(plural, &required);
+                }
+            }
+            Translation::Select(select) => {
+                for value in select.cases.values_mut() {
+                    *value = pseudolocalize(value, options);
+                }
+            }
+        }
+        entry.status = EntryStatus::Translated;
+    }
+    resource
+}
+
+fn fill_required_forms(plural: &mut Plural, required: &std::collections::BTreeSet<PluralCategory>) {
+    let Some(other) = plural.forms.get(&PluralCategory::Other).cloned() else {
+        return;
+    };
+    for category in required {
+        plural
+            .forms
+            .entry(category.clone())
+            .or_insert_with(|| other.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Entry, Metadata};
+
+    #[test]
+    fn test_accented_keeps_protected_spans() {
+        let options = PseudoOptions {
+            expansion: 0.0,
+            ..PseudoOptions::accented()
+        };
+        assert_eq!(
+            pseudolocalize(r"Hi {name}, {{user}} %{x} %d%% &amp; \n $TAB$", &options),
+            r"[Ĥî {name}, {{user}} %{x} %d%% &amp; \n $TAB$]"
+        );
+        assert_eq!(
+            pseudolocalize("<a href=\"x\">Open</a>", &options),
+            "[<a href=\"x\">Öþéñ</a>]"
+        );
+        // A lone `<` or `%` is ordinary text.
+        assert_eq!(pseudolocalize("a < b, 5% off", &options), "[å < ƀ, 5% öƒƒ]");
+    }
+
+    #[test]
+    fn test_short_unicode_escape_before_multibyte_char() {
+        let options = PseudoOptions {
+            expansion: 0.0,
+            ..PseudoOptions::accented()
+        };
+        assert_eq!(pseudolocalize("Path \\u123é", &options), "[Þåţĥ \\u123é]");
+    }
+
+    #[test]
+    fn test_expansion_pads_with_words() {
+        let options = PseudoOptions {
+            accents: false,
+            brackets: false,
+            ..PseudoOptions::accented()
+        };
+        assert_eq!(pseudolocalize("Settings", &options), "Settings one");
+        // Placeholders do not count towards the expanded length.
+        assert_eq!(pseudolocalize("%1$@", &options), "%1$@");
+        let half = PseudoOptions {
+            expansion: 0.5,
+            ..options
+        };
+        assert_eq!(
+            pseudolocalize("Cancel download", &half),
+            "Cancel download one two"
+        );
+    }
+
+    #[test]
+    fn test_bidi_wraps_words() {
+        let text = pseudolocalize("Hi %@ there", &PseudoOptions::bidi());
+        assert_eq!(
+            text,
+            "\u{200F}\u{202E}Hi\u{202C}\u{200F} %@ \u{200F}\u{202E}there\u{202C}\u{200F}"
+        );
+        assert_eq!(PseudoOptions::for_locale("ar-XB"), PseudoOptions::bidi());
+        assert_eq!(
+            PseudoOptions::for_locale("en_XA"),
+            PseudoOptions::accented()
+        );
+    }
+
+    #[test]
+    fn test_resource_covers_plural_forms() {
+        let source = Resource {
+            metadata: Metadata {
+                language: "en".into(),
+                domain: "Localizable".into(),
+                custom: Default::default(),
+            },
+            entries: vec![
+                Entry {
+                    id: "files".into(),
+                    value: Translation::Plural(
+                        Plural::new(
+                            "n",
+                            [
+                                (PluralCategory::One, "%d file".to_string()),
+                                (PluralCategory::Other, "%d files".to_string()),
+                            ]
+                            .into_iter(),
+                        )
+                        .unwrap(),
+                    ),
+                    comment: Some("File count".into()),
+                    status: EntryStatus::New,
+                    custom: Default::default(),
+                },
+                Entry {
+                    id: "brand".into(),
+                    value: Translation::Singular("Acme".into()),
+                    comment: None,
+                    status: EntryStatus::DoNotTranslate,
+                    custom: Default::default(),
+                },
+            ],
+        };
+
+        let pseudo = pseudolocalize_resource(&source, "ar-XB", &PseudoOptions::accented());
+        assert_eq!(pseudo.metadata.language, "ar-XB");
+        assert_eq!(pseudo.metadata.custom["source_language"], "en");
+
+        let files = pseudo.find_entry("files").unwrap();
+        assert_eq!(files.status, EntryStatus::Translated);
+        assert_eq!(files.comment.as_deref(), Some("File count"));
+        let Translation::Plural(plural) = &files.value else {
+            panic!("expected plural");
+        };
+        assert_eq!(plural.forms.len(), 6);
+        assert_eq!(plural.forms[&PluralCategory::One], "[%d ƒîļé one]");
+        assert_eq!(plural.forms[&PluralCategory::Few], "[%d ƒîļéš one]");
+
+        let brand = pseudo.find_entry("brand").unwrap();
+        assert_eq!(brand.value, Translation::Singular("Acme".into()));
+    }
+}
@@ -0,0 +1,392 @@
+//! Key renames and value transforms across every language of a codec.
+//!
+//! A [`KeyMapping`] says which keys get which new names, from explicit pairs,
+//! a mapping CSV or a regular expression. [`rename_keys`] applies it to all
+//! resources at once and refuses when two entries of one resource would end
+//! up with the same key, so a key is never renamed in some languages only.
+//! Each renamed entry records its old key in its provenance.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+    io::BufRead,
+    path::Path,
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    normalize::KeyRename,
+    placeholder::forms_of_mut,
+    provenance::{ProvenanceRecord, entry_provenance, set_entry_provenance},
+    types::Resource,
+};
+
+/// Which keys to rename and what to.
+#[derive(Debug, Clone)]
+pub enum KeyMapping {
+    /// Exact `old key → new key` pairs.
+    Pairs(BTreeMap<String, String>),
+    /// Every match of `regex` in a key is replaced with `replacement`, which
+    /// may refer to capture groups as `$1` or `${name}`.
+    Pattern { regex: Regex, replacement: String },
+}
+
+impl KeyMapping {
+    /// Builds a mapping from `(old, new)` pairs.
+    ///
+    /// Fails when a key is empty or when one old key is given two different
+    /// new keys.
+    pub fn pairs<I, K, V>(pairs: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut map = BTreeMap::new();
+        for (from, to) in pairs {
+            let (from, to) = (from.into(), to.into());
+            if from.is_empty() || to.is_empty() {
+                return Err(Error::InvalidResource(format!(
+                    "Rename mapping '{}' -> '{}' has an empty key",
+                    from, to
+                )));
+            }
+            if let Some(existing) = map.get(&from)
+                && *existing != to
+            {
+                return Err(Error::InvalidResource(format!(
+                    "Key '{}' is mapped to both '{}' and '{}'",
+                    from, existing, to
+                )));
+            }
+            map.insert(from, to);
+        }
+        Ok(KeyMapping::Pairs(map))
+    }
+
+    /// Builds a mapping that rewrites keys with a regular expression.
+    pub fn pattern(pattern: &str, replacement: impl Into<String>) -> Result<Self, Error> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            Error::InvalidResource(format!("Invalid rename pattern '{}': {}", pattern, e))
+        })?;
+        Ok(KeyMapping::Pattern {
+            regex,
+            replacement: replacement.into(),
+        })
+    }
+
+    /// Reads a mapping CSV with a header row.
+    ///
+    /// The old key is in the `from` (or `old`, `old_key`, `key`) column and
+    /// the new key in the `to` (or `new`, `new_key`) column. Rows without an
+    /// old key are skipped.
+    pub fn from_csv_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut rdr = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = rdr.headers().map_err(Error::CsvParse)?.clone();
+
+        let mut from_column = None;
+        let mut to_column = None;
+        for (index, header) in headers.iter().enumerate() {
+            match header
+                .to_ascii_lowercase()
+                .replace(['-', ' '], "_")
+                .as_str()
+            {
+                "from" | "old" | "old_key" | "key" => from_column = Some(index),
+                "to" | "new" | "new_key" => to_column = Some(index),
+                _ => {}
+            }
+        }
+        let (Some(from_column), Some(to_column)) = (from_column, to_column) else {
+            return Err(Error::InvalidResource(
+                "Rename mapping CSV needs 'from' and 'to' columns".to_string(),
+            ));
+        };
+
+        let mut pairs = Vec::new();
+        for record in rdr.records() {
+            let record = record.map_err(Error::CsvParse)?;
+            let Some(from) = record.get(from_column).filter(|s| !s.is_empty()) else {
+                continue;
+            };
+            let to = record.get(to_column).unwrap_or_default();
+            pairs.push((from.to_string(), to.to_string()));
+        }
+        Self::pairs(pairs)
+    }
+
+    /// Reads a `.csv` mapping file.
+    pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let reader = std::io::BufReader::new(std::fs::File::open(path.as_ref())?);
+        Self::from_csv_reader(reader)
+    }
+
+    /// Returns the new key for `key`, or `None` when it keeps its name.
+    pub fn new_key(&self, key: &str) -> Option<String> {
+        let renamed = match self {
+            KeyMapping::Pairs(map) => map.get(key)?.clone(),
+            KeyMapping::Pattern { regex, replacement } => {
+                regex.replace_all(key, replacement.as_str()).into_owned()
+            }
+        };
+        (renamed != key).then_some(renamed)
+    }
+}
+
+/// Keys of one resource that a [`KeyMapping`] would give the same name.
+///
+/// `keys` holds the current keys, including an existing entry that already
+/// has the name `to`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenameCollision {
+    pub language: String,
+    pub domain: String,
+    pub keys: Vec<String>,
+    pub to: String,
+}
+
+impl fmt::Display for RenameCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: Vec<String> = self.keys.iter().map(|key| format!("'{}'", key)).collect();
+        let keys = match keys.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => keys.join(""),
+        };
+        write!(
+            f,
+            "rename collision in language '{}' (domain '{}'): {} would {} be named '{}'",
+            self.language,
+            self.domain,
+            keys,
+            if self.keys.len() == 2 { "both" } else { "all" },
+            self.to
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenameReport {
+    /// Every renamed entry, per language.
+    pub renamed: Vec<KeyRename>,
+    /// Old keys of a [`KeyMapping::Pairs`] mapping found in no language.
+    pub unmatched: Vec<String>,
+}
+
+/// Lists every collision [`rename_keys`] would refuse, across all resources.
+pub fn find_rename_collisions(
+    resources: &[Resource],
+    mapping: &KeyMapping,
+) -> Vec<RenameCollision> {
+    let mut collisions = Vec::new();
+    for resource in resources {
+        let mut by_new_key: BTreeMap<String, (Vec<String>, bool)> = BTreeMap::new();
+        for entry in &resource.entries {
+            let (new_key, renamed) = match mapping.new_key(&entry.id) {
+                Some(new_key) => (new_key, true),
+                None => (entry.id.clone(), false),
+            };
+            let slot = by_new_key.entry(new_key).or_default();
+            slot.0.push(entry.id.clone());
+            slot.1 |= renamed;
+        }
+        for (to, (keys, renamed)) in by_new_key {
+            // Duplicates that are left alone are not the rename's doing.
+            if keys.len() > 1 && renamed {
+                collisions.push(RenameCollision {
+                    language: resource.metadata.language.clone(),
+                    domain: resource.metadata.domain.clone(),
+                    keys,
+                    to,
+                });
+            }
+        }
+    }
+    collisions
+}
+
+/// Renames keys in every resource according to `mapping`.
+///
+/// Nothing is changed when any resource has a collision; the error lists all
+/// of them. The old key is stored as `previous_key` in the provenance of each
+/// renamed entry.
+pub fn rename_keys(
+    resources: &mut [Resource],
+    mapping: &KeyMapping,
+) -> Result<RenameReport, Error> {
+    let collisions = find_rename_collisions(resources, mapping);
+    if !collisions.is_empty() {
+        let messages: Vec<String> = collisions.iter().map(ToString::to_string).collect();
+        return Err(Error::validation_error(messages.join("; ")));
+    }
+
+    let mut report = RenameReport::default();
+    let mut seen = HashSet::new();
+    for resource in resources.iter_mut() {
+        for entry in &mut resource.entries {
+            seen.insert(entry.id.clone());
+            let Some(new_key) = mapping.new_key(&entry.id) else {
+                continue;
+            };
+            let previous_key = std::mem::replace(&mut entry.id, new_key);
+            let record = ProvenanceRecord {
+                previous_key: Some(previous_key.clone()),
+                ..entry_provenance(entry).unwrap_or_default()
+            };
+            set_entry_provenance(entry, &record);
+            report.renamed.push(KeyRename {
+                language: resource.metadata.language.clone(),
+                from: previous_key,
+                to: entry.id.clone(),
+            });
+        }
+    }
+    if let KeyMapping::Pairs(map) = mapping {
+        report.unmatched = map
+            .keys()
+            .filter(|key| !seen.contains(*key))
+            .cloned()
+            .collect();
+    }
+    Ok(report)
+}
+
+/// Replaces every text of every entry, including each plural form and select
+/// case, with `f(language, key, text)`.
+///
+/// Returns the number of entries whose value changed.
+pub fn map_values<F>(resources: &mut [Resource], mut f: F) -> usize
+where
+    F: FnMut(&str, &str, &str) -> String,
+{
+    let mut changed = 0;
+    for resource in resources.iter_mut() {
+        let language = resource.metadata.language.as_str();
+        for entry in &mut resource.entries {
+            let mut entry_changed = false;
+            for text in forms_of_mut(&mut entry.value) {
+                let mapped = f(language, &entry.id, text);
+                if mapped != *text {
+                    *text = mapped;
+                    entry_changed = true;
+                }
+            }
+            if entry_changed {
+                changed += 1;
+            }
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::text_resource;
+    use crate::types::{Entry, Plural, PluralCategory, Translation};
+
+    fn ids(resource: &Resource) -> Vec<&str> {
+        resource.entries.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    #[test]
+    fn renames_pairs_in_every_language_and_records_previous_key() {
+        let mut resources = vec![
+            text_resource("en", &[("login", "Log in"), ("ok", "OK")]),
+            text_resource("fr", &[("login", "Connexion")]),
+        ];
+        let mapping = KeyMapping::pairs([("login", "auth.sign_in"), ("gone", "x")]).unwrap();
+
+        let report = rename_keys(&mut resources, &mapping).unwrap();
+
+        assert_eq!(ids(&resources[0]), ["auth.sign_in", "ok"]);
+        assert_eq!(ids(&resources[1]), ["auth.sign_in"]);
+        assert_eq!(report.renamed.len(), 2);
+        assert_eq!(report.unmatched, ["gone"]);
+        let provenance = entry_provenance(&resources[1].entries[0]).unwrap();
+        assert_eq!(provenance.previous_key.as_deref(), Some("login"));
+    }
+
+    #[test]
+    fn pattern_renames_and_swaps_do_not_collide() {
+        let mut resources = vec![text_resource("en", &[("a", "A"), ("b", "B")])];
+        let swap = KeyMapping::pairs([("a", "b"), ("b", "a")]).unwrap();
+        rename_keys(&mut resources, &swap).unwrap();
+        assert_eq!(ids(&resources[0]), ["b", "a"]);
+
+        let mapping = KeyMapping::pattern(r"^(\w)$", "menu.$1").unwrap();
+        rename_keys(&mut resources, &mapping).unwrap();
+        assert_eq!(ids(&resources[0]), ["menu.b", "menu.a"]);
+    }
+
+    #[test]
+    fn collisions_in_any_language_leave_resources_unchanged() {
+        let mut resources = vec![
+            text_resource("en", &[("login", "Log in")]),
+            text_resource("fr", &[("login", "Connexion"), ("sign_in", "Se connecter")]),
+        ];
+        let original = resources.clone();
+        let mapping = KeyMapping::pairs([("login", "sign_in")]).unwrap();
+
+        let collisions = find_rename_collisions(&resources, &mapping);
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(collisions[0].language, "fr");
+        assert_eq!(collisions[0].keys, ["login", "sign_in"]);
+
+        let err = rename_keys(&mut resources, &mapping).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'login' and 'sign_in' would both be named 'sign_in'")
+        );
+        assert_eq!(resources, original);
+    }
+
+    #[test]
+    fn reads_mapping_csv() {
+        let csv = "old_key,new_key\nlogin,auth.login\n,ignored\n";
+        let mapping = KeyMapping::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(mapping.new_key("login").as_deref(), Some("auth.login"));
+        assert_eq!(mapping.new_key("other"), None);
+
+        assert!(KeyMapping::from_csv_reader("a,b\nx,y\n".as_bytes()).is_err());
+        assert!(KeyMapping::from_csv_reader("from,to\nx,\n".as_bytes()).is_err());
+        assert!(KeyMapping::pairs([("x", "y"), ("x", "z")]).is_err());
+    }
+
+    #[test]
+    fn map_values_covers_every_plural_form() {
+        let mut resources = vec![text_resource("en", &[("title", "Hello")])];
+        let title = resources[0].entries[0].clone();
+        resources[0].entries.push(Entry {
+            id: "files".to_string(),
+            value: Translation::Plural(
+                Plural::new(
+                    "files",
+                    [
+                        (PluralCategory::One, "%d file".to_string()),
+                        (PluralCategory::Other, "%d files".to_string()),
+                    ]
+                    .into_iter(),
+                )
+                .unwrap(),
+            ),
+            ..title
+        });
+
+        let changed = map_values(&mut resources, |_, _, text| {
+            text.replace("file", "document")
+        });
+
+        assert_eq!(changed, 1);
+        let Translation::Plural(plural) = &resources[0].entries[1].value else {
+            panic!("expected plural");
+        };
+        assert_eq!(plural.forms[&PluralCategory::One], "%d document");
+        assert_eq!(plural.forms[&PluralCategory::Other], "%d documents");
+    }
+}
@@ -0,0 +1,485 @@
+//! Machine-readable reports for validation results.
+//!
+//! Lint findings are converted into [`Diagnostic`]s, which can be rendered as
+//! SARIF 2.1.0 (code-scanning alerts), JUnit XML (test reports) or GitHub
+//! Actions workflow commands (inline pull request annotations). Placeholder
+//! and plural checks report through the `placeholders` and `plural_forms`
+//! lint rules, so `lint` is the one path that produces these reports.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use quick_xml::escape::escape;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    lint::{LintFinding, LintSeverity},
+    placeholder::category_label,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/WendellXY/langcodec";
+
+/// Output format for a list of diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    /// Human-readable lines.
+    Text,
+    Json,
+    /// SARIF 2.1.0, e.g. for GitHub code scanning.
+    Sarif,
+    /// JUnit XML, one test case per entry with findings.
+    Junit,
+    /// GitHub Actions `::error file=...` workflow commands.
+    Github,
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Sarif => "sarif",
+            Self::Junit => "junit",
+            Self::Github => "github",
+        })
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "github" | "gha" => Ok(Self::Github),
+            _ => Err(format!("Unknown report format: {}", s)),
+        }
+    }
+}
+
+/// One validation problem with an optional file location.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub rule: String,
+    pub level: LintSeverity,
+    pub key: String,
+    pub language: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// 1-based line of the entry in `file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// 1-based column of the entry on `line`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl Diagnostic {
+    /// Sets the file and line the diagnostic points at.
+    pub fn with_location(mut self, file: impl Into<String>, line: Option<usize>) -> Self {
+        self.file = Some(file.into());
+        self.line = line;
+        self
+    }
+
+    /// Sets the column the diagnostic points at.
+    pub fn with_column(mut self, column: Option<usize>) -> Self {
+        self.column = column;
+        self
+    }
+
+    /// `file:line:col`, as far as the location is known.
+    fn location(&self) -> Option<String> {
+        let file = self.file.as_ref()?;
+        Some(match (self.line, self.column) {
+            (Some(line), Some(column)) => format!("{}:{}:{}", file, line, column),
+            (Some(line), None) => format!("{}:{}", file, line),
+            _ => file.clone(),
+        })
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location() {
+            write!(f, "{}: ", location)?;
+        }
+        write!(
+            f,
+            "{}: lang='{}' key='{}': {} ({})",
+            self.level, self.language, self.key, self.message, self.rule
+        )
+    }
+}
+
+/// Prefixes `message` with the plural category or select case it concerns.
+fn form_message(
+    category: Option<&crate::types::PluralCategory>,
+    case: Option<&String>,
+    message: &str,
+) -> String {
+    match (category, case) {
+        (Some(category), _) => format!("[{}] {}", category_label(category), message),
+        (None, Some(case)) => format!("[{}] {}", case, message),
+        (None, None) => message.to_string(),
+    }
+}
+
+impl From<&LintFinding> for Diagnostic {
+    fn from(finding: &LintFinding) -> Self {
+        Self {
+            rule: finding.rule.clone(),
+            level: finding.severity,
+            key: finding.key.clone(),
+            language: finding.language.clone(),
+            message: form_message(
+                finding.plural_category.as_ref(),
+                finding.select_case.as_ref(),
+                &finding.message,
+            ),
+            file: None,
+            line: None,
+            column: None,
+        }
+    }
+}
+
+/// Finds the 1-based line where `key` is defined in the text of a
+/// localization file.
+///
+/// This is a textual search for the usual key spellings (`name="key"`,
+/// `id="key"`, `"key"`, or a CSV/TSV row starting with the key).
+pub fn locate_key(text: &str, key: &str) -> Option<usize> {
+    let quoted = format!("\"{}\"", key);
+    let patterns = [
+        format!("name={}", quoted),
+        format!("id={}", quoted),
+        format!("resname={}", quoted),
+    ];
+    let lines: Vec<&str> = text.lines().collect();
+    let find = |matches: &dyn Fn(&str) -> bool| {
+        lines
+            .iter()
+            .position(|line| matches(line))
+            .map(|index| index + 1)
+    };
+    find(&|line| patterns.iter().any(|pattern| line.contains(pattern)))
+        .or_else(|| find(&|line| line.trim_start().starts_with(&quoted)))
+        .or_else(|| {
+            find(&|line| {
+                line.strip_prefix(key)
+                    .is_some_and(|rest| rest.starts_with(',') || rest.starts_with('\t'))
+            })
+        })
+        .or_else(|| find(&|line| line.contains(&quoted)))
+}
+
+fn sarif_level(level: LintSeverity) -> &'static str {
+    match level {
+        LintSeverity::Error => "error",
+        LintSeverity::Warning => "warning",
+        LintSeverity::Info | LintSeverity::Off => "note",
+    }
+}
+
+/// Renders diagnostics as a SARIF 2.1.0 log.
+///
+/// `rules` maps rule ids to descriptions for the tool's rule metadata; rules
+/// only seen in diagnostics are listed without a description.
+pub fn to_sarif(diagnostics: &[Diagnostic], rules: &[(&str, &str)]) -> serde_json::Value {
+    let mut rule_ids: Vec<String> = rules.iter().map(|(id, _)| id.to_string()).collect();
+    for diagnostic in diagnostics {
+        if !rule_ids.contains(&diagnostic.rule) {
+            rule_ids.push(diagnostic.rule.clone());
+        }
+    }
+    let descriptions: BTreeMap<&str, &str> = rules.iter().copied().collect();
+    let rule_metadata: Vec<serde_json::Value> = rule_ids
+        .iter()
+        .map(|id| match descriptions.get(id.as_str()) {
+            Some(description) => json!({
+                "id": id,
+                "shortDescription": { "text": description },
+            }),
+            None => json!({ "id": id }),
+        })
+        .collect();
+
+    let results: Vec<serde_json::Value> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut location = json!({
+                "logicalLocations": [{
+                    "name": diagnostic.key,
+                    "fullyQualifiedName": format!("{}/{}", diagnostic.language, diagnostic.key),
+                    "kind": "member",
+                }],
+            });
+            if let Some(file) = &diagnostic.file {
+                let mut physical = json!({
+                    "artifactLocation": { "uri": file.replace('\\', "/") },
+                });
+                if let Some(line) = diagnostic.line {
+                    physical["region"] = json!({ "startLine": line });
+                    if let Some(column) = diagnostic.column {
+                        physical["region"]["startColumn"] = json!(column);
+                    }
+                }
+                location["physicalLocation"] = physical;
+            }
+            json!({
+                "ruleId": diagnostic.rule,
+                "ruleIndex": rule_ids.iter().position(|id| *id == diagnostic.rule),
+                "level": sarif_level(diagnostic.level),
+                "message": {
+                    "text": format!("lang='{}' key='{}': {}", diagnostic.language, diagnostic.key, diagnostic.message),
+                },
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "langcodec",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": INFORMATION_URI,
+                    "rules": rule_metadata,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Renders diagnostics as JUnit XML: one test suite per file and one failing
+/// test case per entry, holding all of that entry's diagnostics.
+pub fn to_junit(diagnostics: &[Diagnostic]) -> String {
+    let mut suites: BTreeMap<&str, BTreeMap<(&str, &str), Vec<&Diagnostic>>> = BTreeMap::new();
+    for diagnostic in diagnostics {
+        suites
+            .entry(diagnostic.file.as_deref().unwrap_or("langcodec"))
+            .or_default()
+            .entry((&diagnostic.language, &diagnostic.key))
+            .or_default()
+            .push(diagnostic);
+    }
+    let total: usize = suites.values().map(BTreeMap::len).sum();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuites name=\"langcodec\" tests=\"{}\" failures=\"{}\">\n",
+        total, total
+    ));
+    for (file, cases) in &suites {
+        out.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            escape(*file),
+            cases.len(),
+            cases.len()
+        ));
+        for ((language, key), found) in cases {
+            let rules: Vec<&str> = found.iter().map(|item| item.rule.as_str()).collect();
+            let details: Vec<String> = found
+                .iter()
+                .map(|item| match item.line.and(item.location()) {
+                    Some(location) => format!(
+                        "{}: {}: {} ({})",
+                        location, item.level, item.message, item.rule
+                    ),
+                    None => format!("{}: {} ({})", item.level, item.message, item.rule),
+                })
+                .collect();
+            out.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\">\n",
+                escape(*file),
+                escape(format!("{}: {}", language, key))
+            ));
+            out.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                escape(rules.join(",")),
+                escape(&found[0].message),
+                escape(details.join("\n"))
+            ));
+            out.push_str("    </testcase>\n");
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+fn escape_command_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_command_property(value: &str) -> String {
+    escape_command_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Renders diagnostics as GitHub Actions workflow commands
+/// (`::error file=...,line=...,title=...::message`), one per line.
+pub fn to_github_annotations(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+    for diagnostic in diagnostics {
+        let command = match diagnostic.level {
+            LintSeverity::Error => "error",
+            LintSeverity::Warning => "warning",
+            LintSeverity::Info | LintSeverity::Off => "notice",
+        };
+        let mut properties = Vec::new();
+        if let Some(file) = &diagnostic.file {
+            properties.push(format!("file={}", escape_command_property(file)));
+            if let Some(line) = diagnostic.line {
+                properties.push(format!("line={}", line));
+                if let Some(column) = diagnostic.column {
+                    properties.push(format!("col={}", column));
+                }
+            }
+        }
+        properties.push(format!(
+            "title={}",
+            escape_command_property(&format!("langcodec {}", diagnostic.rule))
+        ));
+        out.push_str(&format!(
+            "::{} {}::{}\n",
+            command,
+            properties.join(","),
+            escape_command_data(&format!(
+                "lang='{}' key='{}': {}",
+                diagnostic.language, diagnostic.key, diagnostic.message
+            ))
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PluralCategory;
+
+    fn diagnostics() -> Vec<Diagnostic> {
+        vec![
+            Diagnostic::from(&LintFinding {
+                rule: "terminal_punctuation".to_string(),
+                severity: LintSeverity::Warning,
+                key: "files".to_string(),
+                language: "fr".to_string(),
+                plural_category: Some(PluralCategory::Other),
+                select_case: None,
+                message: "translation is missing the final '.'".to_string(),
+            })
+            .with_location("app/src/main/res/values-fr/strings.xml", Some(4)),
+            Diagnostic::from(&LintFinding {
+                rule: "plural_forms".to_string(),
+                severity: LintSeverity::Error,
+                key: "files".to_string(),
+                language: "fr".to_string(),
+                plural_category: None,
+                select_case: None,
+                message: "missing plural categories: many".to_string(),
+            })
+            .with_location("app/src/main/res/values-fr/strings.xml", Some(3))
+            .with_column(Some(5)),
+        ]
+    }
+
+    #[test]
+    fn sarif_has_rules_levels_and_locations() {
+        let sarif = to_sarif(
+            &diagnostics(),
+            &[("terminal_punctuation", "sentence-final punctuation matches")],
+        );
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "langcodec");
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "plural_forms");
+        let result = &run["results"][1];
+        assert_eq!(result["ruleId"], "plural_forms");
+        assert_eq!(result["ruleIndex"], 1);
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0];
+        assert_eq!(
+            location["physicalLocation"]["artifactLocation"]["uri"],
+            "app/src/main/res/values-fr/strings.xml"
+        );
+        assert_eq!(location["physicalLocation"]["region"]["startLine"], 3);
+        assert_eq!(location["physicalLocation"]["region"]["startColumn"], 5);
+        assert_eq!(
+            location["logicalLocations"][0]["fullyQualifiedName"],
+            "fr/files"
+        );
+        assert_eq!(
+            result["message"]["text"],
+            "lang='fr' key='files': missing plural categories: many"
+        );
+    }
+
+    #[test]
+    fn junit_groups_by_file_and_entry() {
+        let xml = to_junit(&diagnostics());
+        assert!(xml.contains("<testsuites name=\"langcodec\" tests=\"1\" failures=\"1\">"));
+        assert!(xml.contains(
+            "<testcase classname=\"app/src/main/res/values-fr/strings.xml\" name=\"fr: files\">"
+        ));
+        assert!(
+            xml.contains("type=\"terminal_punctuation,plural_forms\""),
+            "{}",
+            xml
+        );
+        assert!(
+            xml.contains("message=\"[other] translation is missing the final &apos;.&apos;\""),
+            "{}",
+            xml
+        );
+        assert!(
+            xml.contains("strings.xml:3:5: error: missing plural categories: many (plural_forms)")
+        );
+    }
+
+    #[test]
+    fn github_annotations_escape_properties_and_data() {
+        let mut items = diagnostics();
+        items[0].message = "50% done\nnext".to_string();
+        items[1].file = Some("a,b:c.xml".to_string());
+        let out = to_github_annotations(&items);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "::warning file=app/src/main/res/values-fr/strings.xml,line=4,title=langcodec terminal_punctuation::lang='fr' key='files': 50%25 done%0Anext"
+        );
+        assert!(
+            lines[1].starts_with("::error file=a%2Cb%3Ac.xml,line=3,col=5,"),
+            "{}",
+            lines[1]
+        );
+    }
+
+    #[test]
+    fn locate_key_finds_common_spellings() {
+        let android = "<resources>\n    <string name=\"title\">Title</string>\n</resources>\n";
+        assert_eq!(locate_key(android, "title"), Some(2));
+        let strings = "/* Title */\n\"title\" = \"Title\";\n";
+        assert_eq!(locate_key(strings, "title"), Some(2));
+        let csv = "key,en,fr\ntitle_long,Long,Longue\ntitle,Title,Titre\n";
+        assert_eq!(locate_key(csv, "title"), Some(3));
+        assert_eq!(locate_key(csv, "missing"), None);
+        assert_eq!("gha".parse::<ReportFormat>(), Ok(ReportFormat::Github));
+    }
+}
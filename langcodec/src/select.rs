@@ -0,0 +1,255 @@
+//! Validation of select messages (gender and other enumerated arguments).
+//!
+//! A select message must offer the same cases in every language: a
+//! translation that drops `female` or adds a case the source does not know
+//! about cannot be chosen correctly at runtime. Source messages may be stored
+//! as [`Translation::Select`] or as a singular ICU `select` string, which is
+//! how most file formats carry them.
+
+use std::{borrow::Cow, collections::BTreeSet};
+
+use serde::Serialize;
+
+use crate::{
+    error::Error,
+    types::{Resource, Select, Translation},
+};
+
+/// Selector key differences between a target entry and its source entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SelectValidationReport {
+    pub language: String,
+    pub key: String,
+    /// Argument the source message selects on.
+    pub selector: String,
+    /// Argument the target message selects on, when it differs from the source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_selector: Option<String>,
+    /// Source cases the target does not provide.
+    pub missing: BTreeSet<String>,
+    /// Target cases the source does not define.
+    pub extra: BTreeSet<String>,
+}
+
+/// Returns the select message held by `translation`, if any.
+///
+/// Singular values that consist of a single ICU `select` expression are
+/// parsed on the fly.
+pub fn select_of(translation: &Translation) -> Option<Cow<'_, Select>> {
+    match translation {
+        Translation::Select(select) => Some(Cow::Borrowed(select)),
+        Translation::Singular(value) => Select::from_icu(value).map(Cow::Owned),
+        Translation::Empty | Translation::Plural(_) => None,
+    }
+}
+
+/// Compares every select message of `source_language` with the same key in
+/// the other resources.
+///
+/// Missing or empty target entries are not reported; those are untranslated
+/// rather than malformed. A non-empty target that is not a select message is
+/// reported as missing every source case.
+pub fn collect_select_issues(
+    resources: &[Resource],
+    source_language: &str,
+) -> Vec<SelectValidationReport> {
+    let Some(source) = resources
+        .iter()
+        .find(|res| res.metadata.language == source_language)
+    else {
+        return Vec::new();
+    };
+
+    let mut reports = Vec::new();
+    for source_entry in &source.entries {
+        for target in resources
+            .iter()
+            .filter(|res| res.metadata.language != source_language)
+        {
+            let Some(entry) = target.find_entry(&source_entry.id) else {
+                continue;
+            };
+            reports.extend(compare_selects(
+                &source_entry.value,
+                &entry.value,
+                &target.metadata.language,
+                &entry.id,
+            ));
+        }
+    }
+    reports
+}
+
+/// Compares the select cases of one translation with its source value.
+///
+/// Returns `None` when the source is not a select message, the translation
+/// is empty, or both cover the same cases with the same selector.
+pub(crate) fn compare_selects(
+    source: &Translation,
+    translation: &Translation,
+    language: &str,
+    key: &str,
+) -> Option<SelectValidationReport> {
+    let source_select = select_of(source)?;
+    if matches!(translation, Translation::Empty) {
+        return None;
+    }
+    let expected: BTreeSet<&str> = source_select.keys().collect();
+
+    let (target_selector, have) = match select_of(translation) {
+        Some(select) => (
+            Some(select.selector.clone()),
+            select.keys().map(str::to_string).collect::<BTreeSet<_>>(),
+        ),
+        None => (None, BTreeSet::new()),
+    };
+    let missing: BTreeSet<String> = expected
+        .iter()
+        .filter(|key| !have.contains(**key))
+        .map(|key| key.to_string())
+        .collect();
+    let extra: BTreeSet<String> = have
+        .iter()
+        .filter(|key| !expected.contains(key.as_str()))
+        .cloned()
+        .collect();
+    let target_selector = target_selector.filter(|s| *s != source_select.selector);
+
+    if missing.is_empty() && extra.is_empty() && target_selector.is_none() {
+        return None;
+    }
+    Some(SelectValidationReport {
+        language: language.to_string(),
+        key: key.to_string(),
+        selector: source_select.selector.clone(),
+        target_selector,
+        missing,
+        extra,
+    })
+}
+
+impl SelectValidationReport {
+    /// Describes each difference from the source, e.g. `missing select cases: [female]`.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(target_selector) = &self.target_selector {
+            problems.push(format!(
+                "selects on '{}' instead of '{}'",
+                target_selector, self.selector
+            ));
+        }
+        if !self.missing.is_empty() {
+            let missing: Vec<&str> = self.missing.iter().map(String::as_str).collect();
+            problems.push(format!("missing select cases: [{}]", missing.join(", ")));
+        }
+        if !self.extra.is_empty() {
+            let extra: Vec<&str> = self.extra.iter().map(String::as_str).collect();
+            problems.push(format!("unknown select cases: [{}]", extra.join(", ")));
+        }
+        problems
+    }
+}
+
+/// Validates that every translation of a select message covers the same
+/// selector keys as the source.
+pub fn validate_selects(resources: &[Resource], source_language: &str) -> Result<(), Error> {
+    let reports = collect_select_issues(resources, source_language);
+    if reports.is_empty() {
+        return Ok(());
+    }
+
+    let mut lines = Vec::new();
+    for r in reports {
+        lines.push(format!(
+            "lang='{}' key='{}': {}",
+            r.language,
+            r.key,
+            r.problems().join("; ")
+        ));
+    }
+    Err(Error::validation_error(lines.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::resource;
+
+    fn select(selector: &str, keys: &[&str]) -> Translation {
+        Translation::Select(
+            Select::new(
+                selector,
+                keys.iter().map(|k| (k.to_string(), format!("{} text", k))),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_matching_selects_pass() {
+        let resources = vec![
+            resource(
+                "en",
+                &[("replied", select("gender", &["female", "male", "other"]))],
+            ),
+            resource(
+                "fr",
+                &[(
+                    "replied",
+                    Translation::Singular(
+                        "{gender, select, male {Il} female {Elle} other {Iel}}".into(),
+                    ),
+                )],
+            ),
+            resource("de", &[("replied", Translation::Empty)]),
+        ];
+        assert!(collect_select_issues(&resources, "en").is_empty());
+        assert!(validate_selects(&resources, "en").is_ok());
+    }
+
+    #[test]
+    fn test_reports_missing_extra_and_selector_mismatch() {
+        let resources = vec![
+            resource(
+                "en",
+                &[(
+                    "replied",
+                    Translation::Singular(
+                        "{gender, select, female {She} male {He} other {They}}".into(),
+                    ),
+                )],
+            ),
+            resource(
+                "fr",
+                &[("replied", select("gender", &["female", "neuter", "other"]))],
+            ),
+            resource(
+                "de",
+                &[("replied", select("sex", &["female", "male", "other"]))],
+            ),
+            resource(
+                "es",
+                &[("replied", Translation::Singular("Respondió".into()))],
+            ),
+        ];
+        let reports = collect_select_issues(&resources, "en");
+        assert_eq!(reports.len(), 3);
+
+        let fr = reports.iter().find(|r| r.language == "fr").unwrap();
+        assert_eq!(fr.missing, BTreeSet::from(["male".to_string()]));
+        assert_eq!(fr.extra, BTreeSet::from(["neuter".to_string()]));
+        assert_eq!(fr.target_selector, None);
+
+        let de = reports.iter().find(|r| r.language == "de").unwrap();
+        assert_eq!(de.target_selector.as_deref(), Some("sex"));
+        assert!(de.missing.is_empty() && de.extra.is_empty());
+
+        let es = reports.iter().find(|r| r.language == "es").unwrap();
+        assert_eq!(es.missing.len(), 3);
+
+        let err = validate_selects(&resources, "en").unwrap_err().to_string();
+        assert!(err.contains("lang='fr' key='replied': missing select cases: [male]"));
+        assert!(err.contains("unknown select cases: [neuter]"));
+        assert!(err.contains("selects on 'sex' instead of 'gender'"));
+    }
+}
@@ -0,0 +1,49 @@
+//! Builders shared by the unit tests of this crate.
+
+use std::collections::HashMap;
+
+use crate::types::{Entry, EntryStatus, Metadata, Resource, Translation};
+
+/// A translated entry.
+pub(crate) fn entry(id: &str, value: Translation) -> Entry {
+    Entry {
+        id: id.to_string(),
+        value,
+        comment: None,
+        status: EntryStatus::Translated,
+        custom: HashMap::new(),
+    }
+}
+
+/// A singular value, or [`Translation::Empty`] for an empty string.
+pub(crate) fn text(value: &str) -> Translation {
+    if value.is_empty() {
+        Translation::Empty
+    } else {
+        Translation::Singular(value.to_string())
+    }
+}
+
+/// A resource without a domain holding `entries` in order.
+pub(crate) fn resource(language: &str, entries: &[(&str, Translation)]) -> Resource {
+    Resource {
+        metadata: Metadata {
+            language: language.to_string(),
+            domain: String::new(),
+            custom: HashMap::new(),
+        },
+        entries: entries
+            .iter()
+            .map(|(id, value)| entry(id, value.clone()))
+            .collect(),
+    }
+}
+
+/// Like [`resource`], with plain text values.
+pub(crate) fn text_resource(language: &str, entries: &[(&str, &str)]) -> Resource {
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|(id, value)| (*id, text(value)))
+        .collect();
+    resource(language, &entries)
+}
@@ -0,0 +1,967 @@
+//! Translation memory: reuse existing translations for new or changed source text.
+//!
+//! A [`TranslationMemory`] indexes `(source text, target language, translation)`
+//! units collected from resources or TMX files and answers fuzzy lookups with a
+//! similarity score from 0 to 100. [`fill_from_memory`] uses it to pre-fill
+//! untranslated entries as [`EntryStatus::NeedsReview`].
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    io::{BufRead, Write},
+    path::Path,
+};
+
+use quick_xml::{
+    Reader, Writer,
+    events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    operations::{lang_base, lang_matches, normalize_lang},
+    provenance::{ProvenanceRecord, set_entry_provenance},
+    types::{Entry, EntryStatus, Metadata, Resource, Translation},
+};
+
+/// `match_strategy` recorded in the provenance of entries filled from memory.
+pub const TM_MATCH_STRATEGY: &str = "translation_memory";
+
+/// One source segment and its translation into one language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TmUnit {
+    pub source_language: String,
+    pub source: String,
+    pub target_language: String,
+    pub target: String,
+    /// Key of the entry the unit was collected from, if known (TMX `tuid`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key: Option<String>,
+}
+
+/// A lookup result; `score` is 100 for identical source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TmMatch<'a> {
+    pub score: u8,
+    pub unit: &'a TmUnit,
+}
+
+/// An in-memory index of translation units.
+#[derive(Debug, Clone, Default)]
+pub struct TranslationMemory {
+    units: Vec<TmUnit>,
+    tokens: Vec<Vec<String>>,
+    seen: HashSet<(String, String, String, String)>,
+}
+
+impl TranslationMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    pub fn units(&self) -> &[TmUnit] {
+        &self.units
+    }
+
+    /// Adds a unit; returns `false` for empty text and duplicates.
+    pub fn add(&mut self, unit: TmUnit) -> bool {
+        if unit.source.trim().is_empty() || unit.target.trim().is_empty() {
+            return false;
+        }
+        let identity = (
+            normalize_lang(&unit.source_language),
+            unit.source.clone(),
+            normalize_lang(&unit.target_language),
+            unit.target.clone(),
+        );
+        if !self.seen.insert(identity) {
+            return false;
+        }
+        self.tokens.push(tokenize(&unit.source));
+        self.units.push(unit);
+        true
+    }
+
+    /// Adds every unit of `other`; returns the number of new units.
+    pub fn extend(&mut self, other: TranslationMemory) -> usize {
+        other
+            .units
+            .into_iter()
+            .filter(|unit| self.add(unit.clone()))
+            .count()
+    }
+
+    /// Collects units from resources by pairing each source entry with the
+    /// entries of the same key in the other languages.
+    ///
+    /// Singular values are paired directly, plural forms and select cases by
+    /// category or case. Empty, stale, and do-not-translate entries are
+    /// skipped. The source language defaults to the `source_language`
+    /// metadata. Returns the number of new units.
+    pub fn add_resources(
+        &mut self,
+        resources: &[Resource],
+        source_language: Option<&str>,
+    ) -> Result<usize, Error> {
+        let source_language = match source_language {
+            Some(language) => language.to_string(),
+            None => resources
+                .iter()
+                .find_map(|res| res.metadata.custom.get("source_language").cloned())
+                .ok_or_else(|| {
+                    Error::InvalidResource(
+                        "Cannot determine the source language of the resources".to_string(),
+                    )
+                })?,
+        };
+        let Some(source) = resources
+            .iter()
+            .find(|res| lang_matches(&res.metadata.language, &source_language))
+        else {
+            return Err(Error::InvalidResource(format!(
+                "Source language '{}' not found",
+                source_language
+            )));
+        };
+        let source_language = source.metadata.language.clone();
+
+        let mut added = 0;
+        for resource in resources {
+            if normalize_lang(&resource.metadata.language) == normalize_lang(&source_language) {
+                continue;
+            }
+            for entry in &resource.entries {
+                if matches!(
+                    entry.status,
+                    EntryStatus::Stale | EntryStatus::DoNotTranslate
+                ) {
+                    continue;
+                }
+                let Some(source_entry) = source.find_entry(&entry.id) else {
+                    continue;
+                };
+                for (source_text, target_text) in paired_texts(&source_entry.value, &entry.value) {
+                    let unit = TmUnit {
+                        source_language: source_language.clone(),
+                        source: source_text.to_string(),
+                        target_language: resource.metadata.language.clone(),
+                        target: target_text.to_string(),
+                        key: Some(entry.id.clone()),
+                    };
+                    if self.add(unit) {
+                        added += 1;
+                    }
+                }
+            }
+        }
+        Ok(added)
+    }
+
+    /// Returns the units translating `source` into `target_language` with a
+    /// score of at least `min_score`, best first.
+    ///
+    /// The target language must match exactly; the source language matches
+    /// on its base language, so `en-US` units serve `en` lookups.
+    pub fn lookup(
+        &self,
+        source_language: &str,
+        source: &str,
+        target_language: &str,
+        min_score: u8,
+    ) -> Vec<TmMatch<'_>> {
+        let query = tokenize(source);
+        let target_language = normalize_lang(target_language);
+        let source_language = normalize_lang(source_language);
+
+        let mut matches: Vec<TmMatch<'_>> = self
+            .units
+            .iter()
+            .zip(&self.tokens)
+            .filter(|(unit, _)| {
+                normalize_lang(&unit.target_language) == target_language
+                    && lang_base(&normalize_lang(&unit.source_language))
+                        == lang_base(&source_language)
+            })
+            .filter(|(_, tokens)| length_bound(query.len(), tokens.len()) >= f64::from(min_score))
+            .filter_map(|(unit, tokens)| {
+                let score = score_tokens(source, &query, &unit.source, tokens);
+                (score >= min_score).then_some(TmMatch { score, unit })
+            })
+            .collect();
+        // Stable sort keeps insertion order among equal scores.
+        matches.sort_by_key(|found| std::cmp::Reverse(found.score));
+        matches
+    }
+
+    /// Returns the best unit for `source`, if any scores at least `min_score`.
+    pub fn best_match(
+        &self,
+        source_language: &str,
+        source: &str,
+        target_language: &str,
+        min_score: u8,
+    ) -> Option<TmMatch<'_>> {
+        self.lookup(source_language, source, target_language, min_score)
+            .into_iter()
+            .next()
+    }
+
+    /// Reads a TMX 1.4 document.
+    ///
+    /// The `<tuv>` whose `xml:lang` matches the header `srclang` is the
+    /// source of each `<tu>` (the first `<tuv>` when `srclang` is `*all*`);
+    /// every other variant becomes a unit. Inline markup inside `<seg>` is
+    /// flattened to its text, which restores native codes such as `<ph>%@</ph>`.
+    pub fn from_tmx_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut xml_reader = Reader::from_reader(reader);
+        xml_reader.config_mut().trim_text(false);
+
+        let mut memory = TranslationMemory::new();
+        let mut buf = Vec::new();
+        let mut saw_root = false;
+        let mut header_source: Option<String> = None;
+
+        loop {
+            match xml_reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.name().as_ref() == b"tmx" => saw_root = true,
+                Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e))
+                    if e.name().as_ref() == b"header" =>
+                {
+                    header_source = optional_attr(e, b"srclang")?
+                        .filter(|lang| !lang.eq_ignore_ascii_case("*all*"));
+                }
+                Ok(Event::Start(ref e)) if e.name().as_ref() == b"tu" => {
+                    let tuid = optional_attr(e, b"tuid")?;
+                    let tu_source = optional_attr(e, b"srclang")?
+                        .filter(|lang| !lang.eq_ignore_ascii_case("*all*"))
+                        .or_else(|| header_source.clone());
+                    let variants = read_tu(&mut xml_reader)?;
+                    memory.add_tu(tuid, tu_source.as_deref(), variants);
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => return Err(Error::XmlParse(e)),
+            }
+            buf.clear();
+        }
+
+        if !saw_root {
+            return Err(Error::InvalidResource(
+                "TMX document is missing the <tmx> root element".to_string(),
+            ));
+        }
+        Ok(memory)
+    }
+
+    /// Reads a TMX file; see [`TranslationMemory::from_tmx_reader`].
+    pub fn read_tmx<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        Self::from_tmx_reader(std::io::BufReader::new(file))
+    }
+
+    /// Writes the memory as TMX 1.4, one `<tu>` per source segment and key.
+    pub fn to_tmx_writer<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut groups: BTreeMap<(String, String, Option<String>), Vec<&TmUnit>> = BTreeMap::new();
+        for unit in &self.units {
+            groups
+                .entry((
+                    unit.source_language.clone(),
+                    unit.source.clone(),
+                    unit.key.clone(),
+                ))
+                .or_default()
+                .push(unit);
+        }
+        let source_languages: HashSet<&str> = self
+            .units
+            .iter()
+            .map(|unit| unit.source_language.as_str())
+            .collect();
+        let header_source = match source_languages.len() {
+            1 => source_languages.into_iter().next().unwrap_or("*all*"),
+            _ => "*all*",
+        };
+
+        let mut xml_writer = Writer::new(writer);
+        xml_writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
+        let mut root = BytesStart::new("tmx");
+        root.push_attribute(("version", "1.4"));
+        xml_writer.write_event(Event::Start(root))?;
+        xml_writer.write_event(Event::Text(BytesText::new("\n  ")))?;
+
+        let mut header = BytesStart::new("header");
+        header.push_attribute(("creationtool", "langcodec"));
+        header.push_attribute(("creationtoolversion", env!("CARGO_PKG_VERSION")));
+        header.push_attribute(("segtype", "sentence"));
+        header.push_attribute(("o-tmf", "langcodec"));
+        header.push_attribute(("adminlang", "en"));
+        header.push_attribute(("srclang", header_source));
+        header.push_attribute(("datatype", "plaintext"));
+        xml_writer.write_event(Event::Empty(header))?;
+        xml_writer.write_event(Event::Text(BytesText::new("\n  ")))?;
+        xml_writer.write_event(Event::Start(BytesStart::new("body")))?;
+
+        for ((source_language, source, key), units) in groups {
+            xml_writer.write_event(Event::Text(BytesText::new("\n    ")))?;
+            let mut tu = BytesStart::new("tu");
+            if let Some(key) = &key {
+                tu.push_attribute(("tuid", key.as_str()));
+            }
+            if header_source == "*all*" {
+                tu.push_attribute(("srclang", source_language.as_str()));
+            }
+            xml_writer.write_event(Event::Start(tu))?;
+            write_tuv(&mut xml_writer, &source_language, &source)?;
+            for unit in units {
+                write_tuv(&mut xml_writer, &unit.target_language, &unit.target)?;
+            }
+            xml_writer.write_event(Event::Text(BytesText::new("\n    ")))?;
+            xml_writer.write_event(Event::End(BytesEnd::new("tu")))?;
+        }
+
+        xml_writer.write_event(Event::Text(BytesText::new("\n  ")))?;
+        xml_writer.write_event(Event::End(BytesEnd::new("body")))?;
+        xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
+        xml_writer.write_event(Event::End(BytesEnd::new("tmx")))?;
+        xml_writer.write_event(Event::Text(BytesText::new("\n")))?;
+        Ok(())
+    }
+
+    /// Writes the memory to a TMX file; see [`TranslationMemory::to_tmx_writer`].
+    pub fn write_tmx<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = std::io::BufWriter::new(file);
+        self.to_tmx_writer(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn add_tu(
+        &mut self,
+        tuid: Option<String>,
+        source_language: Option<&str>,
+        variants: Vec<(String, String)>,
+    ) {
+        let source_index = match source_language {
+            Some(language) => variants
+                .iter()
+                .position(|(lang, _)| lang_matches(lang, language)),
+            None => (!variants.is_empty()).then_some(0),
+        };
+        let Some(source_index) = source_index else {
+            return;
+        };
+        let (source_language, source) = variants[source_index].clone();
+        for (index, (target_language, target)) in variants.into_iter().enumerate() {
+            if index == source_index {
+                continue;
+            }
+            self.add(TmUnit {
+                source_language: source_language.clone(),
+                source: source.clone(),
+                target_language,
+                target,
+                key: tuid.clone(),
+            });
+        }
+    }
+}
+
+/// Returns the similarity of two texts from 0 to 100.
+///
+/// Texts are split into words and punctuation, and the score is the token
+/// edit distance relative to the longer text, where replacing a word costs
+/// its relative character edit distance (so `file` → `files` is a partial
+/// edit). Only identical texts score 100; case and whitespace differences
+/// score 99.
+pub fn similarity(a: &str, b: &str) -> u8 {
+    score_tokens(a, &tokenize(a), b, &tokenize(b))
+}
+
+/// Options for [`fill_from_memory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TmFillOptions {
+    /// Source language; defaults to the `source_language` metadata.
+    pub source_language: Option<String>,
+    /// Languages to fill; defaults to every non-source resource. Missing
+    /// languages are added as new resources.
+    pub target_languages: Vec<String>,
+    /// Minimum similarity score (0-100) for a match to be used.
+    pub min_score: u8,
+}
+
+impl Default for TmFillOptions {
+    fn default() -> Self {
+        Self {
+            source_language: None,
+            target_languages: Vec::new(),
+            min_score: 100,
+        }
+    }
+}
+
+/// One entry filled from memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TmFill {
+    pub language: String,
+    pub key: String,
+    pub score: u8,
+    /// Source text of the unit that was reused.
+    pub matched_source: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub matched_key: Option<String>,
+}
+
+/// Report returned by [`fill_from_memory`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TmFillReport {
+    pub source_language: String,
+    /// Untranslated entries that were looked up.
+    pub candidates: usize,
+    /// Fills with a score of 100.
+    pub exact: usize,
+    pub fuzzy: usize,
+    pub unmatched: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub fills: Vec<TmFill>,
+}
+
+/// Pre-fills untranslated entries from a translation memory.
+///
+/// For every singular source entry, each target language whose entry is
+/// missing or empty gets the best translation scoring at least
+/// `options.min_score`. Filled entries are marked
+/// [`EntryStatus::NeedsReview`] and record the reused unit in their
+/// provenance (`match_strategy = "translation_memory"`). Plural and select
+/// entries are left for translation.
+pub fn fill_from_memory(
+    resources: &mut Vec<Resource>,
+    memory: &TranslationMemory,
+    options: &TmFillOptions,
+) -> Result<TmFillReport, Error> {
+    let source_language = match &options.source_language {
+        Some(language) => language.clone(),
+        None => resources
+            .iter()
+            .find_map(|res| res.metadata.custom.get("source_language").cloned())
+            .ok_or_else(|| {
+                Error::InvalidResource(
+                    "Cannot determine the source language of the resources".to_string(),
+                )
+            })?,
+    };
+    let Some(source) = resources
+        .iter()
+        .find(|res| lang_matches(&res.metadata.language, &source_language))
+        .cloned()
+    else {
+        return Err(Error::InvalidResource(format!(
+            "Source language '{}' not found",
+            source_language
+        )));
+    };
+
+    let target_languages: Vec<String> = if options.target_languages.is_empty() {
+        resources
+            .iter()
+            .map(|res| res.metadata.language.clone())
+            .filter(|language| {
+                normalize_lang(language) != normalize_lang(&source.metadata.language)
+            })
+            .collect()
+    } else {
+        options.target_languages.clone()
+    };
+
+    let mut report = TmFillReport {
+        source_language: source.metadata.language.clone(),
+        ..TmFillReport::default()
+    };
+
+    for language in target_languages {
+        let index = match resources
+            .iter()
+            .position(|res| normalize_lang(&res.metadata.language) == normalize_lang(&language))
+        {
+            Some(index) => index,
+            None => {
+                let mut custom = source.metadata.custom.clone();
+                custom.retain(|key, _| key == "source_language");
+                resources.push(Resource {
+                    metadata: Metadata {
+                        language: language.clone(),
+                        domain: source.metadata.domain.clone(),
+                        custom,
+                    },
+                    entries: Vec::new(),
+                });
+                resources.len() - 1
+            }
+        };
+        let target = &mut resources[index];
+
+        for source_entry in &source.entries {
+            if source_entry.status == EntryStatus::DoNotTranslate {
+                continue;
+            }
+            let Translation::Singular(source_text) = &source_entry.value else {
+                continue;
+            };
+            if source_text.trim().is_empty() {
+                continue;
+            }
+            let existing = target.entries.iter().position(|e| e.id == source_entry.id);
+            if let Some(position) = existing
+                && !is_untranslated(&target.entries[position])
+            {
+                continue;
+            }
+            report.candidates += 1;
+
+            let Some(found) = memory.best_match(
+                &source.metadata.language,
+                source_text,
+                &target.metadata.language,
+                options.min_score,
+            ) else {
+                report.unmatched += 1;
+                continue;
+            };
+
+            let entry = match existing {
+                Some(position) => &mut target.entries[position],
+                None => {
+                    target.entries.push(Entry {
+                        id: source_entry.id.clone(),
+                        value: Translation::Empty,
+                        comment: source_entry.comment.clone(),
+                        status: EntryStatus::New,
+                        custom: Default::default(),
+                    });
+                    target.entries.last_mut().expect("entry was just added")
+                }
+            };
+            entry.value = Translation::Singular(found.unit.target.clone());
+            entry.status = EntryStatus::NeedsReview;
+            set_entry_provenance(
+                entry,
+                &ProvenanceRecord {
+                    source_language: Some(found.unit.source_language.clone()),
+                    match_strategy: Some(TM_MATCH_STRATEGY.to_string()),
+                    source_key: found.unit.key.clone(),
+                    ..ProvenanceRecord::default()
+                },
+            );
+
+            if found.score == 100 {
+                report.exact += 1;
+            } else {
+                report.fuzzy += 1;
+            }
+            report.fills.push(TmFill {
+                language: target.metadata.language.clone(),
+                key: source_entry.id.clone(),
+                score: found.score,
+                matched_source: found.unit.source.clone(),
+                matched_key: found.unit.key.clone(),
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+fn is_untranslated(entry: &Entry) -> bool {
+    if entry.status == EntryStatus::DoNotTranslate {
+        return false;
+    }
+    match &entry.value {
+        Translation::Empty => true,
+        Translation::Singular(text) => text.trim().is_empty(),
+        Translation::Plural(_) | Translation::Select(_) => false,
+    }
+}
+
+fn paired_texts<'a>(source: &'a Translation, target: &'a Translation) -> Vec<(&'a str, &'a str)> {
+    match (source, target) {
+        (Translation::Singular(source), Translation::Singular(target)) => {
+            vec![(source.as_str(), target.as_str())]
+        }
+        (Translation::Plural(source), Translation::Plural(target)) => source
+            .forms
+            .iter()
+            .filter_map(|(category, text)| {
+                target
+                    .forms
+                    .get(category)
+                    .map(|translated| (text.as_str(), translated.as_str()))
+            })
+            .collect(),
+        (Translation::Select(source), Translation::Select(target)) => source
+            .cases
+            .iter()
+            .filter_map(|(case, text)| {
+                target
+                    .cases
+                    .get(case)
+                    .map(|translated| (text.as_str(), translated.as_str()))
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            word.extend(ch.to_lowercase());
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+        if !ch.is_whitespace() {
+            tokens.push(ch.to_string());
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// Highest score reachable between texts of `a` and `b` tokens.
+fn length_bound(a: usize, b: usize) -> f64 {
+    let longer = a.max(b);
+    if longer == 0 {
+        return 100.0;
+    }
+    100.0 * a.min(b) as f64 / longer as f64
+}
+
+fn score_tokens(a: &str, a_tokens: &[String], b: &str, b_tokens: &[String]) -> u8 {
+    if a == b {
+        return 100;
+    }
+    let longer = a_tokens.len().max(b_tokens.len());
+    if longer == 0 {
+        return 99;
+    }
+    let distance = token_distance(a_tokens, b_tokens);
+    let score = (100.0 * (1.0 - distance / longer as f64)).floor();
+    score.clamp(0.0, 99.0) as u8
+}
+
+fn token_distance(a: &[String], b: &[String]) -> f64 {
+    let mut previous: Vec<f64> = (0..=b.len()).map(|j| j as f64).collect();
+    let mut current = vec![0.0; b.len() + 1];
+    for (i, a_token) in a.iter().enumerate() {
+        current[0] = (i + 1) as f64;
+        for (j, b_token) in b.iter().enumerate() {
+            let substitution = previous[j] + substitution_cost(a_token, b_token);
+            current[j + 1] = substitution
+                .min(previous[j + 1] + 1.0)
+                .min(current[j] + 1.0);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+fn substitution_cost(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()] as f64 / a.len().max(b.len()) as f64
+}
+
+fn read_tu<R: BufRead>(xml_reader: &mut Reader<R>) -> Result<Vec<(String, String)>, Error> {
+    let mut buf = Vec::new();
+    let mut variants = Vec::new();
+    let mut language: Option<String> = None;
+
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"tuv" => {
+                language = match optional_attr(e, b"xml:lang")? {
+                    Some(lang) => Some(lang),
+                    None => optional_attr(e, b"lang")?,
+                };
+            }
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"seg" => {
+                let text = read_seg(xml_reader)?;
+                let Some(lang) = language.clone() else {
+                    return Err(Error::InvalidResource(
+                        "TMX <tuv> is missing its xml:lang attribute".to_string(),
+                    ));
+                };
+                variants.push((lang, text));
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"tuv" => language = None,
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"tu" => break,
+            Ok(Event::Eof) => {
+                return Err(Error::InvalidResource(
+                    "Unexpected EOF inside <tu>".to_string(),
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+        buf.clear();
+    }
+
+    Ok(variants)
+}
+
+fn read_seg<R: BufRead>(xml_reader: &mut Reader<R>) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    let mut text = String::new();
+    let mut depth = 0usize;
+
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Text(e)) => {
+                text.push_str(e.unescape().map_err(Error::XmlParse)?.as_ref());
+            }
+            Ok(Event::CData(e)) => {
+                let cdata = std::str::from_utf8(e.as_ref()).map_err(|_| {
+                    Error::InvalidResource("Invalid UTF-8 inside <seg> CDATA section".to_string())
+                })?;
+                text.push_str(cdata);
+            }
+            Ok(Event::Start(_)) => depth += 1,
+            Ok(Event::End(ref e)) if depth == 0 && e.name().as_ref() == b"seg" => break,
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) => {
+                return Err(Error::InvalidResource(
+                    "Unexpected EOF inside <seg>".to_string(),
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlParse(e)),
+        }
+        buf.clear();
+    }
+
+    Ok(text)
+}
+
+fn write_tuv<W: Write>(writer: &mut Writer<W>, language: &str, text: &str) -> Result<(), Error> {
+    writer.write_event(Event::Text(BytesText::new("\n      ")))?;
+    let mut tuv = BytesStart::new("tuv");
+    tuv.push_attribute(("xml:lang", language));
+    writer.write_event(Event::Start(tuv))?;
+    writer.write_event(Event::Start(BytesStart::new("seg")))?;
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    writer.write_event(Event::End(BytesEnd::new("seg")))?;
+    writer.write_event(Event::End(BytesEnd::new("tuv")))?;
+    Ok(())
+}
+
+fn optional_attr(e: &BytesStart<'_>, attr_name: &[u8]) -> Result<Option<String>, Error> {
+    for attr in e.attributes().with_checks(false) {
+        let attr = attr.map_err(|e| Error::DataMismatch(e.to_string()))?;
+        if attr.key.as_ref() == attr_name {
+            return Ok(Some(attr.unescape_value()?.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::text_resource;
+
+    fn memory() -> TranslationMemory {
+        let mut en = text_resource(
+            "en",
+            &[("delete_file", "Delete this file?"), ("save", "Save")],
+        );
+        en.metadata
+            .custom
+            .insert("source_language".to_string(), "en".to_string());
+        let fr = text_resource(
+            "fr",
+            &[
+                ("delete_file", "Supprimer ce fichier ?"),
+                ("save", "Enregistrer"),
+            ],
+        );
+        let mut memory = TranslationMemory::new();
+        memory.add_resources(&[en, fr], None).unwrap();
+        memory
+    }
+
+    #[test]
+    fn test_similarity_scores() {
+        assert_eq!(similarity("Delete this file?", "Delete this file?"), 100);
+        assert_eq!(similarity("Delete this file?", "delete this  file?"), 99);
+        assert_eq!(similarity("Delete this file?", "Delete these files?"), 85);
+        assert!(similarity("Delete this file?", "Open settings") < 50);
+        assert_eq!(similarity("", ""), 100);
+    }
+
+    #[test]
+    fn test_lookup_filters_by_language_and_score() {
+        let memory = memory();
+        assert_eq!(memory.len(), 2);
+
+        let found = memory
+            .best_match("en-US", "Delete this file!", "fr", 75)
+            .unwrap();
+        assert_eq!(found.unit.target, "Supprimer ce fichier ?");
+        assert_eq!(found.unit.key.as_deref(), Some("delete_file"));
+        assert!(found.score >= 75 && found.score < 100);
+
+        assert!(
+            memory
+                .best_match("en", "Delete this file!", "de", 0)
+                .is_none()
+        );
+        assert!(
+            memory
+                .best_match("en", "Delete this file!", "fr", 100)
+                .is_none()
+        );
+        assert_eq!(memory.lookup("en", "Save", "fr", 100)[0].score, 100);
+    }
+
+    #[test]
+    fn test_add_skips_duplicates_and_stale_entries() {
+        let mut memory = memory();
+        let mut stale = text_resource("fr", &[("save", "Sauver")]);
+        stale.entries[0].status = EntryStatus::Stale;
+        let added = memory
+            .add_resources(
+                &[text_resource("en", &[("save", "Save")]), stale],
+                Some("en"),
+            )
+            .unwrap();
+        assert_eq!(added, 0);
+        assert_eq!(memory.extend(self::memory()), 0);
+    }
+
+    #[test]
+    fn test_tmx_round_trip() {
+        let memory = memory();
+        let mut out = Vec::new();
+        memory.to_tmx_writer(&mut out).unwrap();
+        let xml = String::from_utf8(out).unwrap();
+        assert!(xml.contains("srclang=\"en\""));
+        assert!(xml.contains("tuid=\"delete_file\""));
+
+        let parsed = TranslationMemory::from_tmx_reader(xml.as_bytes()).unwrap();
+        assert_eq!(parsed.units(), memory.units());
+    }
+
+    #[test]
+    fn test_tmx_reads_inline_markup_and_all_source() {
+        let xml = r#"<?xml version="1.0"?>
+<tmx version="1.4">
+  <header srclang="*all*" datatype="plaintext"/>
+  <body>
+    <tu>
+      <tuv xml:lang="en-US"><seg>Hello, <ph>%@</ph>!</seg></tuv>
+      <tuv xml:lang="de"><seg>Hallo, <ph>%@</ph>!</seg></tuv>
+    </tu>
+  </body>
+</tmx>"#;
+        let memory = TranslationMemory::from_tmx_reader(xml.as_bytes()).unwrap();
+        assert_eq!(memory.len(), 1);
+        let unit = &memory.units()[0];
+        assert_eq!(unit.source, "Hello, %@!");
+        assert_eq!(unit.target, "Hallo, %@!");
+        assert_eq!(unit.source_language, "en-US");
+    }
+
+    #[test]
+    fn test_fill_from_memory_marks_needs_review() {
+        let memory = memory();
+        let mut resources = vec![
+            text_resource(
+                "en",
+                &[
+                    ("confirm_delete", "Delete this file?"),
+                    ("remove", "Delete these files?"),
+                    ("cancel", "Cancel"),
+                ],
+            ),
+            text_resource("fr", &[("confirm_delete", "")]),
+        ];
+        let report = fill_from_memory(
+            &mut resources,
+            &memory,
+            &TmFillOptions {
+                source_language: Some("en".to_string()),
+                min_score: 80,
+                ..TmFillOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(report.candidates, 3);
+        assert_eq!(report.exact, 1);
+        assert_eq!(report.fuzzy, 1);
+        assert_eq!(report.unmatched, 1);
+        let fr = &resources[1];
+        let filled = fr.find_entry("confirm_delete").unwrap();
+        assert_eq!(
+            filled.value,
+            Translation::Singular("Supprimer ce fichier ?".into())
+        );
+        assert_eq!(filled.status, EntryStatus::NeedsReview);
+        let provenance = crate::entry_provenance(filled).unwrap();
+        assert_eq!(
+            provenance.match_strategy.as_deref(),
+            Some(TM_MATCH_STRATEGY)
+        );
+        assert_eq!(provenance.source_key.as_deref(), Some("delete_file"));
+        assert!(fr.find_entry("remove").is_some());
+        assert!(fr.find_entry("cancel").is_none());
+    }
+
+    #[test]
+    fn test_fill_from_memory_adds_requested_language() {
+        let memory = memory();
+        let mut resources = vec![text_resource("en", &[("save", "Save")])];
+        let report = fill_from_memory(
+            &mut resources,
+            &memory,
+            &TmFillOptions {
+                source_language: Some("en".to_string()),
+                target_languages: vec!["fr".to_string()],
+                ..TmFillOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(report.exact, 1);
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[1].metadata.language, "fr");
+    }
+}
@@ -236,6 +236,9 @@ pub enum Translation {
 
     /// A translation with plural forms.
     Plural(Plural),
+
+    /// A translation that varies by gender or another enumerated argument.
+    Select(Select),
 }
 
 impl Translation {
@@ -256,7 +259,25 @@ impl Translation {
                         map
                     },
                 );
-                Translation::Plural(Plural { id, forms })
+                Translation::Plural(Plural {
+                    id,
+                    forms,
+                    kind: plural.kind,
+                })
+            }
+            Translation::Select(select) => {
+                // Keep only the fallback case
+                let selector = select.selector;
+                let cases = select
+                    .cases
+                    .into_iter()
+                    .find(|(key, _)| key == Select::FALLBACK_KEY)
+                    .map_or_else(BTreeMap::new, |(key, value)| {
+                        let mut map = BTreeMap::new();
+                        map.insert(key, make_plain_translation_string(value));
+                        map
+                    });
+                Translation::Select(Select { selector, cases })
             }
         }
     }
@@ -269,6 +290,10 @@ impl Translation {
                 // Return the plural ID, not the first form
                 plural.id.clone()
             }
+            Translation::Select(select) => select
+                .fallback()
+                .map(|value| make_plain_translation_string(value.to_string()))
+                .unwrap_or_default(),
         }
     }
 }
@@ -279,6 +304,7 @@ impl Display for Translation {
             Translation::Empty => write!(f, "Empty"),
             Translation::Singular(value) => write!(f, "{}", value),
             Translation::Plural(plural) => write!(f, "{}", plural.id), // Displaying only the ID for brevity
+            Translation::Select(select) => write!(f, "{}", select.to_icu()),
         }
     }
 }
@@ -294,6 +320,12 @@ pub struct Plural {
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     #[serde(default)]
     pub forms: BTreeMap<PluralCategory, String>,
+
+    /// Whether the forms are selected by cardinal (“5 files”) or ordinal
+    /// (“5th place”) plural rules.
+    #[serde(skip_serializing_if = "PluralKind::is_cardinal")]
+    #[serde(default)]
+    pub kind: PluralKind,
 }
 
 impl Plural {
@@ -309,9 +341,180 @@ impl Plural {
             Some(Self {
                 id: id.to_string(),
                 forms,
+                kind: PluralKind::Cardinal,
             })
         }
     }
+
+    /// Returns `true` for plurals selected by ordinal rules.
+    pub fn is_ordinal(&self) -> bool {
+        self.kind == PluralKind::Ordinal
+    }
+}
+
+/// Variants of a message chosen by an enumerated argument, such as gender.
+///
+/// This is the model for ICU `select` (`{gender, select, female {…} other {…}}`),
+/// Fluent selectors on string variables, and Android's grammatical inflection.
+/// Case keys are free-form identifiers; `other` is the fallback case.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Select {
+    /// Name of the argument the message is selected on (e.g. `gender`).
+    pub selector: String,
+
+    /// Map from case key → translation.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(default)]
+    pub cases: BTreeMap<String, String>,
+}
+
+impl Select {
+    /// The case used when no other key matches.
+    pub const FALLBACK_KEY: &'static str = "other";
+
+    /// Creates a select message; returns `None` when no cases are given.
+    pub fn new(selector: &str, cases: impl IntoIterator<Item = (String, String)>) -> Option<Self> {
+        let cases: BTreeMap<String, String> = cases.into_iter().collect();
+        if cases.is_empty() {
+            None
+        } else {
+            Some(Self {
+                selector: selector.to_string(),
+                cases,
+            })
+        }
+    }
+
+    /// The case keys, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.cases.keys().map(String::as_str)
+    }
+
+    /// The translation of the `other` case, if present.
+    pub fn fallback(&self) -> Option<&str> {
+        self.cases.get(Self::FALLBACK_KEY).map(String::as_str)
+    }
+
+    /// Renders the message as an ICU `select` expression, with `other` last.
+    ///
+    /// ```rust
+    /// use langcodec::types::Select;
+    ///
+    /// let select = Select::new(
+    ///     "gender",
+    ///     [
+    ///         ("other".to_string(), "They replied".to_string()),
+    ///         ("female".to_string(), "She replied".to_string()),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     select.to_icu(),
+    ///     "{gender, select, female {She replied} other {They replied}}"
+    /// );
+    /// ```
+    pub fn to_icu(&self) -> String {
+        let ordered = self
+            .cases
+            .iter()
+            .filter(|(key, _)| *key != Self::FALLBACK_KEY)
+            .chain(self.cases.get_key_value(Self::FALLBACK_KEY));
+        let cases: Vec<String> = ordered
+            .map(|(key, value)| format!("{} {{{}}}", key, value))
+            .collect();
+        format!("{{{}, select, {}}}", self.selector, cases.join(" "))
+    }
+
+    /// Parses a message that consists of a single ICU `select` expression.
+    ///
+    /// Returns `None` for anything else, including `select` expressions
+    /// embedded in surrounding text.
+    pub fn from_icu(message: &str) -> Option<Self> {
+        let inner = message.trim().strip_prefix('{')?.strip_suffix('}')?;
+        let (selector, rest) = inner.split_once(',')?;
+        let (kind, mut rest) = rest.split_once(',')?;
+        let selector = selector.trim();
+        if kind.trim() != "select" || !is_select_identifier(selector) {
+            return None;
+        }
+
+        let mut cases = BTreeMap::new();
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            let open = rest.find('{')?;
+            let key = rest[..open].trim();
+            if !is_select_identifier(key) {
+                return None;
+            }
+            let mut depth = 0usize;
+            let mut close = None;
+            for (idx, ch) in rest[open..].char_indices() {
+                match ch {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            close = Some(open + idx);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let close = close?;
+            cases.insert(key.to_string(), rest[open + 1..close].to_string());
+            rest = &rest[close + 1..];
+        }
+        Select::new(selector, cases)
+    }
+}
+
+fn is_select_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Which CLDR rule set selects a plural's forms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluralKind {
+    /// Counting quantities (ICU `plural`, Android `<plurals>`).
+    #[default]
+    Cardinal,
+    /// Ranking positions such as “1st”, “2nd”, “3rd” (ICU `selectordinal`).
+    Ordinal,
+}
+
+impl PluralKind {
+    pub fn is_cardinal(&self) -> bool {
+        *self == PluralKind::Cardinal
+    }
+}
+
+impl Display for PluralKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluralKind::Cardinal => write!(f, "cardinal"),
+            PluralKind::Ordinal => write!(f, "ordinal"),
+        }
+    }
+}
+
+impl FromStr for PluralKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cardinal" | "plural" => Ok(PluralKind::Cardinal),
+            "ordinal" | "selectordinal" => Ok(PluralKind::Ordinal),
+            _ => Err(format!("Unknown plural kind: {}", s)),
+        }
+    }
 }
 
 /// Standard CLDR plural forms.
@@ -676,4 +879,47 @@ mod tests {
         assert_eq!(parsed.len(), 1);
         assert_eq!(parsed[0].metadata.language, "en");
     }
+
+    #[test]
+    fn test_plural_kind_serde_and_parse() {
+        let mut plural = Plural::new(
+            "place",
+            vec![(PluralCategory::Other, "%dth".to_string())].into_iter(),
+        )
+        .unwrap();
+        let json = serde_json::to_string(&plural).unwrap();
+        assert!(!json.contains("kind"));
+
+        plural.kind = PluralKind::Ordinal;
+        let json = serde_json::to_string(&plural).unwrap();
+        assert!(json.contains("\"kind\":\"ordinal\""));
+        let back: Plural = serde_json::from_str(&json).unwrap();
+        assert!(back.is_ordinal());
+
+        assert_eq!(
+            "selectordinal".parse::<PluralKind>(),
+            Ok(PluralKind::Ordinal)
+        );
+        assert_eq!("plural".parse::<PluralKind>(), Ok(PluralKind::Cardinal));
+        assert!("range".parse::<PluralKind>().is_err());
+    }
+
+    #[test]
+    fn test_select_icu_round_trip() {
+        let message = "{gender, select, female {Elle a {count} messages} male {Il a répondu} other {Réponse}}";
+        let select = Select::from_icu(message).unwrap();
+        assert_eq!(select.selector, "gender");
+        assert_eq!(
+            select.keys().collect::<Vec<_>>(),
+            vec!["female", "male", "other"]
+        );
+        assert_eq!(select.cases["female"], "Elle a {count} messages");
+        assert_eq!(select.fallback(), Some("Réponse"));
+        assert_eq!(select.to_icu(), message);
+
+        assert!(Select::from_icu("Hello {name}").is_none());
+        assert!(Select::from_icu("{count, plural, one {#} other {#}}").is_none());
+        assert!(Select::from_icu("{gender, select, female {unbalanced}").is_none());
+        assert!(Select::new("gender", Vec::new()).is_none());
+    }
 }
@@ -158,7 +158,7 @@ fn parse_edge_case_corpora_table_driven() {
                 expected.push(ExpectedValue {
                     language: "en",
                     key: "apostrophe_text",
-                    value: "Don\\'t stop",
+                    value: "Don't stop",
                 });
                 expected
             },
@@ -1,6 +1,6 @@
 use langcodec::{
     Codec,
-    normalize::{KeyStyle, NormalizeOptions},
+    normalize::{KeyRename, KeyStyle, NormalizeOptions, NormalizeRule, UnicodeForm},
     types::{Entry, EntryStatus, Metadata, Resource, Translation},
 };
 use std::collections::HashMap;
@@ -71,7 +71,7 @@ fn normalize_applies_placeholder_normalization_by_default() {
         _ => unreachable!("test fixture uses singular translation"),
     };
 
-    assert_eq!(value, "%s has %d items");
+    assert_eq!(value, "%s has %ld items");
     assert!(report.changed);
 }
 
@@ -106,6 +106,7 @@ fn normalize_errors_on_key_style_collision_after_transform() {
     let options = NormalizeOptions {
         normalize_placeholders: false,
         key_style: KeyStyle::Snake,
+        ..NormalizeOptions::default()
     };
 
     let error = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap_err();
@@ -137,6 +138,7 @@ fn normalize_preserves_unicode_letters_in_key_style_transform() {
     let options = NormalizeOptions {
         normalize_placeholders: false,
         key_style: KeyStyle::Snake,
+        ..NormalizeOptions::default()
     };
 
     let report = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
@@ -204,9 +206,206 @@ fn normalize_keeps_codec_unchanged_when_key_style_collision_occurs() {
     let options = NormalizeOptions {
         normalize_placeholders: false,
         key_style: KeyStyle::Snake,
+        ..NormalizeOptions::default()
     };
 
     let error = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap_err();
     assert!(error.to_string().contains("collision"));
     assert_eq!(codec.resources, original_resources);
 }
+
+fn single_resource(language: &str, values: &[(&str, &str)]) -> Codec {
+    Codec {
+        resources: vec![Resource {
+            metadata: Metadata {
+                language: language.to_string(),
+                domain: "Localizable".to_string(),
+                custom: HashMap::new(),
+            },
+            entries: values
+                .iter()
+                .map(|(id, value)| Entry {
+                    id: id.to_string(),
+                    value: Translation::Singular(value.to_string()),
+                    comment: None,
+                    status: EntryStatus::Translated,
+                    custom: HashMap::new(),
+                })
+                .collect(),
+        }],
+    }
+}
+
+fn value_of(codec: &Codec, id: &str) -> String {
+    match &codec.resources[0].find_entry(id).unwrap().value {
+        Translation::Singular(value) => value.clone(),
+        other => panic!("unexpected value {other:?}"),
+    }
+}
+
+#[test]
+fn normalize_typography_rules_are_opt_in_and_reported_per_key() {
+    let values = [
+        ("confirm", "Voulez-vous \"supprimer\" ce fichier ?"),
+        ("loading", "Chargement...  \r\nPatientez"),
+        ("cafe", "Cafe\u{301} « ouvert »"),
+        ("plain", "Bonjour"),
+    ];
+
+    let mut untouched = single_resource("fr", &values);
+    let report =
+        langcodec::normalize::normalize_codec(&mut untouched, &Default::default()).unwrap();
+    assert!(report.changes.is_empty());
+    assert_eq!(
+        value_of(&untouched, "confirm"),
+        "Voulez-vous \"supprimer\" ce fichier ?"
+    );
+
+    let mut codec = single_resource("fr", &values);
+    let options = NormalizeOptions {
+        unicode_form: Some(UnicodeForm::Nfc),
+        french_spacing: true,
+        smart_quotes: true,
+        ellipsis: true,
+        trim_trailing_whitespace: true,
+        line_endings: true,
+        ..NormalizeOptions::default()
+    };
+    let report = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
+
+    assert_eq!(
+        value_of(&codec, "confirm"),
+        "Voulez-vous «\u{a0}supprimer\u{a0}» ce fichier\u{a0}?"
+    );
+    assert_eq!(value_of(&codec, "loading"), "Chargement…\nPatientez");
+    assert_eq!(value_of(&codec, "cafe"), "Café «\u{a0}ouvert\u{a0}»");
+    assert_eq!(value_of(&codec, "plain"), "Bonjour");
+
+    let changes: Vec<(&str, NormalizeRule)> = report
+        .changes
+        .iter()
+        .map(|change| (change.key.as_str(), change.rule))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            ("confirm", NormalizeRule::Quotes),
+            ("confirm", NormalizeRule::FrenchSpacing),
+            ("loading", NormalizeRule::LineEndings),
+            ("loading", NormalizeRule::TrailingWhitespace),
+            ("loading", NormalizeRule::Ellipsis),
+            ("cafe", NormalizeRule::UnicodeForm),
+            ("cafe", NormalizeRule::FrenchSpacing),
+        ]
+    );
+    assert!(report.changes.iter().all(|change| change.language == "fr"));
+
+    // A second run has nothing left to do.
+    let report = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
+    assert!(!report.changed);
+}
+
+#[test]
+fn normalize_quotes_follow_the_language() {
+    let options = NormalizeOptions {
+        smart_quotes: true,
+        ..NormalizeOptions::default()
+    };
+    for (language, expected) in [
+        ("de", "„Speichern“ <a href=\"x\">tippen</a>"),
+        ("ja", "「Speichern」 <a href=\"x\">tippen</a>"),
+        ("en", "“Speichern” <a href=\"x\">tippen</a>"),
+    ] {
+        let mut codec = single_resource(
+            language,
+            &[("save", "\"Speichern\" <a href=\"x\">tippen</a>")],
+        );
+        langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
+        assert_eq!(value_of(&codec, "save"), expected, "{language}");
+    }
+
+    // Escaped quotes, as kept in `.strings` values, lose their backslash.
+    let mut codec = single_resource("en", &[("save", "Tap \\\"Save\\\"")]);
+    langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
+    assert_eq!(value_of(&codec, "save"), "Tap “Save”");
+
+    // Unpaired quotes are ambiguous and left alone.
+    let mut codec = single_resource("de", &[("inch", "12\" screen")]);
+    let report = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap();
+    assert!(report.changes.is_empty());
+
+    let nfkc = NormalizeOptions {
+        unicode_form: Some(UnicodeForm::Nfkc),
+        ..NormalizeOptions::default()
+    };
+    let mut codec = single_resource("en", &[("file", "ﬁle １")]);
+    langcodec::normalize::normalize_codec(&mut codec, &nfkc).unwrap();
+    assert_eq!(value_of(&codec, "file"), "file 1");
+}
+
+#[test]
+fn preview_reports_values_renames_and_collisions() {
+    let codec = single_resource(
+        "en",
+        &[
+            ("welcomeTitle", "Welcome..."),
+            ("welcome-title", "Hi"),
+            ("welcome_title", "Hello"),
+            ("signIn", "Sign in"),
+            ("bye", "Bye..."),
+        ],
+    );
+    let options = NormalizeOptions {
+        key_style: KeyStyle::Snake,
+        ellipsis: true,
+        ..NormalizeOptions::default()
+    };
+
+    let report = langcodec::normalize::preview_normalize(&codec, &options);
+    assert_eq!(report.collisions.len(), 1);
+    let collision = &report.collisions[0];
+    assert_eq!(
+        collision.keys,
+        vec!["welcomeTitle", "welcome-title", "welcome_title"]
+    );
+    assert_eq!(collision.normalized, "welcome_title");
+    assert!(collision.to_string().contains(
+        "'welcomeTitle', 'welcome-title' and 'welcome_title' all normalize to 'welcome_title'"
+    ));
+
+    // Colliding keys keep their ids; the others are renamed.
+    assert_eq!(
+        report.renamed_keys,
+        vec![KeyRename {
+            language: "en".to_string(),
+            from: "signIn".to_string(),
+            to: "sign_in".to_string(),
+        }]
+    );
+    let values: Vec<(&str, &str, &str)> = report
+        .values
+        .iter()
+        .map(|change| {
+            (
+                change.key.as_str(),
+                change.before.as_str(),
+                change.after.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            ("welcomeTitle", "Welcome...", "Welcome…"),
+            ("bye", "Bye...", "Bye…")
+        ]
+    );
+    assert_eq!(report.values[0].rules, vec![NormalizeRule::Ellipsis]);
+    assert_eq!(report.reordered, vec!["en"]);
+
+    // The preview leaves the codec alone, and normalizing fails on the collision.
+    assert_eq!(codec.resources[0].entries[0].id, "welcomeTitle");
+    let mut codec = codec;
+    let error = langcodec::normalize::normalize_codec(&mut codec, &options).unwrap_err();
+    assert!(error.to_string().contains("key-style collision"));
+}